@@ -155,6 +155,9 @@ impl TypeNameCode {
     pub fn is_value(&self) -> bool {
         self.type_name == Some(TypeName::Value)
     }
+    pub fn is_i32(&self) -> bool {
+        self.type_name == Some(TypeName::Int32)
+    }
     pub fn is_date_time(&self) -> bool {
         self.type_name == Some(TypeName::DateTime)
     }
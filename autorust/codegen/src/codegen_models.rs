@@ -571,6 +571,34 @@ fn create_enum(
         quote! {}
     };
 
+    // Top-level enums may be used as request parameters, which are formatted with `Display`.
+    let display_code = if namespace.is_none() {
+        let mut display_fields = TokenStream::new();
+        for enum_value in &enum_values {
+            let value = &enum_value.value;
+            let nm = value.to_camel_case_ident()?;
+            display_fields.extend(quote! {
+                Self::#nm => f.write_str(#value),
+            });
+        }
+        if property.is_model_as_string_enum() {
+            display_fields.extend(quote! {
+                Self::UnknownValue(s) => f.write_str(s.as_str()),
+            });
+        }
+        quote! {
+            impl std::fmt::Display for #id {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        #display_fields
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let doc_comment = match &property.schema.common.description {
         Some(description) => {
             quote! { #[doc = #description] }
@@ -587,6 +615,7 @@ fn create_enum(
         }
         #custom_serde_code
         #default_code
+        #display_code
     };
     let type_name = TypeNameCode::from(vec![namespace, Some(id)]);
 
@@ -2,12 +2,13 @@ use crate::{
     codegen::{parse_path_params, PARAM_RE},
     codegen::TypeNameCode,
     identifier::{parse_ident, SnakeCaseIdent},
-    spec::{get_type_name_for_schema_ref, TypeName, WebOperation, WebParameter, WebVerb},
+    spec::{get_schema_array_items, get_type_name_for_schema_ref, TypeName, WebOperation, WebParameter, WebVerb},
     status_codes::get_status_code_ident,
-    CodeGen, Error, ErrorKind,
+    CodeGen, Error, ErrorKind, ResultExt,
 };
 use crate::{content_type, Result};
-use autorust_openapi::{CollectionFormat, Header, ParameterType, Response, StatusCode};
+use autorust_openapi::{CollectionFormat, DataType, Header, ParameterType, ReferenceOr, Response, StatusCode};
+use camino::Utf8Path;
 use heck::ToPascalCase;
 use heck::ToSnakeCase;
 use indexmap::IndexMap;
@@ -17,6 +18,11 @@ use std::collections::{BTreeSet, HashSet};
 
 pub const API_VERSION: &str = "api-version";
 pub const X_MS_VERSION: &str = "x-ms-version";
+pub const CONTINUATION_TOKEN: &str = "continuationToken";
+pub const ORGANIZATION: &str = "organization";
+pub const PROJECT: &str = "project";
+pub const TOP: &str = "top";
+pub const SKIP: &str = "skip";
 
 fn error_variant(operation: &WebOperationGen) -> Result<Ident> {
     let function = operation.rust_function_name().to_pascal_case();
@@ -38,8 +44,48 @@ fn error_fqn(operation: &WebOperationGen) -> Result<TokenStream> {
     }
 }
 
-pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<TokenStream> {
+/// Creates the service `Client`.
+///
+/// `send_json()`, used by hand-written operations, is only generated for modules that have
+/// extension modules (see [`create_extension_modules`]).
+pub fn create_client(modules: &[String], endpoint: Option<&str>, has_extension_modules: bool) -> Result<TokenStream> {
+    let send_json = if has_extension_modules {
+        quote! {
+            #[doc = "Sends a request for an operation that has no generated request builder, and deserializes the JSON response."]
+            #[doc = ""]
+            #[doc = "`path` is relative to the endpoint, and `api_version` is the API version of the operation, which is replaced by the client's API version override (if any)."]
+            #[doc = "`prepare` sets any other query parameters, headers and body of the request."]
+            pub(crate) async fn send_json<T: serde::de::DeserializeOwned>(
+                &self,
+                method: azure_core::Method,
+                path: &str,
+                api_version: &str,
+                prepare: impl FnOnce(&mut azure_core::Request) -> azure_core::Result<()>,
+            ) -> azure_core::Result<T> {
+                let url = azure_core::Url::parse(&format!("{}/{}", self.endpoint(), path))?;
+                let mut req = azure_core::Request::new(url, method);
+                if let Some(auth_header) = self.token_credential().http_authorization_header(&self.scopes).await? {
+                    req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                }
+                req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, &self.api_version(api_version));
+                prepare(&mut req)?;
+                let rsp = self.send(&mut req).await?;
+                let bytes = rsp.into_body().collect().await?;
+                serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!("Failed to deserialize response:\n{}", String::from_utf8_lossy(&bytes)),
+                    )
+                })
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let mut clients = TokenStream::new();
+    let mut organization_clients = TokenStream::new();
+    let mut project_clients = TokenStream::new();
     for md in modules {
         let client = format!("{md}_client").to_snake_case_ident()?;
         let md = md.to_snake_case_ident()?;
@@ -48,6 +94,23 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                 #md::Client(self.clone())
             }
         });
+        organization_clients.extend(quote! {
+            pub fn #client(&self) -> #md::OrganizationClient {
+                #md::OrganizationClient {
+                    client: self.client.#client(),
+                    organization: self.organization.clone(),
+                }
+            }
+        });
+        project_clients.extend(quote! {
+            pub fn #client(&self) -> #md::ProjectClient {
+                #md::ProjectClient {
+                    client: self.client.#client(),
+                    organization: self.organization.clone(),
+                    project: self.project.clone(),
+                }
+            }
+        });
     }
 
     let public_cloud = quote! {
@@ -74,6 +137,7 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
             credential: crate::Credential,
             scopes: Vec<String>,
             pipeline: azure_core::Pipeline,
+            api_version: Option<String>,
         }
 
         #[derive(Clone)]
@@ -82,6 +146,8 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
             endpoint: Option<String>,
             scopes: Option<Vec<String>>,
             options: azure_core::ClientOptions,
+            deployment: crate::Deployment,
+            api_version: Option<String>,
         }
 
         #default_endpoint_code
@@ -95,6 +161,8 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                     endpoint: None,
                     scopes: None,
                     options: azure_core::ClientOptions::default(),
+                    deployment: crate::Deployment::default(),
+                    api_version: None,
                 }
             }
 
@@ -105,6 +173,25 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                 self
             }
 
+            #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+            #[doc = ""]
+            #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+            #[must_use]
+            pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+                self.deployment = deployment;
+                self
+            }
+
+            #[doc = "Set the API version, overriding the API version of each operation."]
+            #[doc = ""]
+            #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+            #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+            #[must_use]
+            pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+                self.api_version = Some(api_version.into());
+                self
+            }
+
             #[doc = "Set the scopes."]
             #[must_use]
             pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -136,9 +223,11 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
             #[doc = "Convert the builder into a `Client` instance."]
             #[must_use]
             pub fn build(self) -> Client {
-                let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+                let endpoint = self.endpoint.unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
                 let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-                Client::new(endpoint, self.credential, scopes, self.options)
+                let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+                client.api_version = self.api_version;
+                client
             }
         }
 
@@ -153,17 +242,33 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
             pub(crate) fn scopes(&self) -> Vec<&str> {
                 self.scopes.iter().map(String::as_str).collect()
             }
+            pub(crate) fn api_version(&self, default: &str) -> String {
+                crate::deployment::api_version(self.api_version.as_deref(), default)
+            }
             pub(crate) async fn send(&self, request: &mut azure_core::Request) -> azure_core::Result<azure_core::Response> {
                 let mut context = azure_core::Context::default();
                 self.pipeline.send(&mut context, request).await
             }
 
+            #send_json
+
             #[doc = "Create a new `ClientBuilder`."]
             #[must_use]
             pub fn builder(credential: crate::Credential) -> ClientBuilder {
                 ClientBuilder::new(credential)
             }
 
+            #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+            pub(crate) fn with_pipeline(endpoint: String, credential: crate::Credential, scopes: Vec<String>, pipeline: azure_core::Pipeline, api_version: Option<String>) -> Self {
+                Self {
+                    endpoint,
+                    credential,
+                    scopes,
+                    pipeline,
+                    api_version,
+                }
+            }
+
             #[doc = "Create a new `Client`."]
             #[must_use]
             pub fn new(endpoint: impl Into<String>, credential: crate::Credential, scopes: Vec<String>, options: azure_core::ClientOptions) -> Self {
@@ -172,23 +277,117 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                     option_env!("CARGO_PKG_NAME"),
                     option_env!("CARGO_PKG_VERSION"),
                     options,
-                    Vec::new(),
-                    Vec::new(),
+                    vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+                    vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
                 );
                 Self {
                     endpoint,
                     credential,
                     scopes,
                     pipeline,
+                    api_version: None,
+                }
+            }
+
+            #[doc = "Returns a client scoped to an organization."]
+            #[doc = ""]
+            #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+            pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+                OrganizationClient {
+                    client: self.clone(),
+                    organization: organization.into(),
+                }
+            }
+
+            #[doc = "Returns a client scoped to a project."]
+            #[doc = ""]
+            #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+            pub fn for_project(&self, organization: impl Into<String>, project: impl Into<String>) -> ProjectClient {
+                ProjectClient {
+                    client: self.clone(),
+                    organization: organization.into(),
+                    project: project.into(),
                 }
             }
 
             #clients
         }
+
+        #[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+        #[derive(Clone)]
+        pub struct OrganizationClient {
+            client: Client,
+            organization: String,
+        }
+
+        impl OrganizationClient {
+            #[doc = "The organization of the scoped client."]
+            pub fn organization(&self) -> &str {
+                &self.organization
+            }
+
+            #organization_clients
+        }
+
+        #[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+        #[derive(Clone)]
+        pub struct ProjectClient {
+            client: Client,
+            organization: String,
+            project: String,
+        }
+
+        impl ProjectClient {
+            #[doc = "The organization of the scoped client."]
+            pub fn organization(&self) -> &str {
+                &self.organization
+            }
+
+            #[doc = "The project of the scoped client."]
+            pub fn project(&self) -> &str {
+                &self.project
+            }
+
+            #project_clients
+        }
     });
     Ok(code)
 }
 
+/// Declares the hand-written modules that extend the generated code.
+///
+/// Any `.rs` file in the output folder other than the generated `mod.rs` and `models.rs`
+/// is an extension module, whose public items are re-exported from the generated module.
+fn create_extension_modules(output_folder: &Utf8Path) -> Result<TokenStream> {
+    let mut names = Vec::new();
+    if output_folder.exists() {
+        let entries = output_folder
+            .read_dir_utf8()
+            .with_context(ErrorKind::Io, || format!("read directory {output_folder}"))?;
+        for entry in entries {
+            let entry = entry.with_context(ErrorKind::Io, || format!("read directory {output_folder}"))?;
+            let path = entry.path();
+            if path.extension() == Some("rs") {
+                if let Some(name) = path.file_stem() {
+                    if name != "mod" && name != "models" {
+                        names.push(name.to_owned());
+                    }
+                }
+            }
+        }
+    }
+    names.sort();
+    let mut code = TokenStream::new();
+    for name in names {
+        let name = parse_ident(&name)?;
+        code.extend(quote! {
+            mod #name;
+            pub use #name::*;
+        });
+    }
+    Ok(code)
+}
+
 pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
     let mut file = TokenStream::new();
     file.extend(quote! {
@@ -199,13 +398,16 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
         #![allow(clippy::redundant_clone)]
         pub mod models;
     });
+    let extension_modules = create_extension_modules(cg.output_folder())?;
+    let has_extension_modules = !extension_modules.is_empty();
+    file.extend(extension_modules);
     let mut operations_code: IndexMap<Option<String>, OperationCode> = IndexMap::new();
     // println!("input_files {:?}", cg.input_files());
 
     let operations: Vec<_> = cg.spec.operations()?.into_iter().map(WebOperationGen).collect();
     let module_names: BTreeSet<_> = operations.iter().flat_map(|op| op.rust_module_name()).collect();
     let module_names: Vec<_> = module_names.into_iter().collect();
-    file.extend(create_client(&module_names, cg.spec.endpoint().as_deref())?);
+    file.extend(create_client(&module_names, cg.spec.endpoint().as_deref(), has_extension_modules)?);
 
     let mut errors = TokenStream::new();
     for operation in &operations {
@@ -242,8 +444,14 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
             module_code,
         } = operation_code;
         let mut builders = TokenStream::new();
-        for builder in client_functions {
-            builders.extend(builder.into_token_stream());
+        let mut organization_builders = TokenStream::new();
+        let mut project_builders = TokenStream::new();
+        for builder in &client_functions {
+            builders.extend(builder.to_token_stream());
+            if builder.is_scoped() {
+                organization_builders.extend(builder.scoped(ClientScope::Organization).into_token_stream());
+                project_builders.extend(builder.scoped(ClientScope::Project).into_token_stream());
+            }
         }
         match module_name {
             Some(module_name) => {
@@ -255,6 +463,25 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
                         impl Client {
                             #builders
                         }
+                        #[doc = "A [`Client`] scoped to an organization."]
+                        #[allow(dead_code)]
+                        pub struct OrganizationClient {
+                            pub(crate) client: Client,
+                            pub(crate) organization: String,
+                        }
+                        impl OrganizationClient {
+                            #organization_builders
+                        }
+                        #[doc = "A [`Client`] scoped to a project."]
+                        #[allow(dead_code)]
+                        pub struct ProjectClient {
+                            pub(crate) client: Client,
+                            pub(crate) organization: String,
+                            pub(crate) project: String,
+                        }
+                        impl ProjectClient {
+                            #project_builders
+                        }
                         #(#module_code)*
                     }
                 });
@@ -434,6 +661,19 @@ impl ToTokens for SetRequestParamsCode {
                                     }
                                 }
                             ),
+                            CollectionFormat::Csv if param.enum_items => Some(quote! {
+                                if !#param_name_var.is_empty() {
+                                    let mut values: Vec<String> = Vec::new();
+                                    for value in #param_name_var {
+                                        let value = value.to_string();
+                                        if !values.contains(&value) {
+                                            values.push(value);
+                                        }
+                                    }
+                                    let value = values.join(",");
+                                    req.url_mut().query_pairs_mut().append_pair(#param_name, &value);
+                                }
+                            }),
                             CollectionFormat::Csv | // TODO #71
                             CollectionFormat::Ssv |
                             CollectionFormat::Tsv |
@@ -548,7 +788,7 @@ impl ToTokens for SetRequestParamsCode {
 
 // Create code for the web operation
 fn create_operation_code(cg: &CodeGen, operation: &WebOperationGen) -> Result<OperationCode> {
-    let parameters = &FunctionParams::new(operation)?;
+    let parameters = &FunctionParams::new(cg, operation)?;
 
     let verb = operation.0.verb.clone();
     let auth = AuthCode {};
@@ -575,8 +815,18 @@ fn create_operation_code(cg: &CodeGen, operation: &WebOperationGen) -> Result<Op
     let request_builder_setters_code = RequestBuilderSettersCode::new(parameters);
     let response_code = ResponseCode::new(operation, produces)?;
     let long_running_operation = operation.0.long_running_operation;
-    let request_builder_send_code =
-        RequestBuilderSendCode::new(new_request_code, request_builder, response_code.clone(), long_running_operation)?;
+    let list_item_type = if parameters.skip_top_params().is_some() {
+        get_list_item_type(cg, operation)?
+    } else {
+        None
+    };
+    let request_builder_send_code = RequestBuilderSendCode::new(
+        new_request_code,
+        request_builder,
+        response_code.clone(),
+        long_running_operation,
+        list_item_type,
+    )?;
     let request_builder_intofuture_code =
         RequestBuilderIntoFutureCode::new(response_code.clone())?;
 
@@ -627,7 +877,7 @@ impl ToTokens for SetRequestCode {
         if self.has_param_api_version {
             let api_version = &self.api_version;
             tokens.extend(quote! {
-                req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, #api_version);
+                req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, &this.client.api_version(#api_version));
             });
         }
         if self.has_param_x_ms_version {
@@ -666,6 +916,8 @@ struct ResponseCode {
     status_responses: Vec<StatusResponseCode>,
     pageable: Option<Pageable>,
     produces: String,
+    /// The operation may produce a non-JSON body, e.g. a file, zip or log download.
+    produces_raw_body: bool,
     headers: HeadersCode,
 }
 
@@ -812,10 +1064,12 @@ impl ResponseCode {
             .collect::<IndexMap<_, _>>()
             .into_values()
             .collect::<Result<Vec<_>>>()?;
+        let produces_raw_body = operation.0.produces.iter().any(|content_type| !content_type::is_json(content_type));
         Ok(Self {
             status_responses,
             pageable: operation.pageable(),
             produces,
+            produces_raw_body,
             headers: HeadersCode::new(headers)?,
         })
     }
@@ -843,6 +1097,14 @@ impl ResponseCode {
     fn produces_xml(&self) -> bool {
         self.produces == content_type::APPLICATION_XML
     }
+
+    /// The response body may be binary or text rather than JSON.
+    fn has_raw_body(&self) -> bool {
+        match self.response_type() {
+            Some(response_type) => self.produces_raw_body || response_type.is_string() || response_type.is_bytes(),
+            None => false,
+        }
+    }
 }
 
 impl ToTokens for ResponseCode {
@@ -876,6 +1138,32 @@ impl ToTokens for ResponseCode {
                 quote! {}
             };
 
+            let raw_body_fns = if self.has_raw_body() {
+                quote! {
+                    #[doc = "Returns the raw response body."]
+                    pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                        self.0.into_body().collect().await
+                    }
+                    #[doc = "Returns the response body as UTF-8 text."]
+                    pub async fn into_text(self) -> azure_core::Result<String> {
+                        crate::body::into_text(self.0.into_body()).await
+                    }
+                    #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+                    pub fn into_stream(self) -> azure_core::ResponseBody {
+                        self.0.into_body()
+                    }
+                    #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+                    pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+                    where
+                        W: futures::io::AsyncWrite + Unpin + ?Sized,
+                    {
+                        crate::body::write_to(self.0.into_body(), writer).await
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
             tokens.extend(quote! {
                 impl Response {
                     pub async fn into_body(self) -> azure_core::Result<#response_type> {
@@ -890,6 +1178,7 @@ impl ToTokens for ResponseCode {
                         &self.0
                     }
                     #headers_fn
+                    #raw_body_fns
                 }
                 impl From<Response> for azure_core::Response {
                     fn from(rsp: Response) -> Self {
@@ -914,6 +1203,7 @@ struct RequestBuilderSendCode {
     response_code: ResponseCode,
     url_args: Vec<Ident>,
     long_running_operation: bool,
+    list_item_type: Option<TypeNameCode>,
 }
 
 impl RequestBuilderSendCode {
@@ -922,6 +1212,7 @@ impl RequestBuilderSendCode {
         request_builder: SetRequestCode,
         response_code: ResponseCode,
         long_running_operation: bool,
+        list_item_type: Option<TypeNameCode>,
     ) -> Result<Self> {
         let params = parse_path_params(&new_request_code.path);
         let url_args: Result<Vec<_>> = params.iter().map(|s| s.to_snake_case_ident()).collect();
@@ -932,6 +1223,7 @@ impl RequestBuilderSendCode {
             response_code,
             url_args,
             long_running_operation,
+            list_item_type,
         })
     }
 }
@@ -1006,7 +1298,7 @@ impl ToTokens for RequestBuilderSendCode {
                     stream_api_version = quote! {
                         let has_api_version_already = req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
                         if !has_api_version_already {
-                            req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, #api_version);
+                            req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, &this.client.api_version(#api_version));
                         }
                     };
                 }
@@ -1066,6 +1358,85 @@ impl ToTokens for RequestBuilderSendCode {
             send_future
         };
         tokens.extend(fut);
+        tokens.extend(self.continuation_stream());
+        tokens.extend(self.skip_top_stream());
+    }
+}
+
+impl RequestBuilderSendCode {
+    /// Creates an `into_stream` function for operations that return results in pages,
+    /// where the service returns a continuation token to fetch the next page.
+    fn continuation_stream(&self) -> TokenStream {
+        if self.response_code.pageable.is_some() || self.response_code.produces_xml() {
+            return quote! {};
+        }
+        let param = match self.request_builder.parameters.continuation_token_param() {
+            Some(param) => param,
+            None => return quote! {},
+        };
+        let response_type = match self.response_code.response_type() {
+            Some(response_type) if !response_type.is_bytes() => response_type,
+            _ => return quote! {},
+        };
+        let variable_name = &param.variable_name;
+        let value = if param.is_string() {
+            quote! { continuation }
+        } else if param.type_name.is_date_time() {
+            quote! { crate::date_time::parse_date_time(&continuation)? }
+        } else {
+            quote! { crate::paging::parse_continuation_token(&continuation)? }
+        };
+        quote! {
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<#response_type>> {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.#variable_name = Some(#value);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
+        }
+    }
+
+    /// Creates `pages` and `items` functions for list operations that are paged using
+    /// the `$top` and `$skip` query parameters.
+    fn skip_top_stream(&self) -> TokenStream {
+        if self.response_code.pageable.is_some() || self.response_code.produces_xml() {
+            return quote! {};
+        }
+        let (top, skip) = match self.request_builder.parameters.skip_top_params() {
+            Some(params) => params,
+            None => return quote! {},
+        };
+        let (response_type, item_type) = match (self.response_code.response_type(), &self.list_item_type) {
+            (Some(response_type), Some(item_type)) => (response_type, item_type),
+            _ => return quote! {},
+        };
+        let top = &top.variable_name;
+        let skip = &skip.variable_name;
+        quote! {
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(self, page_size: i32) -> futures::stream::BoxStream<'static, azure_core::Result<#response_type>> {
+                let skip = self.#skip.unwrap_or(0);
+                let make_request = move |skip: i32| std::future::IntoFuture::into_future(self.clone().#top(page_size).#skip(skip));
+                crate::paging::skip_top_stream(make_request, skip, |page: &#response_type| page.value.len())
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(self, page_size: i32) -> futures::stream::BoxStream<'static, azure_core::Result<#item_type>> {
+                crate::paging::items_stream(self.pages(page_size), |page: #response_type| page.value)
+            }
+        }
     }
 }
 
@@ -1174,6 +1545,8 @@ struct FunctionParam {
     type_name: TypeNameCode,
     kind: ParamKind,
     collection_format: CollectionFormat,
+    /// Set if the parameter is an array of enum values.
+    enum_items: bool,
 }
 impl FunctionParam {
     fn is_vec(&self) -> bool {
@@ -1194,7 +1567,7 @@ struct FunctionParams {
     has_x_ms_version: bool,
 }
 impl FunctionParams {
-    fn new(operation: &WebOperationGen) -> Result<Self> {
+    fn new(cg: &CodeGen, operation: &WebOperationGen) -> Result<Self> {
         let parameters = operation.0.parameters();
         let has_api_version = parameters.iter().any(|p| p.name() == API_VERSION);
         let has_x_ms_version = parameters.iter().any(|p| p.name() == X_MS_VERSION);
@@ -1216,6 +1589,7 @@ impl FunctionParams {
                 .optional(!param.required());
             let kind = ParamKind::from(param.type_());
             let collection_format = param.collection_format().clone();
+            let enum_items = is_enum_array(cg, param)?;
             params.push(FunctionParam {
                 name,
                 description,
@@ -1223,6 +1597,7 @@ impl FunctionParams {
                 type_name,
                 kind,
                 collection_format,
+                enum_items,
             });
         }
         Ok(Self {
@@ -1251,6 +1626,23 @@ impl FunctionParams {
             .iter()
             .any(|p| p.name.eq_ignore_ascii_case("content-type") && p.kind == ParamKind::Header)
     }
+
+    /// The optional query or header parameter used to pass a continuation token, if any.
+    fn continuation_token_param(&self) -> Option<&FunctionParam> {
+        self.optional_params().into_iter().find(|p| {
+            p.name.eq_ignore_ascii_case(CONTINUATION_TOKEN) && matches!(p.kind, ParamKind::Query | ParamKind::Header) && !p.is_vec()
+        })
+    }
+
+    /// The optional `$top` and `$skip` query parameters used to page through results, if any.
+    fn skip_top_params(&self) -> Option<(&FunctionParam, &FunctionParam)> {
+        let find = |name: &str| {
+            self.optional_params().into_iter().find(|p| {
+                p.name.trim_start_matches('$').eq_ignore_ascii_case(name) && p.kind == ParamKind::Query && p.type_name.is_i32()
+            })
+        };
+        Some((find(TOP)?, find(SKIP)?))
+    }
 }
 
 #[derive(Clone)]
@@ -1297,6 +1689,97 @@ impl ClientFunctionCode {
             in_operation_group,
         })
     }
+
+    /// The summary, description and argument doc comments, for the given required parameters.
+    fn doc_comments(&self, required_params: &[&FunctionParam]) -> TokenStream {
+        let summary = DocCommentCode::new(self.summary.clone());
+        let description = DocCommentCode::new(self.description.clone());
+
+        let mut param_descriptions: Vec<TokenStream> = Vec::new();
+        if required_params.iter().any(|param| param.description.is_some()) {
+            // Add a blank link before the arguments if there is a summary or description.
+            if !summary.is_empty() || !description.is_empty() {
+                param_descriptions.push(quote! { #[doc = ""] });
+            }
+            param_descriptions.push(quote! { #[doc = "Arguments:"] });
+            for required_param in required_params.iter() {
+                if let Some(desc) = &required_param.description {
+                    if !desc.is_empty() {
+                        let doc_comment = format!("* `{}`: {}", required_param.variable_name, desc);
+                        param_descriptions.push(quote! { #[doc = #doc_comment] });
+                    }
+                }
+            }
+        };
+        quote! {
+            #summary
+            #description
+            #(#param_descriptions)*
+        }
+    }
+
+    /// Whether the function is included in the scoped clients, i.e. it takes an `organization` parameter.
+    fn is_scoped(&self) -> bool {
+        self.parameters.required_params().iter().any(|p| p.name == ORGANIZATION)
+    }
+
+    fn scoped(&self, scope: ClientScope) -> ScopedClientFunctionCode<'_> {
+        ScopedClientFunctionCode { function: self, scope }
+    }
+}
+
+/// The scope of a scoped client, whose functions omit the scope parameters.
+#[derive(Clone, Copy)]
+enum ClientScope {
+    Organization,
+    Project,
+}
+
+impl ClientScope {
+    /// The names of the parameters that are provided by the scoped client.
+    fn param_names(self) -> &'static [&'static str] {
+        match self {
+            ClientScope::Organization => &[ORGANIZATION],
+            ClientScope::Project => &[ORGANIZATION, PROJECT],
+        }
+    }
+}
+
+/// Create the scoped client function, which calls the client function with the scope parameters.
+struct ScopedClientFunctionCode<'a> {
+    function: &'a ClientFunctionCode,
+    scope: ClientScope,
+}
+
+impl ToTokens for ScopedClientFunctionCode<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let is_scope_param = |p: &FunctionParam| self.scope.param_names().contains(&p.name.as_str());
+        let required_params = self.function.parameters.required_params();
+        let params: Vec<&FunctionParam> = required_params.iter().copied().filter(|p| !is_scope_param(p)).collect();
+        let doc_comments = self.function.doc_comments(&params);
+
+        let params = params.iter().map(|p| {
+            let variable_name = &p.variable_name;
+            let is_vec = p.type_name.is_vec();
+            let type_name = p.type_name.clone().impl_into(!is_vec);
+            quote! { #variable_name: #type_name }
+        });
+        let args = required_params.iter().map(|p| {
+            let variable_name = &p.variable_name;
+            if is_scope_param(p) {
+                quote! { self.#variable_name.clone() }
+            } else {
+                quote! { #variable_name }
+            }
+        });
+        let fname = &self.function.fname;
+        tokens.extend(quote! {
+            #doc_comments
+            pub fn #fname(&self, #(#params),*) -> #fname::RequestBuilder {
+                self.client.#fname(#(#args),*)
+            }
+        });
+    }
 }
 
 impl ToTokens for ClientFunctionCode {
@@ -1331,37 +1814,11 @@ impl ToTokens for ClientFunctionCode {
             }
         }
 
-        let summary = DocCommentCode::new(self.summary.clone());
-        let description = DocCommentCode::new(self.description.clone());
-
-        let mut param_descriptions: Vec<TokenStream> = Vec::new();
-        if self
-            .parameters
-            .required_params()
-            .into_iter()
-            .any(|param| param.description.is_some())
-        {
-            // Add a blank link before the arguments if there is a summary or description.
-            if !summary.is_empty() || !description.is_empty() {
-                param_descriptions.push(quote! { #[doc = ""] });
-            }
-            param_descriptions.push(quote! { #[doc = "Arguments:"] });
-            for required_param in self.parameters.required_params().iter() {
-                if let Some(desc) = &required_param.description {
-                    if !desc.is_empty() {
-                        let doc_comment = format!("* `{}`: {}", required_param.variable_name, desc);
-                        param_descriptions.push(quote! { #[doc = #doc_comment] });
-                    }
-                }
-            }
-        };
-
+        let doc_comments = self.doc_comments(&self.parameters.required_params());
         let fname = &self.fname;
         let parameters = FunctionCallParamsCode(self.parameters.clone());
         tokens.extend(quote! {
-            #summary
-            #description
-            #(#param_descriptions)*
+            #doc_comments
             pub fn #fname(#parameters) -> #fname::RequestBuilder {
                 #fname::RequestBuilder {
                     #(#params),*
@@ -1517,6 +1974,43 @@ pub fn create_response_type(rsp: &Response) -> Result<Option<TypeNameCode>> {
     }
 }
 
+/// Get the item type of a list response, which is an object with a `value` array property.
+fn get_list_item_type(cg: &CodeGen, operation: &WebOperationGen) -> Result<Option<TypeNameCode>> {
+    let reference = match operation.success_responses().values().next().and_then(|rsp| rsp.schema.as_ref()) {
+        Some(ReferenceOr::Reference { reference, .. }) => reference,
+        _ => return Ok(None),
+    };
+    for doc_file in cg.input_files() {
+        if let Ok(resolved) = cg.spec.resolve_schema_ref(doc_file, reference) {
+            if let Some(ReferenceOr::Item(value)) = resolved.schema.properties.get("value") {
+                if value.common.type_ == Some(DataType::Array) {
+                    let items = get_schema_array_items(&value.common)?;
+                    return Ok(Some(TypeNameCode::new(&get_type_name_for_schema_ref(items)?)?.qualify_models(true)));
+                }
+            }
+            return Ok(None);
+        }
+    }
+    Ok(None)
+}
+
+/// Returns `true` if the parameter is an array of values of an enum definition.
+fn is_enum_array(cg: &CodeGen, param: &WebParameter) -> Result<bool> {
+    if !param.is_array() {
+        return Ok(false);
+    }
+    let reference = match param.array_items()? {
+        ReferenceOr::Reference { reference, .. } => reference,
+        ReferenceOr::Item(_) => return Ok(false),
+    };
+    for doc_file in cg.input_files() {
+        if let Ok(resolved) = cg.spec.resolve_schema_ref(doc_file, reference) {
+            return Ok(!resolved.schema.common.enum_.is_empty());
+        }
+    }
+    Ok(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,12 @@ pub const APPLICATION_JSON: &str = "application/json";
 pub const APPLICATION_XML: &str = "application/xml";
 pub const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
 
+/// Whether the content type is JSON, e.g. `application/json` or `application/json; charset=utf-8`
+pub fn is_json(content_type: &str) -> bool {
+    let mime_type = content_type.split(';').next().unwrap_or_default().trim();
+    mime_type.eq_ignore_ascii_case(APPLICATION_JSON) || mime_type.ends_with("+json")
+}
+
 /// Pick `application/json` if it is an option, else the first one in the list
 pub fn pick<'a>(mut list: impl Iterator<Item = &'a str>) -> Option<&'a str> {
     let value = list.next();
@@ -41,4 +47,14 @@ mod tests {
         assert_eq!(Some(APPLICATION_JSON), pick(consumes.into_iter()));
         Ok(())
     }
+
+    #[test]
+    fn test_is_json() {
+        assert!(is_json(APPLICATION_JSON));
+        assert!(is_json("application/json; charset=utf-8"));
+        assert!(is_json("application/json-patch+json"));
+        assert!(!is_json(APPLICATION_OCTET_STREAM));
+        assert!(!is_json("text/plain"));
+        assert!(!is_json("application/zip"));
+    }
 }
@@ -520,6 +520,10 @@ impl WebParameter {
         matches!(self.data_type(), Some(DataType::Array))
     }
 
+    pub fn array_items(&self) -> Result<&ReferenceOr<Schema>> {
+        get_schema_array_items(&self.0.common)
+    }
+
     pub fn is_string(&self) -> bool {
         matches!(self.data_type(), Some(DataType::String))
     }
@@ -0,0 +1,47 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+// ado_client.rs
+// Example demonstrating how to use a single `AdoClient` to access multiple services.
+use anyhow::Result;
+use azure_devops_rust_api::AdoClient;
+use std::env;
+
+mod utils;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize logging
+    env_logger::init();
+
+    // Get authentication credential
+    let credential = utils::get_credential();
+
+    // Get ADO server configuration via environment variables
+    let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
+    let project = env::var("ADO_PROJECT").expect("Must define ADO_PROJECT");
+
+    // Create a client for the organization. The service clients created from it
+    // share the same credential, options and request pipeline.
+    let client = AdoClient::new(credential, organization);
+
+    // List the git repositories in the project
+    let repos = client
+        .git()
+        .repositories_client()
+        .list(&project)
+        .await?
+        .value;
+    println!("{} repos found", repos.len());
+
+    // List the build definitions in the project
+    let definitions = client
+        .build()
+        .definitions_client()
+        .list(&project)
+        .await?
+        .value;
+    println!("{} build definitions found", definitions.len());
+
+    Ok(())
+}
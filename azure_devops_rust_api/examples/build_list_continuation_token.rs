@@ -3,11 +3,9 @@
 
 // build_list_continuation_token.rs
 // Example demonstrating how to make large queries using continuation tokens.
-use anyhow::{anyhow, Context, Result};
-use azure_core::StatusCode;
+use anyhow::Result;
 use azure_devops_rust_api::build;
-use azure_devops_rust_api::build::models::{Build, BuildList};
-use serde_json;
+use futures::StreamExt;
 use std::env;
 use time::format_description::well_known::Rfc3339;
 
@@ -15,44 +13,6 @@ mod utils;
 
 const NUM_BUILD_BATCHES: usize = 5;
 
-async fn get_builds(
-    build_client: &build::Client,
-    organization: &str,
-    project: &str,
-    continuation_token: &Option<String>,
-) -> Result<(Vec<Build>, Option<String>)> {
-    let mut list_builder = build_client.builds_client().list(organization, project);
-
-    if let Some(continuation_token) = continuation_token {
-        println!(
-            "Query builds with continuation_token: {}",
-            continuation_token
-        );
-        list_builder = list_builder.continuation_token(continuation_token)
-    } else {
-        println!("Query builds with no continuation_token");
-    }
-
-    let (status, headers, body) = list_builder.send().await?.into_raw_response().deconstruct();
-
-    if status != StatusCode::Ok {
-        println!("Request failed");
-        return Err(anyhow!("Request failed"));
-    }
-
-    let new_continuation_token = headers.get_optional_string(
-        &azure_core::headers::HeaderName::from_static("x-ms-continuationtoken"),
-    );
-
-    let body_data = body.collect_string().await?;
-    let build_list: BuildList = serde_json::from_str(&body_data)
-        .with_context(|| format!("Failed to parse BuildList: {}", &body_data))?;
-
-    println!("Received {} builds", build_list.count.unwrap_or(0));
-
-    Ok((build_list.value, new_continuation_token))
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize logging
@@ -68,26 +28,28 @@ async fn main() -> Result<()> {
     // Create a build client
     let build_client = build::ClientBuilder::new(credential).build();
 
-    let mut continuation_token = None;
-
-    // Query several batches of builds. Each batch has 1000 builds (by default)
+    // Query several batches of builds. Each batch has 1000 builds (by default).
+    // `into_stream()` automatically passes the continuation token returned with
+    // each batch when requesting the next one.
     println!("Num build batches: {}", NUM_BUILD_BATCHES);
-    for batch in 0..NUM_BUILD_BATCHES {
-        let (builds, new_continuation_token) =
-            get_builds(&build_client, &organization, &project, &continuation_token).await?;
-
-        if let Some(build) = builds.iter().next() {
+    let mut batches = build_client
+        .builds_client()
+        .list(organization, project)
+        .into_stream()
+        .take(NUM_BUILD_BATCHES)
+        .enumerate();
+
+    while let Some((batch, build_list)) = batches.next().await {
+        let build_list = build_list?;
+        println!("Received {} builds", build_list.count.unwrap_or(0));
+
+        if let Some(build) = build_list.value.first() {
             println!(
                 "First build of batch {} start time: {}\n",
                 batch,
                 build.start_time.unwrap().format(&Rfc3339)?
             );
         }
-        continuation_token = new_continuation_token;
-
-        if continuation_token == None {
-            println!("continuation_token is None - exiting");
-        }
     }
 
     Ok(())
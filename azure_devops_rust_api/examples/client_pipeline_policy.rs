@@ -20,6 +20,9 @@ use log::info;
 mod utils;
 
 /// Basic request logger policy
+///
+/// Note that the request headers include the `Authorization` header, so should not be logged.
+/// `azure_devops_rust_api::LoggingPolicy` logs requests and responses with secrets masked.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct RequestLogger {}
 
@@ -37,13 +40,16 @@ impl azure_core::Policy for RequestLogger {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult {
-        info!("> Request:\n{:#?}", request);
+        info!("> Request: {} {}", request.method(), request.url());
         let now = time::Instant::now();
         // Call the next policy in the chain, and await the response
         let rsp = next[0].send(ctx, request, &next[1..]).await;
         let elapsed_time = now.elapsed();
         info!("Request took {} secs", elapsed_time.as_seconds_f32());
-        info!("< Response:\n{:#?}", rsp);
+        match &rsp {
+            Ok(rsp) => info!("< Response: {}", rsp.status()),
+            Err(e) => info!("< Error: {}", e),
+        }
         rsp
     }
 }
@@ -5,6 +5,7 @@
 // Project Teams from organization example.
 use anyhow::Result;
 use azure_devops_rust_api::core;
+use futures::TryStreamExt;
 use std::env;
 
 mod utils;
@@ -21,18 +22,19 @@ async fn main() -> Result<()> {
     let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
     let project = env::var("ADO_PROJECT").expect("Must define ADO_PROJECT");
 
-    // Max number of teams to be fetched, default max is 100
-    let top_teams: i32 = 500;
+    // Number of teams to fetch per request
+    let page_size: i32 = 100;
 
     // Create core client
     let core_client = core::ClientBuilder::new(credential).build();
 
-    let project_teams = core_client
+    // Fetch all the teams, requesting `page_size` teams at a time
+    let project_teams: Vec<core::models::WebApiTeam> = core_client
         .teams_client()
         .get_teams(&organization, &project)
-        .top(top_teams)
-        .await?
-        .value;
+        .items(page_size)
+        .try_collect()
+        .await?;
 
     // Display team names
     println!("\nProject teams:");
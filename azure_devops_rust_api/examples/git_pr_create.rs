@@ -4,7 +4,7 @@
 // git_pr_create.rs
 // Create Pull Request example.
 use anyhow::Result;
-use azure_devops_rust_api::git;
+use azure_devops_rust_api::{git, AdoError};
 use git::models::{GitPullRequestCreateOptions, WebApiCreateTagRequestData};
 use std::env;
 
@@ -52,11 +52,21 @@ async fn main() -> Result<()> {
     ];
 
     // Define the new PR
-    let pr = git_client
+    let result = git_client
         .pull_requests_client()
         .create(organization, repo_name, project, pr_create_options)
-        .await?;
-    println!("Created PR:\n{:#?}", pr);
+        .await;
+
+    match result {
+        Ok(pr) => println!("Created PR:\n{:#?}", pr),
+        // Azure DevOps errors can be identified by their type key
+        Err(e) => match AdoError::from_error(&e) {
+            Some(ado_error) if ado_error.type_key() == Some("GitPullRequestExistsException") => {
+                println!("PR already exists: {}", ado_error.message)
+            }
+            _ => return Err(e.into()),
+        },
+    }
 
     Ok(())
 }
@@ -3,11 +3,11 @@
 // release_logs.rs
 // Release logs example.
 // The log data is saved as a zip file - use `unzip` to extract
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use azure_devops_rust_api::release;
+use futures::io::AllowStdIo;
 use std::env;
 use std::fs::File;
-use std::io::prelude::*;
 
 mod utils;
 
@@ -37,25 +37,18 @@ async fn main() -> Result<()> {
 
     // Get release logs
     println!("\nDownloading release logs for release {}", release_id);
-    let (status, _headers, body) = release_client
+    let response = release_client
         .releases_client()
         .get_logs(organization, project, release_id)
         .send()
-        .await?
-        .into_raw_response()
-        .deconstruct();
+        .await?;
 
-    if status != azure_core::StatusCode::Ok {
-        println!("Request failed. status:{}", status);
-        return Err(anyhow!("Request failed"));
-    }
-
-    // Write the data as a zipfile
+    // Write the data as a zipfile. The data is streamed to the file as it is
+    // received, rather than being buffered in memory.
     println!("Writing data to zipfile: {}", output_file);
-    let data = body.collect().await?;
-    let mut file = File::create(&output_file)?;
-    file.write_all(&data)?;
-    println!("Logs saved");
+    let mut file = AllowStdIo::new(File::create(&output_file)?);
+    let bytes_written = response.write_to(&mut file).await?;
+    println!("Logs saved ({} bytes)", bytes_written);
 
     println!("Use 'unzip {}' to extract the logs", output_file);
 
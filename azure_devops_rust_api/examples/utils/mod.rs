@@ -7,13 +7,14 @@ use std::sync::Arc;
 
 #[allow(dead_code)]
 pub fn get_credential() -> Credential {
-    // Get authentication credential either from a PAT ("ADO_TOKEN") or via the az cli
-    match std::env::var("ADO_TOKEN") {
-        Ok(token) => {
-            println!("Authenticate using PAT provided via $ADO_TOKEN");
-            Credential::from_pat(token)
+    // Get authentication credential either from the environment (`SYSTEM_ACCESSTOKEN`,
+    // `AZURE_DEVOPS_EXT_PAT` or `ADO_TOKEN`) or via the az cli
+    match Credential::from_env() {
+        Some(credential) => {
+            println!("Authenticate using token provided via the environment");
+            credential
         }
-        Err(_) => {
+        None => {
             println!("Authenticate using auto-refereshing DefaultAzureCredential");
             // `DefaultAzureCredential` can authenticate using one of:
             // - `EnvironmentCredential`
@@ -0,0 +1,55 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+// wit_wiql_query.rs
+// Work Item WIQL query example.
+use anyhow::Result;
+use azure_devops_rust_api::wit;
+use futures::TryStreamExt;
+use std::env;
+
+mod utils;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize logging
+    env_logger::init();
+
+    // Get authentication credential
+    let credential = utils::get_credential();
+
+    // Get ADO configuration via environment variables
+    let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
+    let project = env::var("ADO_PROJECT").expect("Must define ADO_PROJECT");
+    let team = env::var("ADO_TEAM").expect("Must define ADO_TEAM");
+
+    // Create a wit client
+    let wit_client = wit::ClientBuilder::new(credential).build();
+
+    // Query the active work items
+    let query = wit::WiqlBuilder::work_items()
+        .select(["System.Id", "System.Title", "System.State"])
+        .filter(wit::WiqlField::new("System.TeamProject").eq(wit::WiqlMacro::Project))
+        .filter(wit::WiqlField::new("System.State").eq("Active"))
+        .order_by("System.ChangedDate", wit::WiqlSortOrder::Desc);
+    println!("{query}");
+    let work_items: Vec<wit::models::WorkItem> = wit_client
+        .wiql_client()
+        .query_work_items(&organization, query, &project, &team)
+        .into_stream()
+        .try_collect()
+        .await?;
+
+    println!("Active work items: {}", work_items.len());
+    for work_item in &work_items {
+        let fields = work_item.typed_fields();
+        println!(
+            "{:8} {:12} {}",
+            work_item.id,
+            fields.state().unwrap_or_default(),
+            fields.title().unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
@@ -5,7 +5,7 @@
 // Work Item query example.
 use anyhow::{anyhow, Context, Result};
 use azure_devops_rust_api::wit;
-use azure_devops_rust_api::wit::models::WorkItemRelation;
+use azure_devops_rust_api::wit::models::{WorkItemExpand, WorkItemRelation};
 use std::env;
 
 mod utils;
@@ -29,9 +29,8 @@ fn work_item_id_from_url(url: &str) -> Result<i32> {
 // Extract work item type from work item.
 fn work_item_type(work_item: &wit::models::WorkItem) -> String {
     work_item
-        .fields
-        .get("System.WorkItemType")
-        .and_then(|value| value.as_str())
+        .typed_fields()
+        .work_item_type()
         .unwrap_or("<unknown>")
         .to_string()
 }
@@ -77,7 +76,7 @@ async fn main() -> Result<()> {
     let work_item = wit_client
         .work_items_client()
         .get_work_item(&organization, work_item_id, &project)
-        .expand("All")
+        .expand(WorkItemExpand::All)
         .await?;
 
     println!("Work item [{work_item_id}]:\n{:#?}", work_item);
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://app.vssps.visualstudio.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,20 +153,86 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn accounts_client(&self) -> accounts::Client {
         accounts::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn accounts_client(&self) -> accounts::OrganizationClient {
+        accounts::OrganizationClient {
+            client: self.client.accounts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn accounts_client(&self) -> accounts::ProjectClient {
+        accounts::ProjectClient {
+            client: self.client.accounts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod accounts {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -136,6 +247,21 @@ pub mod accounts {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {}
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {}
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -228,9 +354,10 @@ pub mod accounts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(owner_id) = &this.owner_id {
                             req.url_mut()
                                 .query_pairs_mut()
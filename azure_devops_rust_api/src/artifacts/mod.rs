@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://feeds.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn artifact_details_client(&self) -> artifact_details::Client {
@@ -143,6 +212,139 @@ impl Client {
         service_settings::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn artifact_details_client(&self) -> artifact_details::OrganizationClient {
+        artifact_details::OrganizationClient {
+            client: self.client.artifact_details_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn change_tracking_client(&self) -> change_tracking::OrganizationClient {
+        change_tracking::OrganizationClient {
+            client: self.client.change_tracking_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn feed_management_client(&self) -> feed_management::OrganizationClient {
+        feed_management::OrganizationClient {
+            client: self.client.feed_management_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn feed_recycle_bin_client(&self) -> feed_recycle_bin::OrganizationClient {
+        feed_recycle_bin::OrganizationClient {
+            client: self.client.feed_recycle_bin_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn provenance_client(&self) -> provenance::OrganizationClient {
+        provenance::OrganizationClient {
+            client: self.client.provenance_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn recycle_bin_client(&self) -> recycle_bin::OrganizationClient {
+        recycle_bin::OrganizationClient {
+            client: self.client.recycle_bin_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn retention_policies_client(&self) -> retention_policies::OrganizationClient {
+        retention_policies::OrganizationClient {
+            client: self.client.retention_policies_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn service_settings_client(&self) -> service_settings::OrganizationClient {
+        service_settings::OrganizationClient {
+            client: self.client.service_settings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifact_details_client(&self) -> artifact_details::ProjectClient {
+        artifact_details::ProjectClient {
+            client: self.client.artifact_details_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn change_tracking_client(&self) -> change_tracking::ProjectClient {
+        change_tracking::ProjectClient {
+            client: self.client.change_tracking_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn feed_management_client(&self) -> feed_management::ProjectClient {
+        feed_management::ProjectClient {
+            client: self.client.feed_management_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn feed_recycle_bin_client(&self) -> feed_recycle_bin::ProjectClient {
+        feed_recycle_bin::ProjectClient {
+            client: self.client.feed_recycle_bin_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn provenance_client(&self) -> provenance::ProjectClient {
+        provenance::ProjectClient {
+            client: self.client.provenance_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn recycle_bin_client(&self) -> recycle_bin::ProjectClient {
+        recycle_bin::ProjectClient {
+            client: self.client.recycle_bin_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn retention_policies_client(&self) -> retention_policies::ProjectClient {
+        retention_policies::ProjectClient {
+            client: self.client.retention_policies_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn service_settings_client(&self) -> service_settings::ProjectClient {
+        service_settings::ProjectClient {
+            client: self.client.service_settings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod service_settings {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -178,6 +380,55 @@ pub mod service_settings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all service-wide feed creation and administration permissions."]
+        pub fn get_global_permissions(&self) -> get_global_permissions::RequestBuilder {
+            self.client
+                .get_global_permissions(self.organization.clone())
+        }
+        #[doc = "Set service-wide permissions that govern feed creation and administration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New permissions for the organization."]
+        pub fn set_global_permissions(
+            &self,
+            body: Vec<models::GlobalPermission>,
+        ) -> set_global_permissions::RequestBuilder {
+            self.client
+                .set_global_permissions(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all service-wide feed creation and administration permissions."]
+        pub fn get_global_permissions(&self) -> get_global_permissions::RequestBuilder {
+            self.client
+                .get_global_permissions(self.organization.clone())
+        }
+        #[doc = "Set service-wide permissions that govern feed creation and administration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New permissions for the organization."]
+        pub fn set_global_permissions(
+            &self,
+            body: Vec<models::GlobalPermission>,
+        ) -> set_global_permissions::RequestBuilder {
+            self.client
+                .set_global_permissions(self.organization.clone(), body)
+        }
+    }
     pub mod get_global_permissions {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -262,9 +513,10 @@ pub mod service_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_ids) = &this.include_ids {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -372,9 +624,10 @@ pub mod service_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -464,6 +717,90 @@ pub mod change_tracking {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Query to determine which feeds have changed since the last call, tracked through the provided continuation token. Only changes to a feed itself are returned and impact the continuation token, not additions or alterations to packages within the feeds.\n\nIf the project parameter is present, gets all feed changes in the given project.\nIf omitted, gets all feed changes in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_changes(
+            &self,
+            project: impl Into<String>,
+        ) -> get_feed_changes::RequestBuilder {
+            self.client
+                .get_feed_changes(self.organization.clone(), project)
+        }
+        #[doc = "Query a feed to determine its current state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_change(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_change::RequestBuilder {
+            self.client
+                .get_feed_change(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Get a batch of package changes made to a feed.  The changes returned are 'most recent change' so if an Add is followed by an Update before you begin enumerating, you'll only see one change in the batch.  While consuming batches using the continuation token, you may see changes to the same package version multiple times if they are happening as you enumerate.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_changes(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_changes::RequestBuilder {
+            self.client
+                .get_package_changes(self.organization.clone(), feed_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Query to determine which feeds have changed since the last call, tracked through the provided continuation token. Only changes to a feed itself are returned and impact the continuation token, not additions or alterations to packages within the feeds.\n\nIf the project parameter is present, gets all feed changes in the given project.\nIf omitted, gets all feed changes in the organization."]
+        pub fn get_feed_changes(&self) -> get_feed_changes::RequestBuilder {
+            self.client
+                .get_feed_changes(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Query a feed to determine its current state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn get_feed_change(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_feed_change::RequestBuilder {
+            self.client
+                .get_feed_change(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Get a batch of package changes made to a feed.  The changes returned are 'most recent change' so if an Add is followed by an Update before you begin enumerating, you'll only see one change in the batch.  While consuming batches using the continuation token, you may see changes to the same package version multiple times if they are happening as you enumerate.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_package_changes(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_package_changes::RequestBuilder {
+            self.client.get_package_changes(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_feed_changes {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -562,9 +899,10 @@ pub mod change_tracking {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_deleted) = &this.include_deleted {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -586,6 +924,25 @@ pub mod change_tracking {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::FeedChangesResponse>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::FeedChangesResponse>;
@@ -684,9 +1041,10 @@ pub mod change_tracking {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -802,9 +1160,10 @@ pub mod change_tracking {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -821,6 +1180,27 @@ pub mod change_tracking {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PackageChangesResponse>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PackageChangesResponse>;
@@ -896,6 +1276,80 @@ pub mod feed_recycle_bin {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Query for feeds within the recycle bin.\n\nIf the project parameter is present, gets all feeds in recycle bin in the given project.\nIf omitted, gets all feeds in recycle bin in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_deleted_feed(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_deleted_feed::RequestBuilder {
+            self.client
+                .restore_deleted_feed(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn permanent_delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> permanent_delete_feed::RequestBuilder {
+            self.client
+                .permanent_delete_feed(self.organization.clone(), feed_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Query for feeds within the recycle bin.\n\nIf the project parameter is present, gets all feeds in recycle bin in the given project.\nIf omitted, gets all feeds in recycle bin in the organization."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        pub fn restore_deleted_feed(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            feed_id: impl Into<String>,
+        ) -> restore_deleted_feed::RequestBuilder {
+            self.client.restore_deleted_feed(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        pub fn permanent_delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> permanent_delete_feed::RequestBuilder {
+            self.client.permanent_delete_feed(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -975,9 +1429,10 @@ pub mod feed_recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1049,9 +1504,10 @@ pub mod feed_recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1110,9 +1566,10 @@ pub mod feed_recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1292,93 +1749,430 @@ pub mod feed_management {
         #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: View to be created."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed_view(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_feed_view::RequestBuilder {
+            create_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_view(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_view::RequestBuilder {
+            get_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: New settings to apply to the specified view."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed_view(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed_view::RequestBuilder {
+            update_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed_view(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed_view::RequestBuilder {
+            delete_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all feeds in an account where you have the provided role access.\n\nIf the project parameter is present, gets all feeds in the given project.\nIf omitted, gets all feeds in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feeds(&self, project: impl Into<String>) -> get_feeds::RequestBuilder {
+            self.client.get_feeds(self.organization.clone(), project)
+        }
+        #[doc = "Create a feed, a container for various package types.\n\nFeeds can be created in a project if the project parameter is included in the request url.\nIf the project parameter is omitted, the feed will not be associated with a project and will be created at the organization level."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing both required and optional attributes for the feed. Name is the only required value."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed(
+            &self,
+            body: impl Into<models::Feed>,
+            project: impl Into<String>,
+        ) -> create_feed::RequestBuilder {
+            self.client
+                .create_feed(self.organization.clone(), body, project)
+        }
+        #[doc = "Get the settings for a specific feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed::RequestBuilder {
+            self.client
+                .get_feed(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Change the attributes of a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing the feed settings to be updated."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed(
+            &self,
+            body: impl Into<models::FeedUpdate>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed::RequestBuilder {
+            self.client
+                .update_feed(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Remove a feed and all its packages. The feed moves to the recycle bin and is reversible.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed::RequestBuilder {
+            self.client
+                .delete_feed(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Get the permissions for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_permissions(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_permissions::RequestBuilder {
+            self.client
+                .get_feed_permissions(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Update the permissions on a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Permissions to set."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_feed_permissions(
+            &self,
+            body: Vec<models::FeedPermission>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> set_feed_permissions::RequestBuilder {
+            self.client
+                .set_feed_permissions(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Get all views for a feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_views(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_views::RequestBuilder {
+            self.client
+                .get_feed_views(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: View to be created."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed_view(
+            &self,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_feed_view::RequestBuilder {
+            self.client
+                .create_feed_view(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_view(
+            &self,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_view::RequestBuilder {
+            self.client
+                .get_feed_view(self.organization.clone(), feed_id, view_id, project)
+        }
+        #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New settings to apply to the specified view."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed_view(
+            &self,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed_view::RequestBuilder {
+            self.client
+                .update_feed_view(self.organization.clone(), body, feed_id, view_id, project)
+        }
+        #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed_view(
+            &self,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed_view::RequestBuilder {
+            self.client
+                .delete_feed_view(self.organization.clone(), feed_id, view_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all feeds in an account where you have the provided role access.\n\nIf the project parameter is present, gets all feeds in the given project.\nIf omitted, gets all feeds in the organization."]
+        pub fn get_feeds(&self) -> get_feeds::RequestBuilder {
+            self.client
+                .get_feeds(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a feed, a container for various package types.\n\nFeeds can be created in a project if the project parameter is included in the request url.\nIf the project parameter is omitted, the feed will not be associated with a project and will be created at the organization level."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing both required and optional attributes for the feed. Name is the only required value."]
+        pub fn create_feed(&self, body: impl Into<models::Feed>) -> create_feed::RequestBuilder {
+            self.client
+                .create_feed(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get the settings for a specific feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_feed(&self, feed_id: impl Into<String>) -> get_feed::RequestBuilder {
+            self.client
+                .get_feed(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Change the attributes of a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing the feed settings to be updated."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn update_feed(
+            &self,
+            body: impl Into<models::FeedUpdate>,
+            feed_id: impl Into<String>,
+        ) -> update_feed::RequestBuilder {
+            self.client.update_feed(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Remove a feed and all its packages. The feed moves to the recycle bin and is reversible.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn delete_feed(&self, feed_id: impl Into<String>) -> delete_feed::RequestBuilder {
+            self.client
+                .delete_feed(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Get the permissions for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_feed_permissions(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_feed_permissions::RequestBuilder {
+            self.client.get_feed_permissions(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update the permissions on a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Permissions to set."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn set_feed_permissions(
+            &self,
+            body: Vec<models::FeedPermission>,
+            feed_id: impl Into<String>,
+        ) -> set_feed_permissions::RequestBuilder {
+            self.client.set_feed_permissions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get all views for a feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_feed_views(&self, feed_id: impl Into<String>) -> get_feed_views::RequestBuilder {
+            self.client
+                .get_feed_views(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `body`: View to be created."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn create_feed_view(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::FeedView>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> create_feed_view::RequestBuilder {
-            create_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.create_feed_view(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_feed_view(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_feed_view::RequestBuilder {
-            get_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            self.client.get_feed_view(
+                self.organization.clone(),
+                feed_id,
+                view_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: New settings to apply to the specified view."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_feed_view(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::FeedView>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_feed_view::RequestBuilder {
-            update_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            self.client.update_feed_view(
+                self.organization.clone(),
+                body,
+                feed_id,
+                view_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_feed_view(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_feed_view::RequestBuilder {
-            delete_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            self.client.delete_feed_view(
+                self.organization.clone(),
+                feed_id,
+                view_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod get_feeds {
@@ -1478,9 +2272,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(feed_role) = &this.feed_role {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1598,9 +2393,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1709,9 +2505,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_deleted_upstreams) = &this.include_deleted_upstreams {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeDeletedUpstreams",
@@ -1820,9 +2617,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1893,9 +2691,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2013,9 +2812,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_ids) = &this.include_ids {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2144,9 +2944,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2250,9 +3051,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2356,9 +3158,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2464,9 +3267,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2572,9 +3376,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2648,9 +3453,10 @@ pub mod feed_management {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2846,24 +3652,302 @@ pub mod artifact_details {
         #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_badge(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_badge::RequestBuilder {
+            get_badge::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_id: package_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_package_metrics(
+            &self,
+            body: impl Into<models::PackageMetricsQuery>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> query_package_metrics::RequestBuilder {
+            self.client
+                .query_package_metrics(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Get details about all of the packages in the feed. Use the various filters to include or exclude information from the result set.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_packages(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_packages::RequestBuilder {
+            self.client
+                .get_packages(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Get details about a specific package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package::RequestBuilder {
+            self.client
+                .get_package(self.organization.clone(), feed_id, package_id, project)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_package_version_metrics(
+            &self,
+            body: impl Into<models::PackageVersionMetricsQuery>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> query_package_version_metrics::RequestBuilder {
+            self.client.query_package_version_metrics(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get a list of package versions, optionally filtering by state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_versions::RequestBuilder {
+            self.client.get_package_versions(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get details about a specific package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+        #[doc = "Gets provenance for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_provenance(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_provenance::RequestBuilder {
+            self.client.get_package_version_provenance(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+        #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_badge(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_badge::RequestBuilder {
+            self.client
+                .get_badge(self.organization.clone(), feed_id, package_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn query_package_metrics(
+            &self,
+            body: impl Into<models::PackageMetricsQuery>,
+            feed_id: impl Into<String>,
+        ) -> query_package_metrics::RequestBuilder {
+            self.client.query_package_metrics(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get details about all of the packages in the feed. Use the various filters to include or exclude information from the result set.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_packages(&self, feed_id: impl Into<String>) -> get_packages::RequestBuilder {
+            self.client
+                .get_packages(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Get details about a specific package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_package::RequestBuilder {
+            self.client.get_package(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
+        }
+        pub fn query_package_version_metrics(
+            &self,
+            body: impl Into<models::PackageVersionMetricsQuery>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> query_package_version_metrics::RequestBuilder {
+            self.client.query_package_version_metrics(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a list of package versions, optionally filtering by state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        pub fn get_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_package_versions::RequestBuilder {
+            self.client.get_package_versions(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get details about a specific package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Gets provenance for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        pub fn get_package_version_provenance(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_package_version_provenance::RequestBuilder {
+            self.client.get_package_version_provenance(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_badge(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_badge::RequestBuilder {
-            get_badge::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_id: package_id.into(),
-                project: project.into(),
-            }
+            self.client.get_badge(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod query_package_metrics {
@@ -2949,9 +4033,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3142,9 +4227,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(protocol_type) = &this.protocol_type {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3224,6 +4310,34 @@ pub mod artifact_details {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::PackageList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::PackageList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::Package>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::PackageList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PackageList>;
@@ -3358,9 +4472,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_all_versions) = &this.include_all_versions {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeAllVersions",
@@ -3499,9 +4614,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3628,9 +4744,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_urls) = &this.include_urls {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3770,9 +4887,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_urls) = &this.include_urls {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3895,9 +5013,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3945,6 +5064,25 @@ pub mod artifact_details {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4004,9 +5142,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4149,6 +5288,188 @@ pub mod recycle_bin {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Query for packages within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_packages(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_packages::RequestBuilder {
+            self.client
+                .get_recycle_bin_packages(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Queues a job to remove all package versions from a feed's recycle bin"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn empty_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> empty_recycle_bin::RequestBuilder {
+            self.client
+                .empty_recycle_bin(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Get information about a package and all its versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package::RequestBuilder {
+            self.client.get_recycle_bin_package(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get a list of package versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package_versions::RequestBuilder {
+            self.client.get_recycle_bin_package_versions(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get information about a package version within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `package_version_id`: The package version Id 9guid Id, not the version string)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package_version::RequestBuilder {
+            self.client.get_recycle_bin_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Query for packages within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_recycle_bin_packages(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_recycle_bin_packages::RequestBuilder {
+            self.client.get_recycle_bin_packages(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Queues a job to remove all package versions from a feed's recycle bin"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed"]
+        pub fn empty_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> empty_recycle_bin::RequestBuilder {
+            self.client
+                .empty_recycle_bin(self.organization.clone(), feed_id, self.project.clone())
+        }
+        #[doc = "Get information about a package and all its versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_recycle_bin_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_recycle_bin_package::RequestBuilder {
+            self.client.get_recycle_bin_package(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a list of package versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_recycle_bin_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_recycle_bin_package_versions::RequestBuilder {
+            self.client.get_recycle_bin_package_versions(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `package_version_id`: The package version Id 9guid Id, not the version string)."]
+        pub fn get_recycle_bin_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_recycle_bin_package_version::RequestBuilder {
+            self.client.get_recycle_bin_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_recycle_bin_packages {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4266,9 +5587,10 @@ pub mod recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(protocol_type) = &this.protocol_type {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4306,6 +5628,34 @@ pub mod recycle_bin {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::PackageList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::PackageList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::Package>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::PackageList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PackageList>;
@@ -4403,9 +5753,10 @@ pub mod recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4516,9 +5867,10 @@ pub mod recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_urls) = &this.include_urls {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4637,9 +5989,10 @@ pub mod recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_urls) = &this.include_urls {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4760,9 +6113,10 @@ pub mod recycle_bin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_urls) = &this.include_urls {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4857,6 +6211,109 @@ pub mod retention_policies {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_retention_policy::RequestBuilder {
+            self.client
+                .get_retention_policy(self.organization.clone(), feed_id, project)
+        }
+        #[doc = "Set the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Feed retention policy."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_retention_policy(
+            &self,
+            body: impl Into<models::FeedRetentionPolicy>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> set_retention_policy::RequestBuilder {
+            self.client
+                .set_retention_policy(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Delete the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_retention_policy::RequestBuilder {
+            self.client
+                .delete_retention_policy(self.organization.clone(), feed_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn get_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_retention_policy::RequestBuilder {
+            self.client.get_retention_policy(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Set the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Feed retention policy."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn set_retention_policy(
+            &self,
+            body: impl Into<models::FeedRetentionPolicy>,
+            feed_id: impl Into<String>,
+        ) -> set_retention_policy::RequestBuilder {
+            self.client.set_retention_policy(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn delete_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> delete_retention_policy::RequestBuilder {
+            self.client.delete_retention_policy(
+                self.organization.clone(),
+                feed_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_retention_policy {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4939,9 +6396,10 @@ pub mod retention_policies {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5048,9 +6506,10 @@ pub mod retention_policies {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5124,9 +6583,10 @@ pub mod retention_policies {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5163,6 +6623,55 @@ pub mod provenance {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Creates a session, a wrapper around a feed that can store additional metadata on the packages published to it."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The feed and metadata for the session"]
+        #[doc = "* `protocol`: The protocol that the session will target"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_session(
+            &self,
+            body: impl Into<models::SessionRequest>,
+            protocol: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_session::RequestBuilder {
+            self.client
+                .create_session(self.organization.clone(), body, protocol, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Creates a session, a wrapper around a feed that can store additional metadata on the packages published to it."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The feed and metadata for the session"]
+        #[doc = "* `protocol`: The protocol that the session will target"]
+        pub fn create_session(
+            &self,
+            body: impl Into<models::SessionRequest>,
+            protocol: impl Into<String>,
+        ) -> create_session::RequestBuilder {
+            self.client.create_session(
+                self.organization.clone(),
+                body,
+                protocol,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod create_session {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5246,9 +6755,10 @@ pub mod provenance {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://pkgs.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn maven_client(&self) -> maven::Client {
@@ -134,6 +203,100 @@ impl Client {
         universal::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn maven_client(&self) -> maven::OrganizationClient {
+        maven::OrganizationClient {
+            client: self.client.maven_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn npm_client(&self) -> npm::OrganizationClient {
+        npm::OrganizationClient {
+            client: self.client.npm_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn nu_get_client(&self) -> nu_get::OrganizationClient {
+        nu_get::OrganizationClient {
+            client: self.client.nu_get_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn python_client(&self) -> python::OrganizationClient {
+        python::OrganizationClient {
+            client: self.client.python_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn universal_client(&self) -> universal::OrganizationClient {
+        universal::OrganizationClient {
+            client: self.client.universal_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn maven_client(&self) -> maven::ProjectClient {
+        maven::ProjectClient {
+            client: self.client.maven_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn npm_client(&self) -> npm::ProjectClient {
+        npm::ProjectClient {
+            client: self.client.npm_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn nu_get_client(&self) -> nu_get::ProjectClient {
+        nu_get::ProjectClient {
+            client: self.client.nu_get_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn python_client(&self) -> python::ProjectClient {
+        python::ProjectClient {
+            client: self.client.python_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn universal_client(&self) -> universal::ProjectClient {
+        universal::ProjectClient {
+            client: self.client.universal_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod maven {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -439,291 +602,568 @@ pub mod maven {
             }
         }
     }
-    pub mod get_upstreaming_behavior {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::UpstreamingBehavior> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::UpstreamingBehavior =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `group_id`: The group id of the package"]
+        #[doc = "* `artifact_id`: The artifact id of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                project,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                body,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) project: String,
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
+        #[doc = "Delete a package version from the feed and move it to the feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::UpstreamingBehavior>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::UpstreamingBehavior>,
-            >;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-    }
-    pub mod set_upstreaming_behavior {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) body: models::UpstreamingBehavior,
-            pub(crate) project: String,
-        }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to false to restore the package."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::MavenRecycleBinPackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-    }
-    pub mod get_package_version {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::Package> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Package = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+        #[doc = "Permanently delete a package from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            self.client
+                .update_recycle_bin_packages(self.organization.clone(), body, feed, project)
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Fulfills Maven package file download requests by either returning the URL of the requested package file or, in the case of Azure DevOps Server (OnPrem), returning the content as a stream.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `group_id`: GroupId of the maven package"]
+        #[doc = "* `artifact_id`: ArtifactId of the maven package"]
+        #[doc = "* `version`: Version of the package"]
+        #[doc = "* `file_name`: File name to download"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            file_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                group_id,
+                artifact_id,
+                version,
+                file_name,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) version: String,
-            pub(crate) project: String,
-            pub(crate) show_deleted: Option<bool>,
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client
+                .update_package_versions(self.organization.clone(), body, feed_id, project)
         }
-        impl RequestBuilder {
-            #[doc = "Set to true to show information for deleted packages."]
-            pub fn show_deleted(mut self, show_deleted: bool) -> Self {
-                self.show_deleted = Some(show_deleted);
-                self
-            }
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(show_deleted) = &this.show_deleted {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("showDeleted", &show_deleted.to_string());
-                        }
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `group_id`: The group id of the package"]
+        #[doc = "* `artifact_id`: The artifact id of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                self.project.clone(),
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::Package>;
-            type IntoFuture =
-                futures::future::BoxFuture<'static, azure_core::Result<models::Package>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                body,
+                self.project.clone(),
+            )
         }
-    }
-    pub mod update_package_version {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from the feed and move it to the feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to false to restore the package."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::MavenRecycleBinPackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Permanently delete a package from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            self.client.update_recycle_bin_packages(
+                self.organization.clone(),
+                body,
+                feed,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Fulfills Maven package file download requests by either returning the URL of the requested package file or, in the case of Azure DevOps Server (OnPrem), returning the content as a stream.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `group_id`: GroupId of the maven package"]
+        #[doc = "* `artifact_id`: ArtifactId of the maven package"]
+        #[doc = "* `version`: Version of the package"]
+        #[doc = "* `file_name`: File name to download"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                group_id,
+                artifact_id,
+                version,
+                file_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client.update_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod get_upstreaming_behavior {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::UpstreamingBehavior> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::UpstreamingBehavior =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
         #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
         #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
         #[doc = r" operation and returns a `Result` with the parsed response."]
@@ -735,11 +1175,9 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::PackageVersionDetails,
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
-            pub(crate) version: String,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -751,8 +1189,8 @@ pub mod maven {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -761,19 +1199,34 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
                 })
             }
         }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::UpstreamingBehavior>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::UpstreamingBehavior>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
     }
-    pub mod delete_package_version {
+    pub mod set_upstreaming_behavior {
         use super::models;
         pub struct Response(azure_core::Response);
         #[derive(Clone)]
@@ -797,7 +1250,7 @@ pub mod maven {
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
-            pub(crate) version: String,
+            pub(crate) body: models::UpstreamingBehavior,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -809,8 +1262,8 @@ pub mod maven {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -819,10 +1272,12 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -830,25 +1285,22 @@ pub mod maven {
             }
         }
     }
-    pub mod get_package_version_from_recycle_bin {
+    pub mod get_package_version {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::MavenPackageVersionDeletionState> {
+            pub async fn into_body(self) -> azure_core::Result<models::Package> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::MavenPackageVersionDeletionState = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
+                let body: models::Package = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
                 Ok(body)
             }
             pub fn into_raw_response(self) -> azure_core::Response {
@@ -891,8 +1343,14 @@ pub mod maven {
             pub(crate) artifact_id: String,
             pub(crate) version: String,
             pub(crate) project: String,
+            pub(crate) show_deleted: Option<bool>,
         }
         impl RequestBuilder {
+            #[doc = "Set to true to show information for deleted packages."]
+            pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+                self.show_deleted = Some(show_deleted);
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -901,7 +1359,7 @@ pub mod maven {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
                         let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
@@ -911,9 +1369,15 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        if let Some(show_deleted) = &this.show_deleted {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("showDeleted", &show_deleted.to_string());
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -922,11 +1386,235 @@ pub mod maven {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::MavenPackageVersionDeletionState>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::MavenPackageVersionDeletionState>,
-            >;
+            type Output = azure_core::Result<models::Package>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::Package>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod update_package_version {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::PackageVersionDetails,
+            pub(crate) feed: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+    pub mod delete_package_version {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) feed: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+    pub mod get_package_version_from_recycle_bin {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::MavenPackageVersionDeletionState> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::MavenPackageVersionDeletionState = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) feed: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed , & this . group_id , & this . artifact_id , & this . version)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::MavenPackageVersionDeletionState>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::MavenPackageVersionDeletionState>,
+            >;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -984,9 +1672,10 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1042,9 +1731,10 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1103,9 +1793,10 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1139,722 +1830,1698 @@ pub mod maven {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) feed_id: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) file_name: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/packaging/feeds/{}/maven/{}/{}/{}/{}/content",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.feed_id,
+                            &this.group_id,
+                            &this.artifact_id,
+                            &this.version,
+                            &this.file_name
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<String>;
+            type IntoFuture = futures::future::BoxFuture<'static, azure_core::Result<String>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod update_package_versions {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::MavenPackagesBatchRequest,
+            pub(crate) feed_id: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/packaging/feeds/{}/maven/packagesbatch",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.feed_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+}
+pub mod npm {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version::RequestBuilder {
+            get_scoped_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_scoped_package::RequestBuilder {
+            update_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_scoped_package::RequestBuilder {
+            unpublish_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package::RequestBuilder {
+            update_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_package::RequestBuilder {
+            unpublish_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_upstreaming_behavior::RequestBuilder {
+            get_package_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_scoped_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_scoped_upstreaming_behavior::RequestBuilder {
+            set_scoped_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_scoped_package::RequestBuilder {
+            download_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_readme(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_readme::RequestBuilder {
+            get_scoped_package_readme::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
+            get_scoped_package_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_readme(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_readme::RequestBuilder {
+            get_package_readme::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_packages(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_packages::RequestBuilder {
+            update_packages::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
+            get_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+            restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+            delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            update_recycle_bin_packages::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version::RequestBuilder {
+            self.client.get_scoped_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_scoped_package(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_scoped_package::RequestBuilder {
+            self.client.update_scoped_package(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_scoped_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_scoped_package::RequestBuilder {
+            self.client.unpublish_scoped_package(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package::RequestBuilder {
+            self.client.update_package(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_package::RequestBuilder {
+            self.client.unpublish_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_upstreaming_behavior::RequestBuilder {
+            self.client.get_package_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                project,
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_scoped_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_scoped_upstreaming_behavior::RequestBuilder {
+            self.client.set_scoped_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                body,
+                project,
+            )
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_scoped_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_scoped_package::RequestBuilder {
+            self.client.download_scoped_package(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_readme(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_readme::RequestBuilder {
+            self.client.get_scoped_package_readme(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
+            self.client.get_scoped_package_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
+        }
+        #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_readme(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_readme::RequestBuilder {
+            self.client.get_package_readme(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_packages(
+            &self,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_packages::RequestBuilder {
+            self.client
+                .update_packages(self.organization.clone(), body, feed_id, project)
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_scoped_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed_id: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) version: String,
-            pub(crate) file_name: String,
-            pub(crate) project: String,
+        #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_scoped_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/packaging/feeds/{}/maven/{}/{}/{}/{}/content",
-                            this.client.endpoint(),
-                            &this.organization,
-                            &this.project,
-                            &this.feed_id,
-                            &this.group_id,
-                            &this.artifact_id,
-                            &this.version,
-                            &this.file_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
+        #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = futures::future::BoxFuture<'static, azure_core::Result<String>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-    }
-    pub mod update_package_versions {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::MavenPackagesBatchRequest,
-            pub(crate) feed_id: String,
-            pub(crate) project: String,
+        #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/packaging/feeds/{}/maven/packagesbatch",
-                            this.client.endpoint(),
-                            &this.organization,
-                            &this.project,
-                            &this.feed_id
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            self.client.update_recycle_bin_packages(
+                self.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
         }
     }
-}
-pub mod npm {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_version::RequestBuilder {
-            get_scoped_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_scoped_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_scoped_package(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_scoped_package::RequestBuilder {
-            update_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.update_scoped_package(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn unpublish_scoped_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> unpublish_scoped_package::RequestBuilder {
-            unpublish_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.unpublish_scoped_package(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_package(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_package::RequestBuilder {
-            update_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.update_package(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn unpublish_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> unpublish_package::RequestBuilder {
-            unpublish_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.unpublish_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_scope`: The scope of the package"]
         #[doc = "* `unscoped_package_name`: The name of the scoped package"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_upstreaming_behavior::RequestBuilder {
-            get_package_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                project: project.into(),
-            }
+            self.client.get_package_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                self.project.clone(),
+            )
         }
         #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_scope`: The scope of the package"]
         #[doc = "* `unscoped_package_name`: The name of the scoped package"]
         #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn set_scoped_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
-            project: impl Into<String>,
         ) -> set_scoped_upstreaming_behavior::RequestBuilder {
-            set_scoped_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client.set_scoped_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                body,
+                self.project.clone(),
+            )
         }
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn download_scoped_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> download_scoped_package::RequestBuilder {
-            download_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.download_scoped_package(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_readme(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_readme::RequestBuilder {
-            get_scoped_package_readme::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_scoped_package_readme(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
-            get_scoped_package_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                project: project.into(),
-            }
+            self.client.get_scoped_package_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                self.project.clone(),
+            )
         }
         #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
-            project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.project.clone(),
+            )
         }
         #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_readme(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_readme::RequestBuilder {
-            get_package_readme::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_package_readme(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_packages(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_packages::RequestBuilder {
-            update_packages::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.update_packages(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
-            get_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
-            restore_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.restore_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
-            delete_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_scoped_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_packages(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_packages::RequestBuilder {
-            update_recycle_bin_packages::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.update_recycle_bin_packages(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod get_scoped_package_version {
@@ -1944,9 +3611,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2056,9 +3724,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2168,9 +3837,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2277,9 +3947,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2387,9 +4058,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2497,9 +4169,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2607,9 +4280,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2687,9 +4361,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2723,6 +4398,25 @@ pub mod npm {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2777,9 +4471,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2824,6 +4519,25 @@ pub mod npm {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2878,9 +4592,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2985,9 +4700,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3063,9 +4779,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3099,6 +4816,25 @@ pub mod npm {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3160,9 +4896,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3207,6 +4944,25 @@ pub mod npm {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3268,9 +5024,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3341,9 +5098,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3434,9 +5192,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3507,9 +5266,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3565,9 +5325,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3656,9 +5417,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3728,9 +5490,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3785,9 +5548,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3846,9 +5610,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3857,285 +5622,754 @@ pub mod npm {
                 })
             }
         }
-    }
-}
-pub mod nu_get {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
-        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+    }
+}
+pub mod nu_get {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            get_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                show_deleted: None,
+            }
+        }
+        #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: New state to apply to the referenced package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to update."]
+        #[doc = "* `package_version`: Version of the package to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            update_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to delete."]
+        #[doc = "* `package_version`: Version of the package to delete."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            delete_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                source_protocol_version: None,
+            }
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            update_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `feed_id`: The name or id of the feed"]
-        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn get_upstreaming_behavior(
+        pub fn delete_package_version_from_recycle_bin(
             &self,
             organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
+            package_version: impl Into<String>,
             project: impl Into<String>,
-        ) -> get_upstreaming_behavior::RequestBuilder {
-            get_upstreaming_behavior::RequestBuilder {
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
                 feed_id: feed_id.into(),
                 package_name: package_name.into(),
+                package_version: package_version.into(),
                 project: project.into(),
             }
         }
-        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            update_recycle_bin_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
             project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
         }
         #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                show_deleted: None,
-            }
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New state to apply to the referenced package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to update."]
+        #[doc = "* `package_version`: Version of the package to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to delete."]
+        #[doc = "* `package_version`: Version of the package to delete."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client
+                .update_package_versions(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: New state to apply to the referenced package."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package to update."]
         #[doc = "* `package_version`: Version of the package to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_version(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_package_version::RequestBuilder {
-            update_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package to delete."]
         #[doc = "* `package_version`: Version of the package to delete."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version::RequestBuilder {
-            delete_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                source_protocol_version: None,
-            }
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_package_versions::RequestBuilder {
-            update_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.update_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
         #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_package_versions::RequestBuilder {
-            update_recycle_bin_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod get_upstreaming_behavior {
@@ -4222,9 +6456,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4300,9 +6535,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4403,9 +6639,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(show_deleted) = &this.show_deleted {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4486,9 +6723,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4583,9 +6821,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4631,6 +6870,25 @@ pub mod nu_get {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4693,9 +6951,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(source_protocol_version) = &this.source_protocol_version {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4721,7 +6980,177 @@ pub mod nu_get {
             }
         }
     }
-    pub mod update_package_versions {
+    pub mod update_package_versions {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::NuGetPackagesBatchRequest,
+            pub(crate) feed_id: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/packaging/feeds/{}/nuget/packagesbatch",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.feed_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+    pub mod get_package_version_from_recycle_bin {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::NuGetPackageVersionDeletionState> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::NuGetPackageVersionDeletionState = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) feed_id: String,
+            pub(crate) package_name: String,
+            pub(crate) package_version: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packages/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed_id , & this . package_name , & this . package_version)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::NuGetPackageVersionDeletionState>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::NuGetPackageVersionDeletionState>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod restore_package_version_from_recycle_bin {
         use super::models;
         pub struct Response(azure_core::Response);
         #[derive(Clone)]
@@ -4742,8 +7171,10 @@ pub mod nu_get {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::NuGetPackagesBatchRequest,
+            pub(crate) body: models::NuGetRecycleBinPackageVersionDetails,
             pub(crate) feed_id: String,
+            pub(crate) package_name: String,
+            pub(crate) package_version: String,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -4755,14 +7186,8 @@ pub mod nu_get {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/packaging/feeds/{}/nuget/packagesbatch",
-                            this.client.endpoint(),
-                            &this.organization,
-                            &this.project,
-                            &this.feed_id
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packages/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed_id , & this . package_name , & this . package_version)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -4771,9 +7196,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4783,44 +7209,9 @@ pub mod nu_get {
             }
         }
     }
-    pub mod get_package_version_from_recycle_bin {
+    pub mod delete_package_version_from_recycle_bin {
         use super::models;
         pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::NuGetPackageVersionDeletionState> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::NuGetPackageVersionDeletionState = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
-        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -4854,7 +7245,7 @@ pub mod nu_get {
                     let this = self.clone();
                     async move {
                         let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packages/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed_id , & this . package_name , & this . package_version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -4863,9 +7254,10 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4873,23 +7265,8 @@ pub mod nu_get {
                 })
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::NuGetPackageVersionDeletionState>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::NuGetPackageVersionDeletionState>,
-            >;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
-        }
     }
-    pub mod restore_package_version_from_recycle_bin {
+    pub mod update_recycle_bin_package_versions {
         use super::models;
         pub struct Response(azure_core::Response);
         #[derive(Clone)]
@@ -4910,243 +7287,403 @@ pub mod nu_get {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::NuGetRecycleBinPackageVersionDetails,
+            pub(crate) body: models::NuGetPackagesBatchRequest,
             pub(crate) feed_id: String,
-            pub(crate) package_name: String,
-            pub(crate) package_version: String,
             pub(crate) project: String,
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packages/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed_id , & this . package_name , & this . package_version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packagesBatch",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.feed_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+}
+pub mod python {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            get_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                show_deleted: None,
+            }
+        }
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            update_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            delete_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `file_name`: Name of the file in the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            file_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                file_name: file_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            update_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
             }
         }
-    }
-    pub mod delete_package_version_from_recycle_bin {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed_id: String,
-            pub(crate) package_name: String,
-            pub(crate) package_version: String,
-            pub(crate) project: String,
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packages/{}/versions/{}" , this . client . endpoint () , & this . organization , & this . project , & this . feed_id , & this . package_name , & this . package_version)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-    }
-    pub mod update_recycle_bin_package_versions {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::NuGetPackagesBatchRequest,
-            pub(crate) feed_id: String,
-            pub(crate) project: String,
+        #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packagesBatch",
-                            this.client.endpoint(),
-                            &this.organization,
-                            &this.project,
-                            &this.feed_id
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            update_recycle_bin_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
             }
         }
     }
-}
-pub mod python {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
         #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             project: impl Into<String>,
         ) -> get_upstreaming_behavior::RequestBuilder {
-            get_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                project: project.into(),
-            }
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
         }
         #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
             project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
         }
         #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                show_deleted: None,
-            }
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Details to be updated."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
@@ -5154,52 +7691,46 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_version(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_version::RequestBuilder {
-            update_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> delete_package_version::RequestBuilder {
-            delete_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
@@ -5207,74 +7738,61 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             file_name: impl Into<String>,
             project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                file_name: file_name.into(),
-                project: project.into(),
-            }
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                file_name,
+                project,
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiPackagesBatchRequest>,
             feed_id: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_versions::RequestBuilder {
-            update_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client
+                .update_package_versions(self.organization.clone(), body, feed_id, project)
         }
         #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
@@ -5282,69 +7800,290 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            self.client.get_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            self.client.set_upstreaming_behavior(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `file_name`: Name of the file in the package"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            self.client.download_package(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                file_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client.update_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
         #[doc = "* `feed_id`: Feed which contains the packages to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_package_versions::RequestBuilder {
-            update_recycle_bin_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod get_upstreaming_behavior {
@@ -5431,9 +8170,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5509,9 +8249,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5612,9 +8353,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(show_deleted) = &this.show_deleted {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5695,9 +8437,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5792,9 +8535,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5840,6 +8584,25 @@ pub mod python {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -5894,9 +8657,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5967,9 +8731,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6059,9 +8824,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6131,9 +8897,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6188,9 +8955,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6249,9 +9017,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6467,6 +9236,353 @@ pub mod universal {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Show information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update information for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client
+                .update_package_versions(self.organization.clone(), body, feed_id, project)
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to 'false' to restore the package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::UPackRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Show information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            self.client.get_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update information for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            self.client.update_package_version(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            self.client.delete_package_version(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            self.client.update_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            self.client.get_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to 'false' to restore the package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::UPackRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            self.client.restore_package_version_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            self.client.delete_package_version_from_recycle_bin(
+                self.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            self.client.update_recycle_bin_package_versions(
+                self.organization.clone(),
+                body,
+                feed_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_package_version {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6558,9 +9674,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(show_deleted) = &this.show_deleted {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6641,9 +9758,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6738,9 +9856,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6812,9 +9931,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6904,9 +10024,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6976,9 +10097,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7033,9 +10155,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7094,9 +10217,10 @@ pub mod universal {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://auditservice.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn actions_client(&self) -> actions::Client {
@@ -131,6 +200,87 @@ impl Client {
         streams::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn actions_client(&self) -> actions::OrganizationClient {
+        actions::OrganizationClient {
+            client: self.client.actions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn audit_log_client(&self) -> audit_log::OrganizationClient {
+        audit_log::OrganizationClient {
+            client: self.client.audit_log_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn download_log_client(&self) -> download_log::OrganizationClient {
+        download_log::OrganizationClient {
+            client: self.client.download_log_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn streams_client(&self) -> streams::OrganizationClient {
+        streams::OrganizationClient {
+            client: self.client.streams_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn actions_client(&self) -> actions::ProjectClient {
+        actions::ProjectClient {
+            client: self.client.actions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn audit_log_client(&self) -> audit_log::ProjectClient {
+        audit_log::ProjectClient {
+            client: self.client.audit_log_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn download_log_client(&self) -> download_log::ProjectClient {
+        download_log::ProjectClient {
+            client: self.client.download_log_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn streams_client(&self) -> streams::ProjectClient {
+        streams::ProjectClient {
+            client: self.client.streams_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod actions {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -147,6 +297,31 @@ pub mod actions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all auditable actions filterable by area."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all auditable actions filterable by area."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -231,9 +406,10 @@ pub mod actions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(area_name) = &this.area_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -283,6 +459,31 @@ pub mod audit_log {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Queries audit log entries"]
+        pub fn query(&self) -> query::RequestBuilder {
+            self.client.query(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Queries audit log entries"]
+        pub fn query(&self) -> query::RequestBuilder {
+            self.client.query(self.organization.clone())
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -391,9 +592,10 @@ pub mod audit_log {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(start_time) = &this.start_time {
                             let formatted_date_time =
                                 crate::date_time::format_date_time(start_time)?;
@@ -428,6 +630,24 @@ pub mod audit_log {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::AuditLogQueryResult>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::AuditLogQueryResult>;
@@ -469,6 +689,37 @@ pub mod download_log {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Downloads audit log entries."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `format`: File format for download. Can be \"json\" or \"csv\"."]
+        pub fn download_log(&self, format: impl Into<String>) -> download_log::RequestBuilder {
+            self.client.download_log(self.organization.clone(), format)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Downloads audit log entries."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `format`: File format for download. Can be \"json\" or \"csv\"."]
+        pub fn download_log(&self, format: impl Into<String>) -> download_log::RequestBuilder {
+            self.client.download_log(self.organization.clone(), format)
+        }
+    }
     pub mod download_log {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -493,6 +744,25 @@ pub mod download_log {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -559,9 +829,10 @@ pub mod download_log {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let format = &this.format;
                         req.url_mut()
                             .query_pairs_mut()
@@ -704,6 +975,133 @@ pub mod streams {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Return all Audit Streams scoped to an organization"]
+        pub fn query_all_streams(&self) -> query_all_streams::RequestBuilder {
+            self.client.query_all_streams(self.organization.clone())
+        }
+        #[doc = "Create new Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        #[doc = "* `days_to_backfill`: The number of days of previously recorded audit data that will be replayed into the stream. A value of zero will result in only new events being streamed."]
+        pub fn create(
+            &self,
+            body: impl Into<models::AuditStream>,
+            days_to_backfill: i32,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, days_to_backfill)
+        }
+        #[doc = "Update existing Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        pub fn update_stream(
+            &self,
+            body: impl Into<models::AuditStream>,
+        ) -> update_stream::RequestBuilder {
+            self.client.update_stream(self.organization.clone(), body)
+        }
+        #[doc = "Return Audit Stream with id of streamId if one exists otherwise throw"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to retrieve"]
+        pub fn query_stream_by_id(&self, stream_id: i32) -> query_stream_by_id::RequestBuilder {
+            self.client
+                .query_stream_by_id(self.organization.clone(), stream_id)
+        }
+        #[doc = "Update existing Audit Stream status"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to be updated"]
+        #[doc = "* `status`: Status of the stream"]
+        pub fn update_status(
+            &self,
+            stream_id: i32,
+            status: impl Into<String>,
+        ) -> update_status::RequestBuilder {
+            self.client
+                .update_status(self.organization.clone(), stream_id, status)
+        }
+        #[doc = "Delete Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to delete"]
+        pub fn delete(&self, stream_id: i32) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), stream_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Return all Audit Streams scoped to an organization"]
+        pub fn query_all_streams(&self) -> query_all_streams::RequestBuilder {
+            self.client.query_all_streams(self.organization.clone())
+        }
+        #[doc = "Create new Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        #[doc = "* `days_to_backfill`: The number of days of previously recorded audit data that will be replayed into the stream. A value of zero will result in only new events being streamed."]
+        pub fn create(
+            &self,
+            body: impl Into<models::AuditStream>,
+            days_to_backfill: i32,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, days_to_backfill)
+        }
+        #[doc = "Update existing Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        pub fn update_stream(
+            &self,
+            body: impl Into<models::AuditStream>,
+        ) -> update_stream::RequestBuilder {
+            self.client.update_stream(self.organization.clone(), body)
+        }
+        #[doc = "Return Audit Stream with id of streamId if one exists otherwise throw"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to retrieve"]
+        pub fn query_stream_by_id(&self, stream_id: i32) -> query_stream_by_id::RequestBuilder {
+            self.client
+                .query_stream_by_id(self.organization.clone(), stream_id)
+        }
+        #[doc = "Update existing Audit Stream status"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to be updated"]
+        #[doc = "* `status`: Status of the stream"]
+        pub fn update_status(
+            &self,
+            stream_id: i32,
+            status: impl Into<String>,
+        ) -> update_status::RequestBuilder {
+            self.client
+                .update_status(self.organization.clone(), stream_id, status)
+        }
+        #[doc = "Delete Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to delete"]
+        pub fn delete(&self, stream_id: i32) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), stream_id)
+        }
+    }
     pub mod query_all_streams {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -782,9 +1180,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -885,9 +1284,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let days_to_backfill = &this.days_to_backfill;
@@ -992,9 +1392,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1096,9 +1497,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1200,9 +1602,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let status = &this.status;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1275,9 +1678,10 @@ pub mod streams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5,21 +5,39 @@
 //!
 //! For more background information on Azure DevOps authentication see: [Azure DevOps authentication](https://docs.microsoft.com/en-us/azure/devops/integrate/get-started/authentication/authentication-guidance)
 
+use crate::Secret;
 use azure_core::auth::TokenCredential;
-use azure_core::error::{Result, ResultExt};
+use azure_core::error::{Error, ErrorKind, Result, ResultExt};
 use base64::{prelude::BASE64_STANDARD, Engine};
+use std::io::Write;
+use std::process::{Command, Stdio};
 use std::sync::Arc;
+use time::OffsetDateTime;
+use zeroize::Zeroizing;
+
+/// Environment variables checked by [`Credential::from_env()`], in order of precedence.
+const CREDENTIAL_ENV_VARS: &[&str] = &["SYSTEM_ACCESSTOKEN", "AZURE_DEVOPS_EXT_PAT", "ADO_TOKEN"];
 
 /// A credential for authenticating with Azure DevOps.
 ///
 /// Supports:
 /// - [Azure DevOps Personal Access Token (PAT)](https://docs.microsoft.com/en-us/azure/devops/organizations/accounts/use-personal-access-tokens-to-authenticate).
 /// - OAuth token credential obtained via the [`azure_identity`](https://crates.io/crates/azure_identity) crate.
+/// - OAuth/Microsoft Entra access token (bearer token), with an optional expiry time.
+///
+/// Secrets are held in a [`Secret`], which is zeroed when dropped, and are not included in the `Debug` output.
+///
+/// [`Credential::from_env()`] and [`Credential::from_git_credential_helper()`] discover
+/// a credential from the environment, so the same code works on developer machines and in Azure Pipelines.
 #[derive(Clone)]
 pub enum Credential {
     Unauthenticated,
-    Pat(String),
+    Pat(Secret),
     TokenCredential(Arc<dyn TokenCredential>),
+    BearerToken {
+        token: Secret,
+        expires_on: Option<OffsetDateTime>,
+    },
 }
 
 impl Credential {
@@ -30,8 +48,7 @@ impl Credential {
 
     /// Creates a new `Credential` using the supplied PAT token.
     pub fn from_pat(pat: impl Into<String>) -> Self {
-        let pat = pat.into();
-        Credential::Pat(pat)
+        Credential::Pat(Secret::new(pat))
     }
 
     /// Creates a new `Credential` using the supplied object that implements [`TokenCredential`](https://docs.rs/azure_core/latest/azure_core/auth/trait.TokenCredential.html).
@@ -45,6 +62,112 @@ impl Credential {
         Credential::TokenCredential(token_credential)
     }
 
+    /// Creates a new `Credential` using the supplied OAuth/Microsoft Entra access token.
+    pub fn from_bearer_token(token: impl Into<String>) -> Self {
+        Credential::BearerToken {
+            token: Secret::new(token),
+            expires_on: None,
+        }
+    }
+
+    /// Creates a new `Credential` using the supplied OAuth/Microsoft Entra access token,
+    /// which expires at `expires_on`.
+    ///
+    /// Requests fail with an error once the token has expired.
+    pub fn from_bearer_token_with_expiry(
+        token: impl Into<String>,
+        expires_on: OffsetDateTime,
+    ) -> Self {
+        Credential::BearerToken {
+            token: Secret::new(token),
+            expires_on: Some(expires_on),
+        }
+    }
+
+    /// Creates a new `Credential` from the first of these environment variables that is set:
+    ///
+    /// - `SYSTEM_ACCESSTOKEN`: the OAuth token of an Azure Pipelines job (which must be
+    ///   mapped into the environment of the step), used as a bearer token.
+    /// - `AZURE_DEVOPS_EXT_PAT`: the PAT used by the Azure DevOps CLI extension.
+    /// - `ADO_TOKEN`: a PAT.
+    ///
+    /// Returns `None` if none of the environment variables are set.
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars<F>(var: F) -> Option<Self>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        CREDENTIAL_ENV_VARS.iter().find_map(|&name| {
+            let value = var(name)?;
+            let value = value.trim();
+            // Pipelines pass unset variables through as the unexpanded macro, e.g. `$(System.AccessToken)`.
+            if value.is_empty() || value.starts_with("$(") {
+                return None;
+            }
+            Some(if name == "SYSTEM_ACCESSTOKEN" {
+                Credential::from_bearer_token(value)
+            } else {
+                Credential::from_pat(value)
+            })
+        })
+    }
+
+    /// Creates a new `Credential` using the password returned by the git credential helper for `url`,
+    /// e.g. `https://dev.azure.com/{organization}`.
+    ///
+    /// This runs `git credential fill`, so uses the credentials that git uses for Azure Repos,
+    /// e.g. those stored by Git Credential Manager. Interactive prompts are disabled.
+    /// The password (a PAT or access token) is passed using Basic authentication.
+    pub fn from_git_credential_helper(url: &str) -> Result<Self> {
+        let url = azure_core::Url::parse(url)
+            .with_context(ErrorKind::Credential, || format!("Invalid URL: {url}"))?;
+        let mut input = format!(
+            "protocol={}\nhost={}\n",
+            url.scheme(),
+            url.host_str().unwrap_or_default()
+        );
+        let path = url.path().trim_matches('/');
+        if !path.is_empty() {
+            input.push_str(&format!("path={path}\n"));
+        }
+        input.push('\n');
+
+        let mut child = Command::new("git")
+            .args(["credential", "fill"])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .context(ErrorKind::Credential, "Failed to run git credential helper")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .context(ErrorKind::Credential, "Failed to run git credential helper")?;
+        }
+        let output = child
+            .wait_with_output()
+            .context(ErrorKind::Credential, "Failed to run git credential helper")?;
+        if !output.status.success() {
+            return Err(Error::with_message(ErrorKind::Credential, || {
+                format!("git credential helper returned no credential for {url}")
+            }));
+        }
+        let stdout = Zeroizing::new(output.stdout);
+        let password = git_credential_password(&Zeroizing::new(
+            String::from_utf8_lossy(&stdout).into_owned(),
+        ))
+        .ok_or_else(|| {
+            Error::with_message(ErrorKind::Credential, || {
+                format!("git credential helper returned no password for {url}")
+            })
+        })?;
+        Ok(Credential::Pat(password))
+    }
+
     /// Returns the HTTP authorization header value containing the credential.
     #[allow(dead_code)]
     pub(crate) async fn http_authorization_header(
@@ -54,10 +177,13 @@ impl Credential {
         match self {
             Credential::Unauthenticated => Ok(None),
             // PAT tokens are passed using Basic authentication.
-            Credential::Pat(pat) => Ok(Some(format!(
-                "Basic {}",
-                BASE64_STANDARD.encode(format!(":{}", &pat))
-            ))),
+            Credential::Pat(pat) => {
+                let user_pass = Zeroizing::new(format!(":{}", pat.expose_secret()));
+                Ok(Some(format!(
+                    "Basic {}",
+                    BASE64_STANDARD.encode(&*user_pass)
+                )))
+            }
             // OAuth tokens are passed using Bearer authentication.
             Credential::TokenCredential(token_credential) => {
                 let token_response = token_credential
@@ -66,6 +192,104 @@ impl Credential {
                     .context(azure_core::error::ErrorKind::Other, "get bearer token")?;
                 Ok(Some(format!("Bearer {}", token_response.token.secret())))
             }
+            Credential::BearerToken { token, expires_on } => {
+                if let Some(expires_on) = expires_on {
+                    if *expires_on <= OffsetDateTime::now_utc() {
+                        return Err(Error::with_message(ErrorKind::Credential, || {
+                            format!("Bearer token expired at {expires_on}")
+                        }));
+                    }
+                }
+                Ok(Some(format!("Bearer {}", token.expose_secret())))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Credential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Credential::Unauthenticated => f.write_str("Unauthenticated"),
+            Credential::Pat(pat) => f.debug_tuple("Pat").field(pat).finish(),
+            Credential::TokenCredential(_) => f.write_str("TokenCredential"),
+            Credential::BearerToken { token, expires_on } => f
+                .debug_struct("BearerToken")
+                .field("token", token)
+                .field("expires_on", expires_on)
+                .finish(),
         }
     }
 }
+
+/// Returns the password from the output of `git credential fill`.
+fn git_credential_password(output: &str) -> Option<Secret> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .filter(|password| !password.is_empty())
+        .map(Secret::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vars() {
+        let vars = |values: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                values
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_owned())
+            }
+        };
+        let credential = Credential::from_vars(vars(&[
+            ("ADO_TOKEN", "pat"),
+            ("SYSTEM_ACCESSTOKEN", "oauth"),
+        ]));
+        assert!(
+            matches!(credential, Some(Credential::BearerToken { token, expires_on: None }) if token.expose_secret() == "oauth")
+        );
+        let credential = Credential::from_vars(vars(&[
+            ("SYSTEM_ACCESSTOKEN", "$(System.AccessToken)"),
+            ("AZURE_DEVOPS_EXT_PAT", "ext-pat"),
+            ("ADO_TOKEN", "pat"),
+        ]));
+        assert!(
+            matches!(credential, Some(Credential::Pat(pat)) if pat.expose_secret() == "ext-pat")
+        );
+        assert!(Credential::from_vars(vars(&[("ADO_TOKEN", " ")])).is_none());
+    }
+
+    #[test]
+    fn test_git_credential_password() {
+        let output = "protocol=https\nhost=dev.azure.com\nusername=user\npassword=secret\n";
+        assert_eq!(
+            git_credential_password(output)
+                .as_ref()
+                .map(Secret::expose_secret),
+            Some("secret")
+        );
+        assert!(git_credential_password("protocol=https\n").is_none());
+    }
+
+    #[test]
+    fn test_bearer_token_expiry() {
+        let expired = Credential::from_bearer_token_with_expiry(
+            "token",
+            OffsetDateTime::now_utc() - time::Duration::minutes(5),
+        );
+        let valid = Credential::from_bearer_token_with_expiry(
+            "token",
+            OffsetDateTime::now_utc() + time::Duration::hours(1),
+        );
+        futures::executor::block_on(async {
+            assert!(expired.http_authorization_header(&[]).await.is_err());
+            assert_eq!(
+                valid.http_authorization_header(&[]).await.unwrap(),
+                Some("Bearer token".to_owned())
+            );
+        });
+    }
+}
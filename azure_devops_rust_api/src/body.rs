@@ -0,0 +1,92 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Support for non-JSON response bodies.
+//!
+//! Operations that download files, zips or logs return binary or text bodies.
+//! The generated `Response` types for these operations provide `into_bytes()`,
+//! `into_text()`, `into_stream()` and `write_to()` methods, which use the helpers
+//! in this module.
+
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::ResponseBody;
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::StreamExt;
+
+/// Collects a response body into a UTF-8 string.
+pub(crate) async fn into_text(body: ResponseBody) -> azure_core::Result<String> {
+    let bytes = body.collect().await?;
+    String::from_utf8(bytes.to_vec()).map_err(|e| {
+        Error::full(
+            ErrorKind::DataConversion,
+            e,
+            "Failed to convert response body to UTF-8 text",
+        )
+    })
+}
+
+/// Writes a response body to `writer` one chunk at a time, returning the number of bytes written.
+pub(crate) async fn write_to<W>(mut body: ResponseBody, writer: &mut W) -> azure_core::Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written: u64 = 0;
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        writer
+            .write_all(&chunk)
+            .await
+            .context(ErrorKind::Io, "Failed to write response body")?;
+        written += chunk.len() as u64;
+    }
+    writer
+        .flush()
+        .await
+        .context(ErrorKind::Io, "Failed to flush response body")?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::headers::Headers;
+    use azure_core::{Response, StatusCode};
+    use bytes::Bytes;
+
+    fn body(chunks: &[&'static [u8]]) -> ResponseBody {
+        let chunks: Vec<azure_core::Result<Bytes>> = chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk)))
+            .collect();
+        Response::new(
+            StatusCode::Ok,
+            Headers::new(),
+            Box::pin(futures::stream::iter(chunks)),
+        )
+        .into_body()
+    }
+
+    #[test]
+    fn test_into_text() {
+        // A multi-byte character split across chunks.
+        let text =
+            futures::executor::block_on(into_text(body(&[b"build \xe2\x9c", b"\x93 passed"])))
+                .unwrap();
+        assert_eq!(text, "build \u{2713} passed");
+
+        let error = futures::executor::block_on(into_text(body(&[b"\xff\xfe"]))).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[test]
+    fn test_write_to() {
+        let mut writer = Vec::new();
+        let written = futures::executor::block_on(write_to(
+            body(&[b"PK\x03\x04", b"", b"zip content"]),
+            &mut writer,
+        ))
+        .unwrap();
+        assert_eq!(written, 15);
+        assert_eq!(writer, b"PK\x03\x04zip content");
+    }
+}
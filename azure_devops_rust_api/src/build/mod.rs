@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn artifacts_client(&self) -> artifacts::Client {
@@ -200,6 +269,386 @@ impl Client {
         yaml::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn artifacts_client(&self) -> artifacts::OrganizationClient {
+        artifacts::OrganizationClient {
+            client: self.client.artifacts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn authorizedresources_client(&self) -> authorizedresources::OrganizationClient {
+        authorizedresources::OrganizationClient {
+            client: self.client.authorizedresources_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn badge_client(&self) -> badge::OrganizationClient {
+        badge::OrganizationClient {
+            client: self.client.badge_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn builds_client(&self) -> builds::OrganizationClient {
+        builds::OrganizationClient {
+            client: self.client.builds_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn controllers_client(&self) -> controllers::OrganizationClient {
+        controllers::OrganizationClient {
+            client: self.client.controllers_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn definitions_client(&self) -> definitions::OrganizationClient {
+        definitions::OrganizationClient {
+            client: self.client.definitions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn folders_client(&self) -> folders::OrganizationClient {
+        folders::OrganizationClient {
+            client: self.client.folders_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn general_settings_client(&self) -> general_settings::OrganizationClient {
+        general_settings::OrganizationClient {
+            client: self.client.general_settings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn history_client(&self) -> history::OrganizationClient {
+        history::OrganizationClient {
+            client: self.client.history_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn latest_client(&self) -> latest::OrganizationClient {
+        latest::OrganizationClient {
+            client: self.client.latest_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn leases_client(&self) -> leases::OrganizationClient {
+        leases::OrganizationClient {
+            client: self.client.leases_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn metrics_client(&self) -> metrics::OrganizationClient {
+        metrics::OrganizationClient {
+            client: self.client.metrics_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn options_client(&self) -> options::OrganizationClient {
+        options::OrganizationClient {
+            client: self.client.options_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn properties_client(&self) -> properties::OrganizationClient {
+        properties::OrganizationClient {
+            client: self.client.properties_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn report_client(&self) -> report::OrganizationClient {
+        report::OrganizationClient {
+            client: self.client.report_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn resource_usage_client(&self) -> resource_usage::OrganizationClient {
+        resource_usage::OrganizationClient {
+            client: self.client.resource_usage_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn resources_client(&self) -> resources::OrganizationClient {
+        resources::OrganizationClient {
+            client: self.client.resources_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn retention_client(&self) -> retention::OrganizationClient {
+        retention::OrganizationClient {
+            client: self.client.retention_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn settings_client(&self) -> settings::OrganizationClient {
+        settings::OrganizationClient {
+            client: self.client.settings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn source_providers_client(&self) -> source_providers::OrganizationClient {
+        source_providers::OrganizationClient {
+            client: self.client.source_providers_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn stages_client(&self) -> stages::OrganizationClient {
+        stages::OrganizationClient {
+            client: self.client.stages_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn status_client(&self) -> status::OrganizationClient {
+        status::OrganizationClient {
+            client: self.client.status_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn tags_client(&self) -> tags::OrganizationClient {
+        tags::OrganizationClient {
+            client: self.client.tags_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn templates_client(&self) -> templates::OrganizationClient {
+        templates::OrganizationClient {
+            client: self.client.templates_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn timeline_client(&self) -> timeline::OrganizationClient {
+        timeline::OrganizationClient {
+            client: self.client.timeline_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn yaml_client(&self) -> yaml::OrganizationClient {
+        yaml::OrganizationClient {
+            client: self.client.yaml_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifacts_client(&self) -> artifacts::ProjectClient {
+        artifacts::ProjectClient {
+            client: self.client.artifacts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn authorizedresources_client(&self) -> authorizedresources::ProjectClient {
+        authorizedresources::ProjectClient {
+            client: self.client.authorizedresources_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn badge_client(&self) -> badge::ProjectClient {
+        badge::ProjectClient {
+            client: self.client.badge_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn builds_client(&self) -> builds::ProjectClient {
+        builds::ProjectClient {
+            client: self.client.builds_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn controllers_client(&self) -> controllers::ProjectClient {
+        controllers::ProjectClient {
+            client: self.client.controllers_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn definitions_client(&self) -> definitions::ProjectClient {
+        definitions::ProjectClient {
+            client: self.client.definitions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn folders_client(&self) -> folders::ProjectClient {
+        folders::ProjectClient {
+            client: self.client.folders_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn general_settings_client(&self) -> general_settings::ProjectClient {
+        general_settings::ProjectClient {
+            client: self.client.general_settings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn history_client(&self) -> history::ProjectClient {
+        history::ProjectClient {
+            client: self.client.history_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn latest_client(&self) -> latest::ProjectClient {
+        latest::ProjectClient {
+            client: self.client.latest_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn leases_client(&self) -> leases::ProjectClient {
+        leases::ProjectClient {
+            client: self.client.leases_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn metrics_client(&self) -> metrics::ProjectClient {
+        metrics::ProjectClient {
+            client: self.client.metrics_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn options_client(&self) -> options::ProjectClient {
+        options::ProjectClient {
+            client: self.client.options_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn properties_client(&self) -> properties::ProjectClient {
+        properties::ProjectClient {
+            client: self.client.properties_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn report_client(&self) -> report::ProjectClient {
+        report::ProjectClient {
+            client: self.client.report_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn resource_usage_client(&self) -> resource_usage::ProjectClient {
+        resource_usage::ProjectClient {
+            client: self.client.resource_usage_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn resources_client(&self) -> resources::ProjectClient {
+        resources::ProjectClient {
+            client: self.client.resources_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn retention_client(&self) -> retention::ProjectClient {
+        retention::ProjectClient {
+            client: self.client.retention_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn settings_client(&self) -> settings::ProjectClient {
+        settings::ProjectClient {
+            client: self.client.settings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn source_providers_client(&self) -> source_providers::ProjectClient {
+        source_providers::ProjectClient {
+            client: self.client.source_providers_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn stages_client(&self) -> stages::ProjectClient {
+        stages::ProjectClient {
+            client: self.client.stages_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn status_client(&self) -> status::ProjectClient {
+        status::ProjectClient {
+            client: self.client.status_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn tags_client(&self) -> tags::ProjectClient {
+        tags::ProjectClient {
+            client: self.client.tags_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn templates_client(&self) -> templates::ProjectClient {
+        templates::ProjectClient {
+            client: self.client.templates_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn timeline_client(&self) -> timeline::ProjectClient {
+        timeline::ProjectClient {
+            client: self.client.timeline_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn yaml_client(&self) -> yaml::ProjectClient {
+        yaml::ProjectClient {
+            client: self.client.yaml_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod artifacts {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -296,6 +745,152 @@ pub mod artifacts {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a specific artifact for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        pub fn get_artifact(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get_artifact::RequestBuilder {
+            self.client
+                .get_artifact(self.organization.clone(), project, build_id, artifact_name)
+        }
+        #[doc = "Gets a file from the build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        #[doc = "* `file_id`: The primary key for the file."]
+        #[doc = "* `file_name`: The name that the file will be set to."]
+        pub fn get_file(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+            file_id: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> get_file::RequestBuilder {
+            self.client.get_file(
+                self.organization.clone(),
+                project,
+                build_id,
+                artifact_name,
+                file_id,
+                file_name,
+            )
+        }
+        #[doc = "Gets all artifacts for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn list(&self, project: impl Into<String>, build_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Associates an artifact with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The artifact."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildArtifact>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, build_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a specific artifact for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        pub fn get_artifact(
+            &self,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get_artifact::RequestBuilder {
+            self.client.get_artifact(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                artifact_name,
+            )
+        }
+        #[doc = "Gets a file from the build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        #[doc = "* `file_id`: The primary key for the file."]
+        #[doc = "* `file_name`: The name that the file will be set to."]
+        pub fn get_file(
+            &self,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+            file_id: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> get_file::RequestBuilder {
+            self.client.get_file(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                artifact_name,
+                file_id,
+                file_name,
+            )
+        }
+        #[doc = "Gets all artifacts for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn list(&self, build_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Associates an artifact with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The artifact."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildArtifact>,
+            build_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get_artifact {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -379,9 +974,10 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let artifact_name = &this.artifact_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -431,6 +1027,25 @@ pub mod artifacts {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -485,9 +1100,10 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let artifact_name = &this.artifact_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -602,9 +1218,10 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -708,9 +1325,10 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -874,9 +1492,183 @@ pub mod leases {
             }
         }
     }
-    pub mod get_retention_leases_by_user_id {
-        use super::models;
-        pub struct Response(azure_core::Response);
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns any leases owned by the specified user, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `user_owner_id`: The user id to search for."]
+        pub fn get_retention_leases_by_user_id(
+            &self,
+            project: impl Into<String>,
+            user_owner_id: impl Into<String>,
+        ) -> get_retention_leases_by_user_id::RequestBuilder {
+            self.client.get_retention_leases_by_user_id(
+                self.organization.clone(),
+                project,
+                user_owner_id,
+            )
+        }
+        #[doc = "Returns any leases owned by the specified entity, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_retention_leases_by_owner_id(
+            &self,
+            project: impl Into<String>,
+        ) -> get_retention_leases_by_owner_id::RequestBuilder {
+            self.client
+                .get_retention_leases_by_owner_id(self.organization.clone(), project)
+        }
+        #[doc = "Returns any leases matching the specified MinimalRetentionLeases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `leases_to_fetch`: List of JSON-serialized MinimalRetentionLeases separated by '|'"]
+        pub fn get_retention_leases_by_minimal_retention_leases(
+            &self,
+            project: impl Into<String>,
+            leases_to_fetch: impl Into<String>,
+        ) -> get_retention_leases_by_minimal_retention_leases::RequestBuilder {
+            self.client
+                .get_retention_leases_by_minimal_retention_leases(
+                    self.organization.clone(),
+                    project,
+                    leases_to_fetch,
+                )
+        }
+        #[doc = "Adds new leases for pipeline runs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: Vec<models::NewRetentionLease>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, project)
+        }
+        #[doc = "Removes specific retention leases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project, ids)
+        }
+        #[doc = "Returns the details of the retention lease given a lease id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, lease_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, lease_id)
+        }
+        #[doc = "Updates the duration or pipeline protection status of a retention lease."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new data for the retention lease."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `lease_id`: The ID of the lease to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RetentionLeaseUpdate>,
+            project: impl Into<String>,
+            lease_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, lease_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns any leases owned by the specified user, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_owner_id`: The user id to search for."]
+        pub fn get_retention_leases_by_user_id(
+            &self,
+            user_owner_id: impl Into<String>,
+        ) -> get_retention_leases_by_user_id::RequestBuilder {
+            self.client.get_retention_leases_by_user_id(
+                self.organization.clone(),
+                self.project.clone(),
+                user_owner_id,
+            )
+        }
+        #[doc = "Returns any leases owned by the specified entity, optionally scoped to a single pipeline definition and run."]
+        pub fn get_retention_leases_by_owner_id(
+            &self,
+        ) -> get_retention_leases_by_owner_id::RequestBuilder {
+            self.client
+                .get_retention_leases_by_owner_id(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Returns any leases matching the specified MinimalRetentionLeases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `leases_to_fetch`: List of JSON-serialized MinimalRetentionLeases separated by '|'"]
+        pub fn get_retention_leases_by_minimal_retention_leases(
+            &self,
+            leases_to_fetch: impl Into<String>,
+        ) -> get_retention_leases_by_minimal_retention_leases::RequestBuilder {
+            self.client
+                .get_retention_leases_by_minimal_retention_leases(
+                    self.organization.clone(),
+                    self.project.clone(),
+                    leases_to_fetch,
+                )
+        }
+        #[doc = "Adds new leases for pipeline runs."]
+        pub fn add(&self, body: Vec<models::NewRetentionLease>) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Removes specific retention leases."]
+        pub fn delete(&self, ids: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), ids)
+        }
+        #[doc = "Returns the details of the retention lease given a lease id."]
+        pub fn get(&self, lease_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), lease_id)
+        }
+        #[doc = "Updates the duration or pipeline protection status of a retention lease."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new data for the retention lease."]
+        #[doc = "* `lease_id`: The ID of the lease to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RetentionLeaseUpdate>,
+            lease_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                lease_id,
+            )
+        }
+    }
+    pub mod get_retention_leases_by_user_id {
+        use super::models;
+        pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::RetentionLeaseList> {
                 let bytes = self.0.into_body().collect().await?;
@@ -968,9 +1760,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let user_owner_id = &this.user_owner_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1103,9 +1896,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(owner_id) = &this.owner_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1223,9 +2017,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let leases_to_fetch = &this.leases_to_fetch;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1332,9 +2127,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1405,9 +2201,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         let req_body = azure_core::EMPTY_BODY;
@@ -1499,9 +2296,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1605,9 +2403,10 @@ pub mod leases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1662,6 +2461,39 @@ pub mod controllers {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets controller, optionally filtered by name"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Gets a controller"]
+        pub fn get(&self, controller_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), controller_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets controller, optionally filtered by name"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Gets a controller"]
+        pub fn get(&self, controller_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), controller_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1745,9 +2577,10 @@ pub mod controllers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(name) = &this.name {
                             req.url_mut().query_pairs_mut().append_pair("name", name);
                         }
@@ -1854,9 +2687,10 @@ pub mod controllers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1894,6 +2728,31 @@ pub mod resource_usage {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets information about build resources in the system."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets information about build resources in the system."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1972,9 +2831,10 @@ pub mod resource_usage {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2013,6 +2873,31 @@ pub mod history {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the retention history for the project collection. This includes pipelines that have custom retention rules that may prevent the retention job from cleaning them up, runs per pipeline with retention type, files associated with pipelines owned by the collection with retention type, and the number of files per pipeline."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the retention history for the project collection. This includes pipelines that have custom retention rules that may prevent the retention job from cleaning them up, runs per pipeline with retention type, files associated with pipelines owned by the collection with retention type, and the number of files per pipeline."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2096,9 +2981,10 @@ pub mod history {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(days_to_lookback) = &this.days_to_lookback {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2174,6 +3060,70 @@ pub mod badge {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "This endpoint is deprecated. Please see the Build Status REST endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: The project ID or name."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Gets a badge that indicates the status of the most recent build for the specified branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repo_type`: The repository type."]
+        pub fn get_build_badge_data(
+            &self,
+            project: impl Into<String>,
+            repo_type: impl Into<String>,
+        ) -> get_build_badge_data::RequestBuilder {
+            self.client
+                .get_build_badge_data(self.organization.clone(), project, repo_type)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "This endpoint is deprecated. Please see the Build Status REST endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Gets a badge that indicates the status of the most recent build for the specified branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repo_type`: The repository type."]
+        pub fn get_build_badge_data(
+            &self,
+            repo_type: impl Into<String>,
+        ) -> get_build_badge_data::RequestBuilder {
+            self.client.get_build_badge_data(
+                self.organization.clone(),
+                self.project.clone(),
+                repo_type,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2198,6 +3148,25 @@ pub mod badge {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2261,9 +3230,10 @@ pub mod badge {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2313,6 +3283,25 @@ pub mod badge {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2382,9 +3371,10 @@ pub mod badge {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(repo_id) = &this.repo_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2455,6 +3445,54 @@ pub mod authorizedresources {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn authorize_project_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            project: impl Into<String>,
+        ) -> authorize_project_resources::RequestBuilder {
+            self.client
+                .authorize_project_resources(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        pub fn authorize_project_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+        ) -> authorize_project_resources::RequestBuilder {
+            self.client.authorize_project_resources(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2547,9 +3585,10 @@ pub mod authorizedresources {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -2662,9 +3701,10 @@ pub mod authorizedresources {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2714,9 +3754,9 @@ pub mod builds {
                 min_time: None,
                 max_time: None,
                 requested_for: None,
-                reason_filter: None,
+                reason_filter: Vec::new(),
                 status_filter: None,
-                result_filter: None,
+                result_filter: Vec::new(),
                 tag_filters: None,
                 properties: None,
                 top: None,
@@ -3001,6 +4041,342 @@ pub mod builds {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a list of builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Queues a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn queue(
+            &self,
+            body: impl Into<models::Build>,
+            project: impl Into<String>,
+        ) -> queue::RequestBuilder {
+            self.client.queue(self.organization.clone(), body, project)
+        }
+        #[doc = "Updates multiple builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The builds to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_builds(
+            &self,
+            body: Vec<models::Build>,
+            project: impl Into<String>,
+        ) -> update_builds::RequestBuilder {
+            self.client
+                .update_builds(self.organization.clone(), body, project)
+        }
+        #[doc = "Gets a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Updates a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The build."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build(
+            &self,
+            body: impl Into<models::Build>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build::RequestBuilder {
+            self.client
+                .update_build(self.organization.clone(), body, project, build_id)
+        }
+        #[doc = "Deletes a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn delete(&self, project: impl Into<String>, build_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets the changes associated with a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_build_changes(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_changes::RequestBuilder {
+            self.client
+                .get_build_changes(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets all retention leases that apply to a specific build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_retention_leases_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_retention_leases_for_build::RequestBuilder {
+            self.client
+                .get_retention_leases_for_build(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets the logs for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_logs(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_logs::RequestBuilder {
+            self.client
+                .get_build_logs(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets an individual log file for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `log_id`: The ID of the log file."]
+        pub fn get_build_log(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            log_id: i32,
+        ) -> get_build_log::RequestBuilder {
+            self.client
+                .get_build_log(self.organization.clone(), project, build_id, log_id)
+        }
+        #[doc = "Gets the work items associated with a build. Only work items in the same project are returned."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs::RequestBuilder {
+            self.client
+                .get_build_work_items_refs(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets the work items associated with a build, filtered to specific commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A comma-delimited list of commit IDs."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs_from_commits(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs_from_commits::RequestBuilder {
+            self.client.get_build_work_items_refs_from_commits(
+                self.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets the changes made to the repository between two given builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changes_between_builds(
+            &self,
+            project: impl Into<String>,
+        ) -> get_changes_between_builds::RequestBuilder {
+            self.client
+                .get_changes_between_builds(self.organization.clone(), project)
+        }
+        #[doc = "Gets all the work items between two builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `from_build_id`: The ID of the first build."]
+        #[doc = "* `to_build_id`: The ID of the last build."]
+        pub fn get_work_items_between_builds(
+            &self,
+            project: impl Into<String>,
+            from_build_id: i32,
+            to_build_id: i32,
+        ) -> get_work_items_between_builds::RequestBuilder {
+            self.client.get_work_items_between_builds(
+                self.organization.clone(),
+                project,
+                from_build_id,
+                to_build_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a list of builds."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Queues a build"]
+        pub fn queue(&self, body: impl Into<models::Build>) -> queue::RequestBuilder {
+            self.client
+                .queue(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Updates multiple builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The builds to update."]
+        pub fn update_builds(&self, body: Vec<models::Build>) -> update_builds::RequestBuilder {
+            self.client
+                .update_builds(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets a build"]
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Updates a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The build."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build(
+            &self,
+            body: impl Into<models::Build>,
+            build_id: i32,
+        ) -> update_build::RequestBuilder {
+            self.client.update_build(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Deletes a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn delete(&self, build_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Gets the changes associated with a build"]
+        pub fn get_build_changes(&self, build_id: i32) -> get_build_changes::RequestBuilder {
+            self.client
+                .get_build_changes(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Gets all retention leases that apply to a specific build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_retention_leases_for_build(
+            &self,
+            build_id: i32,
+        ) -> get_retention_leases_for_build::RequestBuilder {
+            self.client.get_retention_leases_for_build(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the logs for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_logs(&self, build_id: i32) -> get_build_logs::RequestBuilder {
+            self.client
+                .get_build_logs(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Gets an individual log file for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `log_id`: The ID of the log file."]
+        pub fn get_build_log(&self, build_id: i32, log_id: i32) -> get_build_log::RequestBuilder {
+            self.client.get_build_log(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                log_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build. Only work items in the same project are returned."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs(
+            &self,
+            build_id: i32,
+        ) -> get_build_work_items_refs::RequestBuilder {
+            self.client.get_build_work_items_refs(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build, filtered to specific commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A comma-delimited list of commit IDs."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs_from_commits(
+            &self,
+            body: Vec<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs_from_commits::RequestBuilder {
+            self.client.get_build_work_items_refs_from_commits(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the changes made to the repository between two given builds."]
+        pub fn get_changes_between_builds(&self) -> get_changes_between_builds::RequestBuilder {
+            self.client
+                .get_changes_between_builds(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Gets all the work items between two builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `from_build_id`: The ID of the first build."]
+        #[doc = "* `to_build_id`: The ID of the last build."]
+        pub fn get_work_items_between_builds(
+            &self,
+            from_build_id: i32,
+            to_build_id: i32,
+        ) -> get_work_items_between_builds::RequestBuilder {
+            self.client.get_work_items_between_builds(
+                self.organization.clone(),
+                self.project.clone(),
+                from_build_id,
+                to_build_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3061,16 +4437,16 @@ pub mod builds {
             pub(crate) min_time: Option<time::OffsetDateTime>,
             pub(crate) max_time: Option<time::OffsetDateTime>,
             pub(crate) requested_for: Option<String>,
-            pub(crate) reason_filter: Option<String>,
-            pub(crate) status_filter: Option<String>,
-            pub(crate) result_filter: Option<String>,
+            pub(crate) reason_filter: Vec<models::BuildReason>,
+            pub(crate) status_filter: Option<models::BuildStatus>,
+            pub(crate) result_filter: Vec<models::BuildResult>,
             pub(crate) tag_filters: Option<String>,
             pub(crate) properties: Option<String>,
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<String>,
             pub(crate) max_builds_per_definition: Option<i32>,
-            pub(crate) deleted_filter: Option<String>,
-            pub(crate) query_order: Option<String>,
+            pub(crate) deleted_filter: Option<models::QueryDeletedOption>,
+            pub(crate) query_order: Option<models::BuildQueryOrder>,
             pub(crate) branch_name: Option<String>,
             pub(crate) build_ids: Option<String>,
             pub(crate) repository_id: Option<String>,
@@ -3108,18 +4484,18 @@ pub mod builds {
                 self
             }
             #[doc = "If specified, filters to builds that match this reason."]
-            pub fn reason_filter(mut self, reason_filter: impl Into<String>) -> Self {
-                self.reason_filter = Some(reason_filter.into());
+            pub fn reason_filter(mut self, reason_filter: Vec<models::BuildReason>) -> Self {
+                self.reason_filter = reason_filter;
                 self
             }
             #[doc = "If specified, filters to builds that match this status."]
-            pub fn status_filter(mut self, status_filter: impl Into<String>) -> Self {
+            pub fn status_filter(mut self, status_filter: impl Into<models::BuildStatus>) -> Self {
                 self.status_filter = Some(status_filter.into());
                 self
             }
             #[doc = "If specified, filters to builds that match this result."]
-            pub fn result_filter(mut self, result_filter: impl Into<String>) -> Self {
-                self.result_filter = Some(result_filter.into());
+            pub fn result_filter(mut self, result_filter: Vec<models::BuildResult>) -> Self {
+                self.result_filter = result_filter;
                 self
             }
             #[doc = "A comma-delimited list of tags. If specified, filters to builds that have the specified tags."]
@@ -3148,12 +4524,15 @@ pub mod builds {
                 self
             }
             #[doc = "Indicates whether to exclude, include, or only return deleted builds."]
-            pub fn deleted_filter(mut self, deleted_filter: impl Into<String>) -> Self {
+            pub fn deleted_filter(
+                mut self,
+                deleted_filter: impl Into<models::QueryDeletedOption>,
+            ) -> Self {
                 self.deleted_filter = Some(deleted_filter.into());
                 self
             }
             #[doc = "The order in which builds should be returned."]
-            pub fn query_order(mut self, query_order: impl Into<String>) -> Self {
+            pub fn query_order(mut self, query_order: impl Into<models::BuildQueryOrder>) -> Self {
                 self.query_order = Some(query_order.into());
                 self
             }
@@ -3200,9 +4579,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(definitions) = &this.definitions {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3235,20 +4615,38 @@ pub mod builds {
                                 .query_pairs_mut()
                                 .append_pair("requestedFor", requested_for);
                         }
-                        if let Some(reason_filter) = &this.reason_filter {
+                        let reason_filter = &this.reason_filter;
+                        if !reason_filter.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in reason_filter {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("reasonFilter", reason_filter);
+                                .append_pair("reasonFilter", &value);
                         }
                         if let Some(status_filter) = &this.status_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("statusFilter", status_filter);
-                        }
-                        if let Some(result_filter) = &this.result_filter {
+                                .append_pair("statusFilter", &status_filter.to_string());
+                        }
+                        let result_filter = &this.result_filter;
+                        if !result_filter.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in result_filter {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("resultFilter", result_filter);
+                                .append_pair("resultFilter", &value);
                         }
                         if let Some(tag_filters) = &this.tag_filters {
                             req.url_mut()
@@ -3279,12 +4677,12 @@ pub mod builds {
                         if let Some(deleted_filter) = &this.deleted_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("deletedFilter", deleted_filter);
+                                .append_pair("deletedFilter", &deleted_filter.to_string());
                         }
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("queryOrder", query_order);
+                                .append_pair("queryOrder", &query_order.to_string());
                         }
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
@@ -3312,11 +4710,29 @@ pub mod builds {
                     }
                 })
             }
-        }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::BuildList>;
-            type IntoFuture =
-                futures::future::BoxFuture<'static, azure_core::Result<models::BuildList>>;
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::BuildList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::BuildList>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::BuildList>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -3428,9 +4844,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(ignore_warnings) = &this.ignore_warnings {
@@ -3553,9 +4970,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3664,9 +5082,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(property_filters) = &this.property_filters {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3780,9 +5199,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(retry) = &this.retry {
@@ -3859,9 +5279,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3967,9 +5388,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3992,6 +5414,24 @@ pub mod builds {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::ChangeList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ChangeList>;
@@ -4089,9 +5529,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4194,9 +5635,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4242,6 +5684,25 @@ pub mod builds {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4313,9 +5774,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(start_line) = &this.start_line {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4434,9 +5896,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4552,9 +6015,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(top) = &this.top {
@@ -4679,9 +6143,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(from_build_id) = &this.from_build_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4806,9 +6271,10 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let from_build_id = &this.from_build_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -4902,6 +6368,105 @@ pub mod attachments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a specific attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        #[doc = "* `record_id`: The ID of the timeline record."]
+        #[doc = "* `type_`: The type of the attachment."]
+        #[doc = "* `name`: The name of the attachment."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                build_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "Gets the list of attachments of a specific type that are associated with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `type_`: The type of attachment."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, build_id, type_)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a specific attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        #[doc = "* `record_id`: The ID of the timeline record."]
+        #[doc = "* `type_`: The type of the attachment."]
+        #[doc = "* `name`: The name of the attachment."]
+        pub fn get(
+            &self,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "Gets the list of attachments of a specific type that are associated with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `type_`: The type of attachment."]
+        pub fn list(&self, build_id: i32, type_: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                type_,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4926,6 +6491,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4991,9 +6575,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5097,9 +6682,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5211,6 +6797,142 @@ pub mod properties {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_properties(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_properties::RequestBuilder {
+            self.client
+                .get_build_properties(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Updates properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build_properties::RequestBuilder {
+            self.client
+                .update_build_properties(self.organization.clone(), body, project, build_id)
+        }
+        #[doc = "Gets properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_properties(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_properties::RequestBuilder {
+            self.client
+                .get_definition_properties(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Updates properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update_definition_properties::RequestBuilder {
+            self.client.update_definition_properties(
+                self.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_properties(&self, build_id: i32) -> get_build_properties::RequestBuilder {
+            self.client.get_build_properties(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Updates properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            build_id: i32,
+        ) -> update_build_properties::RequestBuilder {
+            self.client.update_build_properties(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_properties(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_properties::RequestBuilder {
+            self.client.get_definition_properties(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Updates properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            definition_id: i32,
+        ) -> update_definition_properties::RequestBuilder {
+            self.client.update_definition_properties(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod get_build_properties {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5299,9 +7021,10 @@ pub mod properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(filter) = &this.filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5413,9 +7136,10 @@ pub mod properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5528,9 +7252,10 @@ pub mod properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(filter) = &this.filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5642,9 +7367,10 @@ pub mod properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5695,6 +7421,40 @@ pub mod report {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a build report."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, build_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a build report."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), build_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5782,9 +7542,10 @@ pub mod report {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -5839,18 +7600,69 @@ pub mod stages {
             }
         }
     }
-    pub mod update {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Update a build stage"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateStageParameters>,
+            build_id: i32,
+            stage_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                build_id,
+                stage_ref_name,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Update a build stage"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateStageParameters>,
+            build_id: i32,
+            stage_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                build_id,
+                stage_ref_name,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod update {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
         #[doc = r" operation and returns a `Result` with the parsed response."]
         #[doc = r""]
         #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
@@ -5891,9 +7703,10 @@ pub mod stages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6142,21 +7955,383 @@ pub mod tags {
         #[doc = "Removes a tag from builds, definitions, and from the tag store"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            tag: impl Into<String>,
+        ) -> delete_tag::RequestBuilder {
+            delete_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                tag: tag.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the tags for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_tags(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_tags::RequestBuilder {
+            self.client
+                .get_build_tags(self.organization.clone(), project, build_id)
+        }
+        #[doc = "Adds tags to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn add_build_tags(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> add_build_tags::RequestBuilder {
+            self.client
+                .add_build_tags(self.organization.clone(), body, project, build_id)
+        }
+        #[doc = "Adds/Removes tags from a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build_tags::RequestBuilder {
+            self.client
+                .update_build_tags(self.organization.clone(), body, project, build_id)
+        }
+        #[doc = "Adds a tag to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_build_tag(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> add_build_tag::RequestBuilder {
+            self.client
+                .add_build_tag(self.organization.clone(), project, build_id, tag)
+        }
+        #[doc = "Removes a tag from a build. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_build_tag(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_build_tag::RequestBuilder {
+            self.client
+                .delete_build_tag(self.organization.clone(), project, build_id, tag)
+        }
+        #[doc = "Gets the tags for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_tags(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_tags::RequestBuilder {
+            self.client
+                .get_definition_tags(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Adds multiple tags to a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn add_definition_tags(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> add_definition_tags::RequestBuilder {
+            self.client
+                .add_definition_tags(self.organization.clone(), body, project, definition_id)
+        }
+        #[doc = "Adds/Removes tags from a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update_definition_tags::RequestBuilder {
+            self.client.update_definition_tags(
+                self.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Adds a tag to a definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_definition_tag(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> add_definition_tag::RequestBuilder {
+            self.client
+                .add_definition_tag(self.organization.clone(), project, definition_id, tag)
+        }
+        #[doc = "Removes a tag from a definition. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_definition_tag(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_definition_tag::RequestBuilder {
+            self.client.delete_definition_tag(
+                self.organization.clone(),
+                project,
+                definition_id,
+                tag,
+            )
+        }
+        #[doc = "Gets a list of all build tags in the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_tags(&self, project: impl Into<String>) -> get_tags::RequestBuilder {
+            self.client.get_tags(self.organization.clone(), project)
+        }
+        #[doc = "Removes a tag from builds, definitions, and from the tag store"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_tag(
+            &self,
+            project: impl Into<String>,
+            tag: impl Into<String>,
+        ) -> delete_tag::RequestBuilder {
+            self.client
+                .delete_tag(self.organization.clone(), project, tag)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the tags for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_tags(&self, build_id: i32) -> get_build_tags::RequestBuilder {
+            self.client
+                .get_build_tags(self.organization.clone(), self.project.clone(), build_id)
+        }
+        #[doc = "Adds tags to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn add_build_tags(
+            &self,
+            body: Vec<String>,
+            build_id: i32,
+        ) -> add_build_tags::RequestBuilder {
+            self.client.add_build_tags(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Adds/Removes tags from a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            build_id: i32,
+        ) -> update_build_tags::RequestBuilder {
+            self.client.update_build_tags(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Adds a tag to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_build_tag(
+            &self,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> add_build_tag::RequestBuilder {
+            self.client.add_build_tag(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                tag,
+            )
+        }
+        #[doc = "Removes a tag from a build. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_build_tag(
+            &self,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_build_tag::RequestBuilder {
+            self.client.delete_build_tag(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                tag,
+            )
+        }
+        #[doc = "Gets the tags for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_tags(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_tags::RequestBuilder {
+            self.client.get_definition_tags(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds multiple tags to a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn add_definition_tags(
+            &self,
+            body: Vec<String>,
+            definition_id: i32,
+        ) -> add_definition_tags::RequestBuilder {
+            self.client.add_definition_tags(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds/Removes tags from a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            definition_id: i32,
+        ) -> update_definition_tags::RequestBuilder {
+            self.client.update_definition_tags(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds a tag to a definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_definition_tag(
+            &self,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> add_definition_tag::RequestBuilder {
+            self.client.add_definition_tag(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+                tag,
+            )
+        }
+        #[doc = "Removes a tag from a definition. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_definition_tag(
+            &self,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_definition_tag::RequestBuilder {
+            self.client.delete_definition_tag(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+                tag,
+            )
+        }
+        #[doc = "Gets a list of all build tags in the project."]
+        pub fn get_tags(&self) -> get_tags::RequestBuilder {
+            self.client
+                .get_tags(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Removes a tag from builds, definitions, and from the tag store"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `tag`: The tag to remove."]
-        pub fn delete_tag(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            tag: impl Into<String>,
-        ) -> delete_tag::RequestBuilder {
-            delete_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                tag: tag.into(),
-            }
+        pub fn delete_tag(&self, tag: impl Into<String>) -> delete_tag::RequestBuilder {
+            self.client
+                .delete_tag(self.organization.clone(), self.project.clone(), tag)
         }
     }
     pub mod get_build_tags {
@@ -6240,9 +8415,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6345,9 +8521,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6451,9 +8628,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6558,9 +8736,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6664,9 +8843,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6774,9 +8954,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(revision) = &this.revision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6884,9 +9065,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6990,9 +9172,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7097,9 +9280,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7203,9 +9387,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7305,9 +9490,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7409,9 +9595,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7460,6 +9647,45 @@ pub mod timeline {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets details for a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, build_id, timeline_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets details for a build"]
+        pub fn get(&self, build_id: i32, timeline_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                timeline_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7553,9 +9779,10 @@ pub mod timeline {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(change_id) = &this.change_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7648,109 +9875,297 @@ pub mod definitions {
         #[doc = "Gets a definition, optionally at a specific revision."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+                revision: None,
+                min_metrics_time: None,
+                property_filters: None,
+                include_latest_builds: None,
+            }
+        }
+        #[doc = "Updates an existing build definition.  In order for this operation to succeed, the value of the \"Revision\" property of the request body must match the existing build definition's. It is recommended that you obtain the existing build definition by using GET, modify the build definition as necessary, and then submit the modified definition with PUT."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The new version of the definition. Its \"Revision\" property must match the existing definition for the update to be accepted."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::BuildDefinition>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                definition_id,
+                secrets_source_definition_id: None,
+                secrets_source_definition_revision: None,
+            }
+        }
+        #[doc = "Restores a deleted definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The identifier of the definition to restore."]
+        #[doc = "* `deleted`: When false, restores a deleted definition."]
+        pub fn restore_definition(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+            deleted: bool,
+        ) -> restore_definition::RequestBuilder {
+            restore_definition::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+                deleted,
+            }
+        }
+        #[doc = "Deletes a definition and all associated builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+            }
+        }
+        #[doc = "Gets all revisions of a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_revisions(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_revisions::RequestBuilder {
+            get_definition_revisions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a list of definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Creates a new definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The definition."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildDefinition>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Gets a definition, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Updates an existing build definition.  In order for this operation to succeed, the value of the \"Revision\" property of the request body must match the existing build definition's. It is recommended that you obtain the existing build definition by using GET, modify the build definition as necessary, and then submit the modified definition with PUT."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the definition. Its \"Revision\" property must match the existing definition for the update to be accepted."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update(
+            &self,
+            body: impl Into<models::BuildDefinition>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, definition_id)
+        }
+        #[doc = "Restores a deleted definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The identifier of the definition to restore."]
+        #[doc = "* `deleted`: When false, restores a deleted definition."]
+        pub fn restore_definition(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            deleted: bool,
+        ) -> restore_definition::RequestBuilder {
+            self.client.restore_definition(
+                self.organization.clone(),
+                project,
+                definition_id,
+                deleted,
+            )
+        }
+        #[doc = "Deletes a definition and all associated builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Gets all revisions of a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_revisions(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_revisions::RequestBuilder {
+            self.client
+                .get_definition_revisions(self.organization.clone(), project, definition_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a list of definitions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Creates a new definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The definition."]
+        pub fn create(&self, body: impl Into<models::BuildDefinition>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets a definition, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `definition_id`: The ID of the definition."]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            definition_id: i32,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
                 definition_id,
-                revision: None,
-                min_metrics_time: None,
-                property_filters: None,
-                include_latest_builds: None,
-            }
+            )
         }
         #[doc = "Updates an existing build definition.  In order for this operation to succeed, the value of the \"Revision\" property of the request body must match the existing build definition's. It is recommended that you obtain the existing build definition by using GET, modify the build definition as necessary, and then submit the modified definition with PUT."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The new version of the definition. Its \"Revision\" property must match the existing definition for the update to be accepted."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::BuildDefinition>,
-            project: impl Into<String>,
             definition_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
                 definition_id,
-                secrets_source_definition_id: None,
-                secrets_source_definition_revision: None,
-            }
+            )
         }
         #[doc = "Restores a deleted definition"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The identifier of the definition to restore."]
         #[doc = "* `deleted`: When false, restores a deleted definition."]
         pub fn restore_definition(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             definition_id: i32,
             deleted: bool,
         ) -> restore_definition::RequestBuilder {
-            restore_definition::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.restore_definition(
+                self.organization.clone(),
+                self.project.clone(),
                 definition_id,
                 deleted,
-            }
+            )
         }
         #[doc = "Deletes a definition and all associated builds."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            definition_id: i32,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn delete(&self, definition_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
                 definition_id,
-            }
+            )
         }
         #[doc = "Gets all revisions of a definition."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         pub fn get_definition_revisions(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             definition_id: i32,
         ) -> get_definition_revisions::RequestBuilder {
-            get_definition_revisions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_definition_revisions(
+                self.organization.clone(),
+                self.project.clone(),
                 definition_id,
-            }
+            )
         }
     }
     pub mod list {
@@ -7937,9 +10352,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(name) = &this.name {
                             req.url_mut().query_pairs_mut().append_pair("name", name);
                         }
@@ -8030,6 +10446,26 @@ pub mod definitions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::BuildDefinitionReferenceList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::BuildDefinitionReferenceList>;
@@ -8141,9 +10577,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(definition_to_clone_id) = &this.definition_to_clone_id {
@@ -8288,9 +10725,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(revision) = &this.revision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8434,9 +10872,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(secrets_source_definition_id) =
@@ -8558,9 +10997,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let deleted = &this.deleted;
                         req.url_mut()
                             .query_pairs_mut()
@@ -8635,9 +11075,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8730,9 +11171,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8802,6 +11244,80 @@ pub mod metrics {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets build metrics for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_metrics(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_metrics::RequestBuilder {
+            self.client
+                .get_definition_metrics(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Gets build metrics for a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `metric_aggregation_type`: The aggregation type to use (hourly, daily)."]
+        pub fn get_project_metrics(
+            &self,
+            project: impl Into<String>,
+            metric_aggregation_type: impl Into<String>,
+        ) -> get_project_metrics::RequestBuilder {
+            self.client.get_project_metrics(
+                self.organization.clone(),
+                project,
+                metric_aggregation_type,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets build metrics for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_metrics(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_metrics::RequestBuilder {
+            self.client.get_definition_metrics(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Gets build metrics for a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `metric_aggregation_type`: The aggregation type to use (hourly, daily)."]
+        pub fn get_project_metrics(
+            &self,
+            metric_aggregation_type: impl Into<String>,
+        ) -> get_project_metrics::RequestBuilder {
+            self.client.get_project_metrics(
+                self.organization.clone(),
+                self.project.clone(),
+                metric_aggregation_type,
+            )
+        }
+    }
     pub mod get_definition_metrics {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8893,9 +11409,10 @@ pub mod metrics {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(min_metrics_time) = &this.min_metrics_time {
                             let formatted_date_time =
                                 crate::date_time::format_date_time(min_metrics_time)?;
@@ -9015,9 +11532,10 @@ pub mod metrics {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(min_metrics_time) = &this.min_metrics_time {
                             let formatted_date_time =
                                 crate::date_time::format_date_time(min_metrics_time)?;
@@ -9088,6 +11606,65 @@ pub mod resources {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>, definition_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, definition_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn authorize_definition_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> authorize_definition_resources::RequestBuilder {
+            self.client.authorize_definition_resources(
+                self.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self, definition_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        pub fn authorize_definition_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            definition_id: i32,
+        ) -> authorize_definition_resources::RequestBuilder {
+            self.client.authorize_definition_resources(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9172,9 +11749,10 @@ pub mod resources {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9283,9 +11861,10 @@ pub mod resources {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -9339,6 +11918,43 @@ pub mod yaml {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Converts a definition to YAML, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Converts a definition to YAML, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9446,9 +12062,10 @@ pub mod yaml {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(revision) = &this.revision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -9517,62 +12134,165 @@ pub mod templates {
         #[doc = "Gets a specific build definition template."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the requested template."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                template_id: template_id.into(),
+            }
+        }
+        #[doc = "Updates an existing build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The new version of the template."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn save_template(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::BuildDefinitionTemplate>,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> save_template::RequestBuilder {
+            save_template::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                template_id: template_id.into(),
+            }
+        }
+        #[doc = "Deletes a build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                template_id: template_id.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets all definition templates."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Gets a specific build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `template_id`: The ID of the requested template."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             template_id: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                template_id: template_id.into(),
-            }
+            self.client
+                .get(self.organization.clone(), project, template_id)
         }
         #[doc = "Updates an existing build definition template."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The new version of the template."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `template_id`: The ID of the template."]
         pub fn save_template(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::BuildDefinitionTemplate>,
             project: impl Into<String>,
             template_id: impl Into<String>,
         ) -> save_template::RequestBuilder {
-            save_template::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                template_id: template_id.into(),
-            }
+            self.client
+                .save_template(self.organization.clone(), body, project, template_id)
         }
         #[doc = "Deletes a build definition template."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `template_id`: The ID of the template."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             template_id: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                template_id: template_id.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), project, template_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets all definition templates."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Gets a specific build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `template_id`: The ID of the requested template."]
+        pub fn get(&self, template_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), template_id)
+        }
+        #[doc = "Updates an existing build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the template."]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn save_template(
+            &self,
+            body: impl Into<models::BuildDefinitionTemplate>,
+            template_id: impl Into<String>,
+        ) -> save_template::RequestBuilder {
+            self.client.save_template(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                template_id,
+            )
+        }
+        #[doc = "Deletes a build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn delete(&self, template_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), template_id)
         }
     }
     pub mod list {
@@ -9657,9 +12377,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9765,9 +12486,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9874,9 +12596,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -9950,9 +12673,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10050,6 +12774,119 @@ pub mod folders {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Updates an existing folder at given  existing path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, path)
+        }
+        #[doc = "Creates a new folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, path)
+        }
+        #[doc = "Deletes a definition folder. Definitions and their corresponding builds will also be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project, path)
+        }
+        #[doc = "Gets a list of build definition folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The path to start with."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, path)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Updates an existing folder at given  existing path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the folder."]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), path)
+        }
+        #[doc = "Creates a new folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The folder."]
+        #[doc = "* `path`: The full path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), path)
+        }
+        #[doc = "Deletes a definition folder. Definitions and their corresponding builds will also be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn delete(&self, path: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), path)
+        }
+        #[doc = "Gets a list of build definition folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: The path to start with."]
+        pub fn list(&self, path: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), path)
+        }
+    }
     pub mod update {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10131,9 +12968,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let path = &this.path;
@@ -10239,9 +13077,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let path = &this.path;
@@ -10314,9 +13153,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let path = &this.path;
                         req.url_mut().query_pairs_mut().append_pair("path", path);
                         let req_body = azure_core::EMPTY_BODY;
@@ -10414,9 +13254,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -10483,6 +13324,54 @@ pub mod general_settings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets pipeline general settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+        #[doc = "Updates pipeline general settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PipelineGeneralSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets pipeline general settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Updates pipeline general settings."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PipelineGeneralSettings>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10563,9 +13452,10 @@ pub mod general_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10670,9 +13560,10 @@ pub mod general_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -10723,6 +13614,44 @@ pub mod latest {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the latest build for a definition, optionally scoped to a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition`: definition name with optional leading folder path, or the definition id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            definition: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the latest build for a definition, optionally scoped to a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition`: definition name with optional leading folder path, or the definition id"]
+        pub fn get(&self, definition: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), definition)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10810,9 +13739,10 @@ pub mod latest {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -10861,6 +13791,35 @@ pub mod options {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets all build definition options supported by the system."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets all build definition options supported by the system."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10941,9 +13900,10 @@ pub mod options {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11007,6 +13967,54 @@ pub mod retention {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the project's retention settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+        #[doc = "Updates the project's retention settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProjectRetentionSettingModel>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the project's retention settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Updates the project's retention settings."]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProjectRetentionSettingModel>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11087,9 +14095,10 @@ pub mod retention {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11194,9 +14203,10 @@ pub mod retention {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11262,6 +14272,55 @@ pub mod settings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+        #[doc = "Updates the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new settings."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::BuildSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the build settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Updates the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new settings."]
+        pub fn update(&self, body: impl Into<models::BuildSettings>) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11341,9 +14400,10 @@ pub mod settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11445,9 +14505,10 @@ pub mod settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11500,6 +14561,44 @@ pub mod status {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "<p>Gets the build status for a definition, optionally scoped to a specific branch, stage, job, and configuration.</p> <p>If there are more than one, then it is required to pass in a stageName value when specifying a jobName, and the same rule then applies for both if passing a configuration parameter.</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition`: Either the definition name with optional leading folder path, or the definition id."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            definition: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "<p>Gets the build status for a definition, optionally scoped to a specific branch, stage, job, and configuration.</p> <p>If there are more than one, then it is required to pass in a stageName value when specifying a jobName, and the same rule then applies for both if passing a configuration parameter.</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition`: Either the definition name with optional leading folder path, or the definition id."]
+        pub fn get(&self, definition: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), definition)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11524,6 +14623,25 @@ pub mod status {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -11611,9 +14729,10 @@ pub mod status {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -11840,6 +14959,238 @@ pub mod source_providers {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of source providers and their capabilities."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Gets a list of branches for the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_branches(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_branches::RequestBuilder {
+            self.client
+                .list_branches(self.organization.clone(), project, provider_name)
+        }
+        #[doc = "Gets the contents of a file in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_file_contents(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> get_file_contents::RequestBuilder {
+            self.client
+                .get_file_contents(self.organization.clone(), project, provider_name)
+        }
+        #[doc = "Gets the contents of a directory in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_path_contents(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> get_path_contents::RequestBuilder {
+            self.client
+                .get_path_contents(self.organization.clone(), project, provider_name)
+        }
+        #[doc = "Gets a pull request object from source provider."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        #[doc = "* `pull_request_id`: Vendor-specific id of the pull request."]
+        pub fn get_pull_request(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+            pull_request_id: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            self.client.get_pull_request(
+                self.organization.clone(),
+                project,
+                provider_name,
+                pull_request_id,
+            )
+        }
+        #[doc = "Gets a list of source code repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_repositories(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_repositories::RequestBuilder {
+            self.client
+                .list_repositories(self.organization.clone(), project, provider_name)
+        }
+        #[doc = "Gets a list of webhooks installed in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_webhooks(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_webhooks::RequestBuilder {
+            self.client
+                .list_webhooks(self.organization.clone(), project, provider_name)
+        }
+        #[doc = "Recreates the webhooks for the specified triggers in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The types of triggers to restore webhooks for."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn restore_webhooks(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> restore_webhooks::RequestBuilder {
+            self.client
+                .restore_webhooks(self.organization.clone(), body, project, provider_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of source providers and their capabilities."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Gets a list of branches for the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_branches(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_branches::RequestBuilder {
+            self.client.list_branches(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a file in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_file_contents(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> get_file_contents::RequestBuilder {
+            self.client.get_file_contents(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a directory in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_path_contents(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> get_path_contents::RequestBuilder {
+            self.client.get_path_contents(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets a pull request object from source provider."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        #[doc = "* `pull_request_id`: Vendor-specific id of the pull request."]
+        pub fn get_pull_request(
+            &self,
+            provider_name: impl Into<String>,
+            pull_request_id: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            self.client.get_pull_request(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+                pull_request_id,
+            )
+        }
+        #[doc = "Gets a list of source code repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_repositories(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_repositories::RequestBuilder {
+            self.client.list_repositories(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets a list of webhooks installed in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_webhooks(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_webhooks::RequestBuilder {
+            self.client.list_webhooks(
+                self.organization.clone(),
+                self.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Recreates the webhooks for the specified triggers in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The types of triggers to restore webhooks for."]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn restore_webhooks(
+            &self,
+            body: Vec<String>,
+            provider_name: impl Into<String>,
+        ) -> restore_webhooks::RequestBuilder {
+            self.client.restore_webhooks(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                provider_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11922,9 +15273,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -12047,9 +15399,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12109,6 +15462,25 @@ pub mod source_providers {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -12190,9 +15562,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12337,9 +15710,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12476,9 +15850,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(repository_id) = &this.repository_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12622,9 +15997,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12656,6 +16032,24 @@ pub mod source_providers {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::SourceRepositories>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::SourceRepositories>;
@@ -12765,9 +16159,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12863,9 +16258,10 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
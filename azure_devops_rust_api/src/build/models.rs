@@ -2375,6 +2375,82 @@ pub mod build_process_template {
         Upgrade,
     }
 }
+#[doc = "Build query order"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "BuildQueryOrder")]
+pub enum BuildQueryOrder {
+    #[serde(rename = "finishTimeAscending")]
+    FinishTimeAscending,
+    #[serde(rename = "finishTimeDescending")]
+    FinishTimeDescending,
+    #[serde(rename = "queueTimeDescending")]
+    QueueTimeDescending,
+    #[serde(rename = "queueTimeAscending")]
+    QueueTimeAscending,
+    #[serde(rename = "startTimeDescending")]
+    StartTimeDescending,
+    #[serde(rename = "startTimeAscending")]
+    StartTimeAscending,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for BuildQueryOrder {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for BuildQueryOrder {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for BuildQueryOrder {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::FinishTimeAscending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 0u32, "finishTimeAscending")
+            }
+            Self::FinishTimeDescending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 1u32, "finishTimeDescending")
+            }
+            Self::QueueTimeDescending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 2u32, "queueTimeDescending")
+            }
+            Self::QueueTimeAscending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 3u32, "queueTimeAscending")
+            }
+            Self::StartTimeDescending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 4u32, "startTimeDescending")
+            }
+            Self::StartTimeAscending => {
+                serializer.serialize_unit_variant("BuildQueryOrder", 5u32, "startTimeAscending")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for BuildQueryOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FinishTimeAscending => f.write_str("finishTimeAscending"),
+            Self::FinishTimeDescending => f.write_str("finishTimeDescending"),
+            Self::QueueTimeDescending => f.write_str("queueTimeDescending"),
+            Self::QueueTimeAscending => f.write_str("queueTimeAscending"),
+            Self::StartTimeDescending => f.write_str("startTimeDescending"),
+            Self::StartTimeAscending => f.write_str("startTimeAscending"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct BuildQueuedEvent {
@@ -2386,6 +2462,118 @@ impl BuildQueuedEvent {
         Self::default()
     }
 }
+#[doc = "Build reason"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "BuildReason")]
+pub enum BuildReason {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "manual")]
+    Manual,
+    #[serde(rename = "individualCI")]
+    IndividualCi,
+    #[serde(rename = "batchedCI")]
+    BatchedCi,
+    #[serde(rename = "schedule")]
+    Schedule,
+    #[serde(rename = "scheduleForced")]
+    ScheduleForced,
+    #[serde(rename = "userCreated")]
+    UserCreated,
+    #[serde(rename = "validateShelveset")]
+    ValidateShelveset,
+    #[serde(rename = "checkInShelveset")]
+    CheckInShelveset,
+    #[serde(rename = "pullRequest")]
+    PullRequest,
+    #[serde(rename = "buildCompletion")]
+    BuildCompletion,
+    #[serde(rename = "resourceTrigger")]
+    ResourceTrigger,
+    #[serde(rename = "triggered")]
+    Triggered,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for BuildReason {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for BuildReason {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for BuildReason {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("BuildReason", 0u32, "none"),
+            Self::Manual => serializer.serialize_unit_variant("BuildReason", 1u32, "manual"),
+            Self::IndividualCi => {
+                serializer.serialize_unit_variant("BuildReason", 2u32, "individualCI")
+            }
+            Self::BatchedCi => serializer.serialize_unit_variant("BuildReason", 3u32, "batchedCI"),
+            Self::Schedule => serializer.serialize_unit_variant("BuildReason", 4u32, "schedule"),
+            Self::ScheduleForced => {
+                serializer.serialize_unit_variant("BuildReason", 5u32, "scheduleForced")
+            }
+            Self::UserCreated => {
+                serializer.serialize_unit_variant("BuildReason", 6u32, "userCreated")
+            }
+            Self::ValidateShelveset => {
+                serializer.serialize_unit_variant("BuildReason", 7u32, "validateShelveset")
+            }
+            Self::CheckInShelveset => {
+                serializer.serialize_unit_variant("BuildReason", 8u32, "checkInShelveset")
+            }
+            Self::PullRequest => {
+                serializer.serialize_unit_variant("BuildReason", 9u32, "pullRequest")
+            }
+            Self::BuildCompletion => {
+                serializer.serialize_unit_variant("BuildReason", 10u32, "buildCompletion")
+            }
+            Self::ResourceTrigger => {
+                serializer.serialize_unit_variant("BuildReason", 11u32, "resourceTrigger")
+            }
+            Self::Triggered => serializer.serialize_unit_variant("BuildReason", 12u32, "triggered"),
+            Self::All => serializer.serialize_unit_variant("BuildReason", 13u32, "all"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for BuildReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Manual => f.write_str("manual"),
+            Self::IndividualCi => f.write_str("individualCI"),
+            Self::BatchedCi => f.write_str("batchedCI"),
+            Self::Schedule => f.write_str("schedule"),
+            Self::ScheduleForced => f.write_str("scheduleForced"),
+            Self::UserCreated => f.write_str("userCreated"),
+            Self::ValidateShelveset => f.write_str("validateShelveset"),
+            Self::CheckInShelveset => f.write_str("checkInShelveset"),
+            Self::PullRequest => f.write_str("pullRequest"),
+            Self::BuildCompletion => f.write_str("buildCompletion"),
+            Self::ResourceTrigger => f.write_str("resourceTrigger"),
+            Self::Triggered => f.write_str("triggered"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = "Represents a reference to a build."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct BuildReference {
@@ -2619,6 +2807,68 @@ impl BuildResourceUsage {
         Self::default()
     }
 }
+#[doc = "Build result"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "BuildResult")]
+pub enum BuildResult {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[serde(rename = "partiallySucceeded")]
+    PartiallySucceeded,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(rename = "canceled")]
+    Canceled,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for BuildResult {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for BuildResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for BuildResult {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("BuildResult", 0u32, "none"),
+            Self::Succeeded => serializer.serialize_unit_variant("BuildResult", 1u32, "succeeded"),
+            Self::PartiallySucceeded => {
+                serializer.serialize_unit_variant("BuildResult", 2u32, "partiallySucceeded")
+            }
+            Self::Failed => serializer.serialize_unit_variant("BuildResult", 3u32, "failed"),
+            Self::Canceled => serializer.serialize_unit_variant("BuildResult", 4u32, "canceled"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for BuildResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Succeeded => f.write_str("succeeded"),
+            Self::PartiallySucceeded => f.write_str("partiallySucceeded"),
+            Self::Failed => f.write_str("failed"),
+            Self::Canceled => f.write_str("canceled"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = "A historical overview of build retention information. This includes a list of snapshots taken about build retention usage, and a list of builds that have exceeded the default 30 day retention policy."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct BuildRetentionHistory {
@@ -2751,6 +3001,80 @@ impl BuildSettings {
         Self::default()
     }
 }
+#[doc = "Build status"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "BuildStatus")]
+pub enum BuildStatus {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "inProgress")]
+    InProgress,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "cancelling")]
+    Cancelling,
+    #[serde(rename = "postponed")]
+    Postponed,
+    #[serde(rename = "notStarted")]
+    NotStarted,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for BuildStatus {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for BuildStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for BuildStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("BuildStatus", 0u32, "none"),
+            Self::InProgress => {
+                serializer.serialize_unit_variant("BuildStatus", 1u32, "inProgress")
+            }
+            Self::Completed => serializer.serialize_unit_variant("BuildStatus", 2u32, "completed"),
+            Self::Cancelling => {
+                serializer.serialize_unit_variant("BuildStatus", 3u32, "cancelling")
+            }
+            Self::Postponed => serializer.serialize_unit_variant("BuildStatus", 4u32, "postponed"),
+            Self::NotStarted => {
+                serializer.serialize_unit_variant("BuildStatus", 5u32, "notStarted")
+            }
+            Self::All => serializer.serialize_unit_variant("BuildStatus", 6u32, "all"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for BuildStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::InProgress => f.write_str("inProgress"),
+            Self::Completed => f.write_str("completed"),
+            Self::Cancelling => f.write_str("cancelling"),
+            Self::Postponed => f.write_str("postponed"),
+            Self::NotStarted => f.write_str("notStarted"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct BuildSummary {
@@ -4395,6 +4719,64 @@ impl PullRequestTrigger {
         Self::default()
     }
 }
+#[doc = "Whether to include deleted items in query results"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "QueryDeletedOption")]
+pub enum QueryDeletedOption {
+    #[serde(rename = "excludeDeleted")]
+    ExcludeDeleted,
+    #[serde(rename = "includeDeleted")]
+    IncludeDeleted,
+    #[serde(rename = "onlyDeleted")]
+    OnlyDeleted,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for QueryDeletedOption {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for QueryDeletedOption {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for QueryDeletedOption {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::ExcludeDeleted => {
+                serializer.serialize_unit_variant("QueryDeletedOption", 0u32, "excludeDeleted")
+            }
+            Self::IncludeDeleted => {
+                serializer.serialize_unit_variant("QueryDeletedOption", 1u32, "includeDeleted")
+            }
+            Self::OnlyDeleted => {
+                serializer.serialize_unit_variant("QueryDeletedOption", 2u32, "onlyDeleted")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for QueryDeletedOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExcludeDeleted => f.write_str("excludeDeleted"),
+            Self::IncludeDeleted => f.write_str("includeDeleted"),
+            Self::OnlyDeleted => f.write_str("onlyDeleted"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct RealtimeBuildEvent {
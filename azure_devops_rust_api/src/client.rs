@@ -0,0 +1,640 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Unified Azure DevOps client.
+//!
+//! [`AdoClient`] is configured once with a credential, organization and client options,
+//! and hands out the clients for each of the enabled services. All the service clients
+//! share a single request pipeline, and use the correct host for each service
+//! (e.g. `https://vsrm.dev.azure.com` for releases).
+
+use crate::{Credential, Deployment};
+use std::sync::Arc;
+
+type NewServiceClient<C> =
+    fn(String, Credential, Vec<String>, azure_core::Pipeline, Option<String>) -> C;
+
+/// A client for all Azure DevOps services in an organization.
+///
+/// The service clients are scoped to the organization, so their operations do not take an
+/// `organization` parameter.
+///
+/// Example:
+///
+/// ```ignore
+/// let client = AdoClient::new(credential, organization);
+/// let repos = client.git().repositories_client().list(project).await?;
+/// ```
+#[derive(Clone)]
+pub struct AdoClient {
+    organization: String,
+    credential: Credential,
+    scopes: Option<Vec<String>>,
+    deployment: Deployment,
+    api_version: Option<String>,
+    pipeline: azure_core::Pipeline,
+}
+
+/// Builder for an [`AdoClient`].
+#[derive(Clone)]
+pub struct AdoClientBuilder {
+    organization: String,
+    credential: Credential,
+    scopes: Option<Vec<String>>,
+    deployment: Deployment,
+    api_version: Option<String>,
+    options: azure_core::ClientOptions,
+}
+
+impl AdoClientBuilder {
+    /// Create a new instance of `AdoClientBuilder`.
+    #[must_use]
+    pub fn new(credential: Credential, organization: impl Into<String>) -> Self {
+        Self {
+            organization: organization.into(),
+            credential,
+            scopes: None,
+            deployment: Deployment::default(),
+            api_version: None,
+            options: azure_core::ClientOptions::default(),
+        }
+    }
+
+    /// Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises).
+    #[must_use]
+    pub fn deployment(mut self, deployment: Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+
+    /// Set the API version, overriding the API version of each operation.
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
+
+    /// Set the scopes.
+    ///
+    /// If not set, the scope of each service client defaults to its endpoint.
+    #[must_use]
+    pub fn scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = Some(scopes.iter().map(|scope| (*scope).to_owned()).collect());
+        self
+    }
+
+    /// Set the retry options.
+    #[must_use]
+    pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
+        self.options = self.options.retry(retry);
+        self
+    }
+
+    /// Set the transport options.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
+        self.options = self.options.transport(transport);
+        self
+    }
+
+    /// Set per-call policies.
+    #[must_use]
+    pub fn per_call_policies(
+        mut self,
+        policies: impl Into<Vec<Arc<dyn azure_core::Policy>>>,
+    ) -> Self {
+        self.options = self.options.per_call_policies(policies);
+        self
+    }
+
+    /// Set per-retry policies.
+    ///
+    /// Per-retry policies run after the retry policy, so are run for each attempt of a request
+    /// and see the response of each attempt, e.g. throttled responses.
+    #[must_use]
+    pub fn per_retry_policies(
+        mut self,
+        policies: impl Into<Vec<Arc<dyn azure_core::Policy>>>,
+    ) -> Self {
+        self.options = self.options.per_retry_policies(policies);
+        self
+    }
+
+    /// Convert the builder into an `AdoClient` instance.
+    #[must_use]
+    pub fn build(self) -> AdoClient {
+        let pipeline = azure_core::Pipeline::new(
+            option_env!("CARGO_PKG_NAME"),
+            option_env!("CARGO_PKG_VERSION"),
+            self.options,
+            vec![Arc::new(crate::error::AdoErrorPolicy)],
+            vec![Arc::new(crate::error::AdoErrorBodyPolicy)],
+        );
+        AdoClient {
+            organization: self.organization,
+            credential: self.credential,
+            scopes: self.scopes,
+            deployment: self.deployment,
+            api_version: self.api_version,
+            pipeline,
+        }
+    }
+}
+
+impl AdoClient {
+    /// Create a new `AdoClient` with default options.
+    #[must_use]
+    pub fn new(credential: Credential, organization: impl Into<String>) -> Self {
+        AdoClientBuilder::new(credential, organization).build()
+    }
+
+    /// Create a new `AdoClientBuilder`.
+    #[must_use]
+    pub fn builder(credential: Credential, organization: impl Into<String>) -> AdoClientBuilder {
+        AdoClientBuilder::new(credential, organization)
+    }
+
+    /// Returns the organization (or project collection, for Azure DevOps Server).
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    /// Creates a service client that shares this client's pipeline.
+    #[allow(dead_code)]
+    fn service_client<C>(&self, cloud_endpoint: &str, new: NewServiceClient<C>) -> C {
+        let endpoint = self.deployment.endpoint(cloud_endpoint);
+        let scopes = self
+            .scopes
+            .clone()
+            .unwrap_or_else(|| vec![format!("{endpoint}/")]);
+        new(
+            endpoint,
+            self.credential.clone(),
+            scopes,
+            self.pipeline.clone(),
+            self.api_version.clone(),
+        )
+    }
+
+    /// Accounts client.
+    #[cfg(feature = "accounts")]
+    pub fn accounts(&self) -> crate::accounts::OrganizationClient {
+        self.service_client(
+            crate::accounts::DEFAULT_ENDPOINT,
+            crate::accounts::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Artifacts client.
+    #[cfg(feature = "artifacts")]
+    pub fn artifacts(&self) -> crate::artifacts::OrganizationClient {
+        self.service_client(
+            crate::artifacts::DEFAULT_ENDPOINT,
+            crate::artifacts::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Artifacts Package Types client.
+    #[cfg(feature = "artifacts_package_types")]
+    pub fn artifacts_package_types(&self) -> crate::artifacts_package_types::OrganizationClient {
+        self.service_client(
+            crate::artifacts_package_types::DEFAULT_ENDPOINT,
+            crate::artifacts_package_types::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Audit client.
+    #[cfg(feature = "audit")]
+    pub fn audit(&self) -> crate::audit::OrganizationClient {
+        self.service_client(
+            crate::audit::DEFAULT_ENDPOINT,
+            crate::audit::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Build client.
+    #[cfg(feature = "build")]
+    pub fn build(&self) -> crate::build::OrganizationClient {
+        self.service_client(
+            crate::build::DEFAULT_ENDPOINT,
+            crate::build::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Cloud Load Test client.
+    #[cfg(feature = "clt")]
+    pub fn clt(&self) -> crate::clt::OrganizationClient {
+        self.service_client(
+            crate::clt::DEFAULT_ENDPOINT,
+            crate::clt::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Core client.
+    #[cfg(feature = "core")]
+    pub fn core(&self) -> crate::core::OrganizationClient {
+        self.service_client(
+            crate::core::DEFAULT_ENDPOINT,
+            crate::core::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Dashboard client.
+    #[cfg(feature = "dashboard")]
+    pub fn dashboard(&self) -> crate::dashboard::OrganizationClient {
+        self.service_client(
+            crate::dashboard::DEFAULT_ENDPOINT,
+            crate::dashboard::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Distributed tasks client.
+    #[cfg(feature = "distributed_task")]
+    pub fn distributed_task(&self) -> crate::distributed_task::OrganizationClient {
+        self.service_client(
+            crate::distributed_task::DEFAULT_ENDPOINT,
+            crate::distributed_task::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Extension Management client.
+    #[cfg(feature = "extension_management")]
+    pub fn extension_management(&self) -> crate::extension_management::OrganizationClient {
+        self.service_client(
+            crate::extension_management::DEFAULT_ENDPOINT,
+            crate::extension_management::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Git client.
+    #[cfg(feature = "git")]
+    pub fn git(&self) -> crate::git::OrganizationClient {
+        self.service_client(
+            crate::git::DEFAULT_ENDPOINT,
+            crate::git::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Graph client.
+    #[cfg(feature = "graph")]
+    pub fn graph(&self) -> crate::graph::OrganizationClient {
+        self.service_client(
+            crate::graph::DEFAULT_ENDPOINT,
+            crate::graph::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Service Hooks client.
+    #[cfg(feature = "hooks")]
+    pub fn hooks(&self) -> crate::hooks::OrganizationClient {
+        self.service_client(
+            crate::hooks::DEFAULT_ENDPOINT,
+            crate::hooks::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Identities client.
+    #[cfg(feature = "ims")]
+    pub fn ims(&self) -> crate::ims::OrganizationClient {
+        self.service_client(
+            crate::ims::DEFAULT_ENDPOINT,
+            crate::ims::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Member Entitlement Management client.
+    #[cfg(feature = "member_entitlement_management")]
+    pub fn member_entitlement_management(
+        &self,
+    ) -> crate::member_entitlement_management::OrganizationClient {
+        self.service_client(
+            crate::member_entitlement_management::DEFAULT_ENDPOINT,
+            crate::member_entitlement_management::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Operations client.
+    #[cfg(feature = "operations")]
+    pub fn operations(&self) -> crate::operations::OrganizationClient {
+        self.service_client(
+            crate::operations::DEFAULT_ENDPOINT,
+            crate::operations::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Permissions Report client.
+    #[cfg(feature = "permissions_report")]
+    pub fn permissions_report(&self) -> crate::permissions_report::OrganizationClient {
+        self.service_client(
+            crate::permissions_report::DEFAULT_ENDPOINT,
+            crate::permissions_report::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Pipelines client.
+    #[cfg(feature = "pipelines")]
+    pub fn pipelines(&self) -> crate::pipelines::OrganizationClient {
+        self.service_client(
+            crate::pipelines::DEFAULT_ENDPOINT,
+            crate::pipelines::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Policy client.
+    #[cfg(feature = "policy")]
+    pub fn policy(&self) -> crate::policy::OrganizationClient {
+        self.service_client(
+            crate::policy::DEFAULT_ENDPOINT,
+            crate::policy::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Process admin client.
+    #[cfg(feature = "processadmin")]
+    pub fn processadmin(&self) -> crate::processadmin::OrganizationClient {
+        self.service_client(
+            crate::processadmin::DEFAULT_ENDPOINT,
+            crate::processadmin::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Processes client.
+    #[cfg(feature = "processes")]
+    pub fn processes(&self) -> crate::processes::OrganizationClient {
+        self.service_client(
+            crate::processes::DEFAULT_ENDPOINT,
+            crate::processes::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Profile client.
+    #[cfg(feature = "profile")]
+    pub fn profile(&self) -> crate::profile::OrganizationClient {
+        self.service_client(
+            crate::profile::DEFAULT_ENDPOINT,
+            crate::profile::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Release client.
+    #[cfg(feature = "release")]
+    pub fn release(&self) -> crate::release::OrganizationClient {
+        self.service_client(
+            crate::release::DEFAULT_ENDPOINT,
+            crate::release::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Search client.
+    #[cfg(feature = "search")]
+    pub fn search(&self) -> crate::search::OrganizationClient {
+        self.service_client(
+            crate::search::DEFAULT_ENDPOINT,
+            crate::search::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Security client.
+    #[cfg(feature = "security")]
+    pub fn security(&self) -> crate::security::OrganizationClient {
+        self.service_client(
+            crate::security::DEFAULT_ENDPOINT,
+            crate::security::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Service Endpoint (aka Service Connection) client.
+    #[cfg(feature = "service_endpoint")]
+    pub fn service_endpoint(&self) -> crate::service_endpoint::OrganizationClient {
+        self.service_client(
+            crate::service_endpoint::DEFAULT_ENDPOINT,
+            crate::service_endpoint::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Status client.
+    #[cfg(feature = "status")]
+    pub fn status(&self) -> crate::status::OrganizationClient {
+        self.service_client(
+            crate::status::DEFAULT_ENDPOINT,
+            crate::status::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Symbol client.
+    #[cfg(feature = "symbol")]
+    pub fn symbol(&self) -> crate::symbol::OrganizationClient {
+        self.service_client(
+            crate::symbol::DEFAULT_ENDPOINT,
+            crate::symbol::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Tests client.
+    #[cfg(feature = "test")]
+    pub fn test(&self) -> crate::test::OrganizationClient {
+        self.service_client(
+            crate::test::DEFAULT_ENDPOINT,
+            crate::test::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Test plans client.
+    #[cfg(feature = "test_plan")]
+    pub fn test_plan(&self) -> crate::test_plan::OrganizationClient {
+        self.service_client(
+            crate::test_plan::DEFAULT_ENDPOINT,
+            crate::test_plan::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Test results client.
+    #[cfg(feature = "test_results")]
+    pub fn test_results(&self) -> crate::test_results::OrganizationClient {
+        self.service_client(
+            crate::test_results::DEFAULT_ENDPOINT,
+            crate::test_results::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Team Foundation Version Control (TFVC) client.
+    #[cfg(feature = "tfvc")]
+    pub fn tfvc(&self) -> crate::tfvc::OrganizationClient {
+        self.service_client(
+            crate::tfvc::DEFAULT_ENDPOINT,
+            crate::tfvc::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Token Admin client.
+    #[cfg(feature = "token_admin")]
+    pub fn token_admin(&self) -> crate::token_admin::OrganizationClient {
+        self.service_client(
+            crate::token_admin::DEFAULT_ENDPOINT,
+            crate::token_admin::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Wiki client.
+    #[cfg(feature = "wiki")]
+    pub fn wiki(&self) -> crate::wiki::OrganizationClient {
+        self.service_client(
+            crate::wiki::DEFAULT_ENDPOINT,
+            crate::wiki::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Work Item Tracking client.
+    #[cfg(feature = "wit")]
+    pub fn wit(&self) -> crate::wit::OrganizationClient {
+        self.service_client(
+            crate::wit::DEFAULT_ENDPOINT,
+            crate::wit::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+
+    /// Work (boards, backlogs, iterations, plans) client.
+    #[cfg(feature = "work")]
+    pub fn work(&self) -> crate::work::OrganizationClient {
+        self.service_client(
+            crate::work::DEFAULT_ENDPOINT,
+            crate::work::Client::with_pipeline,
+        )
+        .for_organization(self.organization.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let client = AdoClient::builder(Credential::from_pat("pat"), "org")
+            .deployment(Deployment::server("https://tfs.corp/tfs/"))
+            .api_version("7.0")
+            .scopes(&["scope"])
+            .build();
+        assert_eq!(client.organization(), "org");
+        assert_eq!(
+            client.deployment,
+            Deployment::server("https://tfs.corp/tfs")
+        );
+        assert_eq!(client.api_version.as_deref(), Some("7.0"));
+        assert_eq!(client.scopes, Some(vec!["scope".to_owned()]));
+
+        let client = AdoClient::new(Credential::from_pat("pat"), "org");
+        assert_eq!(client.deployment, Deployment::Cloud);
+        assert_eq!(client.api_version, None);
+        assert_eq!(client.scopes, None);
+    }
+
+    #[cfg(all(feature = "git", feature = "release"))]
+    #[test]
+    fn test_service_clients() {
+        use azure_core::headers::Headers;
+        use azure_core::{
+            BytesStream, Context, Policy, PolicyResult, Request, Response, StatusCode,
+        };
+        use std::sync::Mutex;
+
+        /// Policy that records the URL of each request, and responds with an empty list.
+        #[derive(Debug, Default)]
+        struct MockTransport {
+            urls: Mutex<Vec<String>>,
+        }
+
+        #[async_trait::async_trait]
+        impl Policy for MockTransport {
+            async fn send(
+                &self,
+                _ctx: &Context,
+                request: &mut Request,
+                _next: &[Arc<dyn Policy>],
+            ) -> PolicyResult {
+                self.urls.lock().unwrap().push(request.url().to_string());
+                Ok(Response::new(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    Box::pin(BytesStream::new(r#"{"count": 0, "value": []}"#)),
+                ))
+            }
+        }
+
+        /// Lists the git repositories and releases of a project, returning the request URLs.
+        fn list(builder: AdoClientBuilder) -> Vec<String> {
+            let transport = Arc::new(MockTransport::default());
+            let client = builder
+                .per_call_policies(vec![transport.clone() as Arc<dyn Policy>])
+                .build();
+            futures::executor::block_on(async {
+                client
+                    .git()
+                    .repositories_client()
+                    .list("project")
+                    .await
+                    .unwrap();
+                client
+                    .release()
+                    .releases_client()
+                    .list("project")
+                    .await
+                    .unwrap();
+            });
+            let urls = transport.urls.lock().unwrap();
+            urls.clone()
+        }
+
+        let urls = list(AdoClient::builder(Credential::from_pat("pat"), "org"));
+        assert_eq!(urls.len(), 2);
+        assert!(urls[0].starts_with("https://dev.azure.com/org/project/_apis/git/repositories?"));
+        assert!(
+            urls[1].starts_with("https://vsrm.dev.azure.com/org/project/_apis/release/releases?")
+        );
+
+        let urls = list(
+            AdoClient::builder(Credential::from_pat("pat"), "org")
+                .deployment(Deployment::server("https://tfs.corp/tfs"))
+                .api_version("7.0"),
+        );
+        assert_eq!(urls.len(), 2);
+        assert!(urls[0].starts_with("https://tfs.corp/tfs/org/project/_apis/git/repositories?"));
+        assert!(urls[1].starts_with("https://tfs.corp/tfs/org/project/_apis/release/releases?"));
+        assert!(urls.iter().all(|url| url.contains("api-version=7.0")));
+    }
+}
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://vsclt.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn agent_groups_client(&self) -> agent_groups::Client {
@@ -158,6 +227,204 @@ impl Client {
         test_runs::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn agent_groups_client(&self) -> agent_groups::OrganizationClient {
+        agent_groups::OrganizationClient {
+            client: self.client.agent_groups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn agents_client(&self) -> agents::OrganizationClient {
+        agents::OrganizationClient {
+            client: self.client.agents_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn applications_client(&self) -> applications::OrganizationClient {
+        applications::OrganizationClient {
+            client: self.client.applications_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn counter_instances_client(&self) -> counter_instances::OrganizationClient {
+        counter_instances::OrganizationClient {
+            client: self.client.counter_instances_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn counter_samples_client(&self) -> counter_samples::OrganizationClient {
+        counter_samples::OrganizationClient {
+            client: self.client.counter_samples_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn counters_client(&self) -> counters::OrganizationClient {
+        counters::OrganizationClient {
+            client: self.client.counters_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn errors_client(&self) -> errors::OrganizationClient {
+        errors::OrganizationClient {
+            client: self.client.errors_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn messages_client(&self) -> messages::OrganizationClient {
+        messages::OrganizationClient {
+            client: self.client.messages_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn plugins_client(&self) -> plugins::OrganizationClient {
+        plugins::OrganizationClient {
+            client: self.client.plugins_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn results_client(&self) -> results::OrganizationClient {
+        results::OrganizationClient {
+            client: self.client.results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_definitions_client(&self) -> test_definitions::OrganizationClient {
+        test_definitions::OrganizationClient {
+            client: self.client.test_definitions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_drops_client(&self) -> test_drops::OrganizationClient {
+        test_drops::OrganizationClient {
+            client: self.client.test_drops_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_runs_client(&self) -> test_runs::OrganizationClient {
+        test_runs::OrganizationClient {
+            client: self.client.test_runs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn agent_groups_client(&self) -> agent_groups::ProjectClient {
+        agent_groups::ProjectClient {
+            client: self.client.agent_groups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn agents_client(&self) -> agents::ProjectClient {
+        agents::ProjectClient {
+            client: self.client.agents_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn applications_client(&self) -> applications::ProjectClient {
+        applications::ProjectClient {
+            client: self.client.applications_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn counter_instances_client(&self) -> counter_instances::ProjectClient {
+        counter_instances::ProjectClient {
+            client: self.client.counter_instances_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn counter_samples_client(&self) -> counter_samples::ProjectClient {
+        counter_samples::ProjectClient {
+            client: self.client.counter_samples_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn counters_client(&self) -> counters::ProjectClient {
+        counters::ProjectClient {
+            client: self.client.counters_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn errors_client(&self) -> errors::ProjectClient {
+        errors::ProjectClient {
+            client: self.client.errors_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn messages_client(&self) -> messages::ProjectClient {
+        messages::ProjectClient {
+            client: self.client.messages_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn plugins_client(&self) -> plugins::ProjectClient {
+        plugins::ProjectClient {
+            client: self.client.plugins_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn results_client(&self) -> results::ProjectClient {
+        results::ProjectClient {
+            client: self.client.results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_definitions_client(&self) -> test_definitions::ProjectClient {
+        test_definitions::ProjectClient {
+            client: self.client.test_definitions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_drops_client(&self) -> test_drops::ProjectClient {
+        test_drops::ProjectClient {
+            client: self.client.test_drops_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_runs_client(&self) -> test_runs::ProjectClient {
+        test_runs::ProjectClient {
+            client: self.client.test_runs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod agent_groups {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -197,6 +464,47 @@ pub mod agent_groups {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Agent group to be created"]
+        pub fn create(&self, body: impl Into<models::AgentGroup>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        pub fn get(&self, agent_group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_group_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Agent group to be created"]
+        pub fn create(&self, body: impl Into<models::AgentGroup>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        pub fn get(&self, agent_group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_group_id)
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -275,9 +583,10 @@ pub mod agent_groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -400,9 +709,10 @@ pub mod agent_groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(machine_setup_input) = &this.machine_setup_input {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -485,6 +795,59 @@ pub mod agents {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        pub fn get(&self, agent_group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_group_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        #[doc = "* `agent_name`: Name of the static agent"]
+        pub fn delete(
+            &self,
+            agent_group_id: impl Into<String>,
+            agent_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), agent_group_id, agent_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        pub fn get(&self, agent_group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_group_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `agent_group_id`: The agent group identifier"]
+        #[doc = "* `agent_name`: Name of the static agent"]
+        pub fn delete(
+            &self,
+            agent_group_id: impl Into<String>,
+            agent_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), agent_group_id, agent_name)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -570,9 +933,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(agent_name) = &this.agent_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -623,6 +987,25 @@ pub mod agents {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -679,9 +1062,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let agent_name = &this.agent_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -737,6 +1121,41 @@ pub mod applications {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `application_id`: Filter by APM application identifier."]
+        pub fn get(&self, application_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), application_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `application_id`: Filter by APM application identifier."]
+        pub fn get(&self, application_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), application_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -821,9 +1240,10 @@ pub mod applications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -927,9 +1347,10 @@ pub mod applications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -968,6 +1389,29 @@ pub mod counters {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1058,9 +1502,10 @@ pub mod counters {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(application_id) = &this.application_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1124,6 +1569,41 @@ pub mod plugins {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Currently ApplicationInsights is the only available plugin type."]
+        pub fn get(&self, type_: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), type_)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Currently ApplicationInsights is the only available plugin type."]
+        pub fn get(&self, type_: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), type_)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1202,9 +1682,10 @@ pub mod plugins {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1308,9 +1789,10 @@ pub mod plugins {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1394,6 +1876,61 @@ pub mod test_definitions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test definition to be created"]
+        pub fn create(&self, body: impl Into<models::TestDefinition>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        pub fn update(&self, body: impl Into<models::TestDefinition>) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_definition_id`: The test definition identifier"]
+        pub fn get(&self, test_definition_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), test_definition_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test definition to be created"]
+        pub fn create(&self, body: impl Into<models::TestDefinition>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        pub fn update(&self, body: impl Into<models::TestDefinition>) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_definition_id`: The test definition identifier"]
+        pub fn get(&self, test_definition_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), test_definition_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1489,9 +2026,10 @@ pub mod test_definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(from_date) = &this.from_date {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1608,9 +2146,10 @@ pub mod test_definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1711,9 +2250,10 @@ pub mod test_definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1815,9 +2355,10 @@ pub mod test_definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1875,6 +2416,47 @@ pub mod test_drops {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test drop to be created"]
+        pub fn create(&self, body: impl Into<models::TestDrop>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_drop_id`: The test drop identifier"]
+        pub fn get(&self, test_drop_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_drop_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test drop to be created"]
+        pub fn create(&self, body: impl Into<models::TestDrop>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_drop_id`: The test drop identifier"]
+        pub fn get(&self, test_drop_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_drop_id)
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1953,9 +2535,10 @@ pub mod test_drops {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2057,9 +2640,10 @@ pub mod test_drops {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2155,6 +2739,67 @@ pub mod test_runs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns test runs based on the filter specified. Returns all runs of the tenant if there is no filter."]
+        pub fn get_test_runs(&self) -> get_test_runs::RequestBuilder {
+            self.client.get_test_runs(self.organization.clone())
+        }
+        pub fn create(&self, body: impl Into<models::TestRun>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: Unique ID of the test run"]
+        pub fn get_test_run(&self, test_run_id: impl Into<String>) -> get_test_run::RequestBuilder {
+            self.client
+                .get_test_run(self.organization.clone(), test_run_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TestRun>,
+            test_run_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, test_run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns test runs based on the filter specified. Returns all runs of the tenant if there is no filter."]
+        pub fn get_test_runs(&self) -> get_test_runs::RequestBuilder {
+            self.client.get_test_runs(self.organization.clone())
+        }
+        pub fn create(&self, body: impl Into<models::TestRun>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: Unique ID of the test run"]
+        pub fn get_test_run(&self, test_run_id: impl Into<String>) -> get_test_run::RequestBuilder {
+            self.client
+                .get_test_run(self.organization.clone(), test_run_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TestRun>,
+            test_run_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, test_run_id)
+        }
+    }
     pub mod get_test_runs {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2290,9 +2935,10 @@ pub mod test_runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(name) = &this.name {
                             req.url_mut().query_pairs_mut().append_pair("name", name);
                         }
@@ -2440,9 +3086,10 @@ pub mod test_runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2544,9 +3191,10 @@ pub mod test_runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2616,9 +3264,10 @@ pub mod test_runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2653,6 +3302,47 @@ pub mod counter_instances {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        #[doc = "* `group_names`: Comma separated names of counter groups, such as 'Application', 'Performance' and 'Throughput'"]
+        pub fn list(
+            &self,
+            test_run_id: impl Into<String>,
+            group_names: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), test_run_id, group_names)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        #[doc = "* `group_names`: Comma separated names of counter groups, such as 'Application', 'Performance' and 'Throughput'"]
+        pub fn list(
+            &self,
+            test_run_id: impl Into<String>,
+            group_names: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), test_run_id, group_names)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2739,9 +3429,10 @@ pub mod counter_instances {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let group_names = &this.group_names;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2797,6 +3488,45 @@ pub mod counter_samples {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            test_run_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), body, test_run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            test_run_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), body, test_run_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2878,9 +3608,10 @@ pub mod counter_samples {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2929,6 +3660,35 @@ pub mod errors {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(&self, test_run_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(&self, test_run_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_run_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3026,9 +3786,10 @@ pub mod errors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -3084,6 +3845,35 @@ pub mod messages {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: Id of the test run"]
+        pub fn list(&self, test_run_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), test_run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: Id of the test run"]
+        pub fn list(&self, test_run_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), test_run_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3164,9 +3954,10 @@ pub mod messages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3209,6 +4000,35 @@ pub mod results {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(&self, test_run_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_run_id`: The test run identifier"]
+        pub fn get(&self, test_run_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), test_run_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3288,9 +4108,10 @@ pub mod results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "6.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("6.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
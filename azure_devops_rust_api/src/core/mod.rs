@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn avatar_client(&self) -> avatar::Client {
@@ -131,6 +200,87 @@ impl Client {
         teams::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn avatar_client(&self) -> avatar::OrganizationClient {
+        avatar::OrganizationClient {
+            client: self.client.avatar_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn processes_client(&self) -> processes::OrganizationClient {
+        processes::OrganizationClient {
+            client: self.client.processes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn projects_client(&self) -> projects::OrganizationClient {
+        projects::OrganizationClient {
+            client: self.client.projects_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn teams_client(&self) -> teams::OrganizationClient {
+        teams::OrganizationClient {
+            client: self.client.teams_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn avatar_client(&self) -> avatar::ProjectClient {
+        avatar::ProjectClient {
+            client: self.client.avatar_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn processes_client(&self) -> processes::ProjectClient {
+        processes::ProjectClient {
+            client: self.client.processes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn projects_client(&self) -> projects::ProjectClient {
+        projects::ProjectClient {
+            client: self.client.projects_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn teams_client(&self) -> teams::ProjectClient {
+        teams::ProjectClient {
+            client: self.client.teams_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod processes {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -162,6 +312,45 @@ pub mod processes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of processes."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a process by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID for a process."]
+        pub fn get(&self, process_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), process_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of processes."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a process by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID for a process."]
+        pub fn get(&self, process_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), process_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -239,9 +428,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -342,9 +532,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -491,6 +682,141 @@ pub mod projects {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all projects in the organization that the authenticated user has access to."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Queues a project to be created. Use the [GetOperation](../../operations/operations/get) to periodically check for create project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The project to create."]
+        pub fn create(&self, body: impl Into<models::TeamProject>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get project with the specified id or name, optionally including capabilities."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project_id)
+        }
+        #[doc = "Update an existing project's name, abbreviation, description, or restore a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updates for the project. The state must be set to wellFormed to restore the project."]
+        #[doc = "* `project_id`: The project id of the project to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamProject>,
+            project_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Queues a project to be deleted. Use the [GetOperation](../../operations/operations/get) to periodically check for delete project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The project id of the project to delete."]
+        pub fn delete(&self, project_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project_id)
+        }
+        #[doc = "Get a collection of team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        pub fn get_project_properties(
+            &self,
+            project_id: impl Into<String>,
+        ) -> get_project_properties::RequestBuilder {
+            self.client
+                .get_project_properties(self.organization.clone(), project_id)
+        }
+        #[doc = "Create, update, and delete team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        #[doc = "* `body`: A JSON Patch document that represents an array of property operations. See RFC 6902 for more details on JSON Patch. The accepted operation verbs are Add and Remove, where Add is used for both creating and updating properties. The path consists of a forward slash and a property name."]
+        pub fn set_project_properties(
+            &self,
+            project_id: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> set_project_properties::RequestBuilder {
+            self.client
+                .set_project_properties(self.organization.clone(), project_id, body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all projects in the organization that the authenticated user has access to."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Queues a project to be created. Use the [GetOperation](../../operations/operations/get) to periodically check for create project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The project to create."]
+        pub fn create(&self, body: impl Into<models::TeamProject>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get project with the specified id or name, optionally including capabilities."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project_id)
+        }
+        #[doc = "Update an existing project's name, abbreviation, description, or restore a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updates for the project. The state must be set to wellFormed to restore the project."]
+        #[doc = "* `project_id`: The project id of the project to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamProject>,
+            project_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Queues a project to be deleted. Use the [GetOperation](../../operations/operations/get) to periodically check for delete project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The project id of the project to delete."]
+        pub fn delete(&self, project_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project_id)
+        }
+        #[doc = "Get a collection of team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        pub fn get_project_properties(
+            &self,
+            project_id: impl Into<String>,
+        ) -> get_project_properties::RequestBuilder {
+            self.client
+                .get_project_properties(self.organization.clone(), project_id)
+        }
+        #[doc = "Create, update, and delete team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        #[doc = "* `body`: A JSON Patch document that represents an array of property operations. See RFC 6902 for more details on JSON Patch. The accepted operation verbs are Add and Remove, where Add is used for both creating and updating properties. The path consists of a forward slash and a property name."]
+        pub fn set_project_properties(
+            &self,
+            project_id: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> set_project_properties::RequestBuilder {
+            self.client
+                .set_project_properties(self.organization.clone(), project_id, body)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -595,9 +921,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(state_filter) = &this.state_filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -630,6 +957,59 @@ pub mod projects {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::TeamProjectReferenceList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::TeamProjectReferenceList>,
+            > {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TeamProjectReferenceList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TeamProjectReference>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TeamProjectReferenceList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TeamProjectReferenceList>;
@@ -726,9 +1106,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -842,9 +1223,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_capabilities) = &this.include_capabilities {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeCapabilities",
@@ -958,9 +1340,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1063,9 +1446,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1173,9 +1557,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(keys) = &this.keys {
                             req.url_mut().query_pairs_mut().append_pair("keys", keys);
                         }
@@ -1250,9 +1635,10 @@ pub mod projects {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1303,6 +1689,71 @@ pub mod avatar {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Sets the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The avatar blob data object to upload."]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn set_project_avatar(
+            &self,
+            body: impl Into<models::ProjectAvatar>,
+            project_id: impl Into<String>,
+        ) -> set_project_avatar::RequestBuilder {
+            self.client
+                .set_project_avatar(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Removes the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn remove_project_avatar(
+            &self,
+            project_id: impl Into<String>,
+        ) -> remove_project_avatar::RequestBuilder {
+            self.client
+                .remove_project_avatar(self.organization.clone(), project_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Sets the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The avatar blob data object to upload."]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn set_project_avatar(
+            &self,
+            body: impl Into<models::ProjectAvatar>,
+            project_id: impl Into<String>,
+        ) -> set_project_avatar::RequestBuilder {
+            self.client
+                .set_project_avatar(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Removes the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn remove_project_avatar(
+            &self,
+            project_id: impl Into<String>,
+        ) -> remove_project_avatar::RequestBuilder {
+            self.client
+                .remove_project_avatar(self.organization.clone(), project_id)
+        }
+    }
     pub mod set_project_avatar {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1351,9 +1802,10 @@ pub mod avatar {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1410,9 +1862,10 @@ pub mod avatar {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1563,6 +2016,177 @@ pub mod teams {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of teams."]
+        pub fn get_teams(&self, project_id: impl Into<String>) -> get_teams::RequestBuilder {
+            self.client.get_teams(self.organization.clone(), project_id)
+        }
+        #[doc = "Create a team in a team project.\n\nPossible failure scenarios\nInvalid project name/ID (project doesn't exist) 404\nInvalid team name or description 400\nTeam already exists 400\nInsufficient privileges 400"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The team data used to create the team."]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project in which to create the team."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Get a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team."]
+        pub fn get(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Update a team's name and/or description."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to update."]
+        #[doc = "* `team_id`: The name of ID of the team to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project_id, team_id)
+        }
+        #[doc = "Delete a team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to delete."]
+        #[doc = "* `team_id`: The name or ID of the team to delete."]
+        pub fn delete(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Get a list of members for a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project the team belongs to."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team ."]
+        pub fn get_team_members_with_extended_properties(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get_team_members_with_extended_properties::RequestBuilder {
+            self.client.get_team_members_with_extended_properties(
+                self.organization.clone(),
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Get a list of all teams."]
+        pub fn get_all_teams(&self) -> get_all_teams::RequestBuilder {
+            self.client.get_all_teams(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of teams."]
+        pub fn get_teams(&self, project_id: impl Into<String>) -> get_teams::RequestBuilder {
+            self.client.get_teams(self.organization.clone(), project_id)
+        }
+        #[doc = "Create a team in a team project.\n\nPossible failure scenarios\nInvalid project name/ID (project doesn't exist) 404\nInvalid team name or description 400\nTeam already exists 400\nInsufficient privileges 400"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The team data used to create the team."]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project in which to create the team."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project_id)
+        }
+        #[doc = "Get a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team."]
+        pub fn get(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Update a team's name and/or description."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to update."]
+        #[doc = "* `team_id`: The name of ID of the team to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project_id, team_id)
+        }
+        #[doc = "Delete a team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to delete."]
+        #[doc = "* `team_id`: The name or ID of the team to delete."]
+        pub fn delete(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Get a list of members for a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project the team belongs to."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team ."]
+        pub fn get_team_members_with_extended_properties(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get_team_members_with_extended_properties::RequestBuilder {
+            self.client.get_team_members_with_extended_properties(
+                self.organization.clone(),
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Get a list of all teams."]
+        pub fn get_all_teams(&self) -> get_all_teams::RequestBuilder {
+            self.client.get_all_teams(self.organization.clone())
+        }
+    }
     pub mod get_teams {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1666,9 +2290,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(mine) = &this.mine {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1695,6 +2320,37 @@ pub mod teams {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WebApiTeamList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::WebApiTeamList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WebApiTeam>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::WebApiTeamList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WebApiTeamList>;
@@ -1790,9 +2446,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1902,9 +2559,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand_identity) = &this.expand_identity {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2013,9 +2671,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2087,9 +2746,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2189,9 +2849,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2208,6 +2869,37 @@ pub mod teams {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TeamMemberList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TeamMemberList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TeamMember>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TeamMemberList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TeamMemberList>;
@@ -2324,9 +3016,10 @@ pub mod teams {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(mine) = &this.mine {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2353,6 +3046,37 @@ pub mod teams {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WebApiTeamList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::WebApiTeamList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WebApiTeam>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::WebApiTeamList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WebApiTeamList>;
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn dashboards_client(&self) -> dashboards::Client {
@@ -128,6 +197,74 @@ impl Client {
         widgets::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn dashboards_client(&self) -> dashboards::OrganizationClient {
+        dashboards::OrganizationClient {
+            client: self.client.dashboards_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn widget_types_client(&self) -> widget_types::OrganizationClient {
+        widget_types::OrganizationClient {
+            client: self.client.widget_types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn widgets_client(&self) -> widgets::OrganizationClient {
+        widgets::OrganizationClient {
+            client: self.client.widgets_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn dashboards_client(&self) -> dashboards::ProjectClient {
+        dashboards::ProjectClient {
+            client: self.client.dashboards_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn widget_types_client(&self) -> widget_types::ProjectClient {
+        widget_types::ProjectClient {
+            client: self.client.widget_types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn widgets_client(&self) -> widgets::ProjectClient {
+        widgets::ProjectClient {
+            client: self.client.widgets_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod widget_types {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -170,6 +307,70 @@ pub mod widget_types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all available widget metadata in alphabetical order, including widgets marked with isVisibleFromCatalog == false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_widget_types(
+            &self,
+            scope: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_widget_types::RequestBuilder {
+            self.client
+                .get_widget_types(self.organization.clone(), scope, project)
+        }
+        #[doc = "Get the widget metadata satisfying the specified contribution ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `contribution_id`: The ID of Contribution for the Widget"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_widget_metadata(
+            &self,
+            contribution_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_widget_metadata::RequestBuilder {
+            self.client
+                .get_widget_metadata(self.organization.clone(), contribution_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all available widget metadata in alphabetical order, including widgets marked with isVisibleFromCatalog == false."]
+        pub fn get_widget_types(
+            &self,
+            scope: impl Into<String>,
+        ) -> get_widget_types::RequestBuilder {
+            self.client
+                .get_widget_types(self.organization.clone(), scope, self.project.clone())
+        }
+        #[doc = "Get the widget metadata satisfying the specified contribution ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `contribution_id`: The ID of Contribution for the Widget"]
+        pub fn get_widget_metadata(
+            &self,
+            contribution_id: impl Into<String>,
+        ) -> get_widget_metadata::RequestBuilder {
+            self.client.get_widget_metadata(
+                self.organization.clone(),
+                contribution_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_widget_types {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -251,9 +452,10 @@ pub mod widget_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let scope = &this.scope;
                         req.url_mut().query_pairs_mut().append_pair("$scope", scope);
                         let req_body = azure_core::EMPTY_BODY;
@@ -361,9 +563,10 @@ pub mod widget_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -523,6 +726,205 @@ pub mod dashboards {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of dashboards under a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Create the supplied dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The initial state of the dashboard"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Dashboard>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Update the name and position of dashboards in the supplied group, and remove omitted dashboards. Does not modify dashboard content."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboards(
+            &self,
+            body: impl Into<models::DashboardGroup>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboards::RequestBuilder {
+            self.client
+                .replace_dashboards(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Get a dashboard by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, dashboard_id, team)
+        }
+        #[doc = "Replace configuration for the specified dashboard. Replaces Widget list on Dashboard, only if property is supplied."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Configuration of the dashboard to replace."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to replace."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboard(
+            &self,
+            body: impl Into<models::Dashboard>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboard::RequestBuilder {
+            self.client.replace_dashboard(
+                self.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Delete a dashboard given its ID. This also deletes the widgets associated with this dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to delete."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, dashboard_id, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of dashboards under a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Create the supplied dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The initial state of the dashboard"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Dashboard>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), team)
+        }
+        #[doc = "Update the name and position of dashboards in the supplied group, and remove omitted dashboards. Does not modify dashboard content."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboards(
+            &self,
+            body: impl Into<models::DashboardGroup>,
+            team: impl Into<String>,
+        ) -> replace_dashboards::RequestBuilder {
+            self.client.replace_dashboards(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get a dashboard by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace configuration for the specified dashboard. Replaces Widget list on Dashboard, only if property is supplied."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Configuration of the dashboard to replace."]
+        #[doc = "* `dashboard_id`: ID of the dashboard to replace."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboard(
+            &self,
+            body: impl Into<models::Dashboard>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboard::RequestBuilder {
+            self.client.replace_dashboard(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Delete a dashboard given its ID. This also deletes the widgets associated with this dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to delete."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -604,9 +1006,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -710,9 +1113,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -817,9 +1221,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -925,9 +1330,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1033,9 +1439,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1109,9 +1516,10 @@ pub mod dashboards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1332,6 +1740,351 @@ pub mod widgets {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get widgets contained on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widgets(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widgets::RequestBuilder {
+            self.client
+                .get_widgets(self.organization.clone(), project, dashboard_id, team)
+        }
+        #[doc = "Create a widget on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State of the widget to add"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of dashboard the widget will be added to."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, dashboard_id, team)
+        }
+        #[doc = "Replace the widgets on specified dashboard with the supplied widgets."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Revised state of widgets to store for the dashboard."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widgets::RequestBuilder {
+            self.client.replace_widgets(
+                self.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Update the supplied widgets on the dashboard using supplied state. State of existing Widgets not passed in the widget list is preserved."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of widget states to update on the dashboard."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widgets::RequestBuilder {
+            self.client
+                .update_widgets(self.organization.clone(), body, project, dashboard_id, team)
+        }
+        #[doc = "Get the current state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widget(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widget::RequestBuilder {
+            self.client.get_widget(
+                self.organization.clone(),
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Override the  state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State to be written for the widget."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widget::RequestBuilder {
+            self.client.replace_widget(
+                self.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Perform a partial update of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Description of the widget changes to apply. All non-null fields will be replaced."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widget::RequestBuilder {
+            self.client.update_widget(
+                self.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Delete the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get widgets contained on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widgets(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widgets::RequestBuilder {
+            self.client.get_widgets(
+                self.organization.clone(),
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Create a widget on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State of the widget to add"]
+        #[doc = "* `dashboard_id`: ID of dashboard the widget will be added to."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace the widgets on specified dashboard with the supplied widgets."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Revised state of widgets to store for the dashboard."]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widgets::RequestBuilder {
+            self.client.replace_widgets(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Update the supplied widgets on the dashboard using supplied state. State of existing Widgets not passed in the widget list is preserved."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of widget states to update on the dashboard."]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widgets::RequestBuilder {
+            self.client.update_widgets(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Get the current state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widget(
+            &self,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widget::RequestBuilder {
+            self.client.get_widget(
+                self.organization.clone(),
+                self.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Override the  state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State to be written for the widget."]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widget::RequestBuilder {
+            self.client.replace_widget(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Perform a partial update of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Description of the widget changes to apply. All non-null fields will be replaced."]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widget::RequestBuilder {
+            self.client.update_widget(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Delete the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+    }
     pub mod get_widgets {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1431,9 +2184,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(e_tag) = &this.e_tag {
                             req.insert_header("etag", e_tag);
                         }
@@ -1542,9 +2296,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1667,9 +2422,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(e_tag) = &this.e_tag {
@@ -1795,9 +2551,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(e_tag) = &this.e_tag {
@@ -1908,9 +2665,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2018,9 +2776,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2129,9 +2888,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2239,9 +2999,10 @@ pub mod widgets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -24,6 +24,13 @@ pub fn format_date_time(date_time: &OffsetDateTime) -> azure_core::error::Result
         })
 }
 
+/// Parses the given RFC3339 format String as a date-time
+pub fn parse_date_time(s: &str) -> azure_core::error::Result<OffsetDateTime> {
+    OffsetDateTime::parse(s, &Rfc3339).with_context(ErrorKind::DataConversion, || {
+        format!("Failed to parse date_time: {s}")
+    })
+}
+
 pub mod rfc3339 {
     use super::*;
 
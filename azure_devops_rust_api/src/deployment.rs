@@ -0,0 +1,97 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps deployment models.
+//!
+//! Azure DevOps is available as a cloud service (Azure DevOps Services) or as an
+//! on-premises server (Azure DevOps Server).
+//!
+//! For Azure DevOps Services each service is hosted on its own host, e.g. `https://dev.azure.com`
+//! for git and `https://vsrm.dev.azure.com` for releases, and requests are made to
+//! `{endpoint}/{organization}/...`.
+//!
+//! For Azure DevOps Server all services are hosted under a single URL, e.g. `https://tfs.corp/tfs`,
+//! and requests are made to `{url}/{collection}/...`. The project collection name is passed as the
+//! `organization` parameter of each operation.
+
+/// The Azure DevOps deployment model.
+///
+/// Set via `ClientBuilder::deployment()` to determine the default endpoint of each service client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Deployment {
+    /// Azure DevOps Services, using the default endpoint of each service.
+    #[default]
+    Cloud,
+    /// Azure DevOps Server, with all services hosted under `url`.
+    Server { url: String },
+}
+
+impl Deployment {
+    /// Creates a new Azure DevOps Services `Deployment`.
+    pub fn cloud() -> Self {
+        Deployment::Cloud
+    }
+
+    /// Creates a new Azure DevOps Server `Deployment`.
+    ///
+    /// `url` is the server URL that contains the project collections, e.g. `https://tfs.corp/tfs`.
+    pub fn server(url: impl Into<String>) -> Self {
+        let url = url.into();
+        Deployment::Server {
+            url: url.trim_end_matches('/').to_owned(),
+        }
+    }
+
+    /// Returns the endpoint for a service, given its Azure DevOps Services endpoint.
+    pub fn endpoint(&self, cloud_endpoint: &str) -> String {
+        match self {
+            Deployment::Cloud => cloud_endpoint.to_owned(),
+            Deployment::Server { url } => url.clone(),
+        }
+    }
+}
+
+/// Returns the API version to use for an operation.
+///
+/// If an API version override is set then it replaces the version of the operation,
+/// retaining the `-preview` suffix and resource version (e.g. `-preview.2`) for preview
+/// operations (unless the override specifies its own suffix).
+#[allow(dead_code)]
+pub(crate) fn api_version(api_version: Option<&str>, default: &str) -> String {
+    match (api_version, default.find("-preview")) {
+        (Some(api_version), Some(suffix)) if !api_version.contains('-') => {
+            format!("{api_version}{}", &default[suffix..])
+        }
+        (Some(api_version), _) => api_version.to_owned(),
+        (None, _) => default.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint() {
+        assert_eq!(
+            Deployment::Cloud.endpoint("https://vsrm.dev.azure.com"),
+            "https://vsrm.dev.azure.com"
+        );
+        assert_eq!(
+            Deployment::server("https://tfs.corp/tfs/").endpoint("https://vsrm.dev.azure.com"),
+            "https://tfs.corp/tfs"
+        );
+    }
+
+    #[test]
+    fn test_api_version() {
+        assert_eq!(api_version(None, "7.1-preview"), "7.1-preview");
+        assert_eq!(api_version(Some("7.0"), "7.1-preview"), "7.0-preview");
+        assert_eq!(api_version(Some("7.0"), "7.1-preview.2"), "7.0-preview.2");
+        assert_eq!(api_version(Some("7.0"), "7.1"), "7.0");
+        assert_eq!(
+            api_version(Some("6.0-preview.1"), "7.1-preview"),
+            "6.0-preview.1"
+        );
+    }
+}
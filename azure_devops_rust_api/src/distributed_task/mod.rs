@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn agentclouds_client(&self) -> agentclouds::Client {
@@ -170,6 +239,260 @@ impl Client {
         yamlschema::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn agentclouds_client(&self) -> agentclouds::OrganizationClient {
+        agentclouds::OrganizationClient {
+            client: self.client.agentclouds_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn agentcloudtypes_client(&self) -> agentcloudtypes::OrganizationClient {
+        agentcloudtypes::OrganizationClient {
+            client: self.client.agentcloudtypes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn agents_client(&self) -> agents::OrganizationClient {
+        agents::OrganizationClient {
+            client: self.client.agents_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn deploymentgroups_client(&self) -> deploymentgroups::OrganizationClient {
+        deploymentgroups::OrganizationClient {
+            client: self.client.deploymentgroups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn elasticpoollogs_client(&self) -> elasticpoollogs::OrganizationClient {
+        elasticpoollogs::OrganizationClient {
+            client: self.client.elasticpoollogs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn elasticpools_client(&self) -> elasticpools::OrganizationClient {
+        elasticpools::OrganizationClient {
+            client: self.client.elasticpools_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn environmentdeployment_records_client(
+        &self,
+    ) -> environmentdeployment_records::OrganizationClient {
+        environmentdeployment_records::OrganizationClient {
+            client: self.client.environmentdeployment_records_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn environments_client(&self) -> environments::OrganizationClient {
+        environments::OrganizationClient {
+            client: self.client.environments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn kubernetes_client(&self) -> kubernetes::OrganizationClient {
+        kubernetes::OrganizationClient {
+            client: self.client.kubernetes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn nodes_client(&self) -> nodes::OrganizationClient {
+        nodes::OrganizationClient {
+            client: self.client.nodes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pools_client(&self) -> pools::OrganizationClient {
+        pools::OrganizationClient {
+            client: self.client.pools_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn queues_client(&self) -> queues::OrganizationClient {
+        queues::OrganizationClient {
+            client: self.client.queues_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn requests_client(&self) -> requests::OrganizationClient {
+        requests::OrganizationClient {
+            client: self.client.requests_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn targets_client(&self) -> targets::OrganizationClient {
+        targets::OrganizationClient {
+            client: self.client.targets_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn taskgroups_client(&self) -> taskgroups::OrganizationClient {
+        taskgroups::OrganizationClient {
+            client: self.client.taskgroups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn variablegroups_client(&self) -> variablegroups::OrganizationClient {
+        variablegroups::OrganizationClient {
+            client: self.client.variablegroups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn yamlschema_client(&self) -> yamlschema::OrganizationClient {
+        yamlschema::OrganizationClient {
+            client: self.client.yamlschema_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn agentclouds_client(&self) -> agentclouds::ProjectClient {
+        agentclouds::ProjectClient {
+            client: self.client.agentclouds_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn agentcloudtypes_client(&self) -> agentcloudtypes::ProjectClient {
+        agentcloudtypes::ProjectClient {
+            client: self.client.agentcloudtypes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn agents_client(&self) -> agents::ProjectClient {
+        agents::ProjectClient {
+            client: self.client.agents_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn deploymentgroups_client(&self) -> deploymentgroups::ProjectClient {
+        deploymentgroups::ProjectClient {
+            client: self.client.deploymentgroups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn elasticpoollogs_client(&self) -> elasticpoollogs::ProjectClient {
+        elasticpoollogs::ProjectClient {
+            client: self.client.elasticpoollogs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn elasticpools_client(&self) -> elasticpools::ProjectClient {
+        elasticpools::ProjectClient {
+            client: self.client.elasticpools_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn environmentdeployment_records_client(
+        &self,
+    ) -> environmentdeployment_records::ProjectClient {
+        environmentdeployment_records::ProjectClient {
+            client: self.client.environmentdeployment_records_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn environments_client(&self) -> environments::ProjectClient {
+        environments::ProjectClient {
+            client: self.client.environments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn kubernetes_client(&self) -> kubernetes::ProjectClient {
+        kubernetes::ProjectClient {
+            client: self.client.kubernetes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn nodes_client(&self) -> nodes::ProjectClient {
+        nodes::ProjectClient {
+            client: self.client.nodes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pools_client(&self) -> pools::ProjectClient {
+        pools::ProjectClient {
+            client: self.client.pools_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn queues_client(&self) -> queues::ProjectClient {
+        queues::ProjectClient {
+            client: self.client.queues_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn requests_client(&self) -> requests::ProjectClient {
+        requests::ProjectClient {
+            client: self.client.requests_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn targets_client(&self) -> targets::ProjectClient {
+        targets::ProjectClient {
+            client: self.client.targets_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn taskgroups_client(&self) -> taskgroups::ProjectClient {
+        taskgroups::ProjectClient {
+            client: self.client.taskgroups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn variablegroups_client(&self) -> variablegroups::ProjectClient {
+        variablegroups::ProjectClient {
+            client: self.client.variablegroups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn yamlschema_client(&self) -> yamlschema::ProjectClient {
+        yamlschema::ProjectClient {
+            client: self.client.yamlschema_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod pools {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -274,6 +597,119 @@ pub mod pools {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of agent pools."]
+        pub fn get_agent_pools(&self) -> get_agent_pools::RequestBuilder {
+            self.client.get_agent_pools(self.organization.clone())
+        }
+        #[doc = "Get a list of agent pools."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: pool Ids to fetch"]
+        pub fn get_agent_pools_by_ids(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_pools_by_ids::RequestBuilder {
+            self.client
+                .get_agent_pools_by_ids(self.organization.clone(), pool_ids)
+        }
+        #[doc = "Create an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the new agent pool"]
+        pub fn add(&self, body: impl Into<models::TaskAgentPool>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Get information about an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: An agent pool ID"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on an agent pool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated agent pool details"]
+        #[doc = "* `pool_id`: The agent pool to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentPool>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, pool_id)
+        }
+        #[doc = "Delete an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: ID of the agent pool to delete"]
+        pub fn delete(&self, pool_id: i32) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), pool_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of agent pools."]
+        pub fn get_agent_pools(&self) -> get_agent_pools::RequestBuilder {
+            self.client.get_agent_pools(self.organization.clone())
+        }
+        #[doc = "Get a list of agent pools."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: pool Ids to fetch"]
+        pub fn get_agent_pools_by_ids(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_pools_by_ids::RequestBuilder {
+            self.client
+                .get_agent_pools_by_ids(self.organization.clone(), pool_ids)
+        }
+        #[doc = "Create an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the new agent pool"]
+        pub fn add(&self, body: impl Into<models::TaskAgentPool>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Get information about an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: An agent pool ID"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on an agent pool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated agent pool details"]
+        #[doc = "* `pool_id`: The agent pool to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentPool>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, pool_id)
+        }
+        #[doc = "Delete an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: ID of the agent pool to delete"]
+        pub fn delete(&self, pool_id: i32) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), pool_id)
+        }
+    }
     pub mod get_agent_pools {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -376,9 +812,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(pool_name) = &this.pool_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -505,9 +942,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let pool_ids = &this.pool_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -616,9 +1054,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -732,9 +1171,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(properties) = &this.properties {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -846,9 +1286,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -918,9 +1359,10 @@ pub mod pools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1072,6 +1514,174 @@ pub mod queues {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of agent queues by their names"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_names`: A comma-separated list of agent names to retrieve"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_by_names(
+            &self,
+            queue_names: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_by_names::RequestBuilder {
+            self.client
+                .get_agent_queues_by_names(self.organization.clone(), queue_names, project)
+        }
+        #[doc = "Get a list of agent queues by their IDs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_ids`: A comma-separated list of agent queue IDs to retrieve"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_by_ids(
+            &self,
+            queue_ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_by_ids::RequestBuilder {
+            self.client
+                .get_agent_queues_by_ids(self.organization.clone(), queue_ids, project)
+        }
+        #[doc = "Get a list of agent queues."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues(
+            &self,
+            project: impl Into<String>,
+        ) -> get_agent_queues::RequestBuilder {
+            self.client
+                .get_agent_queues(self.organization.clone(), project)
+        }
+        #[doc = "Get a list of agent queues by pool ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: A comma-separated list of pool ids to get the corresponding queues for"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_for_pools(
+            &self,
+            pool_ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_for_pools::RequestBuilder {
+            self.client
+                .get_agent_queues_for_pools(self.organization.clone(), pool_ids, project)
+        }
+        #[doc = "Create a new agent queue to connect a project to an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the queue to create"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskAgentQueue>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, project)
+        }
+        #[doc = "Get information about an agent queue."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to get information about"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, queue_id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), queue_id, project)
+        }
+        #[doc = "Removes an agent queue from a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to remove"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(&self, queue_id: i32, project: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), queue_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of agent queues by their names"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_names`: A comma-separated list of agent names to retrieve"]
+        pub fn get_agent_queues_by_names(
+            &self,
+            queue_names: impl Into<String>,
+        ) -> get_agent_queues_by_names::RequestBuilder {
+            self.client.get_agent_queues_by_names(
+                self.organization.clone(),
+                queue_names,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a list of agent queues by their IDs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_ids`: A comma-separated list of agent queue IDs to retrieve"]
+        pub fn get_agent_queues_by_ids(
+            &self,
+            queue_ids: impl Into<String>,
+        ) -> get_agent_queues_by_ids::RequestBuilder {
+            self.client.get_agent_queues_by_ids(
+                self.organization.clone(),
+                queue_ids,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a list of agent queues."]
+        pub fn get_agent_queues(&self) -> get_agent_queues::RequestBuilder {
+            self.client
+                .get_agent_queues(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get a list of agent queues by pool ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: A comma-separated list of pool ids to get the corresponding queues for"]
+        pub fn get_agent_queues_for_pools(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_queues_for_pools::RequestBuilder {
+            self.client.get_agent_queues_for_pools(
+                self.organization.clone(),
+                pool_ids,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a new agent queue to connect a project to an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the queue to create"]
+        pub fn add(&self, body: impl Into<models::TaskAgentQueue>) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get information about an agent queue."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to get information about"]
+        pub fn get(&self, queue_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), queue_id, self.project.clone())
+        }
+        #[doc = "Removes an agent queue from a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to remove"]
+        pub fn delete(&self, queue_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), queue_id, self.project.clone())
+        }
+    }
     pub mod get_agent_queues_by_names {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1160,9 +1770,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let queue_names = &this.queue_names;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1281,9 +1892,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let queue_ids = &this.queue_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1406,9 +2018,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(queue_name) = &this.queue_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1527,9 +2140,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let pool_ids = &this.pool_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1646,9 +2260,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(authorize_pipelines) = &this.authorize_pipelines {
@@ -1764,9 +2379,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(action_filter) = &this.action_filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1842,9 +2458,10 @@ pub mod queues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1895,95 +2512,246 @@ pub mod variablegroups {
             }
         }
         #[doc = "Add a variable group."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        pub fn share_variable_group(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::VariableGroupProjectReference>,
+            variable_group_id: i32,
+        ) -> share_variable_group::RequestBuilder {
+            share_variable_group::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                variable_group_id,
+            }
+        }
+        #[doc = "Update a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `group_id`: Id of the variable group to update."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::VariableGroupParameters>,
+            group_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                group_id,
+            }
+        }
+        #[doc = "Delete a variable group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            group_id: i32,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                group_id,
+                project_ids: project_ids.into(),
+            }
+        }
+        #[doc = "Get variable groups by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_ids`: Comma separated list of Ids of variable groups."]
+        pub fn get_variable_groups_by_id(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            group_ids: impl Into<String>,
+        ) -> get_variable_groups_by_id::RequestBuilder {
+            get_variable_groups_by_id::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                group_ids: group_ids.into(),
+            }
+        }
+        #[doc = "Get a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            group_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                group_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get variable groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_variable_groups(
+            &self,
+            project: impl Into<String>,
+        ) -> get_variable_groups::RequestBuilder {
+            self.client
+                .get_variable_groups(self.organization.clone(), project)
+        }
+        #[doc = "Add a variable group."]
+        pub fn add(&self, body: impl Into<models::VariableGroupParameters>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Add a variable group."]
+        pub fn share_variable_group(
+            &self,
+            body: Vec<models::VariableGroupProjectReference>,
+            variable_group_id: i32,
+        ) -> share_variable_group::RequestBuilder {
+            self.client
+                .share_variable_group(self.organization.clone(), body, variable_group_id)
+        }
+        #[doc = "Update a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::VariableGroupParameters>,
+            group_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a variable group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn delete(
+            &self,
+            group_id: i32,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), group_id, project_ids)
+        }
+        #[doc = "Get variable groups by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_ids`: Comma separated list of Ids of variable groups."]
+        pub fn get_variable_groups_by_id(
+            &self,
+            project: impl Into<String>,
+            group_ids: impl Into<String>,
+        ) -> get_variable_groups_by_id::RequestBuilder {
+            self.client
+                .get_variable_groups_by_id(self.organization.clone(), project, group_ids)
+        }
+        #[doc = "Get a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn get(&self, project: impl Into<String>, group_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, group_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get variable groups."]
+        pub fn get_variable_groups(&self) -> get_variable_groups::RequestBuilder {
+            self.client
+                .get_variable_groups(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Add a variable group."]
+        pub fn add(&self, body: impl Into<models::VariableGroupParameters>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Add a variable group."]
         pub fn share_variable_group(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::VariableGroupProjectReference>,
             variable_group_id: i32,
         ) -> share_variable_group::RequestBuilder {
-            share_variable_group::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body,
-                variable_group_id,
-            }
+            self.client
+                .share_variable_group(self.organization.clone(), body, variable_group_id)
         }
         #[doc = "Update a variable group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `group_id`: Id of the variable group to update."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::VariableGroupParameters>,
             group_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                group_id,
-            }
+            self.client
+                .update(self.organization.clone(), body, group_id)
         }
         #[doc = "Delete a variable group"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `group_id`: Id of the variable group."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             group_id: i32,
             project_ids: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                group_id,
-                project_ids: project_ids.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), group_id, project_ids)
         }
         #[doc = "Get variable groups by ids."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `group_ids`: Comma separated list of Ids of variable groups."]
         pub fn get_variable_groups_by_id(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             group_ids: impl Into<String>,
         ) -> get_variable_groups_by_id::RequestBuilder {
-            get_variable_groups_by_id::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                group_ids: group_ids.into(),
-            }
+            self.client.get_variable_groups_by_id(
+                self.organization.clone(),
+                self.project.clone(),
+                group_ids,
+            )
         }
         #[doc = "Get a variable group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `group_id`: Id of the variable group."]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            group_id: i32,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                group_id,
-            }
+        pub fn get(&self, group_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), group_id)
         }
     }
     pub mod get_variable_groups {
@@ -2096,9 +2864,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(group_name) = &this.group_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2130,6 +2899,25 @@ pub mod variablegroups {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::VariableGroupList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::VariableGroupList>;
@@ -2223,9 +3011,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2295,9 +3084,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let variable_group_id = &this.variable_group_id;
@@ -2391,9 +3181,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2464,9 +3255,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let project_ids = &this.project_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2560,9 +3352,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let group_ids = &this.group_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2669,9 +3462,10 @@ pub mod variablegroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2766,6 +3560,65 @@ pub mod agentclouds {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        pub fn add(&self, body: impl Into<models::TaskAgentCloud>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        pub fn get(&self, agent_cloud_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_cloud_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentCloud>,
+            agent_cloud_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, agent_cloud_id)
+        }
+        pub fn delete(&self, agent_cloud_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), agent_cloud_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        pub fn add(&self, body: impl Into<models::TaskAgentCloud>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        pub fn get(&self, agent_cloud_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), agent_cloud_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentCloud>,
+            agent_cloud_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, agent_cloud_id)
+        }
+        pub fn delete(&self, agent_cloud_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), agent_cloud_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2844,9 +3697,10 @@ pub mod agentclouds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2946,9 +3800,10 @@ pub mod agentclouds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3050,9 +3905,10 @@ pub mod agentclouds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3154,9 +4010,10 @@ pub mod agentclouds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3258,9 +4115,10 @@ pub mod agentclouds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3302,6 +4160,29 @@ pub mod requests {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn list(&self, agent_cloud_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), agent_cloud_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self, agent_cloud_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), agent_cloud_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3382,9 +4263,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3424,6 +4306,31 @@ pub mod agentcloudtypes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get agent cloud types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get agent cloud types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3502,9 +4409,10 @@ pub mod agentcloudtypes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3596,65 +4504,208 @@ pub mod agents {
         #[doc = "Replace an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove and reconfigure an agent from your organization."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Updated details about the replacing agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to replace"]
+        pub fn replace_agent(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> replace_agent::RequestBuilder {
+            replace_agent::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                pool_id,
+                agent_id,
+            }
+        }
+        #[doc = "Update agent details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Updated details about the agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to update"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                pool_id,
+                agent_id,
+            }
+        }
+        #[doc = "Delete an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `pool_id`: The pool ID to remove the agent from"]
+        #[doc = "* `agent_id`: The agent ID to remove"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                pool_id,
+                agent_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of agents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agents"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+        #[doc = "Adds an agent to a pool.  You probably don't want to call this endpoint directly. Instead, [configure an agent](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) using the agent download package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the agent being added"]
+        #[doc = "* `pool_id`: The agent pool in which to add the agent"]
+        pub fn add(&self, body: impl Into<models::TaskAgent>, pool_id: i32) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, pool_id)
+        }
+        #[doc = "Get information about an agent."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agent"]
+        #[doc = "* `agent_id`: The agent ID to get information about"]
+        pub fn get(&self, pool_id: i32, agent_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), pool_id, agent_id)
+        }
+        #[doc = "Replace an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove and reconfigure an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the replacing agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to replace"]
+        pub fn replace_agent(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> replace_agent::RequestBuilder {
+            self.client
+                .replace_agent(self.organization.clone(), body, pool_id, agent_id)
+        }
+        #[doc = "Update agent details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, pool_id, agent_id)
+        }
+        #[doc = "Delete an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The pool ID to remove the agent from"]
+        #[doc = "* `agent_id`: The agent ID to remove"]
+        pub fn delete(&self, pool_id: i32, agent_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), pool_id, agent_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of agents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agents"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+        #[doc = "Adds an agent to a pool.  You probably don't want to call this endpoint directly. Instead, [configure an agent](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) using the agent download package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the agent being added"]
+        #[doc = "* `pool_id`: The agent pool in which to add the agent"]
+        pub fn add(&self, body: impl Into<models::TaskAgent>, pool_id: i32) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, pool_id)
+        }
+        #[doc = "Get information about an agent."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agent"]
+        #[doc = "* `agent_id`: The agent ID to get information about"]
+        pub fn get(&self, pool_id: i32, agent_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), pool_id, agent_id)
+        }
+        #[doc = "Replace an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove and reconfigure an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `body`: Updated details about the replacing agent"]
         #[doc = "* `pool_id`: The agent pool to use"]
         #[doc = "* `agent_id`: The agent to replace"]
         pub fn replace_agent(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TaskAgent>,
             pool_id: i32,
             agent_id: i32,
         ) -> replace_agent::RequestBuilder {
-            replace_agent::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                pool_id,
-                agent_id,
-            }
+            self.client
+                .replace_agent(self.organization.clone(), body, pool_id, agent_id)
         }
         #[doc = "Update agent details."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Updated details about the agent"]
         #[doc = "* `pool_id`: The agent pool to use"]
         #[doc = "* `agent_id`: The agent to update"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TaskAgent>,
             pool_id: i32,
             agent_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                pool_id,
-                agent_id,
-            }
+            self.client
+                .update(self.organization.clone(), body, pool_id, agent_id)
         }
         #[doc = "Delete an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove an agent from your organization."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `pool_id`: The pool ID to remove the agent from"]
         #[doc = "* `agent_id`: The agent ID to remove"]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            pool_id: i32,
-            agent_id: i32,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                pool_id,
-                agent_id,
-            }
+        pub fn delete(&self, pool_id: i32, agent_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), pool_id, agent_id)
         }
     }
     pub mod list {
@@ -3775,9 +4826,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(agent_name) = &this.agent_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3914,9 +4966,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4047,9 +5100,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_capabilities) = &this.include_capabilities {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeCapabilities",
@@ -4178,9 +5232,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4285,9 +5340,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4359,9 +5415,10 @@ pub mod agents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4387,6 +5444,31 @@ pub mod yamlschema {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "GET the Yaml schema used for Yaml file validation."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "GET the Yaml schema used for Yaml file validation."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4470,9 +5552,10 @@ pub mod yamlschema {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(validate_task_names) = &this.validate_task_names {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4608,6 +5691,142 @@ pub mod deploymentgroups {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of deployment groups by name or IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::DeploymentGroupCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a deployment group by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, deployment_group_id)
+        }
+        #[doc = "Update a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::DeploymentGroupUpdateParameter>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                deployment_group_id,
+            )
+        }
+        #[doc = "Delete a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, deployment_group_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of deployment groups by name or IDs."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::DeploymentGroupCreateParameter>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a deployment group by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn get(&self, deployment_group_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Update a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to update."]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::DeploymentGroupUpdateParameter>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Delete a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to be deleted."]
+        pub fn delete(&self, deployment_group_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                deployment_group_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4724,9 +5943,10 @@ pub mod deploymentgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(name) = &this.name {
                             req.url_mut().query_pairs_mut().append_pair("name", name);
                         }
@@ -4759,6 +5979,24 @@ pub mod deploymentgroups {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeploymentGroupList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::DeploymentGroupList>;
@@ -4857,9 +6095,10 @@ pub mod deploymentgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4976,9 +6215,10 @@ pub mod deploymentgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(action_filter) = &this.action_filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5093,9 +6333,10 @@ pub mod deploymentgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5167,9 +6408,10 @@ pub mod deploymentgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5230,54 +6472,195 @@ pub mod targets {
                 client: self.0.clone(),
                 organization: organization.into(),
                 body,
-                project: project.into(),
+                project: project.into(),
+                deployment_group_id,
+            }
+        }
+        #[doc = "Get a deployment target by its ID in a deployment group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to which deployment target belongs."]
+        #[doc = "* `target_id`: ID of the deployment target to return."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+            target_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                deployment_group_id,
+                target_id,
+                expand: None,
+            }
+        }
+        #[doc = "Delete a deployment target in a deployment group. This deletes the agent from associated deployment pool too."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment target is deleted."]
+        #[doc = "* `target_id`: ID of the deployment target to delete."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+            target_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                deployment_group_id,
+                target_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, deployment_group_id)
+        }
+        #[doc = "Update tags of a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment targets with tags to udpdate."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment targets are updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::DeploymentTargetUpdateParameter>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
                 deployment_group_id,
-            }
+            )
         }
         #[doc = "Get a deployment target by its ID in a deployment group"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `deployment_group_id`: ID of the deployment group to which deployment target belongs."]
         #[doc = "* `target_id`: ID of the deployment target to return."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             deployment_group_id: i32,
             target_id: i32,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get(
+                self.organization.clone(),
+                project,
                 deployment_group_id,
                 target_id,
-                expand: None,
-            }
+            )
         }
         #[doc = "Delete a deployment target in a deployment group. This deletes the agent from associated deployment pool too."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment target is deleted."]
         #[doc = "* `target_id`: ID of the deployment target to delete."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             deployment_group_id: i32,
             target_id: i32,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.delete(
+                self.organization.clone(),
+                project,
                 deployment_group_id,
                 target_id,
-            }
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn list(&self, deployment_group_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Update tags of a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment targets with tags to udpdate."]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment targets are updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::DeploymentTargetUpdateParameter>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Get a deployment target by its ID in a deployment group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to which deployment target belongs."]
+        #[doc = "* `target_id`: ID of the deployment target to return."]
+        pub fn get(&self, deployment_group_id: i32, target_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                deployment_group_id,
+                target_id,
+            )
+        }
+        #[doc = "Delete a deployment target in a deployment group. This deletes the agent from associated deployment pool too."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment target is deleted."]
+        #[doc = "* `target_id`: ID of the deployment target to delete."]
+        pub fn delete(&self, deployment_group_id: i32, target_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                deployment_group_id,
+                target_id,
+            )
         }
     }
     pub mod list {
@@ -5421,9 +6804,10 @@ pub mod targets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(tags) = &this.tags {
                             req.url_mut().query_pairs_mut().append_pair("tags", tags);
                         }
@@ -5476,6 +6860,26 @@ pub mod targets {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::DeploymentMachineList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::DeploymentMachineList>;
@@ -5576,9 +6980,10 @@ pub mod targets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5693,9 +7098,10 @@ pub mod targets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5773,9 +7179,10 @@ pub mod targets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5889,6 +7296,134 @@ pub mod environments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all environments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create an environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::EnvironmentCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, project)
+        }
+        #[doc = "Get an environment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn get(&self, project: impl Into<String>, environment_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, environment_id)
+        }
+        #[doc = "Update the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment data to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn update(
+            &self,
+            body: impl Into<models::EnvironmentUpdateParameter>,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, environment_id)
+        }
+        #[doc = "Delete the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, environment_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all environments."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create an environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::EnvironmentCreateParameter>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get an environment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn get(&self, environment_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                environment_id,
+            )
+        }
+        #[doc = "Update the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment data to update."]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn update(
+            &self,
+            body: impl Into<models::EnvironmentUpdateParameter>,
+            environment_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                environment_id,
+            )
+        }
+        #[doc = "Delete the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn delete(&self, environment_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                environment_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5984,9 +7519,10 @@ pub mod environments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(name) = &this.name {
                             req.url_mut().query_pairs_mut().append_pair("name", name);
                         }
@@ -6006,6 +7542,26 @@ pub mod environments {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::EnvironmentInstanceList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::EnvironmentInstanceList>;
@@ -6104,9 +7660,10 @@ pub mod environments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6219,9 +7776,10 @@ pub mod environments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expands) = &this.expands {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6333,9 +7891,10 @@ pub mod environments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6409,9 +7968,10 @@ pub mod environments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6446,6 +8006,43 @@ pub mod environmentdeployment_records {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get environment deployment execution history"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, environment_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get environment deployment execution history"]
+        pub fn list(&self, environment_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                environment_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6534,9 +8131,10 @@ pub mod environmentdeployment_records {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6553,6 +8151,26 @@ pub mod environmentdeployment_records {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::EnvironmentDeploymentExecutionRecordList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::EnvironmentDeploymentExecutionRecordList>;
@@ -6605,32 +8223,121 @@ pub mod kubernetes {
             environment_id: i32,
             resource_id: i32,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                environment_id,
+                resource_id,
+            }
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            environment_id: i32,
+            resource_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                environment_id,
+                resource_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::KubernetesResourceCreateParameters>,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, project, environment_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+            resource_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
                 environment_id,
                 resource_id,
-            }
+            )
         }
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             environment_id: i32,
             resource_id: i32,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.delete(
+                self.organization.clone(),
+                project,
                 environment_id,
                 resource_id,
-            }
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn add(
+            &self,
+            body: impl Into<models::KubernetesResourceCreateParameters>,
+            environment_id: i32,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                environment_id,
+            )
+        }
+        pub fn get(&self, environment_id: i32, resource_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                environment_id,
+                resource_id,
+            )
+        }
+        pub fn delete(&self, environment_id: i32, resource_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                environment_id,
+                resource_id,
+            )
         }
     }
     pub mod add {
@@ -6716,9 +8423,10 @@ pub mod kubernetes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6818,9 +8526,10 @@ pub mod kubernetes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6886,9 +8595,10 @@ pub mod kubernetes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6989,6 +8699,126 @@ pub mod taskgroups {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Create a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group object to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskGroupCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body, project)
+        }
+        #[doc = "List task groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, task_group_id)
+        }
+        #[doc = "Update a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskGroupUpdateParameter>,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, task_group_id)
+        }
+        #[doc = "Delete a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, task_group_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Create a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group object to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskGroupCreateParameter>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "List task groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `task_group_id`: Id of the task group."]
+        pub fn list(&self, task_group_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                task_group_id,
+            )
+        }
+        #[doc = "Update a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group to update."]
+        #[doc = "* `task_group_id`: Id of the task group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskGroupUpdateParameter>,
+            task_group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                task_group_id,
+            )
+        }
+        #[doc = "Delete a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `task_group_id`: Id of the task group to be deleted."]
+        pub fn delete(&self, task_group_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                task_group_id,
+            )
+        }
+    }
     pub mod add {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7069,9 +8899,10 @@ pub mod taskgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7214,9 +9045,10 @@ pub mod taskgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expanded) = &this.expanded {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7255,6 +9087,25 @@ pub mod taskgroups {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TaskGroupList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::date_time::parse_date_time(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TaskGroupList>;
@@ -7352,9 +9203,10 @@ pub mod taskgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7432,9 +9284,10 @@ pub mod taskgroups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(comment) = &this.comment {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7516,6 +9369,93 @@ pub mod elasticpools {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of all Elastic Pools."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a new elastic pool. This will create a new TaskAgentPool at the organization level. If a project id is provided, this will create a new TaskAgentQueue in the specified project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Elastic pool to create. Contains the properties necessary for configuring a new ElasticPool."]
+        #[doc = "* `pool_name`: Name to use for the new TaskAgentPool"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ElasticPool>,
+            pool_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, pool_name)
+        }
+        #[doc = "Returns the Elastic Pool with the specified Pool Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the associated TaskAgentPool"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update settings on a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New Elastic Pool settings data"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticPoolSettings>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, pool_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of all Elastic Pools."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a new elastic pool. This will create a new TaskAgentPool at the organization level. If a project id is provided, this will create a new TaskAgentQueue in the specified project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Elastic pool to create. Contains the properties necessary for configuring a new ElasticPool."]
+        #[doc = "* `pool_name`: Name to use for the new TaskAgentPool"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ElasticPool>,
+            pool_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, pool_name)
+        }
+        #[doc = "Returns the Elastic Pool with the specified Pool Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the associated TaskAgentPool"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update settings on a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New Elastic Pool settings data"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticPoolSettings>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, pool_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7594,9 +9534,10 @@ pub mod elasticpools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7719,9 +9660,10 @@ pub mod elasticpools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let pool_name = &this.pool_name;
@@ -7848,9 +9790,10 @@ pub mod elasticpools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7952,9 +9895,10 @@ pub mod elasticpools {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7996,6 +9940,37 @@ pub mod elasticpoollogs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get elastic pool diagnostics logs for a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the Elastic Pool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get elastic pool diagnostics logs for a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the Elastic Pool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8082,9 +10057,10 @@ pub mod elasticpoollogs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8149,6 +10125,57 @@ pub mod nodes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of ElasticNodes currently in the ElasticPool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool id of the ElasticPool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on a specified ElasticNode"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticNodeSettings>,
+            pool_id: i32,
+            elastic_node_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, pool_id, elastic_node_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of ElasticNodes currently in the ElasticPool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool id of the ElasticPool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on a specified ElasticNode"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticNodeSettings>,
+            pool_id: i32,
+            elastic_node_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, pool_id, elastic_node_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8235,9 +10262,10 @@ pub mod nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(state) = &this.state {
                             req.url_mut().query_pairs_mut().append_pair("$state", state);
                         }
@@ -8344,9 +10372,10 @@ pub mod nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -0,0 +1,336 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps error responses.
+//!
+//! When a request fails, Azure DevOps normally returns a JSON body describing the error:
+//!
+//! ```json
+//! {
+//!   "$id": "1",
+//!   "innerException": null,
+//!   "message": "TF401179: An active pull request for the source and target branch already exists.",
+//!   "typeName": "Microsoft.TeamFoundation.Git.Server.GitPullRequestExistsException, Microsoft.TeamFoundation.SourceControl.WebServer",
+//!   "typeKey": "GitPullRequestExistsException",
+//!   "errorCode": 0,
+//!   "eventId": 3000
+//! }
+//! ```
+//!
+//! The clients parse this body into an [`AdoError`], which is attached to the returned
+//! [`azure_core::Error`] and can be retrieved with [`AdoError::from_error`].
+
+use azure_core::error::{Error, ErrorKind};
+use azure_core::{BytesStream, Context, Policy, PolicyResult, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// An error returned by Azure DevOps.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdoError {
+    /// The HTTP status code of the response.
+    ///
+    /// This is `None` for inner exceptions.
+    #[serde(skip)]
+    pub status: Option<StatusCode>,
+    #[serde(rename = "$id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The error that caused this error, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inner_exception: Option<Box<AdoError>>,
+    /// The error message.
+    #[serde(default)]
+    pub message: String,
+    /// The full .NET type name of the server exception.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    /// The short name of the server exception, e.g. `GitPullRequestExistsException`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<i32>,
+}
+
+impl AdoError {
+    /// Returns the `AdoError` attached to an error returned by a client, if any.
+    pub fn from_error(error: &Error) -> Option<&AdoError> {
+        let inner = error.get_ref()?;
+        if let Some(ado_error) = inner.downcast_ref::<AdoError>() {
+            return Some(ado_error);
+        }
+        // The error may have been wrapped with additional context.
+        inner.downcast_ref::<Error>().and_then(AdoError::from_error)
+    }
+
+    /// Returns the short name of the server exception, e.g. `GitPullRequestExistsException`.
+    pub fn type_key(&self) -> Option<&str> {
+        self.type_key.as_deref()
+    }
+
+    /// Returns `true` if the server returned `404 Not Found`.
+    pub fn is_not_found(&self) -> bool {
+        self.status == Some(StatusCode::NotFound)
+    }
+
+    /// Returns `true` if the server returned `409 Conflict`.
+    pub fn is_conflict(&self) -> bool {
+        self.status == Some(StatusCode::Conflict)
+    }
+
+    /// Returns `true` if the server returned `401 Unauthorized` or `403 Forbidden`.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self.status,
+            Some(StatusCode::Unauthorized) | Some(StatusCode::Forbidden)
+        )
+    }
+}
+
+impl std::fmt::Display for AdoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.type_key {
+            Some(type_key) => write!(f, "{}: {}", type_key, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for AdoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner_exception
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Pipeline policy that attaches an [`AdoError`] to the errors returned by the client.
+///
+/// This is a per-call policy, so it sees the final result of a request after any retries.
+/// The retry policy does not return the body of error responses, so the body of each error
+/// response is parsed by [`AdoErrorBodyPolicy`], which runs for each attempt.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AdoErrorPolicy;
+
+#[async_trait::async_trait]
+impl Policy for AdoErrorPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let last_error = Arc::new(LastAdoError::default());
+        let mut ctx = ctx.clone();
+        ctx.insert(last_error.clone());
+        let result = next[0].send(&ctx, request, &next[1..]).await;
+        let status = match &result {
+            Ok(response) if response.status().is_success() => return result,
+            Ok(response) => response.status(),
+            Err(error) => match error.kind() {
+                ErrorKind::HttpResponse { status, .. } => *status,
+                _ => return result,
+            },
+        };
+        let last_error = last_error.0.lock().unwrap().take();
+        match last_error {
+            Some(error) if error.status == Some(status) => {
+                let message = format!("server returned error status {status}: {error}");
+                let kind = ErrorKind::HttpResponse {
+                    status,
+                    error_code: error.type_key.clone(),
+                };
+                Err(Error::full(kind, error, message))
+            }
+            _ => result,
+        }
+    }
+}
+
+/// The [`AdoError`] parsed from the last error response of a request.
+#[derive(Debug, Default)]
+struct LastAdoError(Mutex<Option<AdoError>>);
+
+/// Pipeline policy that parses the body of each error response into an [`AdoError`], for
+/// [`AdoErrorPolicy`] to attach to the error returned by the client.
+///
+/// This is a per-retry policy, so it sees the response of each attempt. Responses are passed
+/// through to the retry policy unchanged.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AdoErrorBodyPolicy;
+
+#[async_trait::async_trait]
+impl Policy for AdoErrorBodyPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        let last_error = match ctx.get::<Arc<LastAdoError>>() {
+            Some(last_error) if !response.status().is_success() => last_error,
+            _ => return Ok(response),
+        };
+        let (status, headers, body) = response.deconstruct();
+        let bytes = body.collect().await?;
+        *last_error.0.lock().unwrap() = parse_error(status, &bytes);
+        Ok(Response::new(
+            status,
+            headers,
+            Box::pin(BytesStream::new(bytes)),
+        ))
+    }
+}
+
+/// Parses an Azure DevOps error response body.
+pub(crate) fn parse_error(status: StatusCode, body: &[u8]) -> Option<AdoError> {
+    let mut error: AdoError = serde_json::from_slice(body).ok()?;
+    if error.message.is_empty() && error.type_key.is_none() {
+        return None;
+    }
+    error.status = Some(status);
+    Some(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let body = br#"{
+            "$id": "1",
+            "innerException": null,
+            "message": "TF401179: An active pull request for the source and target branch already exists.",
+            "typeName": "Microsoft.TeamFoundation.Git.Server.GitPullRequestExistsException, Microsoft.TeamFoundation.SourceControl.WebServer",
+            "typeKey": "GitPullRequestExistsException",
+            "errorCode": 0,
+            "eventId": 3000
+        }"#;
+        let error = parse_error(StatusCode::Conflict, body).unwrap();
+        assert_eq!(error.id.as_deref(), Some("1"));
+        assert_eq!(error.type_key(), Some("GitPullRequestExistsException"));
+        assert_eq!(error.error_code, Some(0));
+        assert_eq!(error.event_id, Some(3000));
+        assert!(error.is_conflict());
+        assert!(!error.is_not_found());
+        assert_eq!(
+            error.to_string(),
+            "GitPullRequestExistsException: TF401179: An active pull request for the source and target branch already exists."
+        );
+    }
+
+    #[test]
+    fn test_parse_error_inner_exception() {
+        let body = br#"{
+            "message": "outer",
+            "typeKey": "OuterException",
+            "innerException": { "message": "inner", "typeKey": "InnerException" }
+        }"#;
+        let error = parse_error(StatusCode::BadRequest, body).unwrap();
+        let inner = error.inner_exception.as_deref().unwrap();
+        assert_eq!(inner.type_key(), Some("InnerException"));
+        assert_eq!(inner.status, None);
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_parse_error_not_ado_error() {
+        assert!(parse_error(StatusCode::NotFound, b"<html>Not Found</html>").is_none());
+        assert!(parse_error(StatusCode::NotFound, b"{}").is_none());
+    }
+
+    /// Policy that responds with an Azure DevOps error, counting the requests.
+    #[derive(Debug)]
+    struct MockTransport {
+        status: StatusCode,
+        calls: Mutex<u32>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for MockTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            *self.calls.lock().unwrap() += 1;
+            Ok(Response::new(
+                self.status,
+                azure_core::headers::Headers::new(),
+                Box::pin(BytesStream::new(
+                    r#"{"message": "Try again later.", "typeKey": "ServiceUnavailableException"}"#,
+                )),
+            ))
+        }
+    }
+
+    /// Policy that retries error responses once, then returns an error without the response
+    /// body, like the `azure_core` retry policy.
+    #[derive(Debug)]
+    struct MockRetryPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for MockRetryPolicy {
+        async fn send(
+            &self,
+            ctx: &Context,
+            request: &mut Request,
+            next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut retries = 0;
+            loop {
+                let response = next[0].send(ctx, request, &next[1..]).await?;
+                let status = response.status();
+                if status.is_success() {
+                    return Ok(response);
+                }
+                if retries == 1 {
+                    return Err(Error::message(
+                        ErrorKind::http_response(status, None),
+                        "retry policy expired and the request will no longer be retried",
+                    ));
+                }
+                retries += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_policies_attach_error_after_retries() {
+        let transport = Arc::new(MockTransport {
+            status: StatusCode::ServiceUnavailable,
+            calls: Mutex::new(0),
+        });
+        let pipeline: Vec<Arc<dyn Policy>> = vec![
+            Arc::new(MockRetryPolicy),
+            Arc::new(AdoErrorBodyPolicy),
+            transport.clone(),
+        ];
+        let mut request = Request::new(
+            azure_core::Url::parse("https://dev.azure.com/org/_apis/projects").unwrap(),
+            azure_core::Method::Get,
+        );
+        let error = futures::executor::block_on(AdoErrorPolicy.send(
+            &Context::new(),
+            &mut request,
+            &pipeline,
+        ))
+        .unwrap_err();
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+        assert_eq!(
+            error.kind(),
+            &ErrorKind::http_response(
+                StatusCode::ServiceUnavailable,
+                Some("ServiceUnavailableException".to_owned())
+            )
+        );
+        let ado_error = AdoError::from_error(&error).unwrap();
+        assert_eq!(ado_error.status, Some(StatusCode::ServiceUnavailable));
+        assert_eq!(ado_error.message, "Try again later.");
+    }
+}
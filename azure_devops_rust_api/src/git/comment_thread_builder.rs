@@ -0,0 +1,359 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Posting pull request comments on a file and line range.
+//!
+//! A comment on a file is a thread whose `threadContext` gives the file path and the positions
+//! of the commented lines, and whose `pullRequestThreadContext` gives the iterations being
+//! compared and the change tracking ID of the file, e.g.
+//!
+//! ```json
+//! {
+//!   "comments": [{ "parentCommentId": 0, "content": "Unused variable", "commentType": "text" }],
+//!   "status": "active",
+//!   "threadContext": {
+//!     "filePath": "/src/main.rs",
+//!     "rightFileStart": { "line": 12, "offset": 1 },
+//!     "rightFileEnd": { "line": 12, "offset": 1 }
+//!   },
+//!   "pullRequestThreadContext": {
+//!     "changeTrackingId": 4,
+//!     "iterationContext": { "firstComparingIteration": 1, "secondComparingIteration": 3 }
+//!   }
+//! }
+//! ```
+//!
+//! Without the iteration context the comment is not placed on the lines of the latest changes.
+//! [`CommentThreadBuilder`] resolves the latest iteration and the change tracking ID of the file
+//! via the `pull_request_iterations` and `pull_request_iteration_changes` operations.
+
+use super::commit_builder::item_path;
+use super::models::{
+    comment, comment_thread, Comment, CommentIterationContext, CommentPosition, CommentThread,
+    CommentThreadContext, GitPullRequestCommentThread, GitPullRequestCommentThreadContext,
+};
+use super::{pull_request_iteration_changes, pull_request_iterations, pull_request_threads};
+use azure_core::error::{Error, ErrorKind};
+use futures::future::BoxFuture;
+
+/// The number of changes requested per page when resolving change tracking IDs.
+const ITERATION_CHANGES_PAGE_SIZE: i32 = 2000;
+
+impl pull_request_threads::Client {
+    /// Creates a [`CommentThreadBuilder`] that posts a comment thread on a pull request.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: Repository ID of the pull request's target branch.
+    /// * `pull_request_id`: ID of the pull request.
+    /// * `project`: Project ID or project name
+    /// * `content`: The content of the first comment of the thread.
+    pub fn comment(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        pull_request_id: i32,
+        project: impl Into<String>,
+        content: impl Into<String>,
+    ) -> CommentThreadBuilder {
+        CommentThreadBuilder {
+            client: self.0.clone(),
+            organization: organization.into(),
+            repository_id: repository_id.into(),
+            pull_request_id,
+            project: project.into(),
+            content: content.into(),
+            file_path: None,
+            start: None,
+            end: None,
+            iteration: None,
+            base_iteration: None,
+            status: comment_thread::Status::Active,
+        }
+    }
+}
+
+scoped_client_methods! {
+    pull_request_threads;
+    /// Creates a [`CommentThreadBuilder`] that posts a comment thread on a pull request.
+    pub fn comment(
+        repository_id: impl Into<String>,
+        pull_request_id: i32,
+        [project],
+        content: impl Into<String>,
+    ) -> CommentThreadBuilder;
+}
+
+/// Builder for a pull request comment thread, optionally placed on a file and line range.
+///
+/// Without a [`file()`](CommentThreadBuilder::file) the comment is on the pull request as a whole.
+/// With a file but no [`lines()`](CommentThreadBuilder::lines) the comment is on the whole file.
+/// Lines are those of the file in the source branch, i.e. the right side of the diff.
+///
+/// The comment is placed on the latest iteration of the pull request, unless
+/// [`iteration()`](CommentThreadBuilder::iteration) is set. Sending the thread fails if the file
+/// is not changed in that iteration.
+///
+/// Example:
+///
+/// ```ignore
+/// let thread = git_client
+///     .pull_request_threads_client()
+///     .comment(organization, repository, pull_request_id, project, "Unused variable `x`")
+///     .file("src/main.rs")
+///     .lines(12, 12)
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct CommentThreadBuilder {
+    client: super::Client,
+    organization: String,
+    repository_id: String,
+    pull_request_id: i32,
+    project: String,
+    content: String,
+    file_path: Option<String>,
+    start: Option<CommentPosition>,
+    end: Option<CommentPosition>,
+    iteration: Option<i32>,
+    base_iteration: Option<i32>,
+    status: comment_thread::Status,
+}
+
+impl CommentThreadBuilder {
+    /// Places the comment on a file, e.g. `src/main.rs`.
+    pub fn file(mut self, path: impl AsRef<str>) -> Self {
+        self.file_path = Some(item_path(path.as_ref()));
+        self
+    }
+
+    /// Places the comment on a range of lines of the file, from the start of `start_line` to the
+    /// start of `end_line`. Line numbers start at 1.
+    pub fn lines(self, start_line: i32, end_line: i32) -> Self {
+        self.range(start_line, 1, end_line, 1)
+    }
+
+    /// Places the comment on a range of characters of the file. Lines and offsets start at 1.
+    pub fn range(
+        mut self,
+        start_line: i32,
+        start_offset: i32,
+        end_line: i32,
+        end_offset: i32,
+    ) -> Self {
+        self.start = Some(position(start_line, start_offset));
+        self.end = Some(position(end_line, end_offset));
+        self
+    }
+
+    /// Places the comment on an iteration of the pull request (default: the latest iteration).
+    pub fn iteration(mut self, iteration: i32) -> Self {
+        self.iteration = Some(iteration);
+        self
+    }
+
+    /// Sets the iteration that the commented iteration is compared to (default: 1).
+    pub fn base_iteration(mut self, base_iteration: i32) -> Self {
+        self.base_iteration = Some(base_iteration);
+        self
+    }
+
+    /// Sets the status of the thread (default: `Active`), e.g. `Fixed` or `WontFix`.
+    pub fn status(mut self, status: comment_thread::Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Creates the thread, resolving the iteration and change tracking ID of the file.
+    pub async fn send(self) -> azure_core::Result<GitPullRequestCommentThread> {
+        let pull_request_thread_context = match &self.file_path {
+            Some(file_path) => Some(self.pull_request_thread_context(file_path).await?),
+            None => None,
+        };
+        let thread = self.thread(pull_request_thread_context);
+        pull_request_threads::Client(self.client.clone())
+            .create(
+                &self.organization,
+                thread,
+                &self.repository_id,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Builds the thread to create.
+    fn thread(
+        &self,
+        pull_request_thread_context: Option<GitPullRequestCommentThreadContext>,
+    ) -> GitPullRequestCommentThread {
+        let comment = Comment {
+            content: Some(self.content.clone()),
+            comment_type: Some(comment::CommentType::Text),
+            parent_comment_id: Some(0),
+            ..Default::default()
+        };
+        let thread_context = self
+            .file_path
+            .as_ref()
+            .map(|file_path| CommentThreadContext {
+                file_path: Some(file_path.clone()),
+                right_file_start: self.start.clone(),
+                right_file_end: self.end.clone(),
+                ..Default::default()
+            });
+        GitPullRequestCommentThread {
+            comment_thread: CommentThread {
+                comments: vec![comment],
+                status: Some(self.status.clone()),
+                thread_context,
+                ..Default::default()
+            },
+            pull_request_thread_context,
+        }
+    }
+
+    /// Returns the iteration context of the comment, and the change tracking ID of the file.
+    async fn pull_request_thread_context(
+        &self,
+        file_path: &str,
+    ) -> azure_core::Result<GitPullRequestCommentThreadContext> {
+        let iteration = match self.iteration {
+            Some(iteration) => iteration,
+            None => self.latest_iteration().await?,
+        };
+        Ok(GitPullRequestCommentThreadContext {
+            change_tracking_id: Some(self.change_tracking_id(iteration, file_path).await?),
+            iteration_context: Some(CommentIterationContext {
+                first_comparing_iteration: Some(self.base_iteration.unwrap_or(1).into()),
+                second_comparing_iteration: Some(iteration.into()),
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn latest_iteration(&self) -> azure_core::Result<i32> {
+        let iterations = pull_request_iterations::Client(self.client.clone())
+            .list(
+                &self.organization,
+                &self.repository_id,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await?;
+        iterations
+            .value
+            .iter()
+            .filter_map(|iteration| iteration.id)
+            .max()
+            .ok_or_else(|| {
+                Error::with_message(ErrorKind::Other, || {
+                    format!("Pull request {} has no iterations", self.pull_request_id)
+                })
+            })
+    }
+
+    /// Returns the change tracking ID of a file in an iteration.
+    ///
+    /// Fails if the file was not changed, as the comment cannot be placed on the file.
+    async fn change_tracking_id(&self, iteration: i32, file_path: &str) -> azure_core::Result<i32> {
+        let client = pull_request_iteration_changes::Client(self.client.clone());
+        let mut skip = 0;
+        loop {
+            let mut request = client
+                .get(
+                    &self.organization,
+                    &self.repository_id,
+                    self.pull_request_id,
+                    iteration,
+                    &self.project,
+                )
+                .top(ITERATION_CHANGES_PAGE_SIZE)
+                .skip(skip);
+            if let Some(base_iteration) = self.base_iteration {
+                request = request.compare_to(base_iteration);
+            }
+            let changes = request.await?;
+            let change = changes
+                .change_entries
+                .iter()
+                .find(|change| change.git_change.change.item["path"].as_str() == Some(file_path));
+            if let Some(change_tracking_id) = change.and_then(|change| change.change_tracking_id) {
+                return Ok(change_tracking_id);
+            }
+            match changes.next_skip {
+                Some(next_skip) if next_skip > skip => skip = next_skip,
+                _ => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "File {file_path} is not changed in iteration {iteration} of pull request {}",
+                            self.pull_request_id
+                        )
+                    }))
+                }
+            }
+        }
+    }
+}
+
+impl std::future::IntoFuture for CommentThreadBuilder {
+    type Output = azure_core::Result<GitPullRequestCommentThread>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<GitPullRequestCommentThread>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+fn position(line: i32, offset: i32) -> CommentPosition {
+    CommentPosition {
+        line: Some(line),
+        offset: Some(offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Credential;
+    use serde_json::json;
+
+    #[test]
+    fn test_comment_thread() {
+        let client = super::super::ClientBuilder::new(Credential::from_pat("pat")).build();
+        let builder = pull_request_threads::Client(client)
+            .comment("org", "repo", 7, "project", "Unused variable")
+            .file("src/main.rs")
+            .lines(12, 14)
+            .status(comment_thread::Status::WontFix);
+        let context = GitPullRequestCommentThreadContext {
+            change_tracking_id: Some(4),
+            iteration_context: Some(CommentIterationContext {
+                first_comparing_iteration: Some(1),
+                second_comparing_iteration: Some(3),
+            }),
+            ..Default::default()
+        };
+        let thread = serde_json::to_value(builder.thread(Some(context))).unwrap();
+        let comment = &thread["comments"][0];
+        assert_eq!(comment["content"], "Unused variable");
+        assert_eq!(comment["commentType"], "text");
+        assert_eq!(comment["parentCommentId"], 0);
+        assert_eq!(thread["status"], "wontFix");
+        assert_eq!(
+            thread["threadContext"],
+            json!({
+                "filePath": "/src/main.rs",
+                "rightFileStart": { "line": 12, "offset": 1 },
+                "rightFileEnd": { "line": 14, "offset": 1 }
+            })
+        );
+        assert_eq!(
+            thread["pullRequestThreadContext"],
+            json!({
+                "changeTrackingId": 4,
+                "iterationContext": { "firstComparingIteration": 1, "secondComparingIteration": 3 }
+            })
+        );
+    }
+}
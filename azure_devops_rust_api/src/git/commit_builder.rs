@@ -0,0 +1,424 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Committing file changes to a branch.
+//!
+//! A commit is created by a push that contains the commit and a ref update, e.g.
+//!
+//! ```json
+//! {
+//!   "refUpdates": [{ "name": "refs/heads/main", "oldObjectId": "<tip of main>" }],
+//!   "commits": [{
+//!     "comment": "Bump version",
+//!     "changes": [{
+//!       "changeType": "edit",
+//!       "item": { "path": "/version.txt" },
+//!       "newContent": { "content": "MS4yLjM=", "contentType": "base64Encoded" }
+//!     }]
+//!   }]
+//! }
+//! ```
+//!
+//! The `GitChange` and `GitCommitRef` models describe the changes and commits returned by the
+//! service, so cannot express this request body. [`CommitBuilder`] builds and sends it, resolving
+//! the current tip of the branch and retrying if the branch is updated concurrently.
+
+use super::models::{
+    change::ChangeType, item_content::ContentType, GitPush, GitRefUpdate, ItemContent,
+};
+use super::{pushes, refs};
+use crate::AdoError;
+use azure_core::error::{Error, ErrorKind};
+use azure_core::{Method, StatusCode};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use futures::future::BoxFuture;
+use serde::Serialize;
+
+/// The default number of times that a push is retried when the branch has been updated concurrently.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// The API version of the pushes `create` operation.
+const PUSHES_CREATE_API_VERSION: &str = "7.1-preview";
+
+/// Returns the full name of a ref, e.g. `refs/heads/main` for the branch `main`.
+pub(crate) fn full_ref_name(name: &str) -> String {
+    if name.starts_with("refs/") {
+        name.to_owned()
+    } else {
+        format!("refs/heads/{name}")
+    }
+}
+
+/// Returns the object ID of a ref, or `None` if the ref does not exist.
+pub(crate) async fn ref_object_id(
+    client: &super::Client,
+    organization: &str,
+    repository_id: &str,
+    project: &str,
+    name: &str,
+) -> azure_core::Result<Option<String>> {
+    let name = full_ref_name(name);
+    let refs = refs::Client(client.clone())
+        .list(organization, repository_id, project)
+        .filter(name.trim_start_matches("refs/"))
+        .await?;
+    Ok(refs
+        .value
+        .into_iter()
+        .find(|git_ref| git_ref.name == name)
+        .map(|git_ref| git_ref.object_id))
+}
+
+impl pushes::Client {
+    /// Creates a [`CommitBuilder`] that commits file changes to a branch.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: The name or ID of the repository.
+    /// * `project`: Project ID or project name
+    /// * `branch`: The name of the branch, e.g. `main` or `refs/heads/main`.
+    pub fn commit(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        project: impl Into<String>,
+        branch: impl Into<String>,
+    ) -> CommitBuilder {
+        CommitBuilder {
+            client: self.0.clone(),
+            organization: organization.into(),
+            repository_id: repository_id.into(),
+            project: project.into(),
+            branch: full_ref_name(&branch.into()),
+            create_from: None,
+            message: None,
+            author: None,
+            changes: Vec::new(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+}
+
+scoped_client_methods! {
+    pushes;
+    /// Creates a [`CommitBuilder`] that commits file changes to a branch.
+    pub fn commit(
+        repository_id: impl Into<String>,
+        [project],
+        branch: impl Into<String>,
+    ) -> CommitBuilder;
+}
+
+/// Builder for a commit that is pushed to a branch.
+///
+/// The commit is based on the current tip of the branch, which is resolved when the commit is pushed.
+/// If the branch is updated by another client before the push completes, the tip is resolved again
+/// and the push retried, up to [`max_retries`](CommitBuilder::max_retries) times.
+///
+/// Example:
+///
+/// ```ignore
+/// let push = git_client
+///     .pushes_client()
+///     .commit(organization, repository, project, "main")
+///     .edit_file("/version.txt", "1.2.3\n")
+///     .message("Bump version to 1.2.3")
+///     .push()
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct CommitBuilder {
+    client: super::Client,
+    organization: String,
+    repository_id: String,
+    project: String,
+    branch: String,
+    create_from: Option<String>,
+    message: Option<String>,
+    author: Option<Author>,
+    changes: Vec<FileChange>,
+    max_retries: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FileChange {
+    change_type: ChangeType,
+    item: ChangeItem,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_server_item: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_content: Option<ItemContent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct Author {
+    name: String,
+    email: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct ChangeItem {
+    path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PushRequest<'a> {
+    ref_updates: Vec<GitRefUpdate>,
+    commits: Vec<CommitRequest<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitRequest<'a> {
+    comment: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<&'a Author>,
+    changes: &'a [FileChange],
+}
+
+impl CommitBuilder {
+    /// The commit message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The author of the commit. Defaults to the authenticated user.
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(Author {
+            name: name.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    /// Creates the branch from the tip of `source` (e.g. `main`) if it does not exist.
+    pub fn create_from(mut self, source: impl Into<String>) -> Self {
+        self.create_from = Some(source.into());
+        self
+    }
+
+    /// Adds a new file.
+    pub fn add_file(self, path: &str, content: impl AsRef<[u8]>) -> Self {
+        self.change(ChangeType::Add, path, None, Some(content.as_ref()))
+    }
+
+    /// Replaces the content of an existing file.
+    pub fn edit_file(self, path: &str, content: impl AsRef<[u8]>) -> Self {
+        self.change(ChangeType::Edit, path, None, Some(content.as_ref()))
+    }
+
+    /// Deletes a file.
+    pub fn delete(self, path: &str) -> Self {
+        self.change(ChangeType::Delete, path, None, None)
+    }
+
+    /// Renames (moves) a file.
+    pub fn rename(self, from: &str, to: &str) -> Self {
+        self.change(ChangeType::Rename, to, Some(from), None)
+    }
+
+    /// The number of times that the push is retried if the branch is updated concurrently (default 3).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    fn change(
+        mut self,
+        change_type: ChangeType,
+        path: &str,
+        source: Option<&str>,
+        content: Option<&[u8]>,
+    ) -> Self {
+        self.changes.push(FileChange {
+            change_type,
+            item: ChangeItem {
+                path: item_path(path),
+            },
+            source_server_item: source.map(item_path),
+            new_content: content.map(|content| ItemContent {
+                content: Some(BASE64_STANDARD.encode(content)),
+                content_type: Some(ContentType::Base64Encoded),
+            }),
+        });
+        self
+    }
+
+    /// Pushes the commit, returning the push.
+    pub async fn push(self) -> azure_core::Result<GitPush> {
+        if self.changes.is_empty() {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "A commit must contain at least one change",
+            ));
+        }
+        let mut attempt = 0;
+        loop {
+            let old_object_id = self.resolve_tip().await?;
+            match self.send(old_object_id).await {
+                Err(error) if attempt < self.max_retries && is_stale_ref(&error) => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns the object ID that the branch is updated from.
+    async fn resolve_tip(&self) -> azure_core::Result<String> {
+        let tip = self.ref_object_id(&self.branch).await?;
+        if let Some(tip) = tip {
+            return Ok(tip);
+        }
+        match &self.create_from {
+            // A new branch is created with the commit it is based on as its old object ID.
+            Some(source) => self.ref_object_id(source).await?.ok_or_else(|| {
+                Error::with_message(ErrorKind::Other, || {
+                    format!("Branch {source} does not exist")
+                })
+            }),
+            None => Err(Error::with_message(ErrorKind::Other, || {
+                format!("Branch {} does not exist", self.branch)
+            })),
+        }
+    }
+
+    async fn ref_object_id(&self, name: &str) -> azure_core::Result<Option<String>> {
+        ref_object_id(
+            &self.client,
+            &self.organization,
+            &self.repository_id,
+            &self.project,
+            name,
+        )
+        .await
+    }
+
+    fn request_body(&self, old_object_id: String) -> PushRequest<'_> {
+        PushRequest {
+            ref_updates: vec![GitRefUpdate {
+                name: Some(self.branch.clone()),
+                old_object_id: Some(old_object_id),
+                ..Default::default()
+            }],
+            commits: vec![CommitRequest {
+                comment: self.message.as_deref().unwrap_or_default(),
+                author: self.author.as_ref(),
+                changes: &self.changes,
+            }],
+        }
+    }
+
+    async fn send(&self, old_object_id: String) -> azure_core::Result<GitPush> {
+        let body = azure_core::to_json(&self.request_body(old_object_id))?;
+        let path = format!(
+            "{}/{}/_apis/git/repositories/{}/pushes",
+            &self.organization, &self.project, &self.repository_id
+        );
+        self.client
+            .send_json(Method::Post, &path, PUSHES_CREATE_API_VERSION, |req| {
+                req.insert_header("content-type", "application/json");
+                req.set_body(body);
+                Ok(())
+            })
+            .await
+    }
+}
+
+impl std::future::IntoFuture for CommitBuilder {
+    type Output = azure_core::Result<GitPush>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<GitPush>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.push())
+    }
+}
+
+/// Returns the path of an item, relative to the root of the repository.
+pub(crate) fn item_path(path: &str) -> String {
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+/// Returns `true` if a push failed because the branch was updated after its tip was resolved.
+fn is_stale_ref(error: &Error) -> bool {
+    match AdoError::from_error(error) {
+        Some(ado_error) => ado_error.is_conflict(),
+        None => matches!(
+            error.kind(),
+            ErrorKind::HttpResponse {
+                status: StatusCode::Conflict,
+                ..
+            }
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_body() {
+        let changes = vec![
+            FileChange {
+                change_type: ChangeType::Edit,
+                item: ChangeItem {
+                    path: item_path("version.txt"),
+                },
+                source_server_item: None,
+                new_content: Some(ItemContent {
+                    content: Some(BASE64_STANDARD.encode("1.2.3")),
+                    content_type: Some(ContentType::Base64Encoded),
+                }),
+            },
+            FileChange {
+                change_type: ChangeType::Rename,
+                item: ChangeItem {
+                    path: item_path("/docs/new.md"),
+                },
+                source_server_item: Some(item_path("docs/old.md")),
+                new_content: None,
+            },
+        ];
+        let author = Author {
+            name: "Version Bot".to_owned(),
+            email: "bot@example.com".to_owned(),
+        };
+        let body = PushRequest {
+            ref_updates: vec![GitRefUpdate {
+                name: Some(full_ref_name("main")),
+                old_object_id: Some("abc123".to_owned()),
+                ..Default::default()
+            }],
+            commits: vec![CommitRequest {
+                comment: "Bump version",
+                author: Some(&author),
+                changes: &changes,
+            }],
+        };
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!({
+                "refUpdates": [{ "name": "refs/heads/main", "oldObjectId": "abc123" }],
+                "commits": [{
+                    "comment": "Bump version",
+                    "author": { "name": "Version Bot", "email": "bot@example.com" },
+                    "changes": [
+                        {
+                            "changeType": "edit",
+                            "item": { "path": "/version.txt" },
+                            "newContent": { "content": "MS4yLjM=", "contentType": "base64Encoded" }
+                        },
+                        {
+                            "changeType": "rename",
+                            "item": { "path": "/docs/new.md" },
+                            "sourceServerItem": "/docs/old.md"
+                        }
+                    ]
+                }]
+            })
+        );
+    }
+}
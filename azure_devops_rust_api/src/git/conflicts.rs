@@ -0,0 +1,367 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Pull request merge conflicts.
+//!
+//! The conflicts returned by the `pull_request_conflicts` operations are polymorphic: the
+//! `conflictType` field determines which `GitConflict*` type describes the conflict, and the type
+//! of its `resolution`, e.g.
+//!
+//! ```json
+//! {
+//!   "conflictId": 3,
+//!   "conflictPath": "/Cargo.lock",
+//!   "conflictType": "editEdit",
+//!   "resolution": { "mergeType": "takeSourceContent" }
+//! }
+//! ```
+//!
+//! The generated operations use the `GitConflict` base type, which has no `resolution`.
+//! [`PullRequestConflict`] deserializes each conflict into its specific type, and
+//! `resolve_conflicts()` sends the resolutions.
+
+use super::models::{
+    git_conflict::ConflictType, git_resolution_merge_content::MergeType,
+    git_resolution_path_conflict, git_resolution_pick_one_action, git_resolution_rename1to2,
+    GitConflict, GitConflictAddAdd, GitConflictAddRename, GitConflictDeleteEdit,
+    GitConflictDeleteRename, GitConflictDirectoryFile, GitConflictEditDelete, GitConflictEditEdit,
+    GitConflictFileDirectory, GitConflictRename1to2, GitConflictRename2to1, GitConflictRenameAdd,
+    GitConflictRenameDelete, GitConflictRenameRename, GitConflictUpdateResultList,
+    GitResolutionMergeContent, GitResolutionPathConflict, GitResolutionPickOneAction,
+    GitResolutionRename1to2,
+};
+use super::pull_request_conflicts;
+use azure_core::error::{ErrorKind, ResultExt};
+use azure_core::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// The API version of the pull request conflicts `update_conflicts` operation.
+const PULL_REQUEST_CONFLICTS_UPDATE_API_VERSION: &str = "7.1-preview";
+
+/// A pull request merge conflict, of the type given by its `conflictType`.
+///
+/// Conflicts without a specific type (e.g. `directoryChild`) are returned as
+/// [`Other`](PullRequestConflict::Other).
+#[derive(Clone, Debug, PartialEq)]
+pub enum PullRequestConflict {
+    AddAdd(GitConflictAddAdd),
+    AddRename(GitConflictAddRename),
+    DeleteEdit(GitConflictDeleteEdit),
+    DeleteRename(GitConflictDeleteRename),
+    DirectoryFile(GitConflictDirectoryFile),
+    EditDelete(GitConflictEditDelete),
+    EditEdit(GitConflictEditEdit),
+    FileDirectory(GitConflictFileDirectory),
+    Rename1to2(GitConflictRename1to2),
+    Rename2to1(GitConflictRename2to1),
+    RenameAdd(GitConflictRenameAdd),
+    RenameDelete(GitConflictRenameDelete),
+    RenameRename(GitConflictRenameRename),
+    Other(GitConflict),
+}
+
+/// The side of a pull request to take when resolving a conflict.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictSide {
+    /// The source branch of the pull request.
+    Source,
+    /// The target branch of the pull request.
+    Target,
+}
+
+impl PullRequestConflict {
+    /// Returns the fields common to all conflicts.
+    pub fn conflict(&self) -> &GitConflict {
+        match self {
+            Self::AddAdd(c) => &c.git_conflict,
+            Self::AddRename(c) => &c.git_conflict,
+            Self::DeleteEdit(c) => &c.git_conflict,
+            Self::DeleteRename(c) => &c.git_conflict,
+            Self::DirectoryFile(c) => &c.git_conflict,
+            Self::EditDelete(c) => &c.git_conflict,
+            Self::EditEdit(c) => &c.git_conflict,
+            Self::FileDirectory(c) => &c.git_conflict,
+            Self::Rename1to2(c) => &c.git_conflict,
+            Self::Rename2to1(c) => &c.git_conflict,
+            Self::RenameAdd(c) => &c.git_conflict,
+            Self::RenameDelete(c) => &c.git_conflict,
+            Self::RenameRename(c) => &c.git_conflict,
+            Self::Other(c) => c,
+        }
+    }
+
+    /// Returns the ID of the conflict.
+    pub fn conflict_id(&self) -> Option<i32> {
+        self.conflict().conflict_id
+    }
+
+    /// Returns the path of the conflicting item.
+    pub fn conflict_path(&self) -> Option<&str> {
+        self.conflict().conflict_path.as_deref()
+    }
+
+    /// Returns the type of the conflict.
+    pub fn conflict_type(&self) -> Option<&ConflictType> {
+        self.conflict().conflict_type.as_ref()
+    }
+
+    /// Sets the resolution of the conflict to take the content or path of one side.
+    ///
+    /// Returns `false` if the conflict has no resolution that takes one side, i.e. it is
+    /// [`Other`](PullRequestConflict::Other).
+    pub fn resolve(&mut self, side: ConflictSide) -> bool {
+        let source = side == ConflictSide::Source;
+        let merge_content = || GitResolutionMergeContent {
+            merge_type: Some(if source {
+                MergeType::TakeSourceContent
+            } else {
+                MergeType::TakeTargetContent
+            }),
+            ..Default::default()
+        };
+        let pick_one = || GitResolutionPickOneAction {
+            action: Some(if source {
+                git_resolution_pick_one_action::Action::PickSourceAction
+            } else {
+                git_resolution_pick_one_action::Action::PickTargetAction
+            }),
+            ..Default::default()
+        };
+        let path_conflict = || GitResolutionPathConflict {
+            action: Some(if source {
+                git_resolution_path_conflict::Action::KeepSourceDeleteTarget
+            } else {
+                git_resolution_path_conflict::Action::KeepTargetDeleteSource
+            }),
+            ..Default::default()
+        };
+        match self {
+            Self::AddAdd(c) => c.resolution = Some(merge_content()),
+            Self::EditEdit(c) => c.resolution = Some(merge_content()),
+            Self::RenameRename(c) => c.resolution = Some(merge_content()),
+            Self::DeleteEdit(c) => c.resolution = Some(pick_one()),
+            Self::DeleteRename(c) => c.resolution = Some(pick_one()),
+            Self::EditDelete(c) => c.resolution = Some(pick_one()),
+            Self::RenameDelete(c) => c.resolution = Some(pick_one()),
+            Self::AddRename(c) => c.resolution = Some(path_conflict()),
+            Self::DirectoryFile(c) => c.resolution = Some(path_conflict()),
+            Self::FileDirectory(c) => c.resolution = Some(path_conflict()),
+            Self::Rename2to1(c) => c.resolution = Some(path_conflict()),
+            Self::RenameAdd(c) => c.resolution = Some(path_conflict()),
+            Self::Rename1to2(c) => {
+                c.resolution = Some(GitResolutionRename1to2 {
+                    git_resolution_merge_content: merge_content(),
+                    action: Some(if source {
+                        git_resolution_rename1to2::Action::KeepSourcePath
+                    } else {
+                        git_resolution_rename1to2::Action::KeepTargetPath
+                    }),
+                })
+            }
+            Self::Other(_) => return false,
+        }
+        true
+    }
+}
+
+impl From<GitConflict> for PullRequestConflict {
+    fn from(conflict: GitConflict) -> Self {
+        Self::Other(conflict)
+    }
+}
+
+impl Serialize for PullRequestConflict {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::AddAdd(c) => c.serialize(serializer),
+            Self::AddRename(c) => c.serialize(serializer),
+            Self::DeleteEdit(c) => c.serialize(serializer),
+            Self::DeleteRename(c) => c.serialize(serializer),
+            Self::DirectoryFile(c) => c.serialize(serializer),
+            Self::EditDelete(c) => c.serialize(serializer),
+            Self::EditEdit(c) => c.serialize(serializer),
+            Self::FileDirectory(c) => c.serialize(serializer),
+            Self::Rename1to2(c) => c.serialize(serializer),
+            Self::Rename2to1(c) => c.serialize(serializer),
+            Self::RenameAdd(c) => c.serialize(serializer),
+            Self::RenameDelete(c) => c.serialize(serializer),
+            Self::RenameRename(c) => c.serialize(serializer),
+            Self::Other(c) => c.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PullRequestConflict {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        let value = Value::deserialize(deserializer)?;
+        let conflict_type = value
+            .get("conflictType")
+            .cloned()
+            .map(ConflictType::deserialize)
+            .transpose()
+            .map_err(D::Error::custom)?;
+        let conflict = match conflict_type {
+            Some(ConflictType::AddAdd) => serde_json::from_value(value).map(Self::AddAdd),
+            Some(ConflictType::AddRename) => serde_json::from_value(value).map(Self::AddRename),
+            Some(ConflictType::DeleteEdit) => serde_json::from_value(value).map(Self::DeleteEdit),
+            Some(ConflictType::DeleteRename) => {
+                serde_json::from_value(value).map(Self::DeleteRename)
+            }
+            Some(ConflictType::DirectoryFile) => {
+                serde_json::from_value(value).map(Self::DirectoryFile)
+            }
+            Some(ConflictType::EditDelete) => serde_json::from_value(value).map(Self::EditDelete),
+            Some(ConflictType::EditEdit) => serde_json::from_value(value).map(Self::EditEdit),
+            Some(ConflictType::FileDirectory) => {
+                serde_json::from_value(value).map(Self::FileDirectory)
+            }
+            Some(ConflictType::Rename1to2) => serde_json::from_value(value).map(Self::Rename1to2),
+            Some(ConflictType::Rename2to1) => serde_json::from_value(value).map(Self::Rename2to1),
+            Some(ConflictType::RenameAdd) => serde_json::from_value(value).map(Self::RenameAdd),
+            Some(ConflictType::RenameDelete) => {
+                serde_json::from_value(value).map(Self::RenameDelete)
+            }
+            Some(ConflictType::RenameRename) => {
+                serde_json::from_value(value).map(Self::RenameRename)
+            }
+            Some(ConflictType::None | ConflictType::DirectoryChild) | None => {
+                serde_json::from_value(value).map(Self::Other)
+            }
+        };
+        conflict.map_err(D::Error::custom)
+    }
+}
+
+/// The body of a list of conflicts.
+#[derive(Deserialize)]
+struct PullRequestConflictList {
+    #[serde(default)]
+    value: Vec<PullRequestConflict>,
+}
+
+/// Deserializes a response body, with the error context used by the generated operations.
+fn from_slice<'a, T: Deserialize<'a>>(bytes: &'a [u8]) -> azure_core::Result<T> {
+    serde_json::from_slice(bytes).with_context(ErrorKind::DataConversion, || {
+        format!(
+            "Failed to deserialize response:\n{}",
+            String::from_utf8_lossy(bytes)
+        )
+    })
+}
+
+impl pull_request_conflicts::list::Response {
+    /// Returns the conflicts, each deserialized into the type given by its `conflictType`.
+    pub async fn into_conflicts(self) -> azure_core::Result<Vec<PullRequestConflict>> {
+        let bytes = self.into_raw_response().into_body().collect().await?;
+        Ok(from_slice::<PullRequestConflictList>(&bytes)?.value)
+    }
+}
+
+impl pull_request_conflicts::get::Response {
+    /// Returns the conflict, deserialized into the type given by its `conflictType`.
+    pub async fn into_conflict(self) -> azure_core::Result<PullRequestConflict> {
+        let bytes = self.into_raw_response().into_body().collect().await?;
+        from_slice(&bytes)
+    }
+}
+
+impl pull_request_conflicts::Client {
+    /// Resolves the conflicts of a pull request, sending the `resolution` of each conflict.
+    ///
+    /// The generated `update_conflicts` operation takes `GitConflict`s, which cannot hold a
+    /// resolution. The result of each update is returned, including updates that failed.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `body`: The conflicts, each with the resolution to apply, e.g. set by [`PullRequestConflict::resolve()`].
+    /// * `repository_id`: The repository ID of the pull request's target branch.
+    /// * `pull_request_id`: ID of the pull request.
+    /// * `project`: Project ID or project name
+    pub async fn resolve_conflicts(
+        &self,
+        organization: impl Into<String>,
+        body: Vec<PullRequestConflict>,
+        repository_id: impl Into<String>,
+        pull_request_id: i32,
+        project: impl Into<String>,
+    ) -> azure_core::Result<GitConflictUpdateResultList> {
+        let body = azure_core::to_json(&body)?;
+        let path = format!(
+            "{}/{}/_apis/git/repositories/{}/pullRequests/{}/conflicts",
+            organization.into(),
+            project.into(),
+            repository_id.into(),
+            pull_request_id
+        );
+        self.0
+            .send_json(
+                Method::Patch,
+                &path,
+                PULL_REQUEST_CONFLICTS_UPDATE_API_VERSION,
+                |req| {
+                    req.insert_header("content-type", "application/json");
+                    req.set_body(body);
+                    Ok(())
+                },
+            )
+            .await
+    }
+}
+
+scoped_client_methods! {
+    pull_request_conflicts;
+    /// Resolves the conflicts of a pull request, sending the `resolution` of each conflict.
+    pub async fn resolve_conflicts(
+        body: Vec<PullRequestConflict>,
+        repository_id: impl Into<String>,
+        pull_request_id: i32,
+        [project],
+    ) -> azure_core::Result<GitConflictUpdateResultList>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pull_request_conflict() {
+        let mut conflicts: PullRequestConflictList = serde_json::from_value(json!({
+            "count": 2,
+            "value": [
+                {
+                    "conflictId": 3,
+                    "conflictPath": "/Cargo.lock",
+                    "conflictType": "editEdit",
+                    "sourceBlob": { "objectId": "1111111111111111111111111111111111111111" }
+                },
+                { "conflictId": 4, "conflictType": "directoryChild" }
+            ]
+        }))
+        .unwrap();
+        let conflict = &mut conflicts.value[0];
+        assert!(matches!(conflict, PullRequestConflict::EditEdit(_)));
+        assert_eq!(conflict.conflict_path(), Some("/Cargo.lock"));
+        assert!(conflict.resolve(ConflictSide::Target));
+        let value = serde_json::to_value(&*conflict).unwrap();
+        assert_eq!(value["conflictType"], "editEdit");
+        assert_eq!(
+            value["resolution"],
+            json!({ "mergeType": "takeTargetContent" })
+        );
+        assert_eq!(
+            value["sourceBlob"]["objectId"],
+            "1111111111111111111111111111111111111111"
+        );
+        assert!(matches!(
+            conflicts.value[1],
+            PullRequestConflict::Other(GitConflict {
+                conflict_id: Some(4),
+                ..
+            })
+        ));
+        assert!(!conflicts.value[1].resolve(ConflictSide::Source));
+    }
+}
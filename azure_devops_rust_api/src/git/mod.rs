@@ -4,13 +4,24 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 #![allow(clippy::redundant_clone)]
+mod comment_thread_builder;
+mod commit_builder;
+mod conflicts;
 pub mod models;
+mod pull_request_handle;
+mod ref_updates;
+pub use comment_thread_builder::*;
+pub use commit_builder::*;
+pub use conflicts::*;
+pub use pull_request_handle::*;
+pub use ref_updates::*;
 #[derive(Clone)]
 pub struct Client {
     endpoint: String,
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +29,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +42,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +52,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +99,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +119,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -90,11 +129,65 @@ impl Client {
         let mut context = azure_core::Context::default();
         self.pipeline.send(&mut context, request).await
     }
+    #[doc = "Sends a request for an operation that has no generated request builder, and deserializes the JSON response."]
+    #[doc = ""]
+    #[doc = "`path` is relative to the endpoint, and `api_version` is the API version of the operation, which is replaced by the client's API version override (if any)."]
+    #[doc = "`prepare` sets any other query parameters, headers and body of the request."]
+    pub(crate) async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        method: azure_core::Method,
+        path: &str,
+        api_version: &str,
+        prepare: impl FnOnce(&mut azure_core::Request) -> azure_core::Result<()>,
+    ) -> azure_core::Result<T> {
+        let url = azure_core::Url::parse(&format!("{}/{}", self.endpoint(), path))?;
+        let mut req = azure_core::Request::new(url, method);
+        if let Some(auth_header) = self
+            .token_credential()
+            .http_authorization_header(&self.scopes)
+            .await?
+        {
+            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+        }
+        req.url_mut().query_pairs_mut().append_pair(
+            azure_core::query_param::API_VERSION,
+            &self.api_version(api_version),
+        );
+        prepare(&mut req)?;
+        let rsp = self.send(&mut req).await?;
+        let bytes = rsp.into_body().collect().await?;
+        serde_json::from_slice(&bytes).map_err(|e| {
+            azure_core::error::Error::full(
+                azure_core::error::ErrorKind::DataConversion,
+                e,
+                format!(
+                    "Failed to deserialize response:\n{}",
+                    String::from_utf8_lossy(&bytes)
+                ),
+            )
+        })
+    }
     #[doc = "Create a new `ClientBuilder`."]
     #[must_use]
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +201,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn annotated_tags_client(&self) -> annotated_tags::Client {
@@ -160,6 +277,9 @@ impl Client {
     pub fn pull_request_commits_client(&self) -> pull_request_commits::Client {
         pull_request_commits::Client(self.clone())
     }
+    pub fn pull_request_conflicts_client(&self) -> pull_request_conflicts::Client {
+        pull_request_conflicts::Client(self.clone())
+    }
     pub fn pull_request_iteration_changes_client(&self) -> pull_request_iteration_changes::Client {
         pull_request_iteration_changes::Client(self.clone())
     }
@@ -229,6 +349,530 @@ impl Client {
         trees::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn annotated_tags_client(&self) -> annotated_tags::OrganizationClient {
+        annotated_tags::OrganizationClient {
+            client: self.client.annotated_tags_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn blobs_client(&self) -> blobs::OrganizationClient {
+        blobs::OrganizationClient {
+            client: self.client.blobs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn cherry_picks_client(&self) -> cherry_picks::OrganizationClient {
+        cherry_picks::OrganizationClient {
+            client: self.client.cherry_picks_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn commits_client(&self) -> commits::OrganizationClient {
+        commits::OrganizationClient {
+            client: self.client.commits_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn diffs_client(&self) -> diffs::OrganizationClient {
+        diffs::OrganizationClient {
+            client: self.client.diffs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn forks_client(&self) -> forks::OrganizationClient {
+        forks::OrganizationClient {
+            client: self.client.forks_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn import_requests_client(&self) -> import_requests::OrganizationClient {
+        import_requests::OrganizationClient {
+            client: self.client.import_requests_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn items_client(&self) -> items::OrganizationClient {
+        items::OrganizationClient {
+            client: self.client.items_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn merge_bases_client(&self) -> merge_bases::OrganizationClient {
+        merge_bases::OrganizationClient {
+            client: self.client.merge_bases_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn merges_client(&self) -> merges::OrganizationClient {
+        merges::OrganizationClient {
+            client: self.client.merges_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn policy_configurations_client(&self) -> policy_configurations::OrganizationClient {
+        policy_configurations::OrganizationClient {
+            client: self.client.policy_configurations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_attachments_client(&self) -> pull_request_attachments::OrganizationClient {
+        pull_request_attachments::OrganizationClient {
+            client: self.client.pull_request_attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_comment_likes_client(
+        &self,
+    ) -> pull_request_comment_likes::OrganizationClient {
+        pull_request_comment_likes::OrganizationClient {
+            client: self.client.pull_request_comment_likes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_commits_client(&self) -> pull_request_commits::OrganizationClient {
+        pull_request_commits::OrganizationClient {
+            client: self.client.pull_request_commits_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_conflicts_client(&self) -> pull_request_conflicts::OrganizationClient {
+        pull_request_conflicts::OrganizationClient {
+            client: self.client.pull_request_conflicts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_iteration_changes_client(
+        &self,
+    ) -> pull_request_iteration_changes::OrganizationClient {
+        pull_request_iteration_changes::OrganizationClient {
+            client: self.client.pull_request_iteration_changes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_iteration_statuses_client(
+        &self,
+    ) -> pull_request_iteration_statuses::OrganizationClient {
+        pull_request_iteration_statuses::OrganizationClient {
+            client: self.client.pull_request_iteration_statuses_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_iterations_client(&self) -> pull_request_iterations::OrganizationClient {
+        pull_request_iterations::OrganizationClient {
+            client: self.client.pull_request_iterations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_labels_client(&self) -> pull_request_labels::OrganizationClient {
+        pull_request_labels::OrganizationClient {
+            client: self.client.pull_request_labels_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_properties_client(&self) -> pull_request_properties::OrganizationClient {
+        pull_request_properties::OrganizationClient {
+            client: self.client.pull_request_properties_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_query_client(&self) -> pull_request_query::OrganizationClient {
+        pull_request_query::OrganizationClient {
+            client: self.client.pull_request_query_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_reviewers_client(&self) -> pull_request_reviewers::OrganizationClient {
+        pull_request_reviewers::OrganizationClient {
+            client: self.client.pull_request_reviewers_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_share_client(&self) -> pull_request_share::OrganizationClient {
+        pull_request_share::OrganizationClient {
+            client: self.client.pull_request_share_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_statuses_client(&self) -> pull_request_statuses::OrganizationClient {
+        pull_request_statuses::OrganizationClient {
+            client: self.client.pull_request_statuses_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_thread_comments_client(
+        &self,
+    ) -> pull_request_thread_comments::OrganizationClient {
+        pull_request_thread_comments::OrganizationClient {
+            client: self.client.pull_request_thread_comments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_threads_client(&self) -> pull_request_threads::OrganizationClient {
+        pull_request_threads::OrganizationClient {
+            client: self.client.pull_request_threads_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_request_work_items_client(&self) -> pull_request_work_items::OrganizationClient {
+        pull_request_work_items::OrganizationClient {
+            client: self.client.pull_request_work_items_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pull_requests_client(&self) -> pull_requests::OrganizationClient {
+        pull_requests::OrganizationClient {
+            client: self.client.pull_requests_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pushes_client(&self) -> pushes::OrganizationClient {
+        pushes::OrganizationClient {
+            client: self.client.pushes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn refs_client(&self) -> refs::OrganizationClient {
+        refs::OrganizationClient {
+            client: self.client.refs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn refs_favorites_client(&self) -> refs_favorites::OrganizationClient {
+        refs_favorites::OrganizationClient {
+            client: self.client.refs_favorites_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn repositories_client(&self) -> repositories::OrganizationClient {
+        repositories::OrganizationClient {
+            client: self.client.repositories_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn reverts_client(&self) -> reverts::OrganizationClient {
+        reverts::OrganizationClient {
+            client: self.client.reverts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn stats_client(&self) -> stats::OrganizationClient {
+        stats::OrganizationClient {
+            client: self.client.stats_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn statuses_client(&self) -> statuses::OrganizationClient {
+        statuses::OrganizationClient {
+            client: self.client.statuses_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn suggestions_client(&self) -> suggestions::OrganizationClient {
+        suggestions::OrganizationClient {
+            client: self.client.suggestions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn trees_client(&self) -> trees::OrganizationClient {
+        trees::OrganizationClient {
+            client: self.client.trees_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn annotated_tags_client(&self) -> annotated_tags::ProjectClient {
+        annotated_tags::ProjectClient {
+            client: self.client.annotated_tags_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn blobs_client(&self) -> blobs::ProjectClient {
+        blobs::ProjectClient {
+            client: self.client.blobs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn cherry_picks_client(&self) -> cherry_picks::ProjectClient {
+        cherry_picks::ProjectClient {
+            client: self.client.cherry_picks_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn commits_client(&self) -> commits::ProjectClient {
+        commits::ProjectClient {
+            client: self.client.commits_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn diffs_client(&self) -> diffs::ProjectClient {
+        diffs::ProjectClient {
+            client: self.client.diffs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn forks_client(&self) -> forks::ProjectClient {
+        forks::ProjectClient {
+            client: self.client.forks_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn import_requests_client(&self) -> import_requests::ProjectClient {
+        import_requests::ProjectClient {
+            client: self.client.import_requests_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn items_client(&self) -> items::ProjectClient {
+        items::ProjectClient {
+            client: self.client.items_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn merge_bases_client(&self) -> merge_bases::ProjectClient {
+        merge_bases::ProjectClient {
+            client: self.client.merge_bases_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn merges_client(&self) -> merges::ProjectClient {
+        merges::ProjectClient {
+            client: self.client.merges_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn policy_configurations_client(&self) -> policy_configurations::ProjectClient {
+        policy_configurations::ProjectClient {
+            client: self.client.policy_configurations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_attachments_client(&self) -> pull_request_attachments::ProjectClient {
+        pull_request_attachments::ProjectClient {
+            client: self.client.pull_request_attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_comment_likes_client(&self) -> pull_request_comment_likes::ProjectClient {
+        pull_request_comment_likes::ProjectClient {
+            client: self.client.pull_request_comment_likes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_commits_client(&self) -> pull_request_commits::ProjectClient {
+        pull_request_commits::ProjectClient {
+            client: self.client.pull_request_commits_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_conflicts_client(&self) -> pull_request_conflicts::ProjectClient {
+        pull_request_conflicts::ProjectClient {
+            client: self.client.pull_request_conflicts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_iteration_changes_client(
+        &self,
+    ) -> pull_request_iteration_changes::ProjectClient {
+        pull_request_iteration_changes::ProjectClient {
+            client: self.client.pull_request_iteration_changes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_iteration_statuses_client(
+        &self,
+    ) -> pull_request_iteration_statuses::ProjectClient {
+        pull_request_iteration_statuses::ProjectClient {
+            client: self.client.pull_request_iteration_statuses_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_iterations_client(&self) -> pull_request_iterations::ProjectClient {
+        pull_request_iterations::ProjectClient {
+            client: self.client.pull_request_iterations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_labels_client(&self) -> pull_request_labels::ProjectClient {
+        pull_request_labels::ProjectClient {
+            client: self.client.pull_request_labels_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_properties_client(&self) -> pull_request_properties::ProjectClient {
+        pull_request_properties::ProjectClient {
+            client: self.client.pull_request_properties_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_query_client(&self) -> pull_request_query::ProjectClient {
+        pull_request_query::ProjectClient {
+            client: self.client.pull_request_query_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_reviewers_client(&self) -> pull_request_reviewers::ProjectClient {
+        pull_request_reviewers::ProjectClient {
+            client: self.client.pull_request_reviewers_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_share_client(&self) -> pull_request_share::ProjectClient {
+        pull_request_share::ProjectClient {
+            client: self.client.pull_request_share_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_statuses_client(&self) -> pull_request_statuses::ProjectClient {
+        pull_request_statuses::ProjectClient {
+            client: self.client.pull_request_statuses_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_thread_comments_client(
+        &self,
+    ) -> pull_request_thread_comments::ProjectClient {
+        pull_request_thread_comments::ProjectClient {
+            client: self.client.pull_request_thread_comments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_threads_client(&self) -> pull_request_threads::ProjectClient {
+        pull_request_threads::ProjectClient {
+            client: self.client.pull_request_threads_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_request_work_items_client(&self) -> pull_request_work_items::ProjectClient {
+        pull_request_work_items::ProjectClient {
+            client: self.client.pull_request_work_items_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pull_requests_client(&self) -> pull_requests::ProjectClient {
+        pull_requests::ProjectClient {
+            client: self.client.pull_requests_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pushes_client(&self) -> pushes::ProjectClient {
+        pushes::ProjectClient {
+            client: self.client.pushes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn refs_client(&self) -> refs::ProjectClient {
+        refs::ProjectClient {
+            client: self.client.refs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn refs_favorites_client(&self) -> refs_favorites::ProjectClient {
+        refs_favorites::ProjectClient {
+            client: self.client.refs_favorites_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn repositories_client(&self) -> repositories::ProjectClient {
+        repositories::ProjectClient {
+            client: self.client.repositories_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn reverts_client(&self) -> reverts::ProjectClient {
+        reverts::ProjectClient {
+            client: self.client.reverts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn stats_client(&self) -> stats::ProjectClient {
+        stats::ProjectClient {
+            client: self.client.stats_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn statuses_client(&self) -> statuses::ProjectClient {
+        statuses::ProjectClient {
+            client: self.client.statuses_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn suggestions_client(&self) -> suggestions::ProjectClient {
+        suggestions::ProjectClient {
+            client: self.client.suggestions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn trees_client(&self) -> trees::ProjectClient {
+        trees::ProjectClient {
+            client: self.client.trees_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod repositories {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -427,42 +1071,309 @@ pub mod repositories {
             }
         }
     }
-    pub mod get_repository_with_parent {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitRepository> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitRepository = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `include_parent`: Set to true to include parent repository. Only available in authenticated calls."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_repository_with_parent(
+            &self,
+            repository_id: impl Into<String>,
+            include_parent: bool,
+            project: impl Into<String>,
+        ) -> get_repository_with_parent::RequestBuilder {
+            self.client.get_repository_with_parent(
+                self.organization.clone(),
+                repository_id,
+                include_parent,
+                project,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Retrieve deleted git repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_repositories(
+            &self,
+            project: impl Into<String>,
+        ) -> get_deleted_repositories::RequestBuilder {
+            self.client
+                .get_deleted_repositories(self.organization.clone(), project)
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Retrieve soft-deleted git repositories from the recycle bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_repositories(
+            &self,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_repositories::RequestBuilder {
+            self.client
+                .get_recycle_bin_repositories(self.organization.clone(), project)
         }
-        #[derive(Clone)]
+        #[doc = "Recover a soft-deleted Git repository. Recently deleted repositories go into a soft-delete state for a period of time before they are hard deleted and become unrecoverable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn restore_repository_from_recycle_bin(
+            &self,
+            body: impl Into<models::GitRecycleBinRepositoryDetails>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> restore_repository_from_recycle_bin::RequestBuilder {
+            self.client.restore_repository_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Destroy (hard delete) a soft-deleted Git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete_repository_from_recycle_bin(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> delete_repository_from_recycle_bin::RequestBuilder {
+            self.client.delete_repository_from_recycle_bin(
+                self.organization.clone(),
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve git repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a git repository in a team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify the repo name, team project and/or parent repository. Team project information can be omitted from gitRepositoryToCreate if the request is project-scoped (i.e., includes project Id)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRepositoryCreateOptions>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_repository(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_repository::RequestBuilder {
+            self.client
+                .get_repository(self.organization.clone(), repository_id, project)
+        }
+        #[doc = "Updates the Git repository with either a new repo name or a new default branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify a new repo name or a new default branch of the repository"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitRepository>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, repository_id, project)
+        }
+        #[doc = "Delete a git repository"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `include_parent`: Set to true to include parent repository. Only available in authenticated calls."]
+        pub fn get_repository_with_parent(
+            &self,
+            repository_id: impl Into<String>,
+            include_parent: bool,
+        ) -> get_repository_with_parent::RequestBuilder {
+            self.client.get_repository_with_parent(
+                self.organization.clone(),
+                repository_id,
+                include_parent,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve deleted git repositories."]
+        pub fn get_deleted_repositories(&self) -> get_deleted_repositories::RequestBuilder {
+            self.client
+                .get_deleted_repositories(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Retrieve soft-deleted git repositories from the recycle bin."]
+        pub fn get_recycle_bin_repositories(&self) -> get_recycle_bin_repositories::RequestBuilder {
+            self.client
+                .get_recycle_bin_repositories(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Recover a soft-deleted Git repository. Recently deleted repositories go into a soft-delete state for a period of time before they are hard deleted and become unrecoverable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn restore_repository_from_recycle_bin(
+            &self,
+            body: impl Into<models::GitRecycleBinRepositoryDetails>,
+            repository_id: impl Into<String>,
+        ) -> restore_repository_from_recycle_bin::RequestBuilder {
+            self.client.restore_repository_from_recycle_bin(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Destroy (hard delete) a soft-deleted Git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete_repository_from_recycle_bin(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> delete_repository_from_recycle_bin::RequestBuilder {
+            self.client.delete_repository_from_recycle_bin(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve git repositories."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a git repository in a team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify the repo name, team project and/or parent repository. Team project information can be omitted from gitRepositoryToCreate if the request is project-scoped (i.e., includes project Id)."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRepositoryCreateOptions>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_repository(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> get_repository::RequestBuilder {
+            self.client.get_repository(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Updates the Git repository with either a new repo name or a new default branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify a new repo name or a new default branch of the repository"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitRepository>,
+            repository_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a git repository"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete(&self, repository_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod get_repository_with_parent {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitRepository> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitRepository = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
         #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
@@ -510,9 +1421,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let include_parent = &this.include_parent;
                         req.url_mut()
                             .query_pairs_mut()
@@ -618,9 +1530,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -724,9 +1637,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -832,9 +1746,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -906,9 +1821,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1015,9 +1931,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_links) = &this.include_links {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1140,9 +2057,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(source_ref) = &this.source_ref {
@@ -1251,9 +2169,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1357,9 +2276,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1431,9 +2351,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1587,43 +2508,212 @@ pub mod commits {
             }
         }
     }
-    pub mod get_commits {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitCommitRefList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitCommitRefList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve git commits for a project\n\nParameters that use the searchCriteria prefix in their name can be specified without it as query parameters, e.g. searchCriteria.$top -> $top"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_commits(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_commits::RequestBuilder {
+            self.client
+                .get_commits(self.organization.clone(), repository_id, project)
         }
-        #[derive(Clone)]
+        #[doc = "Retrieve a list of commits associated with a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `push_id`: The id of the push."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_push_commits(
+            &self,
+            repository_id: impl Into<String>,
+            push_id: i32,
+            project: impl Into<String>,
+        ) -> get_push_commits::RequestBuilder {
+            self.client
+                .get_push_commits(self.organization.clone(), repository_id, push_id, project)
+        }
+        #[doc = "Retrieve a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), commit_id, repository_id, project)
+        }
+        #[doc = "Retrieve changes for a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changes(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_changes::RequestBuilder {
+            self.client
+                .get_changes(self.organization.clone(), commit_id, repository_id, project)
+        }
+        #[doc = "Retrieve git commits for a project matching the search criteria"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Search options"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_commits_batch(
+            &self,
+            body: impl Into<models::GitQueryCommitsCriteria>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_commits_batch::RequestBuilder {
+            self.client
+                .get_commits_batch(self.organization.clone(), body, repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve git commits for a project\n\nParameters that use the searchCriteria prefix in their name can be specified without it as query parameters, e.g. searchCriteria.$top -> $top"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get_commits(&self, repository_id: impl Into<String>) -> get_commits::RequestBuilder {
+            self.client.get_commits(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a list of commits associated with a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `push_id`: The id of the push."]
+        pub fn get_push_commits(
+            &self,
+            repository_id: impl Into<String>,
+            push_id: i32,
+        ) -> get_push_commits::RequestBuilder {
+            self.client.get_push_commits(
+                self.organization.clone(),
+                repository_id,
+                push_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                commit_id,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve changes for a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get_changes(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> get_changes::RequestBuilder {
+            self.client.get_changes(
+                self.organization.clone(),
+                commit_id,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve git commits for a project matching the search criteria"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Search options"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_commits_batch(
+            &self,
+            body: impl Into<models::GitQueryCommitsCriteria>,
+            repository_id: impl Into<String>,
+        ) -> get_commits_batch::RequestBuilder {
+            self.client.get_commits_batch(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod get_commits {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitCommitRefList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitCommitRefList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
         #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
@@ -1872,9 +2962,10 @@ pub mod commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(search_criteria_skip) = &this.search_criteria_skip {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.$skip",
@@ -2154,9 +3245,10 @@ pub mod commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let push_id = &this.push_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2182,6 +3274,37 @@ pub mod commits {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitCommitRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitCommitRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -2286,9 +3409,10 @@ pub mod commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(change_count) = &this.change_count {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2411,9 +3535,10 @@ pub mod commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2546,9 +3671,10 @@ pub mod commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(skip) = &this.skip {
@@ -2571,6 +3697,37 @@ pub mod commits {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitCommitRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitCommitRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -2677,6 +3834,111 @@ pub mod items {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content, which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `path`: The item path."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), repository_id, path, project)
+        }
+        #[doc = "Get Item Metadata and/or Content for a collection of items. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), repository_id, project)
+        }
+        #[doc = "Post for retrieving a creating a batch out of a set of items in a repo / project given a list of paths or a long path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request data attributes: ItemDescriptors, IncludeContentMetadata, LatestProcessedChange, IncludeLinks. ItemDescriptors: Collection of items to fetch, including path, version, and recursion level. IncludeContentMetadata: Whether to include metadata for all items LatestProcessedChange: Whether to include shallow ref to commit that last changed each item. IncludeLinks: Whether to include the _links field on the shallow references."]
+        #[doc = "* `repository_id`: The name or ID of the repository"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::GitItemRequestData>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            self.client
+                .get_items_batch(self.organization.clone(), body, repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content, which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `path`: The item path."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                path,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get Item Metadata and/or Content for a collection of items. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Post for retrieving a creating a batch out of a set of items in a repo / project given a list of paths or a long path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request data attributes: ItemDescriptors, IncludeContentMetadata, LatestProcessedChange, IncludeLinks. ItemDescriptors: Collection of items to fetch, including path, version, and recursion level. IncludeContentMetadata: Whether to include metadata for all items LatestProcessedChange: Whether to include shallow ref to commit that last changed each item. IncludeLinks: Whether to include the _links field on the shallow references."]
+        #[doc = "* `repository_id`: The name or ID of the repository"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::GitItemRequestData>,
+            repository_id: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            self.client.get_items_batch(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2701,6 +3963,25 @@ pub mod items {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2842,9 +4123,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let path = &this.path;
                         req.url_mut().query_pairs_mut().append_pair("path", path);
                         if let Some(scope_path) = &this.scope_path {
@@ -3088,9 +4370,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(scope_path) = &this.scope_path {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3253,9 +4536,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3330,17 +4614,90 @@ pub mod stats {
             }
         }
     }
-    pub mod get {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitBranchStats> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitBranchStats = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve statistics about a single branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `name`: Name of the branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), repository_id, name, project)
+        }
+        #[doc = "Retrieve statistics about all branches within a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve statistics about a single branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `name`: Name of the branch."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve statistics about all branches within a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod get {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitBranchStats> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitBranchStats = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
                             "Failed to deserialize response:\n{}",
                             String::from_utf8_lossy(&bytes)
                         ),
@@ -3442,9 +4799,10 @@ pub mod stats {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let name = &this.name;
                         req.url_mut().query_pairs_mut().append_pair("name", name);
                         if let Some(base_version_descriptor_version) =
@@ -3603,9 +4961,10 @@ pub mod stats {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(base_version_descriptor_version) =
                             &this.base_version_descriptor_version
                         {
@@ -3732,6 +5091,93 @@ pub mod refs_favorites {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the refs favorites for a repo and an identity."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Creates a ref favorite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref favorite to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRefFavorite>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Gets the refs favorite for a favorite Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `favorite_id`: The Id of the requested ref favorite."]
+        pub fn get(&self, project: impl Into<String>, favorite_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, favorite_id)
+        }
+        #[doc = "Deletes the refs favorite specified"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `favorite_id`: The Id of the ref favorite to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            favorite_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, favorite_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the refs favorites for a repo and an identity."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Creates a ref favorite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref favorite to create."]
+        pub fn create(&self, body: impl Into<models::GitRefFavorite>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets the refs favorite for a favorite Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `favorite_id`: The Id of the requested ref favorite."]
+        pub fn get(&self, favorite_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), favorite_id)
+        }
+        #[doc = "Deletes the refs favorite specified"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `favorite_id`: The Id of the ref favorite to delete."]
+        pub fn delete(&self, favorite_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), favorite_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3824,9 +5270,10 @@ pub mod refs_favorites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(repository_id) = &this.repository_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3938,9 +5385,10 @@ pub mod refs_favorites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4044,9 +5492,10 @@ pub mod refs_favorites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4117,9 +5566,10 @@ pub mod refs_favorites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4155,6 +5605,35 @@ pub mod policy_configurations {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve a list of policy configurations by a given set of scope/filtering criteria.\n\nBelow is a short description of how all of the query parameters interact with each other:\n- repositoryId set, refName set: returns all policy configurations that *apply* to a particular branch in a repository\n- repositoryId set, refName unset: returns all policy configurations that *apply* to a particular repository\n- repositoryId unset, refName unset: returns all policy configurations that are *defined* at the project level\n- repositoryId unset, refName set: returns all project-level branch policies, plus the project level configurations\nFor all of the examples above, when policyType is set, it'll restrict results to the given policy type"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve a list of policy configurations by a given set of scope/filtering criteria.\n\nBelow is a short description of how all of the query parameters interact with each other:\n- repositoryId set, refName set: returns all policy configurations that *apply* to a particular branch in a repository\n- repositoryId set, refName unset: returns all policy configurations that *apply* to a particular repository\n- repositoryId unset, refName unset: returns all policy configurations that are *defined* at the project level\n- repositoryId unset, refName set: returns all project-level branch policies, plus the project level configurations\nFor all of the examples above, when policyType is set, it'll restrict results to the given policy type"]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4277,9 +5756,10 @@ pub mod policy_configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(repository_id) = &this.repository_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4311,6 +5791,26 @@ pub mod policy_configurations {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PolicyConfigurationList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -4487,77 +5987,278 @@ pub mod pull_requests {
             }
         }
     }
-    pub mod get_pull_requests_by_project {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_requests_by_project(
+            &self,
+            project: impl Into<String>,
+        ) -> get_pull_requests_by_project::RequestBuilder {
+            self.client
+                .get_pull_requests_by_project(self.organization.clone(), project)
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_by_id(
+            &self,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_by_id::RequestBuilder {
+            self.client
+                .get_pull_request_by_id(self.organization.clone(), pull_request_id, project)
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_requests(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_pull_requests::RequestBuilder {
+            self.client
+                .get_pull_requests(self.organization.clone(), repository_id, project)
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) project: String,
-            pub(crate) search_criteria_creator_id: Option<String>,
-            pub(crate) search_criteria_include_links: Option<bool>,
-            pub(crate) search_criteria_repository_id: Option<String>,
-            pub(crate) search_criteria_reviewer_id: Option<String>,
-            pub(crate) search_criteria_source_ref_name: Option<String>,
-            pub(crate) search_criteria_source_repository_id: Option<String>,
-            pub(crate) search_criteria_status: Option<String>,
-            pub(crate) search_criteria_target_ref_name: Option<String>,
-            pub(crate) max_comment_length: Option<i32>,
-            pub(crate) skip: Option<i32>,
-            pub(crate) top: Option<i32>,
+        #[doc = "Create a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `create_options`: The pull request to create."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+            create_options: impl Into<models::GitPullRequestCreateOptions>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                repository_id,
+                project,
+                create_options,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "If set, search for pull requests that were created by this identity."]
-            pub fn search_criteria_creator_id(
-                mut self,
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            self.client.get_pull_request(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Update a pull request\n\nThese are the properties that can be updated with the API:\n - Status\n - Title\n - Description (up to 4000 characters)\n - CompletionOptions\n - MergeOptions\n - AutoCompleteSetBy.Id\n - TargetRefName (when the PR retargeting feature is enabled)\n Attempting to update other properties outside of this list will either cause the server to throw an `InvalidArgumentValueException`,\n or to silently ignore the update."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `update_options`: The pull request content to update."]
+        pub fn update(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+            pull_request_id: i32,
+            update_options: impl Into<models::GitPullRequestUpdateOptions>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                repository_id,
+                project,
+                pull_request_id,
+                update_options,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        pub fn get_pull_requests_by_project(&self) -> get_pull_requests_by_project::RequestBuilder {
+            self.client
+                .get_pull_requests_by_project(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        pub fn get_pull_request_by_id(
+            &self,
+            pull_request_id: i32,
+        ) -> get_pull_request_by_id::RequestBuilder {
+            self.client.get_pull_request_by_id(
+                self.organization.clone(),
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        pub fn get_pull_requests(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> get_pull_requests::RequestBuilder {
+            self.client.get_pull_requests(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `create_options`: The pull request to create."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            create_options: impl Into<models::GitPullRequestCreateOptions>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+                create_options,
+            )
+        }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        pub fn get_pull_request(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get_pull_request::RequestBuilder {
+            self.client.get_pull_request(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update a pull request\n\nThese are the properties that can be updated with the API:\n - Status\n - Title\n - Description (up to 4000 characters)\n - CompletionOptions\n - MergeOptions\n - AutoCompleteSetBy.Id\n - TargetRefName (when the PR retargeting feature is enabled)\n Attempting to update other properties outside of this list will either cause the server to throw an `InvalidArgumentValueException`,\n or to silently ignore the update."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `update_options`: The pull request content to update."]
+        pub fn update(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            update_options: impl Into<models::GitPullRequestUpdateOptions>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+                pull_request_id,
+                update_options,
+            )
+        }
+    }
+    pub mod get_pull_requests_by_project {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) project: String,
+            pub(crate) search_criteria_creator_id: Option<String>,
+            pub(crate) search_criteria_include_links: Option<bool>,
+            pub(crate) search_criteria_repository_id: Option<String>,
+            pub(crate) search_criteria_reviewer_id: Option<String>,
+            pub(crate) search_criteria_source_ref_name: Option<String>,
+            pub(crate) search_criteria_source_repository_id: Option<String>,
+            pub(crate) search_criteria_status: Option<models::PullRequestStatus>,
+            pub(crate) search_criteria_target_ref_name: Option<String>,
+            pub(crate) max_comment_length: Option<i32>,
+            pub(crate) skip: Option<i32>,
+            pub(crate) top: Option<i32>,
+        }
+        impl RequestBuilder {
+            #[doc = "If set, search for pull requests that were created by this identity."]
+            pub fn search_criteria_creator_id(
+                mut self,
                 search_criteria_creator_id: impl Into<String>,
             ) -> Self {
                 self.search_criteria_creator_id = Some(search_criteria_creator_id.into());
@@ -4607,7 +6308,7 @@ pub mod pull_requests {
             #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
             pub fn search_criteria_status(
                 mut self,
-                search_criteria_status: impl Into<String>,
+                search_criteria_status: impl Into<models::PullRequestStatus>,
             ) -> Self {
                 self.search_criteria_status = Some(search_criteria_status.into());
                 self
@@ -4658,9 +6359,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(search_criteria_creator_id) = &this.search_criteria_creator_id {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.creatorId",
@@ -4707,9 +6409,10 @@ pub mod pull_requests {
                             );
                         }
                         if let Some(search_criteria_status) = &this.search_criteria_status {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("searchCriteria.status", search_criteria_status);
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "searchCriteria.status",
+                                &search_criteria_status.to_string(),
+                            );
                         }
                         if let Some(search_criteria_target_ref_name) =
                             &this.search_criteria_target_ref_name
@@ -4740,6 +6443,37 @@ pub mod pull_requests {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPullRequestList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitPullRequestList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPullRequest>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitPullRequestList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPullRequestList>;
@@ -4836,9 +6570,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4922,7 +6657,7 @@ pub mod pull_requests {
             pub(crate) search_criteria_reviewer_id: Option<String>,
             pub(crate) search_criteria_source_ref_name: Option<String>,
             pub(crate) search_criteria_source_repository_id: Option<String>,
-            pub(crate) search_criteria_status: Option<String>,
+            pub(crate) search_criteria_status: Option<models::PullRequestStatus>,
             pub(crate) search_criteria_target_ref_name: Option<String>,
             pub(crate) max_comment_length: Option<i32>,
             pub(crate) skip: Option<i32>,
@@ -4981,7 +6716,7 @@ pub mod pull_requests {
             #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
             pub fn search_criteria_status(
                 mut self,
-                search_criteria_status: impl Into<String>,
+                search_criteria_status: impl Into<models::PullRequestStatus>,
             ) -> Self {
                 self.search_criteria_status = Some(search_criteria_status.into());
                 self
@@ -5033,9 +6768,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(search_criteria_creator_id) = &this.search_criteria_creator_id {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.creatorId",
@@ -5082,9 +6818,10 @@ pub mod pull_requests {
                             );
                         }
                         if let Some(search_criteria_status) = &this.search_criteria_status {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("searchCriteria.status", search_criteria_status);
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "searchCriteria.status",
+                                &search_criteria_status.to_string(),
+                            );
                         }
                         if let Some(search_criteria_target_ref_name) =
                             &this.search_criteria_target_ref_name
@@ -5115,6 +6852,37 @@ pub mod pull_requests {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPullRequestList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitPullRequestList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPullRequest>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitPullRequestList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPullRequestList>;
@@ -5218,9 +6986,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.create_options)?;
                         if let Some(supports_iterations) = &this.supports_iterations {
@@ -5362,9 +7131,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(max_comment_length) = &this.max_comment_length {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5508,9 +7278,10 @@ pub mod pull_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_commits) = &this.include_commits {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5594,6 +7365,87 @@ pub mod annotated_tags {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Create an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Object containing details of tag to be created."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAnnotatedTag>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, repository_id)
+        }
+        #[doc = "Get an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `object_id`: ObjectId (Sha1Id) of tag to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            object_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, repository_id, object_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Create an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Object containing details of tag to be created."]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAnnotatedTag>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Get an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `object_id`: ObjectId (Sha1Id) of tag to get."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            object_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+                object_id,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5677,9 +7529,10 @@ pub mod annotated_tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5786,9 +7639,10 @@ pub mod annotated_tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5865,11 +7719,92 @@ pub mod blobs {
             }
         }
     }
-    pub mod get_blobs_zip {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets one or more blobs in a zip file download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Blob IDs (SHA1 hashes) to be returned in the zip file."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_blobs_zip(
+            &self,
+            body: Vec<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_blobs_zip::RequestBuilder {
+            self.client
+                .get_blobs_zip(self.organization.clone(), body, repository_id, project)
+        }
+        #[doc = "Get a single blob.\n\nRepositories have both a name and an identifier. Identifiers are globally unique,\nbut several projects may contain a repository of the same name. You don't need to include\nthe project if you specify a repository by ID. However, if you specify a repository by name,\nyou must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `sha1`: SHA1 hash of the file. You can get the SHA1 of a file using the \"Git/Items/Get Item\" endpoint."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_blob(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_blob::RequestBuilder {
+            self.client
+                .get_blob(self.organization.clone(), repository_id, sha1, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets one or more blobs in a zip file download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Blob IDs (SHA1 hashes) to be returned in the zip file."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_blobs_zip(
+            &self,
+            body: Vec<String>,
+            repository_id: impl Into<String>,
+        ) -> get_blobs_zip::RequestBuilder {
+            self.client.get_blobs_zip(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a single blob.\n\nRepositories have both a name and an identifier. Identifiers are globally unique,\nbut several projects may contain a repository of the same name. You don't need to include\nthe project if you specify a repository by ID. However, if you specify a repository by name,\nyou must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `sha1`: SHA1 hash of the file. You can get the SHA1 of a file using the \"Git/Items/Get Item\" endpoint."]
+        pub fn get_blob(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+        ) -> get_blob::RequestBuilder {
+            self.client.get_blob(
+                self.organization.clone(),
+                repository_id,
+                sha1,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod get_blobs_zip {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<String> {
                 let bytes = self.0.into_body().collect().await?;
                 let body: String = serde_json::from_slice(&bytes).map_err(|e| {
                     azure_core::error::Error::full(
@@ -5889,6 +7824,25 @@ pub mod blobs {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -5952,9 +7906,10 @@ pub mod blobs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(filename) = &this.filename {
@@ -6005,6 +7960,25 @@ pub mod blobs {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -6088,9 +8062,10 @@ pub mod blobs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(download) = &this.download {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6203,6 +8178,125 @@ pub mod cherry_picks {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve information about a cherry pick operation for a specific branch. This operation is expensive due to the underlying object structure, so this API only looks at the 1000 most recent cherry pick operations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the cherry pick operation."]
+        pub fn get_cherry_pick_for_ref_name(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_cherry_pick_for_ref_name::RequestBuilder {
+            self.client.get_cherry_pick_for_ref_name(
+                self.organization.clone(),
+                project,
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Cherry pick a specific commit or commits that are associated to a pull request into a new branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, repository_id)
+        }
+        #[doc = "Retrieve information about a cherry pick operation by cherry pick Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `cherry_pick_id`: ID of the cherry pick."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_cherry_pick(
+            &self,
+            project: impl Into<String>,
+            cherry_pick_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_cherry_pick::RequestBuilder {
+            self.client.get_cherry_pick(
+                self.organization.clone(),
+                project,
+                cherry_pick_id,
+                repository_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve information about a cherry pick operation for a specific branch. This operation is expensive due to the underlying object structure, so this API only looks at the 1000 most recent cherry pick operations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the cherry pick operation."]
+        pub fn get_cherry_pick_for_ref_name(
+            &self,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_cherry_pick_for_ref_name::RequestBuilder {
+            self.client.get_cherry_pick_for_ref_name(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Cherry pick a specific commit or commits that are associated to a pull request into a new branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve information about a cherry pick operation by cherry pick Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `cherry_pick_id`: ID of the cherry pick."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_cherry_pick(
+            &self,
+            cherry_pick_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_cherry_pick::RequestBuilder {
+            self.client.get_cherry_pick(
+                self.organization.clone(),
+                self.project.clone(),
+                cherry_pick_id,
+                repository_id,
+            )
+        }
+    }
     pub mod get_cherry_pick_for_ref_name {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6285,9 +8379,10 @@ pub mod cherry_picks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ref_name = &this.ref_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -6395,9 +8490,10 @@ pub mod cherry_picks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6503,9 +8599,10 @@ pub mod cherry_picks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6583,6 +8680,97 @@ pub mod statuses {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get statuses associated with the Git commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), commit_id, repository_id, project)
+        }
+        #[doc = "Create Git commit status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Git commit status object to create."]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitStatus>,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                commit_id,
+                repository_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get statuses associated with the Git commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn list(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                commit_id,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create Git commit status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Git commit status object to create."]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitStatus>,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                commit_id,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6684,9 +8872,10 @@ pub mod statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6708,6 +8897,36 @@ pub mod statuses {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitStatusList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitStatusList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitStatus>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::GitStatusList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitStatusList>;
@@ -6807,9 +9026,10 @@ pub mod statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6866,6 +9086,47 @@ pub mod diffs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Find the closest common commit (the merge base) between base and target commits, and get the diff between either the base and target commits or common and target commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Find the closest common commit (the merge base) between base and target commits, and get the diff between either the base and target commits or common and target commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get(&self, repository_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7004,9 +9265,10 @@ pub mod diffs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(diff_common_commit) = &this.diff_common_commit {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7168,11 +9430,162 @@ pub mod import_requests {
             }
         }
     }
-    pub mod query {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitImportRequestList> {
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve import requests for a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn query(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), project, repository_id)
+        }
+        #[doc = "Create an import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The import request to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, repository_id)
+        }
+        #[doc = "Retrieve a particular import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                repository_id,
+                import_request_id,
+            )
+        }
+        #[doc = "Retry or abandon a failed import request.\n\nThere can only be one active import request associated with a repository. Marking a failed import request abandoned makes it inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated version of the import request. Currently, the only change allowed is setting the Status to Queued or Abandoned."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                repository_id,
+                import_request_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve import requests for a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn query(&self, repository_id: impl Into<String>) -> query::RequestBuilder {
+            self.client.query(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Create an import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The import request to create."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve a particular import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+                import_request_id,
+            )
+        }
+        #[doc = "Retry or abandon a failed import request.\n\nThere can only be one active import request associated with a repository. Marking a failed import request abandoned makes it inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated version of the import request. Currently, the only change allowed is setting the Status to Queued or Abandoned."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+                import_request_id,
+            )
+        }
+    }
+    pub mod query {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitImportRequestList> {
                 let bytes = self.0.into_body().collect().await?;
                 let body: models::GitImportRequestList =
                     serde_json::from_slice(&bytes).map_err(|e| {
@@ -7256,9 +9669,10 @@ pub mod import_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_abandoned) = &this.include_abandoned {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7370,9 +9784,10 @@ pub mod import_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7479,9 +9894,10 @@ pub mod import_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7588,9 +10004,10 @@ pub mod import_requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7641,6 +10058,55 @@ pub mod pull_request_query {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "This API is used to find what pull requests are related to a given commit.  It can be used to either find the pull request that created a particular merge commit or it can be used to find all pull requests that have ever merged a particular commit.  The input is a list of queries which each contain a list of commits. For each commit that you search against, you will get back a dictionary of commit -> pull requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of queries to perform."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            body: impl Into<models::GitPullRequestQuery>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), body, repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "This API is used to find what pull requests are related to a given commit.  It can be used to either find the pull request that created a particular merge commit or it can be used to find all pull requests that have ever merged a particular commit.  The input is a list of queries which each contain a list of commits. For each commit that you search against, you will get back a dictionary of commit -> pull requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of queries to perform."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get(
+            &self,
+            body: impl Into<models::GitPullRequestQuery>,
+            repository_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7724,9 +10190,10 @@ pub mod pull_request_query {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7857,66 +10324,251 @@ pub mod pull_request_attachments {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::AttachmentList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::AttachmentList = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of files attached to a given pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Get the file content of a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) repository_id: String,
-            pub(crate) pull_request_id: i32,
-            pub(crate) project: String,
+        #[doc = "Attach a new file to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `file_name`: The name of the file."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
+        #[doc = "Delete a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment to delete."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of files attached to a given pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get the file content of a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn get(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Attach a new file to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `file_name`: The name of the file."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment to delete."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn delete(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::AttachmentList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::AttachmentList = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
             #[doc = "However, this function can provide more flexibility when required."]
             pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
@@ -7940,9 +10592,10 @@ pub mod pull_request_attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7988,6 +10641,25 @@ pub mod pull_request_attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -8049,9 +10721,10 @@ pub mod pull_request_attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8158,9 +10831,10 @@ pub mod pull_request_attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/octet-stream");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -8236,9 +10910,10 @@ pub mod pull_request_attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8304,6 +10979,101 @@ pub mod pull_request_commits {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the commits for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_commits::RequestBuilder {
+            self.client.get_pull_request_commits(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the commits for the specified iteration of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the iteration from which to get the commits."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_iteration_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_iteration_commits::RequestBuilder {
+            self.client.get_pull_request_iteration_commits(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the commits for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn get_pull_request_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get_pull_request_commits::RequestBuilder {
+            self.client.get_pull_request_commits(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get the commits for the specified iteration of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the iteration from which to get the commits."]
+        pub fn get_pull_request_iteration_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> get_pull_request_iteration_commits::RequestBuilder {
+            self.client.get_pull_request_iteration_commits(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get_pull_request_commits {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8400,9 +11170,10 @@ pub mod pull_request_commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8419,6 +11190,24 @@ pub mod pull_request_commits {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRefList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -8524,9 +11313,10 @@ pub mod pull_request_commits {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8543,6 +11333,37 @@ pub mod pull_request_commits {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitCommitRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitCommitRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitCommitRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -8559,15 +11380,15 @@ pub mod pull_request_commits {
         }
     }
 }
-pub mod pull_request_iterations {
+pub mod pull_request_conflicts {
     use super::models;
     pub struct Client(pub(crate) super::Client);
     impl Client {
-        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = "Retrieve all conflicts for a pull request"]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn list(
@@ -8583,45 +11404,285 @@ pub mod pull_request_iterations {
                 repository_id: repository_id.into(),
                 pull_request_id,
                 project: project.into(),
-                include_commits: None,
+                skip: None,
+                top: None,
+                include_obsolete: None,
+                exclude_resolved: None,
+                only_resolved: None,
             }
         }
-        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = "Update multiple merge conflict resolutions."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `body`: An array of conflicts, each with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn get(
+        pub fn update_conflicts(
             &self,
             organization: impl Into<String>,
+            body: Vec<models::GitConflict>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            iteration_id: i32,
             project: impl Into<String>,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
+        ) -> update_conflicts::RequestBuilder {
+            update_conflicts::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
+                body,
                 repository_id: repository_id.into(),
                 pull_request_id,
-                iteration_id,
                 project: project.into(),
             }
         }
-    }
-    pub mod list {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::GitPullRequestIterationList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestIterationList = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
+        #[doc = "Retrieve one conflict for a pull request by ID"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                conflict_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update merge conflict resolution"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The conflict, with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitConflict>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                conflict_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all conflicts for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Update multiple merge conflict resolutions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An array of conflicts, each with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_conflicts(
+            &self,
+            body: Vec<models::GitConflict>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update_conflicts::RequestBuilder {
+            self.client.update_conflicts(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve one conflict for a pull request by ID"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                conflict_id,
+                project,
+            )
+        }
+        #[doc = "Update merge conflict resolution"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The conflict, with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitConflict>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                conflict_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all conflicts for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update multiple merge conflict resolutions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An array of conflicts, each with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn update_conflicts(
+            &self,
+            body: Vec<models::GitConflict>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> update_conflicts::RequestBuilder {
+            self.client.update_conflicts(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve one conflict for a pull request by ID"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                conflict_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update merge conflict resolution"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The conflict, with the resolution to apply."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `conflict_id`: ID of the conflict."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitConflict>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            conflict_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                conflict_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitConflictList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitConflictList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
                         azure_core::error::Error::full(
                             azure_core::error::ErrorKind::DataConversion,
                             e,
@@ -8671,12 +11732,36 @@ pub mod pull_request_iterations {
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
             pub(crate) project: String,
-            pub(crate) include_commits: Option<bool>,
+            pub(crate) skip: Option<i32>,
+            pub(crate) top: Option<i32>,
+            pub(crate) include_obsolete: Option<bool>,
+            pub(crate) exclude_resolved: Option<bool>,
+            pub(crate) only_resolved: Option<bool>,
         }
         impl RequestBuilder {
-            #[doc = "If true, include the commits associated with each iteration in the response."]
-            pub fn include_commits(mut self, include_commits: bool) -> Self {
-                self.include_commits = Some(include_commits);
+            #[doc = "Conflicts to skip."]
+            pub fn skip(mut self, skip: i32) -> Self {
+                self.skip = Some(skip);
+                self
+            }
+            #[doc = "Conflicts to return after skip."]
+            pub fn top(mut self, top: i32) -> Self {
+                self.top = Some(top);
+                self
+            }
+            #[doc = "Includes obsolete conflicts."]
+            pub fn include_obsolete(mut self, include_obsolete: bool) -> Self {
+                self.include_obsolete = Some(include_obsolete);
+                self
+            }
+            #[doc = "Excludes conflicts already resolved."]
+            pub fn exclude_resolved(mut self, exclude_resolved: bool) -> Self {
+                self.exclude_resolved = Some(exclude_resolved);
+                self
+            }
+            #[doc = "Returns only the conflicts that are resolved."]
+            pub fn only_resolved(mut self, only_resolved: bool) -> Self {
+                self.only_resolved = Some(only_resolved);
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -8688,7 +11773,7 @@ pub mod pull_request_iterations {
                     let this = self.clone();
                     async move {
                         let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations",
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/conflicts",
                             this.client.endpoint(),
                             &this.organization,
                             &this.project,
@@ -8704,13 +11789,34 @@ pub mod pull_request_iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(include_commits) = &this.include_commits {
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        if let Some(skip) = &this.skip {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("includeCommits", &include_commits.to_string());
+                                .append_pair("$skip", &skip.to_string());
+                        }
+                        if let Some(top) = &this.top {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("$top", &top.to_string());
+                        }
+                        if let Some(include_obsolete) = &this.include_obsolete {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("includeObsolete", &include_obsolete.to_string());
+                        }
+                        if let Some(exclude_resolved) = &this.exclude_resolved {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("excludeResolved", &exclude_resolved.to_string());
+                        }
+                        if let Some(only_resolved) = &this.only_resolved {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("onlyResolved", &only_resolved.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -8718,13 +11824,42 @@ pub mod pull_request_iterations {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitConflictList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::GitConflictList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitConflict>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::GitConflictList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestIterationList>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::GitPullRequestIterationList>,
-            >;
+            type Output = azure_core::Result<models::GitConflictList>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::GitConflictList>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -8735,13 +11870,15 @@ pub mod pull_request_iterations {
             }
         }
     }
-    pub mod get {
+    pub mod update_conflicts {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestIteration> {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::GitConflictUpdateResultList> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestIteration = serde_json::from_slice(&bytes)
+                let body: models::GitConflictUpdateResultList = serde_json::from_slice(&bytes)
                     .map_err(|e| {
                         azure_core::error::Error::full(
                             azure_core::error::ErrorKind::DataConversion,
@@ -8789,9 +11926,9 @@ pub mod pull_request_iterations {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
+            pub(crate) body: Vec<models::GitConflict>,
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -8804,15 +11941,14 @@ pub mod pull_request_iterations {
                     let this = self.clone();
                     async move {
                         let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}",
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/conflicts",
                             this.client.endpoint(),
                             &this.organization,
                             &this.project,
                             &this.repository_id,
-                            &this.pull_request_id,
-                            &this.iteration_id
+                            &this.pull_request_id
                         ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -8821,10 +11957,12 @@ pub mod pull_request_iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -8832,10 +11970,10 @@ pub mod pull_request_iterations {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestIteration>;
+            type Output = azure_core::Result<models::GitConflictUpdateResultList>;
             type IntoFuture = futures::future::BoxFuture<
                 'static,
-                azure_core::Result<models::GitPullRequestIteration>,
+                azure_core::Result<models::GitConflictUpdateResultList>,
             >;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
@@ -8847,50 +11985,13 @@ pub mod pull_request_iterations {
             }
         }
     }
-}
-pub mod pull_request_iteration_changes {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
-        #[doc = "Retrieve the changes made in a pull request between two iterations."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            project: impl Into<String>,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                project: project.into(),
-                top: None,
-                skip: None,
-                compare_to: None,
-            }
-        }
-    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::GitPullRequestIterationChanges> {
+            pub async fn into_body(self) -> azure_core::Result<models::GitConflict> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestIterationChanges = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
+                let body: models::GitConflict = serde_json::from_slice(&bytes).map_err(|e| {
                     azure_core::error::Error::full(
                         azure_core::error::ErrorKind::DataConversion,
                         e,
@@ -8939,28 +12040,10 @@ pub mod pull_request_iteration_changes {
             pub(crate) organization: String,
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
+            pub(crate) conflict_id: i32,
             pub(crate) project: String,
-            pub(crate) top: Option<i32>,
-            pub(crate) skip: Option<i32>,
-            pub(crate) compare_to: Option<i32>,
         }
         impl RequestBuilder {
-            #[doc = "Optional. The number of changes to retrieve.  The default value is 100 and the maximum value is 2000."]
-            pub fn top(mut self, top: i32) -> Self {
-                self.top = Some(top);
-                self
-            }
-            #[doc = "Optional. The number of changes to ignore.  For example, to retrieve changes 101-150, set top 50 and skip to 100."]
-            pub fn skip(mut self, skip: i32) -> Self {
-                self.skip = Some(skip);
-                self
-            }
-            #[doc = "ID of the pull request iteration to compare against.  The default value is zero which indicates the comparison is made against the common commit between the source and target branches"]
-            pub fn compare_to(mut self, compare_to: i32) -> Self {
-                self.compare_to = Some(compare_to);
-                self
-            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -8969,7 +12052,15 @@ pub mod pull_request_iteration_changes {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/changes" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/conflicts/{}",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.repository_id,
+                            &this.pull_request_id,
+                            &this.conflict_id
+                        ))?;
                         let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
@@ -8979,24 +12070,10 @@ pub mod pull_request_iteration_changes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(top) = &this.top {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("$top", &top.to_string());
-                        }
-                        if let Some(skip) = &this.skip {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("$skip", &skip.to_string());
-                        }
-                        if let Some(compare_to) = &this.compare_to {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("$compareTo", &compare_to.to_string());
-                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9005,11 +12082,9 @@ pub mod pull_request_iteration_changes {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestIterationChanges>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::GitPullRequestIterationChanges>,
-            >;
+            type Output = azure_core::Result<models::GitConflict>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::GitConflict>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -9020,166 +12095,22 @@ pub mod pull_request_iteration_changes {
             }
         }
     }
-}
-pub mod pull_request_iteration_statuses {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
-        #[doc = "Get all the statuses associated with a pull request iteration."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn list(
-            &self,
-            organization: impl Into<String>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            project: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                project: project.into(),
-            }
-        }
-        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `body`: Pull request status to create."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn create(
-            &self,
-            organization: impl Into<String>,
-            body: impl Into<models::GitPullRequestStatus>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            project: impl Into<String>,
-        ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                project: project.into(),
-            }
-        }
-        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn update(
-            &self,
-            organization: impl Into<String>,
-            body: impl Into<models::JsonPatchDocument>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            project: impl Into<String>,
-        ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                project: project.into(),
-            }
-        }
-        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration."]
-        #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            status_id: i32,
-            project: impl Into<String>,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                status_id,
-                project: project.into(),
-            }
-        }
-        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `iteration_id`: ID of the pull request iteration."]
-        #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            iteration_id: i32,
-            status_id: i32,
-            project: impl Into<String>,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                iteration_id,
-                status_id,
-                project: project.into(),
-            }
-        }
-    }
-    pub mod list {
+    pub mod update {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatusList> {
+            pub async fn into_body(self) -> azure_core::Result<models::GitConflict> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestStatusList = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
+                let body: models::GitConflict = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
                 Ok(body)
             }
             pub fn into_raw_response(self) -> azure_core::Response {
@@ -9217,9 +12148,10 @@ pub mod pull_request_iteration_statuses {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
+            pub(crate) body: models::GitConflict,
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
+            pub(crate) conflict_id: i32,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -9231,8 +12163,16 @@ pub mod pull_request_iteration_statuses {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/conflicts/{}",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.repository_id,
+                            &this.pull_request_id,
+                            &this.conflict_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -9241,10 +12181,12 @@ pub mod pull_request_iteration_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -9252,11 +12194,9 @@ pub mod pull_request_iteration_statuses {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestStatusList>;
-            type IntoFuture = futures::future::BoxFuture<
-                'static,
-                azure_core::Result<models::GitPullRequestStatusList>,
-            >;
+            type Output = azure_core::Result<models::GitConflict>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::GitConflict>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -9267,20 +12207,171 @@ pub mod pull_request_iteration_statuses {
             }
         }
     }
-    pub mod create {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatus> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestStatus =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
+}
+pub mod pull_request_iterations {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+                include_commits: None,
+            }
+        }
+        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::GitPullRequestIterationList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestIterationList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
                             ),
                         )
                     })?;
@@ -9321,13 +12412,17 @@ pub mod pull_request_iteration_statuses {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::GitPullRequestStatus,
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
             pub(crate) project: String,
+            pub(crate) include_commits: Option<bool>,
         }
         impl RequestBuilder {
+            #[doc = "If true, include the commits associated with each iteration in the response."]
+            pub fn include_commits(mut self, include_commits: bool) -> Self {
+                self.include_commits = Some(include_commits);
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -9336,8 +12431,15 @@ pub mod pull_request_iteration_statuses {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.repository_id,
+                            &this.pull_request_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -9346,11 +12448,16 @@ pub mod pull_request_iteration_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        if let Some(include_commits) = &this.include_commits {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("includeCommits", &include_commits.to_string());
+                        }
+                        let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -9358,10 +12465,10 @@ pub mod pull_request_iteration_statuses {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestStatus>;
+            type Output = azure_core::Result<models::GitPullRequestIterationList>;
             type IntoFuture = futures::future::BoxFuture<
                 'static,
-                azure_core::Result<models::GitPullRequestStatus>,
+                azure_core::Result<models::GitPullRequestIterationList>,
             >;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
@@ -9373,72 +12480,14 @@ pub mod pull_request_iteration_statuses {
             }
         }
     }
-    pub mod update {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::JsonPatchDocument,
-            pub(crate) repository_id: String,
-            pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
-            pub(crate) project: String,
-        }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        req.insert_header("content-type", "application/json-patch+json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-        }
-    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatus> {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestIteration> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestStatus =
-                    serde_json::from_slice(&bytes).map_err(|e| {
+                let body: models::GitPullRequestIteration = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
                         azure_core::error::Error::full(
                             azure_core::error::ErrorKind::DataConversion,
                             e,
@@ -9488,7 +12537,6 @@ pub mod pull_request_iteration_statuses {
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
             pub(crate) iteration_id: i32,
-            pub(crate) status_id: i32,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -9500,7 +12548,15 @@ pub mod pull_request_iteration_statuses {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses/{}" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id , & this . status_id)) ? ;
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.repository_id,
+                            &this.pull_request_id,
+                            &this.iteration_id
+                        ))?;
                         let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
@@ -9510,9 +12566,10 @@ pub mod pull_request_iteration_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9521,10 +12578,10 @@ pub mod pull_request_iteration_statuses {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestStatus>;
+            type Output = azure_core::Result<models::GitPullRequestIteration>;
             type IntoFuture = futures::future::BoxFuture<
                 'static,
-                azure_core::Result<models::GitPullRequestStatus>,
+                azure_core::Result<models::GitPullRequestIteration>,
             >;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
@@ -9536,187 +12593,118 @@ pub mod pull_request_iteration_statuses {
             }
         }
     }
-    pub mod delete {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
-        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
-        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
-        #[doc = r" operation and returns a `Result` with the parsed response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
-        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
-        #[doc = r" [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) repository_id: String,
-            pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
-            pub(crate) status_id: i32,
-            pub(crate) project: String,
-        }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses/{}" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id , & this . status_id)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes)
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-        }
-    }
 }
-pub mod pull_request_labels {
+pub mod pull_request_iteration_changes {
     use super::models;
     pub struct Client(pub(crate) super::Client);
     impl Client {
-        #[doc = "Get all the labels assigned to a pull request."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
-        #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn list(
-            &self,
-            organization: impl Into<String>,
-            repository_id: impl Into<String>,
-            pull_request_id: i32,
-            project: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
-                pull_request_id,
-                project: project.into(),
-                project_id: None,
-            }
-        }
-        #[doc = "Create a label for a specified pull request. The only required field is the name of the new label."]
+        #[doc = "Retrieve the changes made in a pull request between two iterations."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `body`: Label to assign to the pull request."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn create(
+        pub fn get(
             &self,
             organization: impl Into<String>,
-            body: impl Into<models::WebApiCreateTagRequestData>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
+            iteration_id: i32,
             project: impl Into<String>,
-        ) -> create::RequestBuilder {
-            create::RequestBuilder {
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
-                body: body.into(),
                 repository_id: repository_id.into(),
                 pull_request_id,
+                iteration_id,
                 project: project.into(),
-                project_id: None,
+                top: None,
+                skip: None,
+                compare_to: None,
             }
         }
-        #[doc = "Retrieves a single label that has been assigned to a pull request."]
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve the changes made in a pull request between two iterations."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            label_id_or_name: impl Into<String>,
+            iteration_id: i32,
             project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
-                label_id_or_name: label_id_or_name.into(),
-                project: project.into(),
-                project_id: None,
-            }
+                iteration_id,
+                project,
+            )
         }
-        #[doc = "Removes a label from the set of those assigned to the pull request."]
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve the changes made in a pull request between two iterations."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn delete(
+        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
+        pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            label_id_or_name: impl Into<String>,
-            project: impl Into<String>,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
-                label_id_or_name: label_id_or_name.into(),
-                project: project.into(),
-                project_id: None,
-            }
+                iteration_id,
+                self.project.clone(),
+            )
         }
     }
-    pub mod list {
+    pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinitionList> {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::GitPullRequestIterationChanges> {
                 let bytes = self.0.into_body().collect().await?;
-                let body: models::WebApiTagDefinitionList = serde_json::from_slice(&bytes)
+                let body: models::GitPullRequestIterationChanges = serde_json::from_slice(&bytes)
                     .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
                 Ok(body)
             }
             pub fn into_raw_response(self) -> azure_core::Response {
@@ -9756,13 +12744,26 @@ pub mod pull_request_labels {
             pub(crate) organization: String,
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
             pub(crate) project: String,
-            pub(crate) project_id: Option<String>,
+            pub(crate) top: Option<i32>,
+            pub(crate) skip: Option<i32>,
+            pub(crate) compare_to: Option<i32>,
         }
         impl RequestBuilder {
-            #[doc = "Project ID or project name."]
-            pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
-                self.project_id = Some(project_id.into());
+            #[doc = "Optional. The number of changes to retrieve.  The default value is 100 and the maximum value is 2000."]
+            pub fn top(mut self, top: i32) -> Self {
+                self.top = Some(top);
+                self
+            }
+            #[doc = "Optional. The number of changes to ignore.  For example, to retrieve changes 101-150, set top 50 and skip to 100."]
+            pub fn skip(mut self, skip: i32) -> Self {
+                self.skip = Some(skip);
+                self
+            }
+            #[doc = "ID of the pull request iteration to compare against.  The default value is zero which indicates the comparison is made against the common commit between the source and target branches"]
+            pub fn compare_to(mut self, compare_to: i32) -> Self {
+                self.compare_to = Some(compare_to);
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -9773,14 +12774,7 @@ pub mod pull_request_labels {
                 Box::pin({
                     let this = self.clone();
                     async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/labels",
-                            this.client.endpoint(),
-                            &this.organization,
-                            &this.project,
-                            &this.repository_id,
-                            &this.pull_request_id
-                        ))?;
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/changes" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
                         let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
@@ -9790,13 +12784,24 @@ pub mod pull_request_labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(project_id) = &this.project_id {
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("projectId", project_id);
+                                .append_pair("$top", &top.to_string());
+                        }
+                        if let Some(skip) = &this.skip {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("$skip", &skip.to_string());
+                        }
+                        if let Some(compare_to) = &this.compare_to {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("$compareTo", &compare_to.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -9806,10 +12811,10 @@ pub mod pull_request_labels {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::WebApiTagDefinitionList>;
+            type Output = azure_core::Result<models::GitPullRequestIterationChanges>;
             type IntoFuture = futures::future::BoxFuture<
                 'static,
-                azure_core::Result<models::WebApiTagDefinitionList>,
+                azure_core::Result<models::GitPullRequestIterationChanges>,
             >;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
@@ -9821,44 +12826,1281 @@ pub mod pull_request_labels {
             }
         }
     }
-    pub mod create {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinition> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::WebApiTagDefinition =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
-        }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+}
+pub mod pull_request_iteration_statuses {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get all the statuses associated with a pull request iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all the statuses associated with a pull request iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project,
+            )
+        }
+        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all the statuses associated with a pull request iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatusList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestStatusList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::GitPullRequestStatusList>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::GitPullRequestStatusList>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod create {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatus> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestStatus =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::GitPullRequestStatus,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::GitPullRequestStatus>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::GitPullRequestStatus>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod update {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::JsonPatchDocument,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        req.insert_header("content-type", "application/json-patch+json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+    pub mod get {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatus> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestStatus =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) status_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses/{}" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id , & this . status_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::GitPullRequestStatus>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::GitPullRequestStatus>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod delete {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) status_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/iterations/{}/statuses/{}" , this . client . endpoint () , & this . organization , & this . project , & this . repository_id , & this . pull_request_id , & this . iteration_id , & this . status_id)) ? ;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+    }
+}
+pub mod pull_request_labels {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get all the labels assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+                project_id: None,
+            }
+        }
+        #[doc = "Create a label for a specified pull request. The only required field is the name of the new label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Label to assign to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WebApiCreateTagRequestData>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+                project_id: None,
+            }
+        }
+        #[doc = "Retrieves a single label that has been assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                label_id_or_name: label_id_or_name.into(),
+                project: project.into(),
+                project_id: None,
+            }
+        }
+        #[doc = "Removes a label from the set of those assigned to the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                label_id_or_name: label_id_or_name.into(),
+                project: project.into(),
+                project_id: None,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all the labels assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create a label for a specified pull request. The only required field is the name of the new label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Label to assign to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiCreateTagRequestData>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Retrieves a single label that has been assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                project,
+            )
+        }
+        #[doc = "Removes a label from the set of those assigned to the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all the labels assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a label for a specified pull request. The only required field is the name of the new label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Label to assign to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiCreateTagRequestData>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieves a single label that has been assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Removes a label from the set of those assigned to the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                self.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinitionList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::WebApiTagDefinitionList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" The building of a request is typically finalized by invoking `.await` on"]
+        #[doc = r" `RequestBuilder`. This implicitly invokes the [`IntoFuture::into_future()`](#method.into_future)"]
+        #[doc = r" method, which converts `RequestBuilder` into a future that executes the request"]
+        #[doc = r" operation and returns a `Result` with the parsed response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details (e.g. to inspect"]
+        #[doc = r" response headers or raw body data) then you can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future that resolves to a lower-level"]
+        #[doc = r" [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) project: String,
+            pub(crate) project_id: Option<String>,
+        }
+        impl RequestBuilder {
+            #[doc = "Project ID or project name."]
+            pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
+                self.project_id = Some(project_id.into());
+                self
+            }
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = azure_core::Url::parse(&format!(
+                            "{}/{}/{}/_apis/git/repositories/{}/pullRequests/{}/labels",
+                            this.client.endpoint(),
+                            &this.organization,
+                            &this.project,
+                            &this.repository_id,
+                            &this.pull_request_id
+                        ))?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes)
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        if let Some(project_id) = &this.project_id {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("projectId", project_id);
+                        }
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::WebApiTagDefinitionList>;
+            type IntoFuture = futures::future::BoxFuture<
+                'static,
+                azure_core::Result<models::WebApiTagDefinitionList>,
+            >;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod create {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinition> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::WebApiTagDefinition =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
         #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
         #[doc = r" parameters can be chained."]
@@ -9912,9 +14154,10 @@ pub mod pull_request_labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(project_id) = &this.project_id {
@@ -10036,9 +14279,10 @@ pub mod pull_request_labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(project_id) = &this.project_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -10126,9 +14370,10 @@ pub mod pull_request_labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(project_id) = &this.project_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -10195,6 +14440,101 @@ pub mod pull_request_properties {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get external properties of the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create or update pull request external properties. The patch operation can be `add`, `replace` or `remove`. For `add` operation, the path can be empty. If the path is empty, the value must be a list of key value pairs. For `replace` operation, the path cannot be empty. If the path does not exist, the property will be added to the collection. For `remove` operation, the path cannot be empty. If the path does not exist, no action will be performed."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties to add, replace or remove in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get external properties of the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create or update pull request external properties. The patch operation can be `add`, `replace` or `remove`. For `add` operation, the path can be empty. If the path is empty, the value must be a list of key value pairs. For `replace` operation, the path cannot be empty. If the path does not exist, the property will be added to the collection. For `remove` operation, the path cannot be empty. If the path does not exist, no action will be performed."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties to add, replace or remove in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10279,9 +14619,10 @@ pub mod pull_request_properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10390,9 +14731,10 @@ pub mod pull_request_properties {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -10416,138 +14758,333 @@ pub mod pull_request_properties {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
         }
-    }
-}
-pub mod pull_request_reviewers {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
-        #[doc = "Retrieve the reviewers for a pull request"]
+    }
+}
+pub mod pull_request_reviewers {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Retrieve the reviewers for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Add reviewers to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_pull_request_reviewers(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::IdentityRef>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_pull_request_reviewers::RequestBuilder {
+            create_pull_request_reviewers::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Reviewer to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_unmaterialized_pull_request_reviewer(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
+            create_unmaterialized_pull_request_reviewer::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Reset the votes of multiple reviewers on a pull request.  NOTE: This endpoint only supports updating votes, but does not support updating required reviewers (use policy) or display names."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: IDs of the reviewers whose votes will be reset to zero"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pull_request_reviewers(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update_pull_request_reviewers::RequestBuilder {
+            update_pull_request_reviewers::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Retrieve information about a particular reviewer on a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                reviewer_id: reviewer_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Add a reviewer to a pull request or cast a vote."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Reviewer's vote.<br />If the reviewer's ID is included here, it must match the reviewerID parameter.<br />Reviewers can set their own vote with this method.  When adding other reviewers, vote must be set to zero."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn list(
+        pub fn create_pull_request_reviewer(
             &self,
             organization: impl Into<String>,
+            body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
+            reviewer_id: impl Into<String>,
             project: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
+        ) -> create_pull_request_reviewer::RequestBuilder {
+            create_pull_request_reviewer::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
+                body: body.into(),
                 repository_id: repository_id.into(),
                 pull_request_id,
+                reviewer_id: reviewer_id.into(),
                 project: project.into(),
             }
         }
-        #[doc = "Add reviewers to a pull request."]
+        #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn create_pull_request_reviewers(
+        pub fn update_pull_request_reviewer(
             &self,
             organization: impl Into<String>,
-            body: Vec<models::IdentityRef>,
+            body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
+            reviewer_id: impl Into<String>,
             project: impl Into<String>,
-        ) -> create_pull_request_reviewers::RequestBuilder {
-            create_pull_request_reviewers::RequestBuilder {
+        ) -> update_pull_request_reviewer::RequestBuilder {
+            update_pull_request_reviewer::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
-                body,
+                body: body.into(),
                 repository_id: repository_id.into(),
                 pull_request_id,
+                reviewer_id: reviewer_id.into(),
                 project: project.into(),
             }
         }
-        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = "Remove a reviewer from a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `body`: Reviewer to add to the pull request."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn create_unmaterialized_pull_request_reviewer(
+        pub fn delete(
             &self,
             organization: impl Into<String>,
-            body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
+            reviewer_id: impl Into<String>,
             project: impl Into<String>,
-        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
-            create_unmaterialized_pull_request_reviewer::RequestBuilder {
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
-                body: body.into(),
                 repository_id: repository_id.into(),
                 pull_request_id,
+                reviewer_id: reviewer_id.into(),
                 project: project.into(),
             }
         }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve the reviewers for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Add reviewers to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRef>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_pull_request_reviewers::RequestBuilder {
+            self.client.create_pull_request_reviewers(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_unmaterialized_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
+            self.client.create_unmaterialized_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
         #[doc = "Reset the votes of multiple reviewers on a pull request.  NOTE: This endpoint only supports updating votes, but does not support updating required reviewers (use policy) or display names."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: IDs of the reviewers whose votes will be reset to zero"]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_pull_request_reviewers(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             project: impl Into<String>,
         ) -> update_pull_request_reviewers::RequestBuilder {
-            update_pull_request_reviewers::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            self.client.update_pull_request_reviewers(
+                self.organization.clone(),
                 body,
-                repository_id: repository_id.into(),
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                project,
+            )
         }
         #[doc = "Retrieve information about a particular reviewer on a pull request"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `reviewer_id`: ID of the reviewer."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
             project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                project,
+            )
         }
         #[doc = "Add a reviewer to a pull request or cast a vote."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Reviewer's vote.<br />If the reviewer's ID is included here, it must match the reviewerID parameter.<br />Reviewers can set their own vote with this method.  When adding other reviewers, vote must be set to zero."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
@@ -10555,75 +15092,239 @@ pub mod pull_request_reviewers {
         #[doc = "* `project`: Project ID or project name"]
         pub fn create_pull_request_reviewer(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
             project: impl Into<String>,
         ) -> create_pull_request_reviewer::RequestBuilder {
-            create_pull_request_reviewer::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.create_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                project,
+            )
+        }
+        #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_pull_request_reviewer::RequestBuilder {
+            self.client.update_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+        #[doc = "Remove a reviewer from a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve the reviewers for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Add reviewers to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRef>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create_pull_request_reviewers::RequestBuilder {
+            self.client.create_pull_request_reviewers(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create_unmaterialized_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
+            self.client.create_unmaterialized_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Reset the votes of multiple reviewers on a pull request.  NOTE: This endpoint only supports updating votes, but does not support updating required reviewers (use policy) or display names."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: IDs of the reviewers whose votes will be reset to zero"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request"]
+        pub fn update_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> update_pull_request_reviewers::RequestBuilder {
+            self.client.update_pull_request_reviewers(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve information about a particular reviewer on a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Add a reviewer to a pull request or cast a vote."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer's vote.<br />If the reviewer's ID is included here, it must match the reviewerID parameter.<br />Reviewers can set their own vote with this method.  When adding other reviewers, vote must be set to zero."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        pub fn create_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+        ) -> create_pull_request_reviewer::RequestBuilder {
+            self.client.create_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `reviewer_id`: ID of the reviewer."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_pull_request_reviewer(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_pull_request_reviewer::RequestBuilder {
-            update_pull_request_reviewer::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.update_pull_request_reviewer(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Remove a reviewer from a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -10710,9 +15411,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10821,9 +15523,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -10933,9 +15636,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11012,9 +15716,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11110,9 +15815,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11223,9 +15929,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11337,9 +16044,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11417,9 +16125,10 @@ pub mod pull_request_reviewers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11458,6 +16167,63 @@ pub mod pull_request_share {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Sends an e-mail notification about a specific pull request to a set of recipients"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn share_pull_request(
+            &self,
+            body: impl Into<models::ShareNotificationContext>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> share_pull_request::RequestBuilder {
+            self.client.share_pull_request(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Sends an e-mail notification about a specific pull request to a set of recipients"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn share_pull_request(
+            &self,
+            body: impl Into<models::ShareNotificationContext>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> share_pull_request::RequestBuilder {
+            self.client.share_pull_request(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod share_pull_request {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11510,9 +16276,10 @@ pub mod pull_request_share {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11522,132 +16289,353 @@ pub mod pull_request_share {
             }
         }
     }
-}
-pub mod pull_request_statuses {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod pull_request_statuses {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get all the statuses associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all the statuses associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                status_id,
+                project,
+            )
+        }
+        #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                status_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Get all the statuses associated with a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Pull request status to create."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitPullRequestStatus>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::JsonPatchDocument>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             status_id: i32,
-            project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
                 status_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             status_id: i32,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
                 status_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -11734,9 +16722,10 @@ pub mod pull_request_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11845,9 +16834,10 @@ pub mod pull_request_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11924,9 +16914,10 @@ pub mod pull_request_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -12022,9 +17013,10 @@ pub mod pull_request_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -12101,9 +17093,10 @@ pub mod pull_request_statuses {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -12188,38 +17181,223 @@ pub mod pull_request_threads {
                 repository_id: repository_id.into(),
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-                iteration: None,
-                base_iteration: None,
-            }
+                project: project.into(),
+                iteration: None,
+                base_iteration: None,
+            }
+        }
+        #[doc = "Update a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The thread content that should be updated."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all threads in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread to create. Thread must contain at least one comment."]
+        #[doc = "* `repository_id`: Repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Update a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread content that should be updated."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all threads in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread to create. Thread must contain at least one comment."]
+        #[doc = "* `repository_id`: Repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                self.project.clone(),
+            )
         }
         #[doc = "Update a thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The thread content that should be updated."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitPullRequestCommentThread>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -12320,9 +17498,10 @@ pub mod pull_request_threads {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(iteration) = &this.iteration {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12443,9 +17622,10 @@ pub mod pull_request_threads {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -12570,9 +17750,10 @@ pub mod pull_request_threads {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(iteration) = &this.iteration {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12695,9 +17876,10 @@ pub mod pull_request_threads {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -12770,102 +17952,359 @@ pub mod pull_request_thread_comments {
             thread_id: i32,
             project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all comments associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Create a comment on a specific thread in a pull request (up to 500 comments can be created per thread)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The comment to create. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Update a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Delete a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all comments associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Create a comment on a specific thread in a pull request (up to 500 comments can be created per thread)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The comment to create. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Update a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Comment>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
         #[doc = "Delete a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -12945,9 +18384,10 @@ pub mod pull_request_thread_comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13047,9 +18487,10 @@ pub mod pull_request_thread_comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -13150,9 +18591,10 @@ pub mod pull_request_thread_comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13253,9 +18695,10 @@ pub mod pull_request_thread_comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -13324,9 +18767,10 @@ pub mod pull_request_thread_comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13425,6 +18869,167 @@ pub mod pull_request_comment_likes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get likes for a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Add a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Delete a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get likes for a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Add a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Delete a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -13504,9 +19109,10 @@ pub mod pull_request_comment_likes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13574,9 +19180,10 @@ pub mod pull_request_comment_likes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
                         req.set_body(req_body);
@@ -13632,9 +19239,10 @@ pub mod pull_request_comment_likes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13671,6 +19279,59 @@ pub mod pull_request_work_items {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve a list of work items associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve a list of work items associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -13755,9 +19416,10 @@ pub mod pull_request_work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13814,47 +19476,146 @@ pub mod pushes {
         #[doc = "Push changes to the repository."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPush>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Retrieves a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `push_id`: ID of the push."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            push_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                push_id,
+                project: project.into(),
+                include_commits: None,
+                include_ref_updates: None,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieves pushes associated with the specified repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), repository_id, project)
+        }
+        #[doc = "Push changes to the repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `repository_id`: The name or ID of the repository."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitPush>,
             repository_id: impl Into<String>,
             project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
-                project: project.into(),
-            }
+            self.client
+                .create(self.organization.clone(), body, repository_id, project)
         }
         #[doc = "Retrieves a particular push."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The name or ID of the repository."]
         #[doc = "* `push_id`: ID of the push."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             push_id: i32,
             project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            self.client
+                .get(self.organization.clone(), repository_id, push_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieves pushes associated with the specified repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Push changes to the repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPush>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieves a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `push_id`: ID of the push."]
+        pub fn get(&self, repository_id: impl Into<String>, push_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
                 push_id,
-                project: project.into(),
-                include_commits: None,
-                include_ref_updates: None,
-            }
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -14005,9 +19766,10 @@ pub mod pushes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(skip) = &this.skip {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -14064,6 +19826,34 @@ pub mod pushes {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPushList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::GitPushList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitPush>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::GitPushList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPushList>;
@@ -14161,9 +19951,10 @@ pub mod pushes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -14281,9 +20072,10 @@ pub mod pushes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_commits) = &this.include_commits {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -14398,6 +20190,121 @@ pub mod refs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Queries the provided repository for its refs and returns them."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), repository_id, project)
+        }
+        #[doc = "Creating, updating, or deleting refs(branches).\n\nUpdating a ref means making it point at a different commit than it used to. You must specify both the old and new commit to avoid race conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of ref updates to attempt to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_refs(
+            &self,
+            body: Vec<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_refs::RequestBuilder {
+            self.client
+                .update_refs(self.organization.clone(), body, repository_id, project)
+        }
+        #[doc = "Lock or Unlock a branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref update action (lock/unlock) to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `filter`: The name of the branch to lock/unlock"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_ref(
+            &self,
+            body: impl Into<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            filter: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_ref::RequestBuilder {
+            self.client.update_ref(
+                self.organization.clone(),
+                body,
+                repository_id,
+                filter,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Queries the provided repository for its refs and returns them."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Creating, updating, or deleting refs(branches).\n\nUpdating a ref means making it point at a different commit than it used to. You must specify both the old and new commit to avoid race conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of ref updates to attempt to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn update_refs(
+            &self,
+            body: Vec<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+        ) -> update_refs::RequestBuilder {
+            self.client.update_refs(
+                self.organization.clone(),
+                body,
+                repository_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Lock or Unlock a branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref update action (lock/unlock) to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `filter`: The name of the branch to lock/unlock"]
+        pub fn update_ref(
+            &self,
+            body: impl Into<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            filter: impl Into<String>,
+        ) -> update_ref::RequestBuilder {
+            self.client.update_ref(
+                self.organization.clone(),
+                body,
+                repository_id,
+                filter,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -14533,9 +20440,10 @@ pub mod refs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(filter) = &this.filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -14588,6 +20496,24 @@ pub mod refs {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GitRefList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitRefList>;
@@ -14692,9 +20618,10 @@ pub mod refs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(project_id) = &this.project_id {
@@ -14813,9 +20740,10 @@ pub mod refs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let filter = &this.filter;
@@ -14918,6 +20846,121 @@ pub mod reverts {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve information about a revert operation for a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the revert operation."]
+        pub fn get_revert_for_ref_name(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_revert_for_ref_name::RequestBuilder {
+            self.client.get_revert_for_ref_name(
+                self.organization.clone(),
+                project,
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Starts the operation to create a new branch which reverts changes introduced by either a specific commit or commits that are associated to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, repository_id)
+        }
+        #[doc = "Retrieve information about a revert operation by revert Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `revert_id`: ID of the revert operation."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_revert(
+            &self,
+            project: impl Into<String>,
+            revert_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_revert::RequestBuilder {
+            self.client
+                .get_revert(self.organization.clone(), project, revert_id, repository_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve information about a revert operation for a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the revert operation."]
+        pub fn get_revert_for_ref_name(
+            &self,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_revert_for_ref_name::RequestBuilder {
+            self.client.get_revert_for_ref_name(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Starts the operation to create a new branch which reverts changes introduced by either a specific commit or commits that are associated to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve information about a revert operation by revert Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `revert_id`: ID of the revert operation."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_revert(
+            &self,
+            revert_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_revert::RequestBuilder {
+            self.client.get_revert(
+                self.organization.clone(),
+                self.project.clone(),
+                revert_id,
+                repository_id,
+            )
+        }
+    }
     pub mod get_revert_for_ref_name {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -15000,9 +21043,10 @@ pub mod reverts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ref_name = &this.ref_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -15110,9 +21154,10 @@ pub mod reverts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -15218,9 +21263,10 @@ pub mod reverts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -15267,6 +21313,47 @@ pub mod suggestions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve a pull request suggestion for a particular repository or team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), repository_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve a pull request suggestion for a particular repository or team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -15349,9 +21436,10 @@ pub mod suggestions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -15405,6 +21493,55 @@ pub mod trees {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "The Tree endpoint returns the collection of objects underneath the specified tree. Trees are folders in a Git repository.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects may contain a repository of the same name. You don't need to include the project if you specify a repository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: Repository Id."]
+        #[doc = "* `sha1`: SHA1 hash of the tree object."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), repository_id, sha1, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "The Tree endpoint returns the collection of objects underneath the specified tree. Trees are folders in a Git repository.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects may contain a repository of the same name. You don't need to include the project if you specify a repository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: Repository Id."]
+        #[doc = "* `sha1`: SHA1 hash of the tree object."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                repository_id,
+                sha1,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -15429,6 +21566,25 @@ pub mod trees {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -15512,9 +21668,10 @@ pub mod trees {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(project_id) = &this.project_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -15589,6 +21746,65 @@ pub mod merge_bases {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Find the merge bases of two commits, optionally across forks. If otherRepositoryId is not specified, the merge bases will only be calculated within the context of the local repositoryNameOrId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: ID or name of the local repository."]
+        #[doc = "* `commit_id`: First commit, usually the tip of the target branch of the potential merge."]
+        #[doc = "* `other_commit_id`: Other commit, usually the tip of the source branch of the potential merge."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            commit_id: impl Into<String>,
+            other_commit_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_name_or_id,
+                commit_id,
+                other_commit_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Find the merge bases of two commits, optionally across forks. If otherRepositoryId is not specified, the merge bases will only be calculated within the context of the local repositoryNameOrId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: ID or name of the local repository."]
+        #[doc = "* `commit_id`: First commit, usually the tip of the target branch of the potential merge."]
+        #[doc = "* `other_commit_id`: Other commit, usually the tip of the source branch of the potential merge."]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            commit_id: impl Into<String>,
+            other_commit_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_name_or_id,
+                commit_id,
+                other_commit_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -15686,9 +21902,10 @@ pub mod merge_bases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let other_commit_id = &this.other_commit_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -15820,6 +22037,161 @@ pub mod forks {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all forks of a repository in the collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `collection_id`: Team project collection ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            collection_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_name_or_id,
+                collection_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve all requested fork sync operations on this repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_fork_sync_requests(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_fork_sync_requests::RequestBuilder {
+            self.client.get_fork_sync_requests(
+                self.organization.clone(),
+                repository_name_or_id,
+                project,
+            )
+        }
+        #[doc = "Request that another repository's refs be fetched into this one. It syncs two existing forks. To create a fork, please see the <a href=\"https://docs.microsoft.com/en-us/rest/api/vsts/git/repositories/create?view=azure-devops-rest-5.1\"> repositories endpoint</a>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Source repository and ref mapping."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_fork_sync_request(
+            &self,
+            body: impl Into<models::GitForkSyncRequestParameters>,
+            repository_name_or_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_fork_sync_request::RequestBuilder {
+            self.client.create_fork_sync_request(
+                self.organization.clone(),
+                body,
+                repository_name_or_id,
+                project,
+            )
+        }
+        #[doc = "Get a specific fork sync operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `fork_sync_operation_id`: OperationId of the sync request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_fork_sync_request(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            fork_sync_operation_id: i32,
+            project: impl Into<String>,
+        ) -> get_fork_sync_request::RequestBuilder {
+            self.client.get_fork_sync_request(
+                self.organization.clone(),
+                repository_name_or_id,
+                fork_sync_operation_id,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all forks of a repository in the collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `collection_id`: Team project collection ID."]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            collection_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                repository_name_or_id,
+                collection_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Retrieve all requested fork sync operations on this repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn get_fork_sync_requests(
+            &self,
+            repository_name_or_id: impl Into<String>,
+        ) -> get_fork_sync_requests::RequestBuilder {
+            self.client.get_fork_sync_requests(
+                self.organization.clone(),
+                repository_name_or_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Request that another repository's refs be fetched into this one. It syncs two existing forks. To create a fork, please see the <a href=\"https://docs.microsoft.com/en-us/rest/api/vsts/git/repositories/create?view=azure-devops-rest-5.1\"> repositories endpoint</a>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Source repository and ref mapping."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create_fork_sync_request(
+            &self,
+            body: impl Into<models::GitForkSyncRequestParameters>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create_fork_sync_request::RequestBuilder {
+            self.client.create_fork_sync_request(
+                self.organization.clone(),
+                body,
+                repository_name_or_id,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Get a specific fork sync operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `fork_sync_operation_id`: OperationId of the sync request."]
+        pub fn get_fork_sync_request(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            fork_sync_operation_id: i32,
+        ) -> get_fork_sync_request::RequestBuilder {
+            self.client.get_fork_sync_request(
+                self.organization.clone(),
+                repository_name_or_id,
+                fork_sync_operation_id,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -15910,9 +22282,10 @@ pub mod forks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_links) = &this.include_links {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -16035,9 +22408,10 @@ pub mod forks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_abandoned) = &this.include_abandoned {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -16160,9 +22534,10 @@ pub mod forks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(include_links) = &this.include_links {
@@ -16280,9 +22655,10 @@ pub mod forks {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_links) = &this.include_links {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -16361,6 +22737,95 @@ pub mod merges {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Request a git merge operation. Currently we support merging only 2 commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parents commitIds and merge commit messsage."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitMergeParameters>,
+            project: impl Into<String>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                project,
+                repository_name_or_id,
+            )
+        }
+        #[doc = "Get a specific merge operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `merge_operation_id`: OperationId of the merge request."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_name_or_id: impl Into<String>,
+            merge_operation_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                repository_name_or_id,
+                merge_operation_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Request a git merge operation. Currently we support merging only 2 commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parents commitIds and merge commit messsage."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitMergeParameters>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                repository_name_or_id,
+            )
+        }
+        #[doc = "Get a specific merge operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `merge_operation_id`: OperationId of the merge request."]
+        pub fn get(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            merge_operation_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                repository_name_or_id,
+                merge_operation_id,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -16449,9 +22914,10 @@ pub mod merges {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(include_links) = &this.include_links {
@@ -16568,9 +23034,10 @@ pub mod merges {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_links) = &this.include_links {
                             req.url_mut()
                                 .query_pairs_mut()
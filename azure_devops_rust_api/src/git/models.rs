@@ -1822,6 +1822,23 @@ impl GitConflictFileDirectory {
         Self::default()
     }
 }
+#[doc = ""]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct GitConflictList {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::serde::deserialize_null_default"
+    )]
+    pub value: Vec<GitConflict>,
+}
+impl GitConflictList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 #[doc = "Data object for Rename1to2 conflict"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct GitConflictRename1to2 {
@@ -2079,6 +2096,23 @@ pub mod git_conflict_update_result {
 }
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct GitConflictUpdateResultList {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::serde::deserialize_null_default"
+    )]
+    pub value: Vec<GitConflictUpdateResult>,
+}
+impl GitConflictUpdateResultList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = ""]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct GitDeletedRepository {
     #[serde(
         rename = "createdDate",
@@ -3927,6 +3961,20 @@ pub struct GitPullRequestUpdateOptions {
     #[doc = "Pull request status"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<PullRequestStatus>,
+    #[doc = "Identity id"]
+    #[serde(
+        rename = "autoCompleteSetBy",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub auto_complete_set_by: Option<IdentityId>,
+    #[doc = "Preferences about how the pull request should be completed."]
+    #[serde(
+        rename = "completionOptions",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub completion_options: Option<GitPullRequestCompletionOptions>,
 }
 impl GitPullRequestUpdateOptions {
     pub fn new() -> Self {
@@ -6128,6 +6176,7 @@ impl PullRequestCreatedEvent {
 }
 #[doc = "Pull request status"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "PullRequestStatus")]
 pub enum PullRequestStatus {
     #[serde(rename = "notSet")]
     NotSet,
@@ -6137,6 +6186,57 @@ pub enum PullRequestStatus {
     Abandoned,
     #[serde(rename = "completed")]
     Completed,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for PullRequestStatus {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for PullRequestStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for PullRequestStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::NotSet => serializer.serialize_unit_variant("PullRequestStatus", 0u32, "notSet"),
+            Self::Active => serializer.serialize_unit_variant("PullRequestStatus", 1u32, "active"),
+            Self::Abandoned => {
+                serializer.serialize_unit_variant("PullRequestStatus", 2u32, "abandoned")
+            }
+            Self::Completed => {
+                serializer.serialize_unit_variant("PullRequestStatus", 3u32, "completed")
+            }
+            Self::All => serializer.serialize_unit_variant("PullRequestStatus", 4u32, "all"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for PullRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotSet => f.write_str("notSet"),
+            Self::Active => f.write_str("active"),
+            Self::Abandoned => f.write_str("abandoned"),
+            Self::Completed => f.write_str("completed"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
 }
 #[doc = "Initial config contract sent to extensions creating tabs on the pull request page"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -0,0 +1,476 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Creating pull requests and waiting for them to complete.
+//!
+//! [`PullRequestHandle`] wraps the steps of a typical pull request lifecycle: create the pull
+//! request, add reviewers, enable auto-complete with a set of completion options, and then wait
+//! for the pull request to be merged. Waiting requires the `policy` feature, as it reports the
+//! policy evaluations that are blocking the pull request.
+
+use super::models::{
+    GitPullRequest, GitPullRequestCompletionOptions, GitPullRequestCreateOptions,
+    GitPullRequestUpdateOptions, IdentityId, IdentityRef, IdentityRefWithVote,
+};
+use super::{pull_request_reviewers, pull_requests};
+use std::time::Duration;
+
+/// The identity ID that cancels auto-complete.
+const EMPTY_IDENTITY_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+/// The default interval between polls of [`PullRequestHandle::wait_for_completion()`].
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+impl pull_requests::Client {
+    /// Returns a [`PullRequestHandle`] for an existing pull request.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: The repository ID of the pull request's target branch.
+    /// * `project`: Project ID or project name
+    /// * `pull_request_id`: ID of the pull request.
+    pub fn handle(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        project: impl Into<String>,
+        pull_request_id: i32,
+    ) -> PullRequestHandle {
+        PullRequestHandle {
+            client: self.0.clone(),
+            organization: organization.into(),
+            repository_id: repository_id.into(),
+            project: project.into(),
+            pull_request_id,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Creates a pull request, returning a [`PullRequestHandle`] for it and the created pull request.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: The repository ID of the pull request's target branch.
+    /// * `project`: Project ID or project name
+    /// * `create_options`: The pull request to create.
+    pub async fn create_with_handle(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        project: impl Into<String>,
+        create_options: impl Into<GitPullRequestCreateOptions>,
+    ) -> azure_core::Result<(PullRequestHandle, GitPullRequest)> {
+        let organization = organization.into();
+        let repository_id = repository_id.into();
+        let project = project.into();
+        let pull_request = self
+            .create(&organization, &repository_id, &project, create_options)
+            .await?;
+        let handle = self.handle(
+            organization,
+            repository_id,
+            project,
+            pull_request.pull_request_id,
+        );
+        Ok((handle, pull_request))
+    }
+}
+
+scoped_client_methods! {
+    pull_requests;
+    /// Returns a [`PullRequestHandle`] for an existing pull request.
+    pub fn handle(
+        repository_id: impl Into<String>,
+        [project],
+        pull_request_id: i32,
+    ) -> PullRequestHandle;
+
+    /// Creates a pull request, returning a [`PullRequestHandle`] for it and the created pull request.
+    pub async fn create_with_handle(
+        repository_id: impl Into<String>,
+        [project],
+        create_options: impl Into<GitPullRequestCreateOptions>,
+    ) -> azure_core::Result<(PullRequestHandle, GitPullRequest)>;
+}
+
+/// A handle to a pull request, which adds reviewers, sets auto-complete and waits for completion.
+///
+/// Example:
+///
+/// ```ignore
+/// let (pr, _) = git_client
+///     .pull_requests_client()
+///     .create_with_handle(organization, repository, project, create_options)
+///     .await?;
+/// pr.add_reviewer(reviewer_id, true).await?;
+/// pr.set_auto_complete(
+///     my_id,
+///     GitPullRequestCompletionOptions {
+///         merge_strategy: Some(MergeStrategy::Squash),
+///         delete_source_branch: Some(true),
+///         transition_work_items: Some(true),
+///         ..Default::default()
+///     },
+/// )
+/// .await?;
+/// let merged = pr.wait_for_completion(Duration::from_secs(3600)).await?;
+/// ```
+#[derive(Clone)]
+pub struct PullRequestHandle {
+    client: super::Client,
+    organization: String,
+    repository_id: String,
+    project: String,
+    pull_request_id: i32,
+    poll_interval: Duration,
+}
+
+impl std::fmt::Debug for PullRequestHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PullRequestHandle")
+            .field("organization", &self.organization)
+            .field("repository_id", &self.repository_id)
+            .field("project", &self.project)
+            .field("pull_request_id", &self.pull_request_id)
+            .finish()
+    }
+}
+
+impl PullRequestHandle {
+    /// Returns the ID of the pull request.
+    pub fn pull_request_id(&self) -> i32 {
+        self.pull_request_id
+    }
+
+    /// Sets the interval between polls of [`wait_for_completion()`](PullRequestHandle::wait_for_completion)
+    /// (default 15 seconds).
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Retrieves the pull request.
+    pub async fn get(&self) -> azure_core::Result<GitPullRequest> {
+        self.pull_requests()
+            .get_pull_request(
+                &self.organization,
+                &self.repository_id,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Adds a reviewer to the pull request.
+    ///
+    /// Arguments:
+    /// * `reviewer_id`: ID of the reviewer.
+    /// * `is_required`: `true` if the reviewer must approve the pull request.
+    pub async fn add_reviewer(
+        &self,
+        reviewer_id: impl Into<String>,
+        is_required: bool,
+    ) -> azure_core::Result<IdentityRefWithVote> {
+        let reviewer_id = reviewer_id.into();
+        let body = IdentityRefWithVote {
+            is_required: Some(is_required),
+            ..IdentityRefWithVote::new(IdentityRef::new(reviewer_id.clone()))
+        };
+        pull_request_reviewers::Client(self.client.clone())
+            .create_pull_request_reviewer(
+                &self.organization,
+                body,
+                &self.repository_id,
+                self.pull_request_id,
+                reviewer_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Enables auto-complete, so the pull request is completed with the `completion_options`
+    /// when all its policies are satisfied.
+    ///
+    /// Arguments:
+    /// * `set_by`: ID of the identity enabling auto-complete.
+    /// * `completion_options`: How the pull request will be merged, e.g. the merge strategy.
+    pub async fn set_auto_complete(
+        &self,
+        set_by: impl Into<String>,
+        completion_options: GitPullRequestCompletionOptions,
+    ) -> azure_core::Result<GitPullRequest> {
+        self.update(GitPullRequestUpdateOptions {
+            auto_complete_set_by: Some(IdentityId::new(set_by.into())),
+            completion_options: Some(completion_options),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Cancels auto-complete.
+    pub async fn cancel_auto_complete(&self) -> azure_core::Result<GitPullRequest> {
+        self.update(GitPullRequestUpdateOptions {
+            auto_complete_set_by: Some(IdentityId::new(EMPTY_IDENTITY_ID.to_owned())),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Updates the pull request.
+    pub async fn update(
+        &self,
+        update_options: GitPullRequestUpdateOptions,
+    ) -> azure_core::Result<GitPullRequest> {
+        self.pull_requests()
+            .update(
+                &self.organization,
+                &self.repository_id,
+                &self.project,
+                self.pull_request_id,
+                update_options,
+            )
+            .await
+    }
+
+    fn pull_requests(&self) -> pull_requests::Client {
+        pull_requests::Client(self.client.clone())
+    }
+}
+
+#[cfg(feature = "policy")]
+pub use self::wait::{PullRequestWaitError, PullRequestWaitFailure};
+
+#[cfg(feature = "policy")]
+mod wait {
+    use super::super::models::{git_pull_request, GitPullRequest};
+    use super::PullRequestHandle;
+    use crate::policy::models::{policy_evaluation_record::Status, PolicyEvaluationRecord};
+    use azure_core::error::{Error, ErrorKind};
+    use std::time::{Duration, Instant};
+
+    /// Why [`PullRequestHandle::wait_for_completion()`] failed.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum PullRequestWaitFailure {
+        /// The pull request was abandoned.
+        Abandoned,
+        /// A blocking policy rejected the pull request, or failed to run.
+        PolicyRejected,
+        /// The pull request could not be merged, e.g. due to conflicts.
+        MergeFailed,
+        /// The pull request did not complete within the timeout.
+        TimedOut,
+    }
+
+    /// The error returned when a pull request does not complete.
+    ///
+    /// It is attached to the returned [`azure_core::Error`], and can be retrieved with
+    /// [`PullRequestWaitError::from_error`].
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct PullRequestWaitError {
+        /// Why the wait failed.
+        pub failure: PullRequestWaitFailure,
+        /// The pull request, as last retrieved.
+        pub pull_request: GitPullRequest,
+        /// The evaluations of enabled, blocking policies that have not approved the pull request.
+        pub blocking: Vec<PolicyEvaluationRecord>,
+    }
+
+    impl PullRequestWaitError {
+        /// Returns the `PullRequestWaitError` attached to an error returned by
+        /// [`PullRequestHandle::wait_for_completion()`], if any.
+        pub fn from_error(error: &Error) -> Option<&PullRequestWaitError> {
+            error.get_ref()?.downcast_ref::<PullRequestWaitError>()
+        }
+    }
+
+    impl std::fmt::Display for PullRequestWaitError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let id = self.pull_request.pull_request_id;
+            match self.failure {
+                PullRequestWaitFailure::Abandoned => write!(f, "Pull request {id} was abandoned")?,
+                PullRequestWaitFailure::PolicyRejected => {
+                    write!(f, "Pull request {id} was rejected by policy")?
+                }
+                PullRequestWaitFailure::MergeFailed => {
+                    write!(f, "Pull request {id} could not be merged")?;
+                    if let Some(message) = &self.pull_request.merge_failure_message {
+                        write!(f, ": {message}")?;
+                    }
+                }
+                PullRequestWaitFailure::TimedOut => {
+                    write!(f, "Timed out waiting for pull request {id} to complete")?
+                }
+            }
+            if !self.blocking.is_empty() {
+                let policies: Vec<String> = self
+                    .blocking
+                    .iter()
+                    .map(|evaluation| {
+                        format!(
+                            "{} ({:?})",
+                            policy_name(evaluation),
+                            evaluation.status.as_ref()
+                        )
+                    })
+                    .collect();
+                write!(f, "; blocking policies: {}", policies.join(", "))?;
+            }
+            Ok(())
+        }
+    }
+
+    impl std::error::Error for PullRequestWaitError {}
+
+    impl PullRequestHandle {
+        /// Polls the pull request until it is completed, returning the completed pull request.
+        ///
+        /// An error is returned if the pull request is abandoned, cannot be merged, is rejected
+        /// by a blocking policy, or does not complete within `timeout`. A
+        /// [`PullRequestWaitError`] attached to the error reports the policy evaluations that are
+        /// blocking the pull request.
+        ///
+        /// The pull request is only completed by the service if auto-complete is enabled, e.g. by
+        /// [`set_auto_complete()`](PullRequestHandle::set_auto_complete).
+        pub async fn wait_for_completion(
+            &self,
+            timeout: Duration,
+        ) -> azure_core::Result<GitPullRequest> {
+            let deadline = Instant::now() + timeout;
+            loop {
+                let pull_request = self.get().await?;
+                let failure = match pull_request.status {
+                    git_pull_request::Status::Completed => return Ok(pull_request),
+                    git_pull_request::Status::Abandoned => Some(PullRequestWaitFailure::Abandoned),
+                    _ => match pull_request.merge_status {
+                        Some(
+                            git_pull_request::MergeStatus::Conflicts
+                            | git_pull_request::MergeStatus::Failure,
+                        ) => Some(PullRequestWaitFailure::MergeFailed),
+                        Some(git_pull_request::MergeStatus::RejectedByPolicy) => {
+                            Some(PullRequestWaitFailure::PolicyRejected)
+                        }
+                        _ => None,
+                    },
+                };
+                let blocking = self.blocking_evaluations(&pull_request).await?;
+                let failure = failure
+                    .or_else(|| {
+                        blocking
+                            .iter()
+                            .any(|evaluation| {
+                                matches!(evaluation.status, Some(Status::Rejected | Status::Broken))
+                            })
+                            .then_some(PullRequestWaitFailure::PolicyRejected)
+                    })
+                    .or_else(|| {
+                        (Instant::now() >= deadline).then_some(PullRequestWaitFailure::TimedOut)
+                    });
+                if let Some(failure) = failure {
+                    let error = PullRequestWaitError {
+                        failure,
+                        pull_request,
+                        blocking,
+                    };
+                    let message = error.to_string();
+                    return Err(Error::full(ErrorKind::Other, error, message));
+                }
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                azure_core::sleep::sleep(self.poll_interval.min(remaining)).await;
+            }
+        }
+
+        /// Returns the evaluations of enabled, blocking policies that have not approved the pull request.
+        async fn blocking_evaluations(
+            &self,
+            pull_request: &GitPullRequest,
+        ) -> azure_core::Result<Vec<PolicyEvaluationRecord>> {
+            let artifact_id = pull_request.artifact_id.clone().unwrap_or_else(|| {
+                format!(
+                    "vstfs:///CodeReview/CodeReviewId/{}/{}",
+                    pull_request.repository.project.id, pull_request.pull_request_id
+                )
+            });
+            let client = crate::policy::Client::with_pipeline(
+                self.client.endpoint.clone(),
+                self.client.credential.clone(),
+                self.client.scopes.clone(),
+                self.client.pipeline.clone(),
+                self.client.api_version.clone(),
+            );
+            let evaluations = client
+                .evaluations_client()
+                .list(&self.organization, &self.project, artifact_id)
+                .await?;
+            Ok(evaluations.value.into_iter().filter(is_blocking).collect())
+        }
+    }
+
+    /// Returns `true` if an evaluation is of an enabled, blocking policy that has not approved
+    /// the pull request.
+    fn is_blocking(evaluation: &PolicyEvaluationRecord) -> bool {
+        let blocking = evaluation
+            .configuration
+            .as_ref()
+            .map_or(false, |configuration| {
+                configuration.is_blocking == Some(true) && configuration.is_enabled != Some(false)
+            });
+        blocking
+            && !matches!(
+                evaluation.status,
+                Some(Status::Approved | Status::NotApplicable)
+            )
+    }
+
+    /// Returns the display name of the type of an evaluated policy, e.g. "Minimum number of reviewers".
+    fn policy_name(evaluation: &PolicyEvaluationRecord) -> &str {
+        evaluation
+            .configuration
+            .as_ref()
+            .and_then(|configuration| {
+                configuration
+                    .versioned_policy_configuration_ref
+                    .policy_configuration_ref
+                    .type_
+                    .as_ref()
+            })
+            .map_or("Unknown policy", |type_| type_.display_name.as_str())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use serde_json::json;
+
+        #[test]
+        fn test_blocking_evaluations() {
+            let evaluations: Vec<PolicyEvaluationRecord> = serde_json::from_value(json!([
+                {
+                    "evaluationId": "1",
+                    "status": "rejected",
+                    "configuration": {
+                        "id": 1,
+                        "isBlocking": true,
+                        "isEnabled": true,
+                        "type": { "id": "fa4e907d", "displayName": "Build", "url": "" }
+                    }
+                },
+                {
+                    "evaluationId": "2",
+                    "status": "approved",
+                    "configuration": { "id": 2, "isBlocking": true, "isEnabled": true }
+                },
+                {
+                    "evaluationId": "3",
+                    "status": "running",
+                    "configuration": { "id": 3, "isBlocking": false, "isEnabled": true }
+                }
+            ]))
+            .unwrap();
+            let blocking: Vec<_> = evaluations.into_iter().filter(is_blocking).collect();
+            assert_eq!(blocking.len(), 1);
+            assert_eq!(blocking[0].evaluation_id.as_deref(), Some("1"));
+            assert_eq!(policy_name(&blocking[0]), "Build");
+        }
+    }
+}
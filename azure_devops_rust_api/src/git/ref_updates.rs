@@ -0,0 +1,407 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Creating, moving and deleting branches and tags.
+//!
+//! Refs are updated by `update_refs`, which takes a list of `GitRefUpdate`s, each with the
+//! object ID that the ref is expected to point to (`oldObjectId`) and the object ID to point it
+//! to (`newObjectId`). A ref that does not exist, or is deleted, is represented by an object ID of
+//! 40 zeros. The service applies each update independently, and reports failures in the
+//! `updateStatus` of each result rather than as an error.
+//!
+//! [`RefUpdateBuilder`] builds these updates from branch and tag operations, resolving the
+//! current object IDs of the refs, and returns a [`RefUpdateError`] if any update fails.
+
+use super::commit_builder::{full_ref_name, ref_object_id};
+use super::models::{GitRef, GitRefUpdate, GitRefUpdateResult};
+use super::refs;
+use azure_core::error::{Error, ErrorKind};
+use futures::future::BoxFuture;
+
+/// The object ID of a ref that does not exist.
+pub const EMPTY_OBJECT_ID: &str = "0000000000000000000000000000000000000000";
+
+/// An operation on a ref, added to a [`RefUpdateBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RefOperation {
+    /// Creates a branch pointing to `from`, a branch name, ref name or commit ID.
+    CreateBranch { name: String, from: String },
+    /// Deletes a branch.
+    DeleteBranch { name: String },
+    /// Moves a branch to `to`, a branch name, ref name or commit ID, whatever it currently points to.
+    ForceMove { name: String, to: String },
+    /// Creates a lightweight tag pointing to `target`, a branch name, ref name or commit ID.
+    CreateTag { name: String, target: String },
+    /// Deletes a tag.
+    DeleteTag { name: String },
+}
+
+/// The error returned when one or more ref updates fail.
+///
+/// It is attached to the returned [`azure_core::Error`], and can be retrieved with
+/// [`RefUpdateError::from_error`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefUpdateError {
+    /// The results of the updates that failed.
+    pub failed: Vec<GitRefUpdateResult>,
+    /// `true` if the updates that succeeded were reverted, in all-or-nothing mode.
+    pub rolled_back: bool,
+}
+
+impl RefUpdateError {
+    /// Returns the `RefUpdateError` attached to an error returned by a [`RefUpdateBuilder`], if any.
+    pub fn from_error(error: &Error) -> Option<&RefUpdateError> {
+        error.get_ref()?.downcast_ref::<RefUpdateError>()
+    }
+}
+
+impl std::fmt::Display for RefUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to update refs:")?;
+        for result in &self.failed {
+            write!(f, " {}", result.name.as_deref().unwrap_or_default())?;
+            match (&result.update_status, &result.custom_message) {
+                (Some(status), Some(message)) => write!(f, " ({status:?}: {message})")?,
+                (Some(status), None) => write!(f, " ({status:?})")?,
+                (None, Some(message)) => write!(f, " ({message})")?,
+                (None, None) => {}
+            }
+        }
+        if self.rolled_back {
+            write!(f, "; the other updates were reverted")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RefUpdateError {}
+
+impl refs::Client {
+    /// Creates a [`RefUpdateBuilder`] that creates, moves and deletes branches and tags.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: The name or ID of the repository.
+    /// * `project`: Project ID or project name
+    pub fn ref_updates(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        project: impl Into<String>,
+    ) -> RefUpdateBuilder {
+        RefUpdateBuilder {
+            client: self.0.clone(),
+            organization: organization.into(),
+            repository_id: repository_id.into(),
+            project: project.into(),
+            operations: Vec::new(),
+            all_or_nothing: false,
+        }
+    }
+
+    /// Locks or unlocks a branch.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `repository_id`: The name or ID of the repository.
+    /// * `project`: Project ID or project name
+    /// * `branch`: The name of the branch, e.g. `main` or `refs/heads/main`.
+    /// * `locked`: `true` to lock the branch, `false` to unlock it.
+    pub async fn lock_branch(
+        &self,
+        organization: impl Into<String>,
+        repository_id: impl Into<String>,
+        project: impl Into<String>,
+        branch: &str,
+        locked: bool,
+    ) -> azure_core::Result<GitRef> {
+        let filter = full_ref_name(branch);
+        let body = GitRefUpdate {
+            is_locked: Some(locked),
+            ..Default::default()
+        };
+        self.update_ref(
+            organization,
+            body,
+            repository_id,
+            filter.trim_start_matches("refs/"),
+            project,
+        )
+        .await
+    }
+}
+
+scoped_client_methods! {
+    refs;
+    /// Creates a [`RefUpdateBuilder`] that creates, moves and deletes branches and tags.
+    pub fn ref_updates(repository_id: impl Into<String>, [project]) -> RefUpdateBuilder;
+
+    /// Locks or unlocks a branch.
+    pub async fn lock_branch(
+        repository_id: impl Into<String>,
+        [project],
+        branch: &str,
+        locked: bool,
+    ) -> azure_core::Result<GitRef>;
+}
+
+/// Builder for a set of branch and tag updates, sent in a single `update_refs` request.
+///
+/// Branch and tag names may be short names (e.g. `main`, `v1.0`) or full ref names
+/// (e.g. `refs/heads/main`). Sources and targets may also be commit IDs.
+///
+/// The service applies each update independently. If any update fails then a [`RefUpdateError`]
+/// is returned. In [all-or-nothing](RefUpdateBuilder::all_or_nothing) mode the updates that
+/// succeeded are then reverted.
+///
+/// Example:
+///
+/// ```ignore
+/// let results = git_client
+///     .refs_client()
+///     .ref_updates(organization, repository, project)
+///     .create_branch("release/1.2", "main")
+///     .create_tag("v1.2.0", "main")
+///     .all_or_nothing(true)
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct RefUpdateBuilder {
+    client: super::Client,
+    organization: String,
+    repository_id: String,
+    project: String,
+    operations: Vec<RefOperation>,
+    all_or_nothing: bool,
+}
+
+impl RefUpdateBuilder {
+    /// Adds an operation.
+    pub fn operation(mut self, operation: RefOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Creates a branch pointing to `from`, a branch name, ref name or commit ID.
+    pub fn create_branch(self, name: impl Into<String>, from: impl Into<String>) -> Self {
+        self.operation(RefOperation::CreateBranch {
+            name: name.into(),
+            from: from.into(),
+        })
+    }
+
+    /// Deletes a branch.
+    pub fn delete_branch(self, name: impl Into<String>) -> Self {
+        self.operation(RefOperation::DeleteBranch { name: name.into() })
+    }
+
+    /// Moves a branch to `to`, a branch name, ref name or commit ID, whatever it currently points to.
+    pub fn force_move(self, name: impl Into<String>, to: impl Into<String>) -> Self {
+        self.operation(RefOperation::ForceMove {
+            name: name.into(),
+            to: to.into(),
+        })
+    }
+
+    /// Creates a lightweight tag pointing to `target`, a branch name, ref name or commit ID.
+    pub fn create_tag(self, name: impl Into<String>, target: impl Into<String>) -> Self {
+        self.operation(RefOperation::CreateTag {
+            name: name.into(),
+            target: target.into(),
+        })
+    }
+
+    /// Deletes a tag.
+    pub fn delete_tag(self, name: impl Into<String>) -> Self {
+        self.operation(RefOperation::DeleteTag { name: name.into() })
+    }
+
+    /// Reverts the updates that succeeded if any update fails (default `false`).
+    pub fn all_or_nothing(mut self, all_or_nothing: bool) -> Self {
+        self.all_or_nothing = all_or_nothing;
+        self
+    }
+
+    /// Sends the updates, returning the result of each update.
+    pub async fn send(self) -> azure_core::Result<Vec<GitRefUpdateResult>> {
+        let mut updates = Vec::with_capacity(self.operations.len());
+        for operation in &self.operations {
+            updates.push(self.ref_update(operation).await?);
+        }
+        let results = self.update_refs(updates).await?;
+        let (succeeded, failed): (Vec<_>, Vec<_>) = results
+            .iter()
+            .cloned()
+            .partition(|result| result.success == Some(true));
+        if failed.is_empty() {
+            return Ok(results);
+        }
+        let mut rolled_back = false;
+        if self.all_or_nothing && !succeeded.is_empty() {
+            let reverts = succeeded.iter().map(revert).collect();
+            let reverted = self.update_refs(reverts).await?;
+            rolled_back = reverted.iter().all(|result| result.success == Some(true));
+        }
+        let error = RefUpdateError {
+            failed,
+            rolled_back,
+        };
+        let message = error.to_string();
+        Err(Error::full(ErrorKind::Other, error, message))
+    }
+
+    /// Builds the update for an operation, resolving the object IDs it refers to.
+    async fn ref_update(&self, operation: &RefOperation) -> azure_core::Result<GitRefUpdate> {
+        let (name, old_object_id, new_object_id) = match operation {
+            RefOperation::CreateBranch { name, from } => (
+                full_ref_name(name),
+                EMPTY_OBJECT_ID.to_owned(),
+                self.resolve(from).await?,
+            ),
+            RefOperation::DeleteBranch { name } => {
+                let name = full_ref_name(name);
+                let old_object_id = self.resolve(&name).await?;
+                (name, old_object_id, EMPTY_OBJECT_ID.to_owned())
+            }
+            RefOperation::ForceMove { name, to } => {
+                let name = full_ref_name(name);
+                let old_object_id = self.resolve(&name).await?;
+                (name, old_object_id, self.resolve(to).await?)
+            }
+            RefOperation::CreateTag { name, target } => (
+                full_tag_name(name),
+                EMPTY_OBJECT_ID.to_owned(),
+                self.resolve(target).await?,
+            ),
+            RefOperation::DeleteTag { name } => {
+                let name = full_tag_name(name);
+                let old_object_id = self.resolve(&name).await?;
+                (name, old_object_id, EMPTY_OBJECT_ID.to_owned())
+            }
+        };
+        Ok(GitRefUpdate {
+            name: Some(name),
+            old_object_id: Some(old_object_id),
+            new_object_id: Some(new_object_id),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the object ID of a commit ID, branch name or ref name.
+    async fn resolve(&self, name: &str) -> azure_core::Result<String> {
+        if is_object_id(name) {
+            return Ok(name.to_owned());
+        }
+        ref_object_id(
+            &self.client,
+            &self.organization,
+            &self.repository_id,
+            &self.project,
+            name,
+        )
+        .await?
+        .ok_or_else(|| {
+            Error::with_message(ErrorKind::Other, || {
+                format!("Ref {} does not exist", full_ref_name(name))
+            })
+        })
+    }
+
+    async fn update_refs(
+        &self,
+        updates: Vec<GitRefUpdate>,
+    ) -> azure_core::Result<Vec<GitRefUpdateResult>> {
+        let results = refs::Client(self.client.clone())
+            .update_refs(
+                &self.organization,
+                updates,
+                &self.repository_id,
+                &self.project,
+            )
+            .await?;
+        Ok(results.value)
+    }
+}
+
+impl std::future::IntoFuture for RefUpdateBuilder {
+    type Output = azure_core::Result<Vec<GitRefUpdateResult>>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<Vec<GitRefUpdateResult>>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+/// Returns the full name of a tag, e.g. `refs/tags/v1.0` for the tag `v1.0`.
+fn full_tag_name(name: &str) -> String {
+    if name.starts_with("refs/") {
+        name.to_owned()
+    } else {
+        format!("refs/tags/{name}")
+    }
+}
+
+/// Returns `true` if `name` is a full commit ID.
+fn is_object_id(name: &str) -> bool {
+    name.len() == 40 && name.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Returns the update that reverts a successful update.
+fn revert(result: &GitRefUpdateResult) -> GitRefUpdate {
+    GitRefUpdate {
+        name: result.name.clone(),
+        old_object_id: result.new_object_id.clone(),
+        new_object_id: result.old_object_id.clone(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ref_names() {
+        assert_eq!(full_ref_name("main"), "refs/heads/main");
+        assert_eq!(full_tag_name("v1.0"), "refs/tags/v1.0");
+        assert_eq!(full_tag_name("refs/tags/v1.0"), "refs/tags/v1.0");
+        assert!(is_object_id("0123456789abcdef0123456789abcdef01234567"));
+        assert!(!is_object_id("main"));
+    }
+
+    #[test]
+    fn test_ref_update_error() {
+        let results: Vec<GitRefUpdateResult> = serde_json::from_value(json!([
+            {
+                "name": "refs/heads/release",
+                "oldObjectId": EMPTY_OBJECT_ID,
+                "newObjectId": "0123456789abcdef0123456789abcdef01234567",
+                "success": true,
+                "updateStatus": "succeeded"
+            },
+            {
+                "name": "refs/tags/v1.0",
+                "success": false,
+                "updateStatus": "refNameConflict",
+                "customMessage": "The tag already exists"
+            }
+        ]))
+        .unwrap();
+        let update = revert(&results[0]);
+        assert_eq!(update.name.as_deref(), Some("refs/heads/release"));
+        assert_eq!(update.new_object_id.as_deref(), Some(EMPTY_OBJECT_ID));
+
+        let error = RefUpdateError {
+            failed: vec![results[1].clone()],
+            rolled_back: true,
+        };
+        let message = error.to_string();
+        let error = Error::full(ErrorKind::Other, error, message);
+        let error = RefUpdateError::from_error(&error).unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Failed to update refs: refs/tags/v1.0 (RefNameConflict: The tag already exists); the other updates were reverted"
+        );
+    }
+}
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://vssps.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn avatars_client(&self) -> avatars::Client {
@@ -155,6 +224,191 @@ impl Client {
         users::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn avatars_client(&self) -> avatars::OrganizationClient {
+        avatars::OrganizationClient {
+            client: self.client.avatars_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn descriptors_client(&self) -> descriptors::OrganizationClient {
+        descriptors::OrganizationClient {
+            client: self.client.descriptors_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn groups_client(&self) -> groups::OrganizationClient {
+        groups::OrganizationClient {
+            client: self.client.groups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn identity_translation_client(&self) -> identity_translation::OrganizationClient {
+        identity_translation::OrganizationClient {
+            client: self.client.identity_translation_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn membership_states_client(&self) -> membership_states::OrganizationClient {
+        membership_states::OrganizationClient {
+            client: self.client.membership_states_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn memberships_client(&self) -> memberships::OrganizationClient {
+        memberships::OrganizationClient {
+            client: self.client.memberships_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn provider_info_client(&self) -> provider_info::OrganizationClient {
+        provider_info::OrganizationClient {
+            client: self.client.provider_info_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn request_access_client(&self) -> request_access::OrganizationClient {
+        request_access::OrganizationClient {
+            client: self.client.request_access_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn storage_keys_client(&self) -> storage_keys::OrganizationClient {
+        storage_keys::OrganizationClient {
+            client: self.client.storage_keys_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn subject_lookup_client(&self) -> subject_lookup::OrganizationClient {
+        subject_lookup::OrganizationClient {
+            client: self.client.subject_lookup_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn subject_query_client(&self) -> subject_query::OrganizationClient {
+        subject_query::OrganizationClient {
+            client: self.client.subject_query_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn users_client(&self) -> users::OrganizationClient {
+        users::OrganizationClient {
+            client: self.client.users_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn avatars_client(&self) -> avatars::ProjectClient {
+        avatars::ProjectClient {
+            client: self.client.avatars_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn descriptors_client(&self) -> descriptors::ProjectClient {
+        descriptors::ProjectClient {
+            client: self.client.descriptors_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn groups_client(&self) -> groups::ProjectClient {
+        groups::ProjectClient {
+            client: self.client.groups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn identity_translation_client(&self) -> identity_translation::ProjectClient {
+        identity_translation::ProjectClient {
+            client: self.client.identity_translation_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn membership_states_client(&self) -> membership_states::ProjectClient {
+        membership_states::ProjectClient {
+            client: self.client.membership_states_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn memberships_client(&self) -> memberships::ProjectClient {
+        memberships::ProjectClient {
+            client: self.client.memberships_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn provider_info_client(&self) -> provider_info::ProjectClient {
+        provider_info::ProjectClient {
+            client: self.client.provider_info_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn request_access_client(&self) -> request_access::ProjectClient {
+        request_access::ProjectClient {
+            client: self.client.request_access_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn storage_keys_client(&self) -> storage_keys::ProjectClient {
+        storage_keys::ProjectClient {
+            client: self.client.storage_keys_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn subject_lookup_client(&self) -> subject_lookup::ProjectClient {
+        subject_lookup::ProjectClient {
+            client: self.client.subject_lookup_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn subject_query_client(&self) -> subject_query::ProjectClient {
+        subject_query::ProjectClient {
+            client: self.client.subject_query_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn users_client(&self) -> users::ProjectClient {
+        users::ProjectClient {
+            client: self.client.users_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod descriptors {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -176,6 +430,37 @@ pub mod descriptors {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Resolve a storage key to a descriptor"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `storage_key`: Storage key of the subject (user, group, scope, etc.) to resolve"]
+        pub fn get(&self, storage_key: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(storage_key, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Resolve a storage key to a descriptor"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `storage_key`: Storage key of the subject (user, group, scope, etc.) to resolve"]
+        pub fn get(&self, storage_key: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(storage_key, self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -256,9 +541,10 @@ pub mod descriptors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -370,6 +656,107 @@ pub mod groups {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets a list of all groups in the current scope (usually organization or account).\n\nThe optional parameters are used to filter down the returned results. Returned results are in no guaranteed order.\n\n Since the list of groups may be large, results are returned in pages of groups.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a new Azure DevOps group or materialize an existing AAD group.\n\nThe body of the request must be a derived type of GraphGroupCreationContext:\n  * GraphGroupVstsCreationContext - Create a new Azure DevOps group that is not backed by an external provider.\n  * GraphGroupMailAddressCreationContext - Create a new group using the mail address as a reference to an existing group from an external AD or AAD backed provider.\n  * GraphGroupOriginIdCreationContext - Create a new group using the OriginID as a reference to a group from an external AD or AAD backed provider.\n\n Optionally, you can add the newly created group as a member of an existing Azure DevOps group and/or specify a custom storage key for the group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph group used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphGroupCreationContext>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a group by its descriptor.\n\nThe group will be returned even if it has been deleted from the account or has had all its memberships\ndeleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the desired graph group."]
+        pub fn get(&self, group_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_descriptor)
+        }
+        #[doc = "Update the properties of an Azure DevOps group.\n\nCurrently limited to only changing the description and account name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to modify."]
+        #[doc = "* `body`: The JSON+Patch document containing the fields to alter."]
+        pub fn update(
+            &self,
+            group_descriptor: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), group_descriptor, body)
+        }
+        #[doc = "Removes an Azure DevOps group from all of its parent groups.\n\nThe group will still be visible, but membership\n checks for the group, and all descendants which derive membership through it, will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to delete."]
+        pub fn delete(&self, group_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), group_descriptor)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets a list of all groups in the current scope (usually organization or account).\n\nThe optional parameters are used to filter down the returned results. Returned results are in no guaranteed order.\n\n Since the list of groups may be large, results are returned in pages of groups.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a new Azure DevOps group or materialize an existing AAD group.\n\nThe body of the request must be a derived type of GraphGroupCreationContext:\n  * GraphGroupVstsCreationContext - Create a new Azure DevOps group that is not backed by an external provider.\n  * GraphGroupMailAddressCreationContext - Create a new group using the mail address as a reference to an existing group from an external AD or AAD backed provider.\n  * GraphGroupOriginIdCreationContext - Create a new group using the OriginID as a reference to a group from an external AD or AAD backed provider.\n\n Optionally, you can add the newly created group as a member of an existing Azure DevOps group and/or specify a custom storage key for the group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph group used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphGroupCreationContext>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a group by its descriptor.\n\nThe group will be returned even if it has been deleted from the account or has had all its memberships\ndeleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the desired graph group."]
+        pub fn get(&self, group_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_descriptor)
+        }
+        #[doc = "Update the properties of an Azure DevOps group.\n\nCurrently limited to only changing the description and account name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to modify."]
+        #[doc = "* `body`: The JSON+Patch document containing the fields to alter."]
+        pub fn update(
+            &self,
+            group_descriptor: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), group_descriptor, body)
+        }
+        #[doc = "Removes an Azure DevOps group from all of its parent groups.\n\nThe group will still be visible, but membership\n checks for the group, and all descendants which derive membership through it, will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to delete."]
+        pub fn delete(&self, group_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), group_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -477,9 +864,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(scope_descriptor) = &this.scope_descriptor {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -501,6 +889,24 @@ pub mod groups {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GraphGroupList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GraphGroupList>;
@@ -606,9 +1012,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(scope_descriptor) = &this.scope_descriptor {
@@ -720,9 +1127,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -824,9 +1232,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -896,9 +1305,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -924,6 +1334,29 @@ pub mod identity_translation {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn translate(&self) -> translate::RequestBuilder {
+            self.client.translate(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn translate(&self) -> translate::RequestBuilder {
+            self.client.translate(self.organization.clone())
+        }
+    }
     pub mod translate {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -948,6 +1381,25 @@ pub mod identity_translation {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1011,9 +1463,10 @@ pub mod identity_translation {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(master_id) = &this.master_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1144,6 +1597,167 @@ pub mod memberships {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all the memberships where this descriptor is a member in the relationship.\n\nThe default value for direction is 'up' meaning return all memberships where the subject is a member (e.g. all groups the subject is a member of).\n Alternatively, passing the direction as 'down' will return all memberships where the subject is a container (e.g. all members of the subject group)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Fetch all direct memberships of this descriptor."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), subject_descriptor)
+        }
+        #[doc = "Get a membership relationship between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to the container in the relationship."]
+        pub fn get(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Create a new membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that can be the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that can be the container in the relationship."]
+        pub fn add(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Deletes a membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that is the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that is the container in the relationship."]
+        pub fn remove_membership(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> remove_membership::RequestBuilder {
+            self.client.remove_membership(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Check to see if a membership relationship between a container and subject exists."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The group or user that is a child subject of the relationship."]
+        #[doc = "* `container_descriptor`: The group that is the container in the relationship."]
+        pub fn check_membership_existence(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> check_membership_existence::RequestBuilder {
+            self.client.check_membership_existence(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all the memberships where this descriptor is a member in the relationship.\n\nThe default value for direction is 'up' meaning return all memberships where the subject is a member (e.g. all groups the subject is a member of).\n Alternatively, passing the direction as 'down' will return all memberships where the subject is a container (e.g. all members of the subject group)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Fetch all direct memberships of this descriptor."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), subject_descriptor)
+        }
+        #[doc = "Get a membership relationship between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to the container in the relationship."]
+        pub fn get(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Create a new membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that can be the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that can be the container in the relationship."]
+        pub fn add(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Deletes a membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that is the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that is the container in the relationship."]
+        pub fn remove_membership(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> remove_membership::RequestBuilder {
+            self.client.remove_membership(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Check to see if a membership relationship between a container and subject exists."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The group or user that is a child subject of the relationship."]
+        #[doc = "* `container_descriptor`: The group that is the container in the relationship."]
+        pub fn check_membership_existence(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> check_membership_existence::RequestBuilder {
+            self.client.check_membership_existence(
+                self.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1236,9 +1850,10 @@ pub mod memberships {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(direction) = &this.direction {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1354,9 +1969,10 @@ pub mod memberships {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1460,9 +2076,10 @@ pub mod memberships {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1533,9 +2150,10 @@ pub mod memberships {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1593,9 +2211,10 @@ pub mod memberships {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1626,6 +2245,39 @@ pub mod membership_states {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Check whether a subject is active or inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Descriptor of the subject (user, group, scope, etc.) to check state of"]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), subject_descriptor)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Check whether a subject is active or inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Descriptor of the subject (user, group, scope, etc.) to check state of"]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), subject_descriptor)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1706,9 +2358,10 @@ pub mod membership_states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1752,6 +2405,35 @@ pub mod request_access {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn request_access(
+            &self,
+            body: impl Into<models::JToken>,
+        ) -> request_access::RequestBuilder {
+            self.client.request_access(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn request_access(
+            &self,
+            body: impl Into<models::JToken>,
+        ) -> request_access::RequestBuilder {
+            self.client.request_access(self.organization.clone(), body)
+        }
+    }
     pub mod request_access {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1798,9 +2480,10 @@ pub mod request_access {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1831,6 +2514,33 @@ pub mod storage_keys {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Resolve a descriptor to a storage key."]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(subject_descriptor, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Resolve a descriptor to a storage key."]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(subject_descriptor, self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1911,9 +2621,10 @@ pub mod storage_keys {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1959,6 +2670,43 @@ pub mod subject_lookup {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Resolve descriptors to users, groups or scopes (Subjects) in a batch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of descriptors that specifies a subset of subjects to retrieve. Each descriptor uniquely identifies the subject across all instance scopes, but only at a single point in time."]
+        pub fn lookup_subjects(
+            &self,
+            body: impl Into<models::GraphSubjectLookup>,
+        ) -> lookup_subjects::RequestBuilder {
+            self.client.lookup_subjects(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Resolve descriptors to users, groups or scopes (Subjects) in a batch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of descriptors that specifies a subset of subjects to retrieve. Each descriptor uniquely identifies the subject across all instance scopes, but only at a single point in time."]
+        pub fn lookup_subjects(
+            &self,
+            body: impl Into<models::GraphSubjectLookup>,
+        ) -> lookup_subjects::RequestBuilder {
+            self.client.lookup_subjects(self.organization.clone(), body)
+        }
+    }
     pub mod lookup_subjects {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2037,9 +2785,10 @@ pub mod subject_lookup {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2084,6 +2833,37 @@ pub mod subject_query {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Search for Azure Devops users, or/and groups. Results will be returned in a batch with no more than 100 graph subjects."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query that we'll be using to search includes the following: Query: the search term. The search will be prefix matching only. SubjectKind: \"User\" or \"Group\" can be specified, both or either ScopeDescriptor: Non-default scope can be specified, i.e. project scope descriptor"]
+        pub fn query(&self, body: impl Into<models::GraphSubjectQuery>) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Search for Azure Devops users, or/and groups. Results will be returned in a batch with no more than 100 graph subjects."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query that we'll be using to search includes the following: Query: the search term. The search will be prefix matching only. SubjectKind: \"User\" or \"Group\" can be specified, both or either ScopeDescriptor: Non-default scope can be specified, i.e. project scope descriptor"]
+        pub fn query(&self, body: impl Into<models::GraphSubjectQuery>) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body)
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2163,9 +2943,10 @@ pub mod subject_query {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2240,6 +3021,55 @@ pub mod avatars {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(subject_descriptor, self.organization.clone())
+        }
+        pub fn set_avatar(
+            &self,
+            body: impl Into<models::Avatar>,
+            subject_descriptor: impl Into<String>,
+        ) -> set_avatar::RequestBuilder {
+            self.client
+                .set_avatar(body, subject_descriptor, self.organization.clone())
+        }
+        pub fn delete(&self, subject_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(subject_descriptor, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(subject_descriptor, self.organization.clone())
+        }
+        pub fn set_avatar(
+            &self,
+            body: impl Into<models::Avatar>,
+            subject_descriptor: impl Into<String>,
+        ) -> set_avatar::RequestBuilder {
+            self.client
+                .set_avatar(body, subject_descriptor, self.organization.clone())
+        }
+        pub fn delete(&self, subject_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(subject_descriptor, self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2329,9 +3159,10 @@ pub mod avatars {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(size) = &this.size {
                             req.url_mut().query_pairs_mut().append_pair("size", size);
                         }
@@ -2409,9 +3240,10 @@ pub mod avatars {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2468,9 +3300,10 @@ pub mod avatars {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2566,6 +3399,107 @@ pub mod users {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of all users in a given scope.\n\nSince the list of users may be large, results are returned in pages of users.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD or MSA user into the VSTS account.\n\nNOTE: Created users are not active in an account unless they have been explicitly assigned a parent group at creation time or have signed in\n  and been autolicensed through AAD group memberships.\n\n Adding a user to an account is required before the user can be added to VSTS groups or assigned an asset.\n\n The body of the request must be a derived type of GraphUserCreationContext:\n  * GraphUserMailAddressCreationContext - Create a new user using the mail address as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserOriginIdCreationContext - Create a new user using the OriginID as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserPrincipalNameCreationContext - Create a new user using the principal name as a reference to an existing user from an external AD or AAD backed provider.\n\n If the user to be added corresponds to a user that was previously deleted, then that user will be restored.\n\n Optionally, you can add the newly created user as a member of an existing VSTS group and/or specify a custom storage key for the user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphUserCreationContext>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a user by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the desired user."]
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), user_descriptor)
+        }
+        #[doc = "Map an existing user to a different identity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        #[doc = "* `user_descriptor`: the descriptor of the user to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GraphUserUpdateContext>,
+            user_descriptor: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, user_descriptor)
+        }
+        #[doc = "Disables a user.\n\nThe user will still be visible, but membership checks for the user will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the user to delete."]
+        pub fn delete(&self, user_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), user_descriptor)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of all users in a given scope.\n\nSince the list of users may be large, results are returned in pages of users.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD or MSA user into the VSTS account.\n\nNOTE: Created users are not active in an account unless they have been explicitly assigned a parent group at creation time or have signed in\n  and been autolicensed through AAD group memberships.\n\n Adding a user to an account is required before the user can be added to VSTS groups or assigned an asset.\n\n The body of the request must be a derived type of GraphUserCreationContext:\n  * GraphUserMailAddressCreationContext - Create a new user using the mail address as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserOriginIdCreationContext - Create a new user using the OriginID as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserPrincipalNameCreationContext - Create a new user using the principal name as a reference to an existing user from an external AD or AAD backed provider.\n\n If the user to be added corresponds to a user that was previously deleted, then that user will be restored.\n\n Optionally, you can add the newly created user as a member of an existing VSTS group and/or specify a custom storage key for the user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphUserCreationContext>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a user by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the desired user."]
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), user_descriptor)
+        }
+        #[doc = "Map an existing user to a different identity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        #[doc = "* `user_descriptor`: the descriptor of the user to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GraphUserUpdateContext>,
+            user_descriptor: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, user_descriptor)
+        }
+        #[doc = "Disables a user.\n\nThe user will still be visible, but membership checks for the user will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the user to delete."]
+        pub fn delete(&self, user_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), user_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2673,9 +3607,10 @@ pub mod users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(subject_types) = &this.subject_types {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2697,6 +3632,24 @@ pub mod users {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::GraphUserList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GraphUserList>;
@@ -2796,9 +3749,10 @@ pub mod users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(group_descriptors) = &this.group_descriptors {
@@ -2905,9 +3859,10 @@ pub mod users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3009,9 +3964,10 @@ pub mod users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3081,9 +4037,10 @@ pub mod users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3112,6 +4069,29 @@ pub mod provider_info {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(user_descriptor, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(user_descriptor, self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3192,9 +4172,10 @@ pub mod provider_info {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
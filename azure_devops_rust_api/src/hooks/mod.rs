@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn consumers_client(&self) -> consumers::Client {
@@ -134,6 +203,100 @@ impl Client {
         subscriptions::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn consumers_client(&self) -> consumers::OrganizationClient {
+        consumers::OrganizationClient {
+            client: self.client.consumers_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn diagnostics_client(&self) -> diagnostics::OrganizationClient {
+        diagnostics::OrganizationClient {
+            client: self.client.diagnostics_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn notifications_client(&self) -> notifications::OrganizationClient {
+        notifications::OrganizationClient {
+            client: self.client.notifications_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn publishers_client(&self) -> publishers::OrganizationClient {
+        publishers::OrganizationClient {
+            client: self.client.publishers_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn subscriptions_client(&self) -> subscriptions::OrganizationClient {
+        subscriptions::OrganizationClient {
+            client: self.client.subscriptions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn consumers_client(&self) -> consumers::ProjectClient {
+        consumers::ProjectClient {
+            client: self.client.consumers_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn diagnostics_client(&self) -> diagnostics::ProjectClient {
+        diagnostics::ProjectClient {
+            client: self.client.diagnostics_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn notifications_client(&self) -> notifications::ProjectClient {
+        notifications::ProjectClient {
+            client: self.client.notifications_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn publishers_client(&self) -> publishers::ProjectClient {
+        publishers::ProjectClient {
+            client: self.client.publishers_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn subscriptions_client(&self) -> subscriptions::ProjectClient {
+        subscriptions::ProjectClient {
+            client: self.client.subscriptions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod consumers {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -204,6 +367,99 @@ pub mod consumers {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of available service hook consumer services. Optionally filter by consumers that support at least one event type from the specific publisher."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a specific consumer service. Optionally filter out consumer actions that do not support any event types for the specified publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn get(&self, consumer_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), consumer_id)
+        }
+        #[doc = "Get a list of consumer actions for a specific consumer."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn list_consumer_actions(
+            &self,
+            consumer_id: impl Into<String>,
+        ) -> list_consumer_actions::RequestBuilder {
+            self.client
+                .list_consumer_actions(self.organization.clone(), consumer_id)
+        }
+        #[doc = "Get details about a specific consumer action."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        #[doc = "* `consumer_action_id`: ID for a consumerActionId."]
+        pub fn get_consumer_action(
+            &self,
+            consumer_id: impl Into<String>,
+            consumer_action_id: impl Into<String>,
+        ) -> get_consumer_action::RequestBuilder {
+            self.client.get_consumer_action(
+                self.organization.clone(),
+                consumer_id,
+                consumer_action_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of available service hook consumer services. Optionally filter by consumers that support at least one event type from the specific publisher."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a specific consumer service. Optionally filter out consumer actions that do not support any event types for the specified publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn get(&self, consumer_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), consumer_id)
+        }
+        #[doc = "Get a list of consumer actions for a specific consumer."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn list_consumer_actions(
+            &self,
+            consumer_id: impl Into<String>,
+        ) -> list_consumer_actions::RequestBuilder {
+            self.client
+                .list_consumer_actions(self.organization.clone(), consumer_id)
+        }
+        #[doc = "Get details about a specific consumer action."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        #[doc = "* `consumer_action_id`: ID for a consumerActionId."]
+        pub fn get_consumer_action(
+            &self,
+            consumer_id: impl Into<String>,
+            consumer_action_id: impl Into<String>,
+        ) -> get_consumer_action::RequestBuilder {
+            self.client.get_consumer_action(
+                self.organization.clone(),
+                consumer_id,
+                consumer_action_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -286,9 +542,10 @@ pub mod consumers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(publisher_id) = &this.publisher_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -399,9 +656,10 @@ pub mod consumers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(publisher_id) = &this.publisher_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -513,9 +771,10 @@ pub mod consumers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(publisher_id) = &this.publisher_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -628,9 +887,10 @@ pub mod consumers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(publisher_id) = &this.publisher_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -731,6 +991,77 @@ pub mod notifications {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Query for notifications. A notification includes details about the event, the request to and the response from the consumer service."]
+        pub fn query(&self, body: impl Into<models::NotificationsQuery>) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body)
+        }
+        #[doc = "Get a list of notifications for a specific subscription. A notification includes details about the event, the request to and the response from the consumer service."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn list(&self, subscription_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Get a specific notification for a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(
+            &self,
+            subscription_id: impl Into<String>,
+            notification_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), subscription_id, notification_id)
+        }
+        #[doc = "Sends a test notification. This is useful for verifying the configuration of an updated or new service hooks subscription."]
+        pub fn create(&self, body: impl Into<models::Notification>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Query for notifications. A notification includes details about the event, the request to and the response from the consumer service."]
+        pub fn query(&self, body: impl Into<models::NotificationsQuery>) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body)
+        }
+        #[doc = "Get a list of notifications for a specific subscription. A notification includes details about the event, the request to and the response from the consumer service."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn list(&self, subscription_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Get a specific notification for a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(
+            &self,
+            subscription_id: impl Into<String>,
+            notification_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), subscription_id, notification_id)
+        }
+        #[doc = "Sends a test notification. This is useful for verifying the configuration of an updated or new service hooks subscription."]
+        pub fn create(&self, body: impl Into<models::Notification>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -810,9 +1141,10 @@ pub mod notifications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -933,9 +1265,10 @@ pub mod notifications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(max_results) = &this.max_results {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1053,9 +1386,10 @@ pub mod notifications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1161,9 +1495,10 @@ pub mod notifications {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(use_real_data) = &this.use_real_data {
@@ -1288,6 +1623,123 @@ pub mod publishers {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of publishers."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a specific service hooks publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get(&self, publisher_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), publisher_id)
+        }
+        #[doc = "Get the event types for a specific publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn list_event_types(
+            &self,
+            publisher_id: impl Into<String>,
+        ) -> list_event_types::RequestBuilder {
+            self.client
+                .list_event_types(self.organization.clone(), publisher_id)
+        }
+        #[doc = "Get a specific event type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get_event_type(
+            &self,
+            publisher_id: impl Into<String>,
+            event_type_id: impl Into<String>,
+        ) -> get_event_type::RequestBuilder {
+            self.client
+                .get_event_type(self.organization.clone(), publisher_id, event_type_id)
+        }
+        pub fn query_input_values(
+            &self,
+            body: impl Into<models::InputValuesQuery>,
+            publisher_id: impl Into<String>,
+        ) -> query_input_values::RequestBuilder {
+            self.client
+                .query_input_values(self.organization.clone(), body, publisher_id)
+        }
+        #[doc = "Query for service hook publishers."]
+        pub fn query_publishers(
+            &self,
+            body: impl Into<models::PublishersQuery>,
+        ) -> query_publishers::RequestBuilder {
+            self.client
+                .query_publishers(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of publishers."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a specific service hooks publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get(&self, publisher_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), publisher_id)
+        }
+        #[doc = "Get the event types for a specific publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn list_event_types(
+            &self,
+            publisher_id: impl Into<String>,
+        ) -> list_event_types::RequestBuilder {
+            self.client
+                .list_event_types(self.organization.clone(), publisher_id)
+        }
+        #[doc = "Get a specific event type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get_event_type(
+            &self,
+            publisher_id: impl Into<String>,
+            event_type_id: impl Into<String>,
+        ) -> get_event_type::RequestBuilder {
+            self.client
+                .get_event_type(self.organization.clone(), publisher_id, event_type_id)
+        }
+        pub fn query_input_values(
+            &self,
+            body: impl Into<models::InputValuesQuery>,
+            publisher_id: impl Into<String>,
+        ) -> query_input_values::RequestBuilder {
+            self.client
+                .query_input_values(self.organization.clone(), body, publisher_id)
+        }
+        #[doc = "Query for service hook publishers."]
+        pub fn query_publishers(
+            &self,
+            body: impl Into<models::PublishersQuery>,
+        ) -> query_publishers::RequestBuilder {
+            self.client
+                .query_publishers(self.organization.clone(), body)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1365,9 +1817,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1468,9 +1921,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1572,9 +2026,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1680,9 +2135,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1787,9 +2243,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1891,9 +2348,10 @@ pub mod publishers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2016,6 +2474,109 @@ pub mod subscriptions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of subscriptions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Subscription to be created."]
+        pub fn create(&self, body: impl Into<models::Subscription>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Update a subscription. <param name=\"subscriptionId\">ID for a subscription that you wish to update.</param>"]
+        pub fn replace_subscription(
+            &self,
+            body: impl Into<models::Subscription>,
+            subscription_id: impl Into<String>,
+        ) -> replace_subscription::RequestBuilder {
+            self.client
+                .replace_subscription(self.organization.clone(), body, subscription_id)
+        }
+        #[doc = "Delete a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn delete(&self, subscription_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Query for service hook subscriptions."]
+        pub fn create_subscriptions_query(
+            &self,
+            body: impl Into<models::SubscriptionsQuery>,
+        ) -> create_subscriptions_query::RequestBuilder {
+            self.client
+                .create_subscriptions_query(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of subscriptions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Subscription to be created."]
+        pub fn create(&self, body: impl Into<models::Subscription>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Update a subscription. <param name=\"subscriptionId\">ID for a subscription that you wish to update.</param>"]
+        pub fn replace_subscription(
+            &self,
+            body: impl Into<models::Subscription>,
+            subscription_id: impl Into<String>,
+        ) -> replace_subscription::RequestBuilder {
+            self.client
+                .replace_subscription(self.organization.clone(), body, subscription_id)
+        }
+        #[doc = "Delete a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn delete(&self, subscription_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), subscription_id)
+        }
+        #[doc = "Query for service hook subscriptions."]
+        pub fn create_subscriptions_query(
+            &self,
+            body: impl Into<models::SubscriptionsQuery>,
+        ) -> create_subscriptions_query::RequestBuilder {
+            self.client
+                .create_subscriptions_query(self.organization.clone(), body)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2118,9 +2679,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(publisher_id) = &this.publisher_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2240,9 +2802,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2344,9 +2907,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2448,9 +3012,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2520,9 +3085,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2610,9 +3176,10 @@ pub mod subscriptions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2671,6 +3238,45 @@ pub mod diagnostics {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), subscription_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateSubscripitonDiagnosticsParameters>,
+            subscription_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, subscription_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), subscription_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateSubscripitonDiagnosticsParameters>,
+            subscription_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, subscription_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2751,9 +3357,10 @@ pub mod diagnostics {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2858,9 +3465,10 @@ pub mod diagnostics {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -13,6 +13,12 @@
 //! - [Autogenerator repo](https://github.com/microsoft/azure-devops-rust-api/)
 //! - [Examples](https://github.com/microsoft/azure-devops-rust-api/tree/main/azure_devops_rust_api/examples)
 
+// Declares the scoped client variants of the methods added to `Client` by the hand-written
+// modules, so must precede the service modules.
+#[cfg(any(feature = "git", feature = "wit"))]
+#[macro_use]
+mod scoped_client;
+
 /// Accounts
 #[cfg(feature = "accounts")]
 pub mod accounts;
@@ -125,6 +131,66 @@ pub mod work;
 mod auth;
 pub use auth::Credential;
 
+mod deployment;
+pub use deployment::Deployment;
+
+mod client;
+pub use client::{AdoClient, AdoClientBuilder};
+
+mod error;
+pub use error::AdoError;
+
+mod rate_limit;
+pub use rate_limit::{RateLimitPolicy, RateLimitState};
+
+mod secret;
+pub use secret::Secret;
+
+mod logging;
+pub use logging::{redact_json, LoggingPolicy};
+
+// The body helpers are only used by the service modules with binary or text responses.
+#[cfg(any(
+    feature = "artifacts",
+    feature = "artifacts_package_types",
+    feature = "audit",
+    feature = "build",
+    feature = "clt",
+    feature = "git",
+    feature = "graph",
+    feature = "permissions_report",
+    feature = "processadmin",
+    feature = "release",
+    feature = "symbol",
+    feature = "test",
+    feature = "wit",
+    feature = "work"
+))]
+mod body;
+
 pub mod date_time;
 
+// The paging helpers are only used by the service modules with paged list operations.
+#[cfg(any(
+    feature = "artifacts",
+    feature = "audit",
+    feature = "build",
+    feature = "core",
+    feature = "distributed_task",
+    feature = "git",
+    feature = "graph",
+    feature = "member_entitlement_management",
+    feature = "pipelines",
+    feature = "policy",
+    feature = "release",
+    feature = "service_endpoint",
+    feature = "test",
+    feature = "test_plan",
+    feature = "test_results",
+    feature = "tfvc",
+    feature = "token_admin",
+    feature = "wit"
+))]
+mod paging;
+
 pub(crate) mod serde;
@@ -0,0 +1,278 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Request logging with secrets redacted.
+//!
+//! Logging a request with its `Debug` implementation includes the `Authorization` header, which
+//! contains the PAT or access token. [`LoggingPolicy`] logs requests and responses via the
+//! [`log`](https://crates.io/crates/log) crate with the values of secret headers masked, and
+//! optionally logs JSON bodies with known secret fields masked by [`redact_json()`].
+
+use azure_core::headers::{self, Headers};
+use azure_core::{Body, BytesStream, Context, Policy, PolicyResult, Request, Response};
+use serde_json::Value;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// The value that replaces secrets in log output.
+const REDACTED: &str = "***";
+
+/// The largest body that is logged (64KB).
+///
+/// Response bodies are only read for logging if their `Content-Length` is no larger than this,
+/// so that downloads are not held in memory.
+const MAX_LOGGED_BODY_SIZE: usize = 64 * 1024;
+
+/// Headers whose values are secrets.
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+];
+
+/// Pipeline policy that logs requests and responses at `debug` level, with secrets masked.
+///
+/// The values of the `Authorization`, `Proxy-Authorization`, `Cookie` and `Set-Cookie` headers
+/// are replaced with `***`. If [`log_bodies()`](LoggingPolicy::log_bodies) is enabled then JSON
+/// request and response bodies of up to 64KB are also logged, with the secret fields masked by
+/// [`redact_json()`]. Other response bodies, e.g. zip and log downloads, are passed on unread.
+///
+/// Example:
+///
+/// ```ignore
+/// let git_client = git::ClientBuilder::new(credential)
+///     .per_call_policies(vec![Arc::new(LoggingPolicy::new()) as Arc<dyn azure_core::Policy>])
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LoggingPolicy {
+    log_bodies: bool,
+}
+
+impl LoggingPolicy {
+    /// Creates a new `LoggingPolicy`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log JSON request and response bodies, with secret fields masked (default `false`).
+    #[must_use]
+    pub fn log_bodies(mut self, log_bodies: bool) -> Self {
+        self.log_bodies = log_bodies;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for LoggingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if !log::log_enabled!(log::Level::Debug) {
+            return next[0].send(ctx, request, &next[1..]).await;
+        }
+
+        let mut message = format!("> {} {}\n", request.method(), request.url());
+        write_headers(&mut message, request.headers());
+        if self.log_bodies {
+            if let Body::Bytes(bytes) = request.body() {
+                write_body(&mut message, bytes);
+            }
+        }
+        log::debug!("{}", message);
+
+        let start = std::time::Instant::now();
+        let response = match next[0].send(ctx, request, &next[1..]).await {
+            Ok(response) => response,
+            Err(error) => {
+                log::debug!("< Error after {:?}: {}", start.elapsed(), error);
+                return Err(error);
+            }
+        };
+        let mut message = format!("< {} ({:?})\n", response.status(), start.elapsed());
+        write_headers(&mut message, response.headers());
+        if !self.log_bodies || !is_loggable_response(response.headers()) {
+            log::debug!("{}", message);
+            return Ok(response);
+        }
+        let (status, headers, body) = response.deconstruct();
+        let bytes = body.collect().await?;
+        write_body(&mut message, &bytes);
+        log::debug!("{}", message);
+        Ok(Response::new(
+            status,
+            headers,
+            Box::pin(BytesStream::new(bytes)),
+        ))
+    }
+}
+
+fn write_headers(message: &mut String, headers: &Headers) {
+    let mut headers: Vec<_> = headers.iter().collect();
+    headers.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+    for (name, value) in headers {
+        let value = if SECRET_HEADERS.contains(&name.as_str().to_ascii_lowercase().as_str()) {
+            REDACTED
+        } else {
+            value.as_str()
+        };
+        let _ = writeln!(message, "{}: {}", name.as_str(), value);
+    }
+}
+
+/// Returns `true` if the response body is JSON or text with a `Content-Length` of up to
+/// `MAX_LOGGED_BODY_SIZE`, so can be read into memory to be logged.
+fn is_loggable_response(headers: &Headers) -> bool {
+    let content_type = headers
+        .get_optional_str(&headers::CONTENT_TYPE)
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let content_length = headers
+        .get_optional_str(&headers::CONTENT_LENGTH)
+        .and_then(|content_length| content_length.trim().parse::<usize>().ok());
+    (content_type.contains("json") || content_type.starts_with("text/"))
+        && matches!(content_length, Some(len) if len <= MAX_LOGGED_BODY_SIZE)
+}
+
+fn write_body(message: &mut String, bytes: &[u8]) {
+    if bytes.is_empty() {
+        return;
+    }
+    if bytes.len() > MAX_LOGGED_BODY_SIZE {
+        let _ = writeln!(message, "<{} bytes>", bytes.len());
+        return;
+    }
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            let _ = writeln!(message, "{value}");
+        }
+        // Only JSON bodies are logged, as other bodies may contain secrets that cannot be masked.
+        Err(_) => {
+            let _ = writeln!(message, "<{} bytes>", bytes.len());
+        }
+    }
+}
+
+/// Masks the known secret fields of a JSON request or response body:
+///
+/// - The `parameters` of service endpoint `authorization` objects, e.g. passwords and tokens.
+/// - The `value` of variables with `isSecret: true`, e.g. in variable groups and build definitions.
+pub fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if object.get("isSecret") == Some(&Value::Bool(true)) {
+                if let Some(secret) = object.get_mut("value") {
+                    redact(secret);
+                }
+            }
+            if let Some(Value::Object(parameters)) = object
+                .get_mut("authorization")
+                .and_then(|authorization| authorization.get_mut("parameters"))
+            {
+                parameters.values_mut().for_each(redact);
+            }
+            object.values_mut().for_each(redact_json);
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+fn redact(value: &mut Value) {
+    if !value.is_null() {
+        *value = Value::String(REDACTED.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact_json() {
+        let mut value = json!({
+            "name": "my-endpoint",
+            "authorization": {
+                "scheme": "UsernamePassword",
+                "parameters": { "username": "user", "password": "hunter2" }
+            },
+            "variableGroups": [{
+                "variables": {
+                    "user": { "value": "admin" },
+                    "password": { "value": "hunter2", "isSecret": true },
+                    "unset": { "value": null, "isSecret": true }
+                }
+            }]
+        });
+        redact_json(&mut value);
+        assert_eq!(
+            value,
+            json!({
+                "name": "my-endpoint",
+                "authorization": {
+                    "scheme": "UsernamePassword",
+                    "parameters": { "username": "***", "password": "***" }
+                },
+                "variableGroups": [{
+                    "variables": {
+                        "user": { "value": "admin" },
+                        "password": { "value": "***", "isSecret": true },
+                        "unset": { "value": null, "isSecret": true }
+                    }
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_loggable_response() {
+        let response_headers = |content_type: &'static str, content_length: Option<&str>| {
+            let mut headers = Headers::new();
+            headers.insert("content-type", content_type);
+            if let Some(content_length) = content_length {
+                headers.insert("content-length", content_length.to_owned());
+            }
+            headers
+        };
+        assert!(is_loggable_response(&response_headers(
+            "application/json; charset=utf-8",
+            Some("512")
+        )));
+        assert!(is_loggable_response(&response_headers(
+            "text/plain",
+            Some("65536")
+        )));
+        // Binary, too large, or of unknown length.
+        assert!(!is_loggable_response(&response_headers(
+            "application/zip",
+            Some("512")
+        )));
+        assert!(!is_loggable_response(&response_headers(
+            "application/json",
+            Some("65537")
+        )));
+        assert!(!is_loggable_response(&response_headers(
+            "application/json",
+            None
+        )));
+    }
+
+    #[test]
+    fn test_write_headers() {
+        let mut headers = Headers::new();
+        headers.insert("authorization", "Basic OnNlY3JldA==");
+        headers.insert("content-type", "application/json");
+        let mut message = String::new();
+        write_headers(&mut message, &headers);
+        assert_eq!(
+            message,
+            "authorization: ***\ncontent-type: application/json\n"
+        );
+    }
+}
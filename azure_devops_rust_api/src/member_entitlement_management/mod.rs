@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://vsaex.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn group_entitlements_client(&self) -> group_entitlements::Client {
@@ -131,6 +200,87 @@ impl Client {
         user_entitlements::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn group_entitlements_client(&self) -> group_entitlements::OrganizationClient {
+        group_entitlements::OrganizationClient {
+            client: self.client.group_entitlements_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn members_client(&self) -> members::OrganizationClient {
+        members::OrganizationClient {
+            client: self.client.members_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn user_entitlement_summary_client(&self) -> user_entitlement_summary::OrganizationClient {
+        user_entitlement_summary::OrganizationClient {
+            client: self.client.user_entitlement_summary_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn user_entitlements_client(&self) -> user_entitlements::OrganizationClient {
+        user_entitlements::OrganizationClient {
+            client: self.client.user_entitlements_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn group_entitlements_client(&self) -> group_entitlements::ProjectClient {
+        group_entitlements::ProjectClient {
+            client: self.client.group_entitlements_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn members_client(&self) -> members::ProjectClient {
+        members::ProjectClient {
+            client: self.client.members_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn user_entitlement_summary_client(&self) -> user_entitlement_summary::ProjectClient {
+        user_entitlement_summary::ProjectClient {
+            client: self.client.user_entitlement_summary_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn user_entitlements_client(&self) -> user_entitlements::ProjectClient {
+        user_entitlements::ProjectClient {
+            client: self.client.user_entitlements_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod group_entitlements {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -217,6 +367,99 @@ pub mod group_entitlements {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the group entitlements for an account."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a group entitlement with license rule, extension rule."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: GroupEntitlement object specifying License Rule, Extensions Rule for the group. Based on the rules the members of the group will be given licenses and extensions. The Group Entitlement can be used to add the group to another project level groups"]
+        pub fn add(&self, body: impl Into<models::GroupEntitlement>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Get a group entitlement.\n\nIf the group entitlement does not exist, returns null."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_id)
+        }
+        #[doc = "Update entitlements (License Rule, Extensions Rule, Project memberships etc.) for a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the group."]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a group entitlement."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group to delete."]
+        pub fn delete(&self, group_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), group_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the group entitlements for an account."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Create a group entitlement with license rule, extension rule."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: GroupEntitlement object specifying License Rule, Extensions Rule for the group. Based on the rules the members of the group will be given licenses and extensions. The Group Entitlement can be used to add the group to another project level groups"]
+        pub fn add(&self, body: impl Into<models::GroupEntitlement>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Get a group entitlement.\n\nIf the group entitlement does not exist, returns null."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_id)
+        }
+        #[doc = "Update entitlements (License Rule, Extensions Rule, Project memberships etc.) for a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the group."]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a group entitlement."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group to delete."]
+        pub fn delete(&self, group_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), group_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -295,9 +538,10 @@ pub mod group_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -408,9 +652,10 @@ pub mod group_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(rule_option) = &this.rule_option {
@@ -520,9 +765,10 @@ pub mod group_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -633,9 +879,10 @@ pub mod group_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(rule_option) = &this.rule_option {
@@ -759,9 +1006,10 @@ pub mod group_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(rule_option) = &this.rule_option {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -858,6 +1106,89 @@ pub mod members {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get direct members of a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_id)
+        }
+        #[doc = "Add a member to a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        #[doc = "* `member_id`: Id of the member to add."]
+        pub fn add(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), group_id, member_id)
+        }
+        #[doc = "Remove a member from a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the group."]
+        #[doc = "* `member_id`: Id of the member to remove."]
+        pub fn remove_member_from_group(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> remove_member_from_group::RequestBuilder {
+            self.client
+                .remove_member_from_group(self.organization.clone(), group_id, member_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get direct members of a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), group_id)
+        }
+        #[doc = "Add a member to a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        #[doc = "* `member_id`: Id of the member to add."]
+        pub fn add(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), group_id, member_id)
+        }
+        #[doc = "Remove a member from a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the group."]
+        #[doc = "* `member_id`: Id of the member to remove."]
+        pub fn remove_member_from_group(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> remove_member_from_group::RequestBuilder {
+            self.client
+                .remove_member_from_group(self.organization.clone(), group_id, member_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -950,9 +1281,10 @@ pub mod members {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(max_results) = &this.max_results {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1035,9 +1367,10 @@ pub mod members {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1095,9 +1428,10 @@ pub mod members {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1213,6 +1547,123 @@ pub mod user_entitlements {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a paged set of user entitlements matching the filter and sort criteria built with properties that match the select input."]
+        pub fn search_user_entitlements(&self) -> search_user_entitlements::RequestBuilder {
+            self.client
+                .search_user_entitlements(self.organization.clone())
+        }
+        #[doc = "Add a user, assign license and extensions and make them a member of a project group in an account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: UserEntitlement object specifying License, Extensions and Project/Team groups the user should be added to."]
+        pub fn add(&self, body: impl Into<models::UserEntitlement>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more users."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_user_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_user_entitlements::RequestBuilder {
+            self.client
+                .update_user_entitlements(self.organization.clone(), body)
+        }
+        #[doc = "Get User Entitlement for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn get(&self, user_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), user_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the user."]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn update_user_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            user_id: impl Into<String>,
+        ) -> update_user_entitlement::RequestBuilder {
+            self.client
+                .update_user_entitlement(self.organization.clone(), body, user_id)
+        }
+        #[doc = "Delete a user from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the user from all project memberships.\nThe user would continue to have access to the account if she is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn delete(&self, user_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), user_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a paged set of user entitlements matching the filter and sort criteria built with properties that match the select input."]
+        pub fn search_user_entitlements(&self) -> search_user_entitlements::RequestBuilder {
+            self.client
+                .search_user_entitlements(self.organization.clone())
+        }
+        #[doc = "Add a user, assign license and extensions and make them a member of a project group in an account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: UserEntitlement object specifying License, Extensions and Project/Team groups the user should be added to."]
+        pub fn add(&self, body: impl Into<models::UserEntitlement>) -> add::RequestBuilder {
+            self.client.add(self.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more users."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_user_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_user_entitlements::RequestBuilder {
+            self.client
+                .update_user_entitlements(self.organization.clone(), body)
+        }
+        #[doc = "Get User Entitlement for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn get(&self, user_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), user_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the user."]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn update_user_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            user_id: impl Into<String>,
+        ) -> update_user_entitlement::RequestBuilder {
+            self.client
+                .update_user_entitlement(self.organization.clone(), body, user_id)
+        }
+        #[doc = "Delete a user from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the user from all project memberships.\nThe user would continue to have access to the account if she is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn delete(&self, user_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), user_id)
+        }
+    }
     pub mod search_user_entitlements {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1315,9 +1766,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1344,6 +1796,24 @@ pub mod user_entitlements {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::PagedGraphMemberList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PagedGraphMemberList>;
@@ -1442,9 +1912,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1559,9 +2030,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(do_not_send_invite_for_new_users) =
@@ -1674,9 +2146,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1781,9 +2254,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1855,9 +2329,10 @@ pub mod user_entitlements {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1883,6 +2358,31 @@ pub mod user_entitlement_summary {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get summary of Licenses, Extension, Projects, Groups and their assignments in the collection."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get summary of Licenses, Extension, Projects, Groups and their assignments in the collection."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client.get(self.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1966,9 +2466,10 @@ pub mod user_entitlement_summary {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(select) = &this.select {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -0,0 +1,432 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Paging support for list operations.
+//!
+//! Azure DevOps list operations that return large result sets split them into
+//! pages. The service returns a continuation token with each page (normally via
+//! the `x-ms-continuationtoken` response header, though some operations return it
+//! in a `continuationToken` body field), which must be passed back on the next
+//! request to fetch the following page.
+//!
+//! The generated `into_stream()` methods use the helpers in this module to follow
+//! the continuation tokens automatically.
+//!
+//! Other list operations are paged using the `$top` and `$skip` query parameters
+//! instead. For these operations the generated `pages()` and `items()` methods
+//! request consecutive pages until the service returns an empty page. The service
+//! may return fewer items than requested (e.g. capping pages at 100 or 1000 items),
+//! so a short page does not mean that there are no more items.
+
+use azure_core::error::{Error, ErrorKind};
+use azure_core::headers::{HeaderName, Headers};
+use futures::future::Future;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// Response header used to return a continuation token.
+const CONTINUATION_TOKEN: HeaderName = HeaderName::from_static("x-ms-continuationtoken");
+
+/// Continuation fields that some operations return in the response body.
+#[derive(Deserialize, Default)]
+struct ContinuationBody {
+    #[serde(rename = "continuationToken", default)]
+    continuation_token: Option<serde_json::Value>,
+    #[serde(rename = "isLastBatch", default)]
+    is_last_batch: Option<bool>,
+}
+
+enum State {
+    Start,
+    Continue(String),
+    Done,
+}
+
+/// Returns the continuation token for the next page, or `None` if this is the last page.
+fn continuation_token(headers: &Headers, body: &[u8]) -> Option<String> {
+    if let Some(token) = headers.get_optional_string(&CONTINUATION_TOKEN) {
+        if !token.is_empty() {
+            return Some(token);
+        }
+    }
+    let body: ContinuationBody = serde_json::from_slice(body).unwrap_or_default();
+    if body.is_last_batch == Some(true) {
+        return None;
+    }
+    match body.continuation_token {
+        Some(serde_json::Value::String(token)) if !token.is_empty() => Some(token),
+        Some(serde_json::Value::Number(token)) => Some(token.to_string()),
+        _ => None,
+    }
+}
+
+/// Deserializes a page from a raw response, returning the page and the continuation token.
+async fn into_page<T>(rsp: azure_core::Response) -> azure_core::Result<(T, Option<String>)>
+where
+    T: DeserializeOwned,
+{
+    let (_status, headers, body) = rsp.deconstruct();
+    let bytes = body.collect().await?;
+    let page: T = serde_json::from_slice(&bytes).map_err(|e| {
+        Error::full(
+            ErrorKind::DataConversion,
+            e,
+            format!(
+                "Failed to deserialize response:\n{}",
+                String::from_utf8_lossy(&bytes)
+            ),
+        )
+    })?;
+    Ok((page, continuation_token(&headers, &bytes)))
+}
+
+/// Creates a stream of pages.
+///
+/// `make_request` is called with `None` to fetch the first page, and then with the
+/// continuation token returned by each page until the service stops returning one.
+pub(crate) fn continuation_stream<T, F, Fut>(
+    make_request: F,
+) -> BoxStream<'static, azure_core::Result<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+    F: Fn(Option<String>) -> Fut + Send + 'static,
+    Fut: Future<Output = azure_core::Result<azure_core::Response>> + Send + 'static,
+{
+    stream::unfold(
+        (State::Start, make_request),
+        |(state, make_request)| async move {
+            let continuation = match state {
+                State::Start => None,
+                State::Continue(token) => Some(token),
+                State::Done => return None,
+            };
+            let page = match make_request(continuation.clone()).await {
+                Ok(rsp) => into_page::<T>(rsp).await,
+                Err(e) => Err(e),
+            };
+            match page {
+                Ok((page, next)) => {
+                    // Guard against services that repeat the same token on the final page.
+                    let state = match next {
+                        Some(next) if Some(&next) != continuation.as_ref() => State::Continue(next),
+                        _ => State::Done,
+                    };
+                    Some((Ok(page), (state, make_request)))
+                }
+                Err(e) => Some((Err(e), (State::Done, make_request))),
+            }
+        },
+    )
+    .boxed()
+}
+
+/// Creates a stream of pages for operations that are paged using `$top` and `$skip`.
+///
+/// `make_request` is called with the number of items to skip, starting at `skip` and
+/// advancing by the number of items in each page until an empty page is returned.
+#[cfg_attr(
+    not(any(
+        feature = "artifacts",
+        feature = "core",
+        feature = "git",
+        feature = "policy",
+        feature = "test",
+        feature = "tfvc",
+        feature = "wit"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn skip_top_stream<T, F, Fut>(
+    make_request: F,
+    skip: i32,
+    page_len: fn(&T) -> usize,
+) -> BoxStream<'static, azure_core::Result<T>>
+where
+    T: Send + 'static,
+    F: Fn(i32) -> Fut + Send + 'static,
+    Fut: Future<Output = azure_core::Result<T>> + Send + 'static,
+{
+    stream::unfold(
+        (Some(skip), make_request),
+        move |(skip, make_request)| async move {
+            let skip = skip?;
+            match make_request(skip).await {
+                Ok(page) => {
+                    // Advance by the items actually returned, as the service may cap the page size.
+                    let next = match page_len(&page) {
+                        0 => None,
+                        len => i32::try_from(len)
+                            .ok()
+                            .and_then(|len| skip.checked_add(len)),
+                    };
+                    Some((Ok(page), (next, make_request)))
+                }
+                Err(e) => Some((Err(e), (None, make_request))),
+            }
+        },
+    )
+    .boxed()
+}
+
+/// Flattens a stream of pages into a stream of the items they contain.
+#[cfg_attr(
+    not(any(
+        feature = "artifacts",
+        feature = "core",
+        feature = "git",
+        feature = "policy",
+        feature = "test",
+        feature = "tfvc",
+        feature = "wit"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn items_stream<T, I>(
+    pages: BoxStream<'static, azure_core::Result<T>>,
+    into_items: fn(T) -> Vec<I>,
+) -> BoxStream<'static, azure_core::Result<I>>
+where
+    T: Send + 'static,
+    I: Send + 'static,
+{
+    pages
+        .map_ok(move |page| stream::iter(into_items(page).into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+/// Parses a continuation token into the type of the request parameter.
+#[cfg_attr(
+    not(any(
+        feature = "artifacts",
+        feature = "distributed_task",
+        feature = "release",
+        feature = "service_endpoint"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn parse_continuation_token<T>(token: &str) -> azure_core::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    token.parse().map_err(|e| {
+        Error::full(
+            ErrorKind::DataConversion,
+            e,
+            format!("Failed to parse continuation token: {token}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{BytesStream, StatusCode};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Page {
+        value: Vec<i32>,
+    }
+
+    fn response(
+        headers: &[(&'static str, &'static str)],
+        body: &'static str,
+    ) -> azure_core::Response {
+        let mut response_headers = Headers::new();
+        for (name, value) in headers {
+            response_headers.insert(*name, *value);
+        }
+        azure_core::Response::new(
+            StatusCode::Ok,
+            response_headers,
+            Box::pin(BytesStream::new(body)),
+        )
+    }
+
+    /// Collects the pages of a continuation stream, returning the pages and the requested tokens.
+    fn collect_pages(
+        responses: fn(Option<&str>) -> azure_core::Result<azure_core::Response>,
+    ) -> (Vec<azure_core::Result<Page>>, Vec<Option<String>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let stream = continuation_stream::<Page, _, _>({
+            let requests = requests.clone();
+            move |continuation: Option<String>| {
+                requests.lock().unwrap().push(continuation.clone());
+                futures::future::ready(responses(continuation.as_deref()))
+            }
+        });
+        let pages = futures::executor::block_on(stream.collect::<Vec<_>>());
+        let requests = requests.lock().unwrap().clone();
+        (pages, requests)
+    }
+
+    #[test]
+    fn test_continuation_token() {
+        let headers = |token: &'static str| {
+            let mut headers = Headers::new();
+            headers.insert("x-ms-continuationtoken", token);
+            headers
+        };
+        assert_eq!(
+            continuation_token(&headers("abc"), b"{}"),
+            Some("abc".to_owned())
+        );
+        assert_eq!(
+            continuation_token(&headers(""), br#"{"continuationToken": "def"}"#),
+            Some("def".to_owned())
+        );
+        assert_eq!(
+            continuation_token(&Headers::new(), br#"{"continuationToken": 42}"#),
+            Some("42".to_owned())
+        );
+        assert_eq!(
+            continuation_token(
+                &Headers::new(),
+                br#"{"continuationToken": "def", "isLastBatch": true}"#
+            ),
+            None
+        );
+        assert_eq!(continuation_token(&Headers::new(), b"[1, 2]"), None);
+    }
+
+    #[test]
+    fn test_continuation_stream() {
+        let (pages, requests) = collect_pages(|continuation| match continuation {
+            None => Ok(response(
+                &[("x-ms-continuationtoken", "a")],
+                r#"{"value": [1, 2]}"#,
+            )),
+            Some("a") => Ok(response(&[], r#"{"value": [3], "continuationToken": "b"}"#)),
+            Some("b") => Ok(response(
+                &[],
+                r#"{"value": [4], "continuationToken": "c", "isLastBatch": true}"#,
+            )),
+            Some(_) => panic!("unexpected request"),
+        });
+        let pages: Vec<Page> = pages.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            pages,
+            vec![
+                Page { value: vec![1, 2] },
+                Page { value: vec![3] },
+                Page { value: vec![4] }
+            ]
+        );
+        assert_eq!(
+            requests,
+            vec![None, Some("a".to_owned()), Some("b".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_continuation_stream_repeated_token() {
+        let (pages, requests) = collect_pages(|continuation| match continuation {
+            None | Some("a") => Ok(response(
+                &[("x-ms-continuationtoken", "a")],
+                r#"{"value": [1]}"#,
+            )),
+            Some(_) => panic!("unexpected request"),
+        });
+        assert_eq!(pages.len(), 2);
+        assert_eq!(requests, vec![None, Some("a".to_owned())]);
+    }
+
+    #[test]
+    fn test_continuation_stream_error() {
+        let (pages, requests) = collect_pages(|continuation| match continuation {
+            None => Ok(response(
+                &[("x-ms-continuationtoken", "a")],
+                r#"{"value": [1]}"#,
+            )),
+            Some("a") => Ok(response(
+                &[("x-ms-continuationtoken", "b")],
+                r#"{"value": "invalid"}"#,
+            )),
+            Some(_) => Err(Error::message(ErrorKind::Io, "connection reset")),
+        });
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].is_ok());
+        assert_eq!(
+            pages[1].as_ref().unwrap_err().kind(),
+            &ErrorKind::DataConversion
+        );
+        assert_eq!(requests, vec![None, Some("a".to_owned())]);
+
+        let (pages, requests) = collect_pages(|_| Err(Error::message(ErrorKind::Io, "timeout")));
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap_err().kind(), &ErrorKind::Io);
+        assert_eq!(requests, vec![None]);
+    }
+
+    #[test]
+    fn test_skip_top_stream() {
+        // The service caps pages at 2 items, below the requested page size.
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let stream = skip_top_stream(
+            {
+                let requests = requests.clone();
+                move |skip: i32| {
+                    requests.lock().unwrap().push(skip);
+                    let value = (skip..5).take(2).collect();
+                    futures::future::ready(Ok(Page { value }))
+                }
+            },
+            1,
+            |page: &Page| page.value.len(),
+        );
+        let items = futures::executor::block_on(
+            items_stream(stream, |page: Page| page.value).try_collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(*requests.lock().unwrap(), vec![1, 3, 5]);
+
+        let stream = skip_top_stream(
+            |_| futures::future::ready(Err::<Page, _>(Error::message(ErrorKind::Io, "timeout"))),
+            0,
+            |page: &Page| page.value.len(),
+        );
+        let pages = futures::executor::block_on(stream.collect::<Vec<_>>());
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].is_err());
+    }
+
+    #[test]
+    fn test_items_stream() {
+        let pages = stream::iter(vec![
+            Ok(Page { value: vec![1, 2] }),
+            Ok(Page { value: vec![] }),
+            Ok(Page { value: vec![3] }),
+            Err(Error::message(ErrorKind::Io, "timeout")),
+        ])
+        .boxed();
+        let items = futures::executor::block_on(
+            items_stream(pages, |page: Page| page.value).collect::<Vec<_>>(),
+        );
+        assert_eq!(items.len(), 4);
+        assert_eq!(
+            items[..3]
+                .iter()
+                .map(|item| *item.as_ref().unwrap())
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(items[3].is_err());
+    }
+
+    #[test]
+    fn test_parse_continuation_token() {
+        assert_eq!(parse_continuation_token::<i32>("42").unwrap(), 42);
+        assert_eq!(
+            parse_continuation_token::<String>("abc").unwrap(),
+            "abc".to_owned()
+        );
+        assert_eq!(
+            parse_continuation_token::<i32>("abc").unwrap_err().kind(),
+            &ErrorKind::DataConversion
+        );
+    }
+}
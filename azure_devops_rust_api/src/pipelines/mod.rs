@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn artifacts_client(&self) -> artifacts::Client {
@@ -134,6 +203,100 @@ impl Client {
         runs::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn artifacts_client(&self) -> artifacts::OrganizationClient {
+        artifacts::OrganizationClient {
+            client: self.client.artifacts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn logs_client(&self) -> logs::OrganizationClient {
+        logs::OrganizationClient {
+            client: self.client.logs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pipelines_client(&self) -> pipelines::OrganizationClient {
+        pipelines::OrganizationClient {
+            client: self.client.pipelines_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn preview_client(&self) -> preview::OrganizationClient {
+        preview::OrganizationClient {
+            client: self.client.preview_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn runs_client(&self) -> runs::OrganizationClient {
+        runs::OrganizationClient {
+            client: self.client.runs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifacts_client(&self) -> artifacts::ProjectClient {
+        artifacts::ProjectClient {
+            client: self.client.artifacts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn logs_client(&self) -> logs::ProjectClient {
+        logs::ProjectClient {
+            client: self.client.logs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pipelines_client(&self) -> pipelines::ProjectClient {
+        pipelines::ProjectClient {
+            client: self.client.pipelines_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn preview_client(&self) -> preview::ProjectClient {
+        preview::ProjectClient {
+            client: self.client.preview_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn runs_client(&self) -> runs::ProjectClient {
+        runs::ProjectClient {
+            client: self.client.runs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod pipelines {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -197,6 +360,75 @@ pub mod pipelines {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of pipelines."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Input parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePipelineParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Gets a pipeline, optionally at the specified version"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID"]
+        pub fn get(&self, project: impl Into<String>, pipeline_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, pipeline_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of pipelines."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Input parameters."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePipelineParameters>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets a pipeline, optionally at the specified version"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline ID"]
+        pub fn get(&self, pipeline_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), pipeline_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -294,9 +526,10 @@ pub mod pipelines {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(order_by) = &this.order_by {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -318,6 +551,24 @@ pub mod pipelines {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::PipelineList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PipelineList>;
@@ -413,9 +664,10 @@ pub mod pipelines {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -525,9 +777,10 @@ pub mod pipelines {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(pipeline_version) = &this.pipeline_version {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -583,6 +836,55 @@ pub mod preview {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Queues a dry run of the pipeline and returns an object containing the final yaml."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn preview(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            project: impl Into<String>,
+            pipeline_id: i32,
+        ) -> preview::RequestBuilder {
+            self.client
+                .preview(self.organization.clone(), body, project, pipeline_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Queues a dry run of the pipeline and returns an object containing the final yaml."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn preview(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            pipeline_id: i32,
+        ) -> preview::RequestBuilder {
+            self.client.preview(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod preview {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -671,9 +973,10 @@ pub mod preview {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(pipeline_version) = &this.pipeline_version {
@@ -771,6 +1074,100 @@ pub mod runs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets top 10000 runs for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        pub fn list(&self, project: impl Into<String>, pipeline_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, pipeline_id)
+        }
+        #[doc = "Runs a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn run_pipeline(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            project: impl Into<String>,
+            pipeline_id: i32,
+        ) -> run_pipeline::RequestBuilder {
+            self.client
+                .run_pipeline(self.organization.clone(), body, project, pipeline_id)
+        }
+        #[doc = "Gets a run for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        #[doc = "* `run_id`: The run id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, pipeline_id, run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets top 10000 runs for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        pub fn list(&self, pipeline_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), pipeline_id)
+        }
+        #[doc = "Runs a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn run_pipeline(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            pipeline_id: i32,
+        ) -> run_pipeline::RequestBuilder {
+            self.client.run_pipeline(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                pipeline_id,
+            )
+        }
+        #[doc = "Gets a run for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        #[doc = "* `run_id`: The run id"]
+        pub fn get(&self, pipeline_id: i32, run_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                pipeline_id,
+                run_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -852,9 +1249,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -964,9 +1362,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(pipeline_version) = &this.pipeline_version {
@@ -1076,9 +1475,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1131,6 +1531,65 @@ pub mod artifacts {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a specific artifact from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `artifact_name`: Name of the artifact."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+                artifact_name,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a specific artifact from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `artifact_name`: Name of the artifact."]
+        pub fn get(
+            &self,
+            pipeline_id: i32,
+            run_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                pipeline_id,
+                run_id,
+                artifact_name,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1221,9 +1680,10 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let artifact_name = &this.artifact_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1309,6 +1769,88 @@ pub mod logs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of logs from a pipeline run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, pipeline_id, run_id)
+        }
+        #[doc = "Get a specific log from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `log_id`: ID of the log."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+            log_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+                log_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of logs from a pipeline run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        pub fn list(&self, pipeline_id: i32, run_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                pipeline_id,
+                run_id,
+            )
+        }
+        #[doc = "Get a specific log from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `log_id`: ID of the log."]
+        pub fn get(&self, pipeline_id: i32, run_id: i32, log_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                pipeline_id,
+                run_id,
+                log_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1398,9 +1940,10 @@ pub mod logs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1518,9 +2061,10 @@ pub mod logs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn configurations_client(&self) -> configurations::Client {
@@ -131,6 +200,87 @@ impl Client {
         types::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn configurations_client(&self) -> configurations::OrganizationClient {
+        configurations::OrganizationClient {
+            client: self.client.configurations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn evaluations_client(&self) -> evaluations::OrganizationClient {
+        evaluations::OrganizationClient {
+            client: self.client.evaluations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn revisions_client(&self) -> revisions::OrganizationClient {
+        revisions::OrganizationClient {
+            client: self.client.revisions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn types_client(&self) -> types::OrganizationClient {
+        types::OrganizationClient {
+            client: self.client.types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn configurations_client(&self) -> configurations::ProjectClient {
+        configurations::ProjectClient {
+            client: self.client.configurations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn evaluations_client(&self) -> evaluations::ProjectClient {
+        evaluations::ProjectClient {
+            client: self.client.evaluations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn revisions_client(&self) -> revisions::ProjectClient {
+        revisions::ProjectClient {
+            client: self.client.revisions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn types_client(&self) -> types::ProjectClient {
+        types::ProjectClient {
+            client: self.client.types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod configurations {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -237,6 +387,145 @@ pub mod configurations {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of policy configurations in a project.\n\nThe 'scope' parameter for this API should not be used, except for legacy compatability reasons. It returns specifically\nscoped policies and does not support heirarchical nesting. Instead, use the /_apis/git/policy/configurations API, which provides\nfirst class scope filtering support.\n\nThe optional `policyType` parameter can be used to filter the set of policies returned from this method."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Get a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the policy configuration"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, configuration_id)
+        }
+        #[doc = "Create a policy configuration of a given policy type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, configuration_id)
+        }
+        #[doc = "Update a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the existing policy configuration to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, configuration_id)
+        }
+        #[doc = "Delete a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the policy configuration to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, configuration_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of policy configurations in a project.\n\nThe 'scope' parameter for this API should not be used, except for legacy compatability reasons. It returns specifically\nscoped policies and does not support heirarchical nesting. Instead, use the /_apis/git/policy/configurations API, which provides\nfirst class scope filtering support.\n\nThe optional `policyType` parameter can be used to filter the set of policies returned from this method."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: ID of the policy configuration"]
+        pub fn get(&self, configuration_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Create a policy configuration of a given policy type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to create."]
+        pub fn create(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            configuration_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Update a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to update."]
+        #[doc = "* `configuration_id`: ID of the existing policy configuration to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            configuration_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Delete a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: ID of the policy configuration to delete."]
+        pub fn delete(&self, configuration_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                configuration_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -341,9 +630,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(scope) = &this.scope {
                             req.url_mut().query_pairs_mut().append_pair("scope", scope);
                         }
@@ -368,6 +658,26 @@ pub mod configurations {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PolicyConfigurationList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -467,9 +777,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -576,9 +887,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -686,9 +998,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -762,9 +1075,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -822,6 +1136,79 @@ pub mod revisions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all revisions for a given policy."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, configuration_id)
+        }
+        #[doc = "Retrieve a specific revision of a given policy by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        #[doc = "* `revision_id`: The revision ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+            revision_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                configuration_id,
+                revision_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all revisions for a given policy."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        pub fn list(&self, configuration_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Retrieve a specific revision of a given policy by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        #[doc = "* `revision_id`: The revision ID."]
+        pub fn get(&self, configuration_id: i32, revision_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                configuration_id,
+                revision_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -916,9 +1303,10 @@ pub mod revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -935,6 +1323,39 @@ pub mod revisions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PolicyConfigurationList>,
+            > {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::PolicyConfigurationList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::PolicyConfiguration>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::PolicyConfigurationList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -1036,9 +1457,10 @@ pub mod revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1128,6 +1550,95 @@ pub mod evaluations {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieves a list of all the policy evaluation statuses for a specific pull request.\n\nEvaluations are retrieved using an artifact ID which uniquely identifies the pull request.\nTo generate an artifact ID for a pull request, use this template:\n```\nvstfs:///CodeReview/CodeReviewId/{projectId}/{pullRequestId}\n```"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `artifact_id`: A string which uniquely identifies the target of a policy evaluation."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            artifact_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, artifact_id)
+        }
+        #[doc = "Gets the present evaluation state of a policy.\n\nEach policy which applies to a pull request will have an evaluation state which is specific to that policy running\nin the context of that pull request. Each evaluation is uniquely identified via a Guid. You can find all the policy\nevaluations for a specific pull request using the List operation of this controller."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            evaluation_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, evaluation_id)
+        }
+        #[doc = "Requeue the policy evaluation.\n\nSome policies define a \"requeue\" action which performs some policy-specific operation.\nYou can trigger this operation by updating an existing policy evaluation and setting the\nPolicyEvaluationRecord.Status field to Queued.\nAlthough any policy evaluation can be requeued, at present only build policies perform any action\nin response. Requeueing a build policy will queue a new build to run (cancelling any existing build which\nis running)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn requeue_policy_evaluation(
+            &self,
+            project: impl Into<String>,
+            evaluation_id: impl Into<String>,
+        ) -> requeue_policy_evaluation::RequestBuilder {
+            self.client
+                .requeue_policy_evaluation(self.organization.clone(), project, evaluation_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieves a list of all the policy evaluation statuses for a specific pull request.\n\nEvaluations are retrieved using an artifact ID which uniquely identifies the pull request.\nTo generate an artifact ID for a pull request, use this template:\n```\nvstfs:///CodeReview/CodeReviewId/{projectId}/{pullRequestId}\n```"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `artifact_id`: A string which uniquely identifies the target of a policy evaluation."]
+        pub fn list(&self, artifact_id: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), artifact_id)
+        }
+        #[doc = "Gets the present evaluation state of a policy.\n\nEach policy which applies to a pull request will have an evaluation state which is specific to that policy running\nin the context of that pull request. Each evaluation is uniquely identified via a Guid. You can find all the policy\nevaluations for a specific pull request using the List operation of this controller."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn get(&self, evaluation_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                evaluation_id,
+            )
+        }
+        #[doc = "Requeue the policy evaluation.\n\nSome policies define a \"requeue\" action which performs some policy-specific operation.\nYou can trigger this operation by updating an existing policy evaluation and setting the\nPolicyEvaluationRecord.Status field to Queued.\nAlthough any policy evaluation can be requeued, at present only build policies perform any action\nin response. Requeueing a build policy will queue a new build to run (cancelling any existing build which\nis running)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn requeue_policy_evaluation(
+            &self,
+            evaluation_id: impl Into<String>,
+        ) -> requeue_policy_evaluation::RequestBuilder {
+            self.client.requeue_policy_evaluation(
+                self.organization.clone(),
+                self.project.clone(),
+                evaluation_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1227,9 +1738,10 @@ pub mod evaluations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let artifact_id = &this.artifact_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1256,6 +1768,41 @@ pub mod evaluations {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PolicyEvaluationRecordList>,
+            > {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::PolicyEvaluationRecordList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::PolicyEvaluationRecord>,
+            > {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::PolicyEvaluationRecordList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyEvaluationRecordList>;
@@ -1355,9 +1902,10 @@ pub mod evaluations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1463,9 +2011,10 @@ pub mod evaluations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1530,6 +2079,55 @@ pub mod types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve all available policy types."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Retrieve a specific policy type by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_id`: The policy ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            type_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, type_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve all available policy types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Retrieve a specific policy type by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_id`: The policy ID."]
+        pub fn get(&self, type_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), type_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1609,9 +2207,10 @@ pub mod types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1714,9 +2313,10 @@ pub mod types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
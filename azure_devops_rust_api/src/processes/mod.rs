@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn behaviors_client(&self) -> behaviors::Client {
@@ -158,6 +227,206 @@ impl Client {
         work_item_types_behaviors::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn behaviors_client(&self) -> behaviors::OrganizationClient {
+        behaviors::OrganizationClient {
+            client: self.client.behaviors_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn controls_client(&self) -> controls::OrganizationClient {
+        controls::OrganizationClient {
+            client: self.client.controls_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn fields_client(&self) -> fields::OrganizationClient {
+        fields::OrganizationClient {
+            client: self.client.fields_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn groups_client(&self) -> groups::OrganizationClient {
+        groups::OrganizationClient {
+            client: self.client.groups_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn layout_client(&self) -> layout::OrganizationClient {
+        layout::OrganizationClient {
+            client: self.client.layout_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn lists_client(&self) -> lists::OrganizationClient {
+        lists::OrganizationClient {
+            client: self.client.lists_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pages_client(&self) -> pages::OrganizationClient {
+        pages::OrganizationClient {
+            client: self.client.pages_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn processes_client(&self) -> processes::OrganizationClient {
+        processes::OrganizationClient {
+            client: self.client.processes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn rules_client(&self) -> rules::OrganizationClient {
+        rules::OrganizationClient {
+            client: self.client.rules_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn states_client(&self) -> states::OrganizationClient {
+        states::OrganizationClient {
+            client: self.client.states_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn system_controls_client(&self) -> system_controls::OrganizationClient {
+        system_controls::OrganizationClient {
+            client: self.client.system_controls_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::OrganizationClient {
+        work_item_types::OrganizationClient {
+            client: self.client.work_item_types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_types_behaviors_client(
+        &self,
+    ) -> work_item_types_behaviors::OrganizationClient {
+        work_item_types_behaviors::OrganizationClient {
+            client: self.client.work_item_types_behaviors_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn behaviors_client(&self) -> behaviors::ProjectClient {
+        behaviors::ProjectClient {
+            client: self.client.behaviors_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn controls_client(&self) -> controls::ProjectClient {
+        controls::ProjectClient {
+            client: self.client.controls_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn fields_client(&self) -> fields::ProjectClient {
+        fields::ProjectClient {
+            client: self.client.fields_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn groups_client(&self) -> groups::ProjectClient {
+        groups::ProjectClient {
+            client: self.client.groups_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn layout_client(&self) -> layout::ProjectClient {
+        layout::ProjectClient {
+            client: self.client.layout_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn lists_client(&self) -> lists::ProjectClient {
+        lists::ProjectClient {
+            client: self.client.lists_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pages_client(&self) -> pages::ProjectClient {
+        pages::ProjectClient {
+            client: self.client.pages_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn processes_client(&self) -> processes::ProjectClient {
+        processes::ProjectClient {
+            client: self.client.processes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn rules_client(&self) -> rules::ProjectClient {
+        rules::ProjectClient {
+            client: self.client.rules_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn states_client(&self) -> states::ProjectClient {
+        states::ProjectClient {
+            client: self.client.states_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn system_controls_client(&self) -> system_controls::ProjectClient {
+        system_controls::ProjectClient {
+            client: self.client.system_controls_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::ProjectClient {
+        work_item_types::ProjectClient {
+            client: self.client.work_item_types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_types_behaviors_client(&self) -> work_item_types_behaviors::ProjectClient {
+        work_item_types_behaviors::ProjectClient {
+            client: self.client.work_item_types_behaviors_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod groups {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -321,6 +590,309 @@ pub mod groups {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Moves a group to a different page and section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is i.n"]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_page_id`: ID of the page to remove the group from."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_page(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_page_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_page::RequestBuilder {
+            self.client.move_group_to_page(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_page_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Adds a group to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page to add the group to."]
+        #[doc = "* `section_id`: The ID of the section to add the group to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+            )
+        }
+        #[doc = "Moves a group to a different section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_section(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_section::RequestBuilder {
+            self.client.move_group_to_section(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Updates a group in the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+        #[doc = "Removes a group from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page the group is in"]
+        #[doc = "* `section_id`: The ID of the section to the group is in"]
+        #[doc = "* `group_id`: The ID of the group"]
+        pub fn remove_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> remove_group::RequestBuilder {
+            self.client.remove_group(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Moves a group to a different page and section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is i.n"]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_page_id`: ID of the page to remove the group from."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_page(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_page_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_page::RequestBuilder {
+            self.client.move_group_to_page(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_page_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Adds a group to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page to add the group to."]
+        #[doc = "* `section_id`: The ID of the section to add the group to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+            )
+        }
+        #[doc = "Moves a group to a different section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_section(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_section::RequestBuilder {
+            self.client.move_group_to_section(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Updates a group in the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+        #[doc = "Removes a group from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page the group is in"]
+        #[doc = "* `section_id`: The ID of the section to the group is in"]
+        #[doc = "* `group_id`: The ID of the group"]
+        pub fn remove_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> remove_group::RequestBuilder {
+            self.client.remove_group(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+    }
     pub mod move_group_to_page {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -402,9 +974,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let remove_from_page_id = &this.remove_from_page_id;
@@ -513,9 +1086,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -618,9 +1192,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let remove_from_section_id = &this.remove_from_section_id;
@@ -726,9 +1301,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -797,9 +1373,10 @@ pub mod groups {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -889,6 +1466,87 @@ pub mod processes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get list of all processes including system and inherited."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Creates a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: CreateProcessModel."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessModel>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a single process of a specified ID."]
+        pub fn get(&self, process_type_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), process_type_id)
+        }
+        #[doc = "Edit a process of a specific ID."]
+        pub fn edit_process(
+            &self,
+            body: impl Into<models::UpdateProcessModel>,
+            process_type_id: impl Into<String>,
+        ) -> edit_process::RequestBuilder {
+            self.client
+                .edit_process(self.organization.clone(), body, process_type_id)
+        }
+        #[doc = "Removes a process of a specific ID."]
+        pub fn delete(&self, process_type_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_type_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get list of all processes including system and inherited."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Creates a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: CreateProcessModel."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessModel>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Get a single process of a specified ID."]
+        pub fn get(&self, process_type_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), process_type_id)
+        }
+        #[doc = "Edit a process of a specific ID."]
+        pub fn edit_process(
+            &self,
+            body: impl Into<models::UpdateProcessModel>,
+            process_type_id: impl Into<String>,
+        ) -> edit_process::RequestBuilder {
+            self.client
+                .edit_process(self.organization.clone(), body, process_type_id)
+        }
+        #[doc = "Removes a process of a specific ID."]
+        pub fn delete(&self, process_type_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_type_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -972,9 +1630,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1079,9 +1738,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1188,9 +1848,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1297,9 +1958,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1369,9 +2031,10 @@ pub mod processes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1481,6 +2144,149 @@ pub mod behaviors {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all behaviors in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), process_id)
+        }
+        #[doc = "Creates a single behavior in the given process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ProcessBehaviorCreateRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a behavior of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, behavior_ref_name)
+        }
+        #[doc = "Replaces a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ProcessBehaviorUpdateRequest>,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_id, behavior_ref_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of all behaviors in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), process_id)
+        }
+        #[doc = "Creates a single behavior in the given process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ProcessBehaviorCreateRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a behavior of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, behavior_ref_name)
+        }
+        #[doc = "Replaces a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ProcessBehaviorUpdateRequest>,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_id, behavior_ref_name)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1566,9 +2372,10 @@ pub mod behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1678,9 +2485,10 @@ pub mod behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1790,9 +2598,10 @@ pub mod behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1902,9 +2711,10 @@ pub mod behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1976,9 +2786,10 @@ pub mod behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1986,106 +2797,241 @@ pub mod behaviors {
                 })
             }
         }
-    }
-}
-pub mod work_item_types {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
-        #[doc = "Returns a list of all work item types in a process."]
+    }
+}
+pub mod work_item_types {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Returns a list of all work item types in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                expand: None,
+            }
+        }
+        #[doc = "Creates a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process on which to create work item type."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::CreateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+            }
+        }
+        #[doc = "Returns a single work item type in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                expand: None,
+            }
+        }
+        #[doc = "Updates a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Removes a work itewm type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all work item types in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), process_id)
+        }
+        #[doc = "Creates a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process on which to create work item type."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a single work item type in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Updates a work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
-        pub fn list(
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
             &self,
-            organization: impl Into<String>,
+            body: impl Into<models::UpdateProcessWorkItemTypeRequest>,
             process_id: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                expand: None,
-            }
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Removes a work itewm type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_id, wit_ref_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of all work item types in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), process_id)
         }
         #[doc = "Creates a work item type in the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process on which to create work item type."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::CreateProcessWorkItemTypeRequest>,
             process_id: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-            }
+            self.client
+                .create(self.organization.clone(), body, process_id)
         }
         #[doc = "Returns a single work item type in a process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                expand: None,
-            }
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name)
         }
         #[doc = "Updates a work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateProcessWorkItemTypeRequest>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .update(self.organization.clone(), body, process_id, wit_ref_name)
         }
         #[doc = "Removes a work itewm type in the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), process_id, wit_ref_name)
         }
     }
     pub mod list {
@@ -2174,9 +3120,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2286,9 +3233,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2401,9 +3349,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2515,9 +3464,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2591,9 +3541,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2629,92 +3580,281 @@ pub mod fields {
         #[doc = "Adds a field to a work item type."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::AddProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            add::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Returns a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                field_ref_name: field_ref_name.into(),
+                expand: None,
+            }
+        }
+        #[doc = "Updates a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                field_ref_name: field_ref_name.into(),
+            }
+        }
+        #[doc = "Removes a field from a work item type. Does not permanently delete the field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn remove_work_item_type_field(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> remove_work_item_type_field::RequestBuilder {
+            remove_work_item_type_field::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                field_ref_name: field_ref_name.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all fields in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Adds a field to a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::AddProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Returns a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Updates a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Removes a field from a work item type. Does not permanently delete the field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn remove_work_item_type_field(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> remove_work_item_type_field::RequestBuilder {
+            self.client.remove_work_item_type_field(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of all fields in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Adds a field to a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         pub fn add(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::AddProcessWorkItemTypeFieldRequest>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> add::RequestBuilder {
-            add::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
         }
         #[doc = "Returns a field in a work item type."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `field_ref_name`: The reference name of the field."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             field_ref_name: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                field_ref_name: field_ref_name.into(),
-                expand: None,
-            }
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
         }
         #[doc = "Updates a field in a work item type."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `field_ref_name`: The reference name of the field."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateProcessWorkItemTypeFieldRequest>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             field_ref_name: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                field_ref_name: field_ref_name.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
         }
         #[doc = "Removes a field from a work item type. Does not permanently delete the field."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `field_ref_name`: The reference name of the field."]
         pub fn remove_work_item_type_field(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             field_ref_name: impl Into<String>,
         ) -> remove_work_item_type_field::RequestBuilder {
-            remove_work_item_type_field::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                field_ref_name: field_ref_name.into(),
-            }
+            self.client.remove_work_item_type_field(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
         }
     }
     pub mod list {
@@ -2801,9 +3941,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2910,9 +4051,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3026,9 +4168,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3142,9 +4285,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3220,9 +4364,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3256,6 +4401,49 @@ pub mod layout {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the form layout."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the form layout."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3337,9 +4525,10 @@ pub mod layout {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3347,54 +4536,268 @@ pub mod layout {
                 })
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::FormLayout>;
-            type IntoFuture =
-                futures::future::BoxFuture<'static, azure_core::Result<models::FormLayout>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::FormLayout>;
+            type IntoFuture =
+                futures::future::BoxFuture<'static, azure_core::Result<models::FormLayout>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+}
+pub mod controls {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Creates a control in a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to add the control to."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                group_id: group_id.into(),
+            }
+        }
+        #[doc = "Moves a control to a specified group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to move the control to."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn move_control_to_group(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> move_control_to_group::RequestBuilder {
+            move_control_to_group::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                group_id: group_id.into(),
+                control_id: control_id.into(),
+                remove_from_group_id: None,
+            }
+        }
+        #[doc = "Updates a control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The updated control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                group_id: group_id.into(),
+                control_id: control_id.into(),
+            }
+        }
+        #[doc = "Removes a control from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control to remove."]
+        pub fn remove_control_from_group(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> remove_control_from_group::RequestBuilder {
+            remove_control_from_group::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                group_id: group_id.into(),
+                control_id: control_id.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Creates a control in a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to add the control to."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+            )
+        }
+        #[doc = "Moves a control to a specified group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to move the control to."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn move_control_to_group(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> move_control_to_group::RequestBuilder {
+            self.client.move_control_to_group(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+        #[doc = "Updates a control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+        #[doc = "Removes a control from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control to remove."]
+        pub fn remove_control_from_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> remove_control_from_group::RequestBuilder {
+            self.client.remove_control_from_group(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
     }
-}
-pub mod controls {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Creates a control in a group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The control."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `group_id`: The ID of the group to add the control to."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Control>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             group_id: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                group_id: group_id.into(),
-            }
+            self.client.create(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+            )
         }
         #[doc = "Moves a control to a specified group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The control."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
@@ -3402,28 +4805,24 @@ pub mod controls {
         #[doc = "* `control_id`: The ID of the control."]
         pub fn move_control_to_group(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Control>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             group_id: impl Into<String>,
             control_id: impl Into<String>,
         ) -> move_control_to_group::RequestBuilder {
-            move_control_to_group::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                group_id: group_id.into(),
-                control_id: control_id.into(),
-                remove_from_group_id: None,
-            }
+            self.client.move_control_to_group(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
         #[doc = "Updates a control on the work item form."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The updated control."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
@@ -3431,47 +4830,42 @@ pub mod controls {
         #[doc = "* `control_id`: The ID of the control."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Control>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             group_id: impl Into<String>,
             control_id: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                group_id: group_id.into(),
-                control_id: control_id.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
         #[doc = "Removes a control from the work item form."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `group_id`: The ID of the group."]
         #[doc = "* `control_id`: The ID of the control to remove."]
         pub fn remove_control_from_group(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             group_id: impl Into<String>,
             control_id: impl Into<String>,
         ) -> remove_control_from_group::RequestBuilder {
-            remove_control_from_group::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                group_id: group_id.into(),
-                control_id: control_id.into(),
-            }
+            self.client.remove_control_from_group(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
     }
     pub mod create {
@@ -3551,9 +4945,10 @@ pub mod controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3660,9 +5055,10 @@ pub mod controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(remove_from_group_id) = &this.remove_from_group_id {
@@ -3768,9 +5164,10 @@ pub mod controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3838,9 +5235,10 @@ pub mod controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3921,6 +5319,113 @@ pub mod pages {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Adds a page to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Updates a page on the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Removes a page from the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page"]
+        pub fn remove_page(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+        ) -> remove_page::RequestBuilder {
+            self.client
+                .remove_page(self.organization.clone(), process_id, wit_ref_name, page_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Adds a page to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Updates a page on the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Removes a page from the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page"]
+        pub fn remove_page(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+        ) -> remove_page::RequestBuilder {
+            self.client
+                .remove_page(self.organization.clone(), process_id, wit_ref_name, page_id)
+        }
+    }
     pub mod add {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4003,9 +5508,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4109,9 +5615,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4184,9 +5691,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4195,75 +5703,198 @@ pub mod pages {
             }
         }
     }
-}
-pub mod system_controls {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod system_controls {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Gets edited system controls for a work item type in a process. To get all system controls (base + edited) use layout API(s)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Updates/adds a system control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                control_id: control_id.into(),
+            }
+        }
+        #[doc = "Deletes a system control modification on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                control_id: control_id.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets edited system controls for a work item type in a process. To get all system controls (base + edited) use layout API(s)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Updates/adds a system control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+        #[doc = "Deletes a system control modification on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Gets edited system controls for a work item type in a process. To get all system controls (base + edited) use layout API(s)"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
         }
         #[doc = "Updates/adds a system control on the work item form."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `control_id`: The ID of the control."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Control>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             control_id: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                control_id: control_id.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
         }
         #[doc = "Deletes a system control modification on the work item form."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process."]
         #[doc = "* `wit_ref_name`: The reference name of the work item type."]
         #[doc = "* `control_id`: The ID of the control."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             control_id: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                control_id: control_id.into(),
-            }
+            self.client.delete(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
         }
     }
     pub mod list {
@@ -4347,9 +5978,10 @@ pub mod system_controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4448,9 +6080,10 @@ pub mod system_controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4549,9 +6182,10 @@ pub mod system_controls {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4573,118 +6207,291 @@ pub mod system_controls {
             }
         }
     }
-}
-pub mod rules {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod rules {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Returns a list of all rules in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Adds a rule to work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn add(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::CreateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            add::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Returns a single rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                rule_id: rule_id.into(),
+            }
+        }
+        #[doc = "Updates a rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                rule_id: rule_id.into(),
+            }
+        }
+        #[doc = "Removes a rule from the work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                rule_id: rule_id.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all rules in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Adds a rule to work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn add(
+            &self,
+            body: impl Into<models::CreateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Returns a single rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name, rule_id)
+        }
+        #[doc = "Updates a rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+        #[doc = "Removes a rule from the work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), process_id, wit_ref_name, rule_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Returns a list of all rules in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
         }
         #[doc = "Adds a rule to work item type in the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn add(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::CreateProcessRuleRequest>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> add::RequestBuilder {
-            add::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            self.client
+                .add(self.organization.clone(), body, process_id, wit_ref_name)
         }
         #[doc = "Returns a single rule in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `rule_id`: The ID of the rule"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             rule_id: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                rule_id: rule_id.into(),
-            }
+            self.client
+                .get(self.organization.clone(), process_id, wit_ref_name, rule_id)
         }
         #[doc = "Updates a rule in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `rule_id`: The ID of the rule"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateProcessRuleRequest>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             rule_id: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                rule_id: rule_id.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
         }
         #[doc = "Removes a rule from the work item type in the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `rule_id`: The ID of the rule"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             rule_id: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                rule_id: rule_id.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), process_id, wit_ref_name, rule_id)
         }
     }
     pub mod list {
@@ -4769,9 +6576,10 @@ pub mod rules {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4875,9 +6683,10 @@ pub mod rules {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4983,9 +6792,10 @@ pub mod rules {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5091,9 +6901,10 @@ pub mod rules {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5167,9 +6978,10 @@ pub mod rules {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5237,83 +7049,314 @@ pub mod states {
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn hide_state_definition(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::HideStateModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> hide_state_definition::RequestBuilder {
+            hide_state_definition::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Updates a given state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Removes a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all state definitions in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Creates a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Returns a single state definition in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn hide_state_definition(
+            &self,
+            body: impl Into<models::HideStateModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> hide_state_definition::RequestBuilder {
+            self.client.hide_state_definition(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Updates a given state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Removes a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of all state definitions in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Creates a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, process_id, wit_ref_name)
+        }
+        #[doc = "Returns a single state definition in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: The ID of the state"]
         pub fn hide_state_definition(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::HideStateModel>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> hide_state_definition::RequestBuilder {
-            hide_state_definition::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            self.client.hide_state_definition(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Updates a given state definition in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: ID of the state"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemStateInputModel>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Removes a state definition in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: ID of the state"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            self.client.delete(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
     }
     pub mod list {
@@ -5400,9 +7443,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5509,9 +7553,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5620,9 +7665,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5731,9 +7777,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5843,9 +7890,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5921,9 +7969,10 @@ pub mod states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6043,6 +8092,203 @@ pub mod work_item_types_behaviors {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of all behaviors for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Adds a behavior to the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn add(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Updates a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Returns a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn remove_behavior_from_work_item_type(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> remove_behavior_from_work_item_type::RequestBuilder {
+            self.client.remove_behavior_from_work_item_type(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of all behaviors for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Adds a behavior to the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn add(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Updates a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Returns a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn remove_behavior_from_work_item_type(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> remove_behavior_from_work_item_type::RequestBuilder {
+            self.client.remove_behavior_from_work_item_type(
+                self.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6125,9 +8371,10 @@ pub mod work_item_types_behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6234,9 +8481,10 @@ pub mod work_item_types_behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6344,9 +8592,10 @@ pub mod work_item_types_behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6455,9 +8704,10 @@ pub mod work_item_types_behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6532,9 +8782,10 @@ pub mod work_item_types_behaviors {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6625,6 +8876,95 @@ pub mod lists {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns meta data of the picklist."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Creates a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Picklist"]
+        pub fn create(&self, body: impl Into<models::PickList>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Returns a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn get(&self, list_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), list_id)
+        }
+        #[doc = "Updates a list."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PickList>,
+            list_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, list_id)
+        }
+        #[doc = "Removes a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn delete(&self, list_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), list_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns meta data of the picklist."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Creates a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Picklist"]
+        pub fn create(&self, body: impl Into<models::PickList>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Returns a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn get(&self, list_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), list_id)
+        }
+        #[doc = "Updates a list."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PickList>,
+            list_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, list_id)
+        }
+        #[doc = "Removes a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn delete(&self, list_id: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), list_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6703,9 +9043,10 @@ pub mod lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6807,9 +9148,10 @@ pub mod lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6911,9 +9253,10 @@ pub mod lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7015,9 +9358,10 @@ pub mod lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7087,9 +9431,10 @@ pub mod lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
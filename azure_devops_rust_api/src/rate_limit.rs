@@ -0,0 +1,425 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Rate limit handling.
+//!
+//! Azure DevOps limits the resources consumed by each user, measured in Azure DevOps
+//! throughput units (TSTUs). Responses to requests that approach or exceed a limit include
+//! the following headers:
+//!
+//! * `Retry-After`: the number of seconds to wait before sending another request.
+//! * `X-RateLimit-Resource`: the service and type of threshold that was reached.
+//! * `X-RateLimit-Delay`: how long the request was delayed, in seconds.
+//! * `X-RateLimit-Limit`: the total number of TSTUs allowed before delays are imposed.
+//! * `X-RateLimit-Remaining`: the number of TSTUs remaining before delays are imposed.
+//! * `X-RateLimit-Reset`: the time at which usage returns to 0 TSTUs, as a Unix timestamp.
+//!
+//! Throttled requests fail with `429 Too Many Requests` (or `503 Service Unavailable`).
+//! [`RateLimitPolicy`] retries these requests after the delay requested by the service,
+//! and records the rate limit headers of each response.
+
+use azure_core::error::{Error, ErrorKind};
+use azure_core::headers::{HeaderName, Headers, RETRY_AFTER};
+use azure_core::{Context, Policy, PolicyResult, Request, StatusCode};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+const RATE_LIMIT_RESOURCE: HeaderName = HeaderName::from_static("x-ratelimit-resource");
+const RATE_LIMIT_DELAY: HeaderName = HeaderName::from_static("x-ratelimit-delay");
+const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// The default number of times that a throttled request is retried.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// The default maximum time to wait before sending a request.
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// The rate limit headers of a response.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateLimitState {
+    /// The status code of the response.
+    pub status: Option<StatusCode>,
+    /// The time to wait before sending another request (`Retry-After`).
+    pub retry_after: Option<Duration>,
+    /// The service and type of threshold that was reached (`X-RateLimit-Resource`).
+    pub resource: Option<String>,
+    /// How long the request was delayed by the service (`X-RateLimit-Delay`).
+    pub delay: Option<Duration>,
+    /// The total number of TSTUs allowed before delays are imposed (`X-RateLimit-Limit`).
+    pub limit: Option<f64>,
+    /// The number of TSTUs remaining before delays are imposed (`X-RateLimit-Remaining`).
+    pub remaining: Option<f64>,
+    /// The time at which usage returns to 0 TSTUs (`X-RateLimit-Reset`).
+    pub reset: Option<OffsetDateTime>,
+}
+
+impl RateLimitState {
+    /// Parses the rate limit headers of a response.
+    ///
+    /// Returns `None` if the response has no rate limit headers.
+    pub fn from_headers(status: StatusCode, headers: &Headers) -> Option<Self> {
+        let seconds = |name: &HeaderName| {
+            headers
+                .get_optional_str(name)
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .filter(|value| value.is_finite() && *value >= 0.0)
+                .map(Duration::from_secs_f64)
+        };
+        let number = |name: &HeaderName| {
+            headers
+                .get_optional_str(name)
+                .and_then(|value| value.trim().parse::<f64>().ok())
+        };
+        let state = Self {
+            status: Some(status),
+            retry_after: seconds(&RETRY_AFTER).or_else(|| {
+                // Retry-After may also be an HTTP date.
+                let date = headers.get_optional_str(&RETRY_AFTER)?;
+                let date = azure_core::date::parse_rfc1123(date).ok()?;
+                Some(duration_until(date, OffsetDateTime::now_utc()))
+            }),
+            resource: headers.get_optional_string(&RATE_LIMIT_RESOURCE),
+            delay: seconds(&RATE_LIMIT_DELAY),
+            limit: number(&RATE_LIMIT_LIMIT),
+            remaining: number(&RATE_LIMIT_REMAINING),
+            reset: number(&RATE_LIMIT_RESET)
+                .and_then(|reset| OffsetDateTime::from_unix_timestamp(reset as i64).ok()),
+        };
+        if state.retry_after.is_none()
+            && state.resource.is_none()
+            && state.delay.is_none()
+            && state.remaining.is_none()
+            && state.reset.is_none()
+        {
+            return None;
+        }
+        Some(state)
+    }
+
+    /// Returns `true` if the request was throttled.
+    pub fn is_throttled(&self) -> bool {
+        matches!(
+            self.status,
+            Some(StatusCode::TooManyRequests) | Some(StatusCode::ServiceUnavailable)
+        )
+    }
+}
+
+/// Pipeline policy that honours the Azure DevOps rate limit headers.
+///
+/// * Throttled requests (`429 Too Many Requests`, or `503 Service Unavailable` with a `Retry-After`
+///   header) are retried after the `Retry-After` delay, up to [`max_retries`](RateLimitPolicy::max_retries) times.
+/// * The rate limit headers of the last response are available from [`state()`](RateLimitPolicy::state).
+/// * If enabled with [`slow_down()`](RateLimitPolicy::slow_down), requests are delayed when the remaining
+///   TSTUs fall below a threshold, rather than waiting to be throttled.
+///
+/// The policy is added to a client as a per-retry policy, e.g. via
+/// [`AdoClientBuilder::per_retry_policies()`](crate::AdoClientBuilder::per_retry_policies),
+/// so that it runs after the client's retry policy and sees the response of each request
+/// before it is converted into an error. Clones of the policy share the same state, so a clone
+/// can be kept to read the state.
+///
+/// Throttled requests are retried by this policy. If they are still throttled after
+/// `max_retries` retries then an `HttpResponse` error is returned, which the client's retry
+/// policy does not retry.
+///
+/// Example:
+///
+/// ```ignore
+/// let rate_limit = RateLimitPolicy::new().slow_down(100.0);
+/// let client = AdoClient::builder(credential, organization)
+///     .per_retry_policies(vec![Arc::new(rate_limit.clone()) as Arc<dyn azure_core::Policy>])
+///     .build();
+/// // ...
+/// if let Some(state) = rate_limit.state() {
+///     println!("{:?} TSTUs remaining", state.remaining);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimitPolicy {
+    max_retries: u32,
+    max_delay: Duration,
+    slow_down_threshold: Option<f64>,
+    state: Arc<Mutex<Option<RateLimitState>>>,
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimitPolicy {
+    /// Creates a new `RateLimitPolicy`.
+    pub fn new() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_delay: DEFAULT_MAX_DELAY,
+            slow_down_threshold: None,
+            state: Arc::default(),
+        }
+    }
+
+    /// The number of times that a throttled request is retried (default 5).
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The maximum time to wait before sending a request (default 5 minutes).
+    #[must_use]
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Delays requests when the remaining TSTUs fall below `threshold`.
+    ///
+    /// The delay grows as the remaining TSTUs approach zero, up to the time until usage is reset.
+    #[must_use]
+    pub fn slow_down(mut self, threshold: f64) -> Self {
+        self.slow_down_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the rate limit headers of the last response, if it had any.
+    pub fn state(&self) -> Option<RateLimitState> {
+        self.state.lock().unwrap().clone()
+    }
+
+    fn set_state(&self, state: Option<RateLimitState>) {
+        *self.state.lock().unwrap() = state;
+    }
+
+    /// Returns the time to wait before sending a request, given the last rate limit state.
+    fn slow_down_delay(&self, now: OffsetDateTime) -> Option<Duration> {
+        let threshold = self.slow_down_threshold?;
+        let state = self.state()?;
+        let remaining = state.remaining?;
+        if threshold <= 0.0 || remaining >= threshold {
+            return None;
+        }
+        let until_reset = duration_until(state.reset?, now);
+        let fraction = 1.0 - remaining.max(0.0) / threshold;
+        Some(until_reset.mul_f64(fraction).min(self.max_delay))
+    }
+
+    /// Returns the time to wait before retrying a throttled request.
+    fn retry_delay(&self, state: Option<&RateLimitState>, attempt: u32) -> Duration {
+        state
+            .and_then(|state| state.retry_after)
+            .unwrap_or_else(|| Duration::from_secs(1 << attempt.min(8)))
+            .min(self.max_delay)
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for RateLimitPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if let Some(delay) = self.slow_down_delay(OffsetDateTime::now_utc()) {
+            if !delay.is_zero() {
+                azure_core::sleep::sleep(delay).await;
+            }
+        }
+        let mut attempt = 0;
+        loop {
+            let response = next[0].send(ctx, request, &next[1..]).await?;
+            let status = response.status();
+            let state = RateLimitState::from_headers(status, response.headers());
+            self.set_state(state.clone());
+            let throttled = status == StatusCode::TooManyRequests
+                || (status == StatusCode::ServiceUnavailable
+                    && state.as_ref().and_then(|state| state.retry_after).is_some());
+            if !throttled {
+                return Ok(response);
+            }
+            if attempt >= self.max_retries {
+                return Err(Error::with_message(
+                    ErrorKind::HttpResponse {
+                        status,
+                        error_code: None,
+                    },
+                    || {
+                        format!(
+                            "server returned error status {status} after {attempt} rate limit retries"
+                        )
+                    },
+                ));
+            }
+            azure_core::sleep::sleep(self.retry_delay(state.as_ref(), attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Returns the time from `now` until `time`, or zero if `time` has passed.
+fn duration_until(time: OffsetDateTime, now: OffsetDateTime) -> Duration {
+    Duration::try_from(time - now).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(values: &[(&'static str, &'static str)]) -> Headers {
+        let mut headers = Headers::new();
+        for (name, value) in values {
+            headers.insert(*name, *value);
+        }
+        headers
+    }
+
+    #[test]
+    fn test_state_from_headers() {
+        let headers = headers(&[
+            ("retry-after", "30"),
+            ("x-ratelimit-resource", "Core"),
+            ("x-ratelimit-delay", "0.5"),
+            ("x-ratelimit-limit", "200"),
+            ("x-ratelimit-remaining", "12.5"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]);
+        let state = RateLimitState::from_headers(StatusCode::TooManyRequests, &headers).unwrap();
+        assert!(state.is_throttled());
+        assert_eq!(state.retry_after, Some(Duration::from_secs(30)));
+        assert_eq!(state.resource.as_deref(), Some("Core"));
+        assert_eq!(state.delay, Some(Duration::from_millis(500)));
+        assert_eq!(state.limit, Some(200.0));
+        assert_eq!(state.remaining, Some(12.5));
+        assert_eq!(
+            state.reset.map(OffsetDateTime::unix_timestamp),
+            Some(1_700_000_000)
+        );
+
+        assert!(RateLimitState::from_headers(StatusCode::Ok, &Headers::new()).is_none());
+    }
+
+    #[test]
+    fn test_delays() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let policy = RateLimitPolicy::new()
+            .slow_down(100.0)
+            .max_delay(Duration::from_secs(60));
+        assert_eq!(policy.slow_down_delay(now), None);
+
+        policy.set_state(Some(RateLimitState {
+            remaining: Some(75.0),
+            reset: Some(now + Duration::from_secs(40)),
+            ..Default::default()
+        }));
+        assert_eq!(policy.slow_down_delay(now), Some(Duration::from_secs(10)));
+
+        policy.set_state(Some(RateLimitState {
+            remaining: Some(0.0),
+            reset: Some(now + Duration::from_secs(600)),
+            ..Default::default()
+        }));
+        assert_eq!(policy.slow_down_delay(now), Some(Duration::from_secs(60)));
+
+        let throttled = RateLimitState {
+            retry_after: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.retry_delay(Some(&throttled), 3),
+            Duration::from_secs(5)
+        );
+        assert_eq!(policy.retry_delay(None, 2), Duration::from_secs(4));
+    }
+
+    /// Policy that responds with each of `responses` in turn.
+    #[derive(Debug)]
+    struct MockTransport {
+        responses: Mutex<Vec<(StatusCode, Headers)>>,
+        calls: Mutex<u32>,
+    }
+
+    impl MockTransport {
+        fn new(mut responses: Vec<(StatusCode, Headers)>) -> Self {
+            responses.reverse();
+            Self {
+                responses: Mutex::new(responses),
+                calls: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for MockTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            *self.calls.lock().unwrap() += 1;
+            let (status, headers) = self.responses.lock().unwrap().pop().unwrap();
+            Ok(azure_core::Response::new(
+                status,
+                headers,
+                Box::pin(azure_core::BytesStream::new_empty()),
+            ))
+        }
+    }
+
+    fn send(policy: &RateLimitPolicy, transport: Arc<MockTransport>) -> PolicyResult {
+        let mut request = Request::new(
+            azure_core::Url::parse("https://dev.azure.com/org/_apis/projects").unwrap(),
+            azure_core::Method::Get,
+        );
+        futures::executor::block_on(policy.send(
+            &Context::new(),
+            &mut request,
+            &[transport as Arc<dyn Policy>],
+        ))
+    }
+
+    #[test]
+    fn test_send_retries_throttled_request() {
+        let transport = Arc::new(MockTransport::new(vec![
+            (
+                StatusCode::TooManyRequests,
+                headers(&[("retry-after", "0"), ("x-ratelimit-resource", "Core")]),
+            ),
+            (StatusCode::Ok, headers(&[("x-ratelimit-remaining", "150")])),
+        ]));
+        let policy = RateLimitPolicy::new();
+        let response = send(&policy, transport.clone()).unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+        let state = policy.state().unwrap();
+        assert_eq!(state.status, Some(StatusCode::Ok));
+        assert_eq!(state.remaining, Some(150.0));
+    }
+
+    #[test]
+    fn test_send_gives_up_after_max_retries() {
+        let throttled = || {
+            (
+                StatusCode::TooManyRequests,
+                headers(&[("retry-after", "0")]),
+            )
+        };
+        let transport = Arc::new(MockTransport::new(vec![throttled(), throttled()]));
+        let policy = RateLimitPolicy::new().max_retries(1);
+        let error = send(&policy, transport.clone()).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::HttpResponse {
+                status: StatusCode::TooManyRequests,
+                ..
+            }
+        ));
+        assert_eq!(*transport.calls.lock().unwrap(), 2);
+        assert!(policy.state().unwrap().is_throttled());
+    }
+}
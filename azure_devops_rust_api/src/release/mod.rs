@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://vsrm.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn approvals_client(&self) -> approvals::Client {
@@ -143,6 +212,139 @@ impl Client {
         releases::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn approvals_client(&self) -> approvals::OrganizationClient {
+        approvals::OrganizationClient {
+            client: self.client.approvals_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn definitions_client(&self) -> definitions::OrganizationClient {
+        definitions::OrganizationClient {
+            client: self.client.definitions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn deployments_client(&self) -> deployments::OrganizationClient {
+        deployments::OrganizationClient {
+            client: self.client.deployments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn folders_client(&self) -> folders::OrganizationClient {
+        folders::OrganizationClient {
+            client: self.client.folders_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn gates_client(&self) -> gates::OrganizationClient {
+        gates::OrganizationClient {
+            client: self.client.gates_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn manual_interventions_client(&self) -> manual_interventions::OrganizationClient {
+        manual_interventions::OrganizationClient {
+            client: self.client.manual_interventions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn releases_client(&self) -> releases::OrganizationClient {
+        releases::OrganizationClient {
+            client: self.client.releases_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn approvals_client(&self) -> approvals::ProjectClient {
+        approvals::ProjectClient {
+            client: self.client.approvals_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn definitions_client(&self) -> definitions::ProjectClient {
+        definitions::ProjectClient {
+            client: self.client.definitions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn deployments_client(&self) -> deployments::ProjectClient {
+        deployments::ProjectClient {
+            client: self.client.deployments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn folders_client(&self) -> folders::ProjectClient {
+        folders::ProjectClient {
+            client: self.client.folders_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn gates_client(&self) -> gates::ProjectClient {
+        gates::ProjectClient {
+            client: self.client.gates_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn manual_interventions_client(&self) -> manual_interventions::ProjectClient {
+        manual_interventions::ProjectClient {
+            client: self.client.manual_interventions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn releases_client(&self) -> releases::ProjectClient {
+        releases::ProjectClient {
+            client: self.client.releases_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod releases {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -195,7 +397,7 @@ pub mod releases {
                 query_order: None,
                 top: None,
                 continuation_token: None,
-                expand: None,
+                expand: Vec::new(),
                 artifact_type_id: None,
                 source_id: None,
                 artifact_version_id: None,
@@ -390,6 +592,330 @@ pub mod releases {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a Release"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_release(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> get_release::RequestBuilder {
+            self.client
+                .get_release(self.organization.clone(), project, release_id)
+        }
+        #[doc = "Get a list of releases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to create a release."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseStartMetadata>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Get release for a given revision number."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `definition_snapshot_revision`: Definition snapshot revision number."]
+        pub fn get_release_revision(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            definition_snapshot_revision: i32,
+        ) -> get_release_revision::RequestBuilder {
+            self.client.get_release_revision(
+                self.organization.clone(),
+                project,
+                release_id,
+                definition_snapshot_revision,
+            )
+        }
+        #[doc = "Update a complete release object."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release object for update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release(
+            &self,
+            body: impl Into<models::Release>,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> update_release::RequestBuilder {
+            self.client
+                .update_release(self.organization.clone(), body, project, release_id)
+        }
+        #[doc = "Update few properties of a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties of release to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release_resource(
+            &self,
+            body: impl Into<models::ReleaseUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> update_release_resource::RequestBuilder {
+            self.client.update_release_resource(
+                self.organization.clone(),
+                body,
+                project,
+                release_id,
+            )
+        }
+        #[doc = "Get a release environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        pub fn get_release_environment(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> get_release_environment::RequestBuilder {
+            self.client.get_release_environment(
+                self.organization.clone(),
+                project,
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Update the status of a release environment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment update meta data."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        pub fn update_release_environment(
+            &self,
+            body: impl Into<models::ReleaseEnvironmentUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> update_release_environment::RequestBuilder {
+            self.client.update_release_environment(
+                self.organization.clone(),
+                body,
+                project,
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Gets the task log of a release as a plain text file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        #[doc = "* `release_deploy_phase_id`: Release deploy phase Id."]
+        #[doc = "* `task_id`: ReleaseTask Id for the log."]
+        pub fn get_task_log(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            release_deploy_phase_id: i32,
+            task_id: i32,
+        ) -> get_task_log::RequestBuilder {
+            self.client.get_task_log(
+                self.organization.clone(),
+                project,
+                release_id,
+                environment_id,
+                release_deploy_phase_id,
+                task_id,
+            )
+        }
+        #[doc = "Get logs for a release Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_logs(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> get_logs::RequestBuilder {
+            self.client
+                .get_logs(self.organization.clone(), project, release_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a Release"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_release(&self, release_id: i32) -> get_release::RequestBuilder {
+            self.client
+                .get_release(self.organization.clone(), self.project.clone(), release_id)
+        }
+        #[doc = "Get a list of releases"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to create a release."]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseStartMetadata>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get release for a given revision number."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `definition_snapshot_revision`: Definition snapshot revision number."]
+        pub fn get_release_revision(
+            &self,
+            release_id: i32,
+            definition_snapshot_revision: i32,
+        ) -> get_release_revision::RequestBuilder {
+            self.client.get_release_revision(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                definition_snapshot_revision,
+            )
+        }
+        #[doc = "Update a complete release object."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release object for update."]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release(
+            &self,
+            body: impl Into<models::Release>,
+            release_id: i32,
+        ) -> update_release::RequestBuilder {
+            self.client.update_release(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Update few properties of a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties of release to update."]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release_resource(
+            &self,
+            body: impl Into<models::ReleaseUpdateMetadata>,
+            release_id: i32,
+        ) -> update_release_resource::RequestBuilder {
+            self.client.update_release_resource(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Get a release environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        pub fn get_release_environment(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+        ) -> get_release_environment::RequestBuilder {
+            self.client.get_release_environment(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Update the status of a release environment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment update meta data."]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        pub fn update_release_environment(
+            &self,
+            body: impl Into<models::ReleaseEnvironmentUpdateMetadata>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> update_release_environment::RequestBuilder {
+            self.client.update_release_environment(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Gets the task log of a release as a plain text file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        #[doc = "* `release_deploy_phase_id`: Release deploy phase Id."]
+        #[doc = "* `task_id`: ReleaseTask Id for the log."]
+        pub fn get_task_log(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            release_deploy_phase_id: i32,
+            task_id: i32,
+        ) -> get_task_log::RequestBuilder {
+            self.client.get_task_log(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+                release_deploy_phase_id,
+                task_id,
+            )
+        }
+        #[doc = "Get logs for a release Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_logs(&self, release_id: i32) -> get_logs::RequestBuilder {
+            self.client
+                .get_logs(self.organization.clone(), self.project.clone(), release_id)
+        }
+    }
     pub mod get_release {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -447,7 +973,7 @@ pub mod releases {
             pub(crate) release_id: i32,
             pub(crate) approval_filters: Option<String>,
             pub(crate) property_filters: Option<String>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::SingleReleaseExpands>,
             pub(crate) top_gate_records: Option<i32>,
         }
         impl RequestBuilder {
@@ -462,7 +988,7 @@ pub mod releases {
                 self
             }
             #[doc = "A property that should be expanded in the release."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::SingleReleaseExpands>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -495,9 +1021,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(approval_filters) = &this.approval_filters {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -511,7 +1038,7 @@ pub mod releases {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(top_gate_records) = &this.top_gate_records {
                             req.url_mut()
@@ -604,7 +1131,7 @@ pub mod releases {
             pub(crate) query_order: Option<String>,
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<i32>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::ReleaseExpands>,
             pub(crate) artifact_type_id: Option<String>,
             pub(crate) source_id: Option<String>,
             pub(crate) artifact_version_id: Option<String>,
@@ -676,8 +1203,8 @@ pub mod releases {
                 self
             }
             #[doc = "The property that should be expanded in the list of releases."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::ReleaseExpands>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Releases with given artifactTypeId will be returned. Values can be Build, Jenkins, GitHub, Nuget, Team Build (external), ExternalTFSBuild, Git, TFVC, ExternalTfsXamlBuild."]
@@ -748,9 +1275,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(definition_id) = &this.definition_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -812,10 +1340,19 @@ pub mod releases {
                                 .query_pairs_mut()
                                 .append_pair("continuationToken", &continuation_token.to_string());
                         }
-                        if let Some(expand) = &this.expand {
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         if let Some(artifact_type_id) = &this.artifact_type_id {
                             req.url_mut()
@@ -860,11 +1397,30 @@ pub mod releases {
                         if let Some(path) = &this.path {
                             req.url_mut().query_pairs_mut().append_pair("path", path);
                         }
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::ReleaseList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
                     }
-                })
+                };
+                crate::paging::continuation_stream(make_request)
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
@@ -961,9 +1517,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1010,6 +1567,25 @@ pub mod releases {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1068,9 +1644,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let definition_snapshot_revision = &this.definition_snapshot_revision;
                         req.url_mut().query_pairs_mut().append_pair(
                             "definitionSnapshotRevision",
@@ -1178,9 +1755,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1285,9 +1863,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1367,11 +1946,11 @@ pub mod releases {
             pub(crate) project: String,
             pub(crate) release_id: i32,
             pub(crate) environment_id: i32,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::ReleaseEnvironmentExpands>,
         }
         impl RequestBuilder {
             #[doc = "A property that should be expanded in the environment."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::ReleaseEnvironmentExpands>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1400,13 +1979,14 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -1514,9 +2094,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1563,6 +2144,25 @@ pub mod releases {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1629,9 +2229,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(start_line) = &this.start_line {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1686,6 +2287,25 @@ pub mod releases {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1743,9 +2363,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1818,6 +2439,67 @@ pub mod approvals {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of approvals"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Update status of an approval"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: ReleaseApproval object having status, approver and comments."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ReleaseApproval>,
+            project: impl Into<String>,
+            approval_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, approval_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of approvals"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Update status of an approval"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: ReleaseApproval object having status, approver and comments."]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ReleaseApproval>,
+            approval_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                approval_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1946,9 +2628,10 @@ pub mod approvals {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(assigned_to_filter) = &this.assigned_to_filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1996,6 +2679,25 @@ pub mod approvals {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::ReleaseApprovalList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReleaseApprovalList>;
@@ -2096,9 +2798,10 @@ pub mod approvals {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2141,7 +2844,7 @@ pub mod definitions {
                 organization: organization.into(),
                 project: project.into(),
                 search_text: None,
-                expand: None,
+                expand: Vec::new(),
                 artifact_type: None,
                 artifact_source_id: None,
                 top: None,
@@ -2277,6 +2980,185 @@ pub mod definitions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of release definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: release definition object to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseDefinition>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Update a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release definition object to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ReleaseDefinition>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Delete a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, definition_id)
+        }
+        #[doc = "Get revision history for a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        pub fn get_release_definition_history(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_release_definition_history::RequestBuilder {
+            self.client.get_release_definition_history(
+                self.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Get release definition for a given definition_id and revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        #[doc = "* `revision`: Id of the revision."]
+        pub fn get_definition_revision(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            revision: i32,
+        ) -> get_definition_revision::RequestBuilder {
+            self.client.get_definition_revision(
+                self.organization.clone(),
+                project,
+                definition_id,
+                revision,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of release definitions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: release definition object to create."]
+        pub fn create(&self, body: impl Into<models::ReleaseDefinition>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Update a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release definition object to update."]
+        pub fn update(&self, body: impl Into<models::ReleaseDefinition>) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Delete a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn delete(&self, definition_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Get revision history for a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        pub fn get_release_definition_history(
+            &self,
+            definition_id: i32,
+        ) -> get_release_definition_history::RequestBuilder {
+            self.client.get_release_definition_history(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Get release definition for a given definition_id and revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        #[doc = "* `revision`: Id of the revision."]
+        pub fn get_definition_revision(
+            &self,
+            definition_id: i32,
+            revision: i32,
+        ) -> get_definition_revision::RequestBuilder {
+            self.client.get_definition_revision(
+                self.organization.clone(),
+                self.project.clone(),
+                definition_id,
+                revision,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2333,7 +3215,7 @@ pub mod definitions {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) search_text: Option<String>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::ReleaseDefinitionExpands>,
             pub(crate) artifact_type: Option<String>,
             pub(crate) artifact_source_id: Option<String>,
             pub(crate) top: Option<i32>,
@@ -2354,8 +3236,8 @@ pub mod definitions {
                 self
             }
             #[doc = "The properties that should be expanded in the list of Release definitions."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::ReleaseDefinitionExpands>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Release definitions with given artifactType will be returned. Values can be Build, Jenkins, GitHub, Nuget, Team Build (external), ExternalTFSBuild, Git, TFVC, ExternalTfsXamlBuild."]
@@ -2444,18 +3326,28 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(search_text) = &this.search_text {
                             req.url_mut()
                                 .query_pairs_mut()
                                 .append_pair("searchText", search_text);
                         }
-                        if let Some(expand) = &this.expand {
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         if let Some(artifact_type) = &this.artifact_type {
                             req.url_mut()
@@ -2524,6 +3416,26 @@ pub mod definitions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ReleaseDefinitionList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReleaseDefinitionList>;
@@ -2622,9 +3534,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2728,9 +3641,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2841,9 +3755,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(property_filters) = &this.property_filters {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2931,9 +3846,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(comment) = &this.comment {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3036,9 +3952,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3086,6 +4003,25 @@ pub mod definitions {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3145,9 +4081,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3204,6 +4141,33 @@ pub mod deployments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3370,9 +4334,10 @@ pub mod deployments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(definition_id) = &this.definition_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3458,11 +4423,30 @@ pub mod deployments {
                                 .query_pairs_mut()
                                 .append_pair("sourceBranch", source_branch);
                         }
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeploymentList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
                     }
-                })
+                };
+                crate::paging::continuation_stream(make_request)
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
@@ -3568,6 +4552,119 @@ pub mod folders {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, path)
+        }
+        #[doc = "This method is no longer supported. Use CreateFolder with folder parameter API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, path)
+        }
+        #[doc = "Updates an existing folder at given existing path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, path)
+        }
+        #[doc = "Deletes a definition folder for given folder name and path and all it's existing definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project, path)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn list(&self, path: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), path)
+        }
+        #[doc = "This method is no longer supported. Use CreateFolder with folder parameter API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), path)
+        }
+        #[doc = "Updates an existing folder at given existing path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `path`: Path of the folder to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), path)
+        }
+        #[doc = "Deletes a definition folder for given folder name and path and all it's existing definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Path of the folder to delete."]
+        pub fn delete(&self, path: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), path)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3655,9 +4752,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3766,9 +4864,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3873,9 +4972,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3947,9 +5047,10 @@ pub mod folders {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3986,6 +5087,55 @@ pub mod gates {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Updates the gate for a deployment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to patch the Release Gates."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `gate_step_id`: Gate step Id."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GateUpdateMetadata>,
+            project: impl Into<String>,
+            gate_step_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, gate_step_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Updates the gate for a deployment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to patch the Release Gates."]
+        #[doc = "* `gate_step_id`: Gate step Id."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GateUpdateMetadata>,
+            gate_step_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                gate_step_id,
+            )
+        }
+    }
     pub mod update {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4068,9 +5218,10 @@ pub mod gates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4093,15 +5244,161 @@ pub mod gates {
             }
         }
     }
-}
-pub mod attachments {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod attachments {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the release task attachments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_release_task_attachments(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_release_task_attachments::RequestBuilder {
+            get_release_task_attachments::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id: plan_id.into(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "Get a release task attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_release_task_attachment_content(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_release_task_attachment_content::RequestBuilder {
+            get_release_task_attachment_content::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id: plan_id.into(),
+                timeline_id: timeline_id.into(),
+                record_id: record_id.into(),
+                type_: type_.into(),
+                name: name.into(),
+            }
+        }
+        #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_task_attachments(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_task_attachments::RequestBuilder {
+            get_task_attachments::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id: timeline_id.into(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_task_attachment_content(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_task_attachment_content::RequestBuilder {
+            get_task_attachment_content::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id: timeline_id.into(),
+                record_id: record_id.into(),
+                type_: type_.into(),
+                name: name.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
         #[doc = "Get the release task attachments."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4110,7 +5407,6 @@ pub mod attachments {
         #[doc = "* `type_`: Type of the attachment."]
         pub fn get_release_task_attachments(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4118,21 +5414,19 @@ pub mod attachments {
             plan_id: impl Into<String>,
             type_: impl Into<String>,
         ) -> get_release_task_attachments::RequestBuilder {
-            get_release_task_attachments::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_release_task_attachments(
+                self.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                plan_id: plan_id.into(),
-                type_: type_.into(),
-            }
+                plan_id,
+                type_,
+            )
         }
         #[doc = "Get a release task attachment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4144,7 +5438,6 @@ pub mod attachments {
         #[doc = "* `name`: Name of the attachment."]
         pub fn get_release_task_attachment_content(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4155,24 +5448,22 @@ pub mod attachments {
             type_: impl Into<String>,
             name: impl Into<String>,
         ) -> get_release_task_attachment_content::RequestBuilder {
-            get_release_task_attachment_content::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_release_task_attachment_content(
+                self.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                plan_id: plan_id.into(),
-                timeline_id: timeline_id.into(),
-                record_id: record_id.into(),
-                type_: type_.into(),
-                name: name.into(),
-            }
+                plan_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
         }
         #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4181,7 +5472,6 @@ pub mod attachments {
         #[doc = "* `type_`: Type of the attachment."]
         pub fn get_task_attachments(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4189,21 +5479,19 @@ pub mod attachments {
             timeline_id: impl Into<String>,
             type_: impl Into<String>,
         ) -> get_task_attachments::RequestBuilder {
-            get_task_attachments::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_task_attachments(
+                self.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                timeline_id: timeline_id.into(),
-                type_: type_.into(),
-            }
+                timeline_id,
+                type_,
+            )
         }
         #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4214,7 +5502,6 @@ pub mod attachments {
         #[doc = "* `name`: Name of the attachment."]
         pub fn get_task_attachment_content(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4224,18 +5511,145 @@ pub mod attachments {
             type_: impl Into<String>,
             name: impl Into<String>,
         ) -> get_task_attachment_content::RequestBuilder {
-            get_task_attachment_content::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_task_attachment_content(
+                self.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                timeline_id: timeline_id.into(),
-                record_id: record_id.into(),
-                type_: type_.into(),
-                name: name.into(),
-            }
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the release task attachments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_release_task_attachments(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_release_task_attachments::RequestBuilder {
+            self.client.get_release_task_attachments(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id,
+                type_,
+            )
+        }
+        #[doc = "Get a release task attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_release_task_attachment_content(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_release_task_attachment_content::RequestBuilder {
+            self.client.get_release_task_attachment_content(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_task_attachments(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_task_attachments::RequestBuilder {
+            self.client.get_task_attachments(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id,
+                type_,
+            )
+        }
+        #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_task_attachment_content(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_task_attachment_content::RequestBuilder {
+            self.client.get_task_attachment_content(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
         }
     }
     pub mod get_release_task_attachments {
@@ -4318,9 +5732,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4368,6 +5783,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4426,9 +5860,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4529,9 +5964,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4579,6 +6015,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4636,9 +6091,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4731,6 +6187,114 @@ pub mod manual_interventions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "List all manual interventions for a given release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn list(&self, project: impl Into<String>, release_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, release_id)
+        }
+        #[doc = "Get manual intervention for a given release and manual intervention id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                release_id,
+                manual_intervention_id,
+            )
+        }
+        #[doc = "Update manual intervention."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Meta data to update manual intervention."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ManualInterventionUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                release_id,
+                manual_intervention_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "List all manual interventions for a given release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn list(&self, release_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), release_id)
+        }
+        #[doc = "Get manual intervention for a given release and manual intervention id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn get(&self, release_id: i32, manual_intervention_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                release_id,
+                manual_intervention_id,
+            )
+        }
+        #[doc = "Update manual intervention."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Meta data to update manual intervention."]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ManualInterventionUpdateMetadata>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                release_id,
+                manual_intervention_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4813,9 +6377,10 @@ pub mod manual_interventions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4923,9 +6488,10 @@ pub mod manual_interventions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5032,9 +6598,10 @@ pub mod manual_interventions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4761,6 +4761,88 @@ impl ReleaseDefinitionEnvironmentTemplate {
         Self::default()
     }
 }
+#[doc = "Properties to include in a list of release definitions"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "ReleaseDefinitionExpands")]
+pub enum ReleaseDefinitionExpands {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "environments")]
+    Environments,
+    #[serde(rename = "artifacts")]
+    Artifacts,
+    #[serde(rename = "triggers")]
+    Triggers,
+    #[serde(rename = "variables")]
+    Variables,
+    #[serde(rename = "tags")]
+    Tags,
+    #[serde(rename = "lastRelease")]
+    LastRelease,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ReleaseDefinitionExpands {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ReleaseDefinitionExpands {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ReleaseDefinitionExpands {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 0u32, "none")
+            }
+            Self::Environments => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 1u32, "environments")
+            }
+            Self::Artifacts => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 2u32, "artifacts")
+            }
+            Self::Triggers => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 3u32, "triggers")
+            }
+            Self::Variables => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 4u32, "variables")
+            }
+            Self::Tags => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 5u32, "tags")
+            }
+            Self::LastRelease => {
+                serializer.serialize_unit_variant("ReleaseDefinitionExpands", 6u32, "lastRelease")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for ReleaseDefinitionExpands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Environments => f.write_str("environments"),
+            Self::Artifacts => f.write_str("artifacts"),
+            Self::Triggers => f.write_str("triggers"),
+            Self::Variables => f.write_str("variables"),
+            Self::Tags => f.write_str("tags"),
+            Self::LastRelease => f.write_str("lastRelease"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReleaseDefinitionGate {
@@ -5412,6 +5494,58 @@ pub mod release_environment_completed_event {
         RedeployTrigger,
     }
 }
+#[doc = "Properties to include in a release environment"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "ReleaseEnvironmentExpands")]
+pub enum ReleaseEnvironmentExpands {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "tasks")]
+    Tasks,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ReleaseEnvironmentExpands {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ReleaseEnvironmentExpands {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ReleaseEnvironmentExpands {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => {
+                serializer.serialize_unit_variant("ReleaseEnvironmentExpands", 0u32, "none")
+            }
+            Self::Tasks => {
+                serializer.serialize_unit_variant("ReleaseEnvironmentExpands", 1u32, "tasks")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for ReleaseEnvironmentExpands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Tasks => f.write_str("tasks"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReleaseEnvironmentShallowReference {
@@ -5619,6 +5753,84 @@ impl ReleaseEvent {
         Self::default()
     }
 }
+#[doc = "Properties to include in a list of releases"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "ReleaseExpands")]
+pub enum ReleaseExpands {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "environments")]
+    Environments,
+    #[serde(rename = "artifacts")]
+    Artifacts,
+    #[serde(rename = "approvals")]
+    Approvals,
+    #[serde(rename = "manualInterventions")]
+    ManualInterventions,
+    #[serde(rename = "variables")]
+    Variables,
+    #[serde(rename = "tags")]
+    Tags,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ReleaseExpands {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ReleaseExpands {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ReleaseExpands {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("ReleaseExpands", 0u32, "none"),
+            Self::Environments => {
+                serializer.serialize_unit_variant("ReleaseExpands", 1u32, "environments")
+            }
+            Self::Artifacts => {
+                serializer.serialize_unit_variant("ReleaseExpands", 2u32, "artifacts")
+            }
+            Self::Approvals => {
+                serializer.serialize_unit_variant("ReleaseExpands", 3u32, "approvals")
+            }
+            Self::ManualInterventions => {
+                serializer.serialize_unit_variant("ReleaseExpands", 4u32, "manualInterventions")
+            }
+            Self::Variables => {
+                serializer.serialize_unit_variant("ReleaseExpands", 5u32, "variables")
+            }
+            Self::Tags => serializer.serialize_unit_variant("ReleaseExpands", 6u32, "tags"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for ReleaseExpands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Environments => f.write_str("environments"),
+            Self::Artifacts => f.write_str("artifacts"),
+            Self::Approvals => f.write_str("approvals"),
+            Self::ManualInterventions => f.write_str("manualInterventions"),
+            Self::Variables => f.write_str("variables"),
+            Self::Tags => f.write_str("tags"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReleaseGates {
@@ -6613,6 +6825,54 @@ impl ServiceEndpointReference {
         Self::default()
     }
 }
+#[doc = "Properties to include in a release"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "SingleReleaseExpands")]
+pub enum SingleReleaseExpands {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "tasks")]
+    Tasks,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for SingleReleaseExpands {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for SingleReleaseExpands {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for SingleReleaseExpands {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("SingleReleaseExpands", 0u32, "none"),
+            Self::Tasks => serializer.serialize_unit_variant("SingleReleaseExpands", 1u32, "tasks"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for SingleReleaseExpands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Tasks => f.write_str("tasks"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct SourceIdInput {
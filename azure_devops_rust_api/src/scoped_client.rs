@@ -0,0 +1,97 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Scoped client variants of hand-written `Client` methods.
+//!
+//! The generated `OrganizationClient` and `ProjectClient` of an operation group provide the
+//! operations of its `Client` without the `organization` (and `project`) parameters.
+//! [`scoped_client_methods!`] does the same for methods added to a `Client` by the
+//! hand-written modules.
+
+/// Implements methods on the `OrganizationClient` and `ProjectClient` of an operation group
+/// that call the `Client` method of the same name with the organization (and project) of the
+/// scoped client.
+///
+/// Each method is declared with the parameters of the `Client` method, without the leading
+/// `organization` parameter and with `[project]` in place of the `project` parameter:
+///
+/// ```ignore
+/// scoped_client_methods! {
+///     pushes;
+///     /// Starts a commit that is pushed to `branch`.
+///     pub fn commit(repository_id: impl Into<String>, [project], branch: impl Into<String>) -> CommitBuilder;
+/// }
+/// ```
+macro_rules! scoped_client_methods {
+    ($group:ident; $($methods:tt)*) => {
+        impl $group::OrganizationClient {
+            scoped_client_methods!(@organization $group; $($methods)*);
+        }
+        impl $group::ProjectClient {
+            scoped_client_methods!(@project $group; $($methods)*);
+        }
+    };
+    (@$scope:ident $group:ident;) => {};
+    (@$scope:ident $group:ident; $(#[$attr:meta])* pub async fn $($rest:tt)*) => {
+        scoped_client_methods!(@$scope $group [async] [.await] $(#[$attr])* $($rest)*);
+    };
+    (@$scope:ident $group:ident; $(#[$attr:meta])* pub fn $($rest:tt)*) => {
+        scoped_client_methods!(@$scope $group [] [] $(#[$attr])* $($rest)*);
+    };
+    (
+        @organization $group:ident [$($async:tt)*] [$($await:tt)*]
+        $(#[$attr:meta])*
+        $name:ident $(<$($generic:ident),+>)?(
+            $($before:ident: $before_type:ty,)* [project] $(, $after:ident: $after_type:ty)* $(,)?
+        ) -> $ret:ty
+        $(where $($bounded:ident: $first_bound:ident $(+ $bound:ident)*),+)?;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        ///
+        #[doc = concat!("See [`", stringify!($group), "::Client::", stringify!($name), "()`].")]
+        pub $($async)* fn $name $(<$($generic),+>)?(
+            &self,
+            $($before: $before_type,)*
+            project: impl Into<String>
+            $(, $after: $after_type)*
+        ) -> $ret
+        $(where $($bounded: $first_bound $(+ $bound)*),+)?
+        {
+            self.client
+                .$name(self.organization.clone(), $($before,)* project $(, $after)*)
+                $($await)*
+        }
+        scoped_client_methods!(@organization $group; $($rest)*);
+    };
+    (
+        @project $group:ident [$($async:tt)*] [$($await:tt)*]
+        $(#[$attr:meta])*
+        $name:ident $(<$($generic:ident),+>)?(
+            $($before:ident: $before_type:ty,)* [project] $(, $after:ident: $after_type:ty)* $(,)?
+        ) -> $ret:ty
+        $(where $($bounded:ident: $first_bound:ident $(+ $bound:ident)*),+)?;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        ///
+        #[doc = concat!("See [`", stringify!($group), "::Client::", stringify!($name), "()`].")]
+        pub $($async)* fn $name $(<$($generic),+>)?(
+            &self
+            $(, $before: $before_type)*
+            $(, $after: $after_type)*
+        ) -> $ret
+        $(where $($bounded: $first_bound $(+ $bound)*),+)?
+        {
+            self.client
+                .$name(
+                    self.organization.clone(),
+                    $($before,)*
+                    self.project.clone()
+                    $(, $after)*
+                )
+                $($await)*
+        }
+        scoped_client_methods!(@project $group; $($rest)*);
+    };
+}
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://almsearch.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn code_search_results_client(&self) -> code_search_results::Client {
@@ -137,6 +206,113 @@ impl Client {
         work_item_search_results::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn code_search_results_client(&self) -> code_search_results::OrganizationClient {
+        code_search_results::OrganizationClient {
+            client: self.client.code_search_results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn package_search_results_client(&self) -> package_search_results::OrganizationClient {
+        package_search_results::OrganizationClient {
+            client: self.client.package_search_results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn repositories_client(&self) -> repositories::OrganizationClient {
+        repositories::OrganizationClient {
+            client: self.client.repositories_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn tfvc_client(&self) -> tfvc::OrganizationClient {
+        tfvc::OrganizationClient {
+            client: self.client.tfvc_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn wiki_search_results_client(&self) -> wiki_search_results::OrganizationClient {
+        wiki_search_results::OrganizationClient {
+            client: self.client.wiki_search_results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_search_results_client(&self) -> work_item_search_results::OrganizationClient {
+        work_item_search_results::OrganizationClient {
+            client: self.client.work_item_search_results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn code_search_results_client(&self) -> code_search_results::ProjectClient {
+        code_search_results::ProjectClient {
+            client: self.client.code_search_results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn package_search_results_client(&self) -> package_search_results::ProjectClient {
+        package_search_results::ProjectClient {
+            client: self.client.package_search_results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn repositories_client(&self) -> repositories::ProjectClient {
+        repositories::ProjectClient {
+            client: self.client.repositories_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn tfvc_client(&self) -> tfvc::ProjectClient {
+        tfvc::ProjectClient {
+            client: self.client.tfvc_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn wiki_search_results_client(&self) -> wiki_search_results::ProjectClient {
+        wiki_search_results::ProjectClient {
+            client: self.client.wiki_search_results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_search_results_client(&self) -> work_item_search_results::ProjectClient {
+        work_item_search_results::ProjectClient {
+            client: self.client.work_item_search_results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod package_search_results {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -158,6 +334,45 @@ pub mod package_search_results {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Package Search Request."]
+        pub fn fetch_package_search_results(
+            &self,
+            body: impl Into<models::PackageSearchRequest>,
+        ) -> fetch_package_search_results::RequestBuilder {
+            self.client
+                .fetch_package_search_results(self.organization.clone(), body)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Package Search Request."]
+        pub fn fetch_package_search_results(
+            &self,
+            body: impl Into<models::PackageSearchRequest>,
+        ) -> fetch_package_search_results::RequestBuilder {
+            self.client
+                .fetch_package_search_results(self.organization.clone(), body)
+        }
+    }
     pub mod fetch_package_search_results {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -249,9 +464,10 @@ pub mod package_search_results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -301,6 +517,50 @@ pub mod code_search_results {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Code Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_code_search_results(
+            &self,
+            body: impl Into<models::CodeSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_code_search_results::RequestBuilder {
+            self.client
+                .fetch_code_search_results(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Code Search Request."]
+        pub fn fetch_code_search_results(
+            &self,
+            body: impl Into<models::CodeSearchRequest>,
+        ) -> fetch_code_search_results::RequestBuilder {
+            self.client.fetch_code_search_results(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod fetch_code_search_results {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -382,9 +642,10 @@ pub mod code_search_results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -432,6 +693,44 @@ pub mod repositories {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides status of Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository`: Repository ID or repository name."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, repository)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides status of Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository`: Repository ID or repository name."]
+        pub fn get(&self, repository: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), repository)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -514,9 +813,10 @@ pub mod repositories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -562,6 +862,35 @@ pub mod tfvc {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides status of TFVC Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides status of TFVC Repository."]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -644,9 +973,10 @@ pub mod tfvc {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -695,6 +1025,50 @@ pub mod wiki_search_results {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Wiki Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_wiki_search_results(
+            &self,
+            body: impl Into<models::WikiSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_wiki_search_results::RequestBuilder {
+            self.client
+                .fetch_wiki_search_results(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Wiki Search Request."]
+        pub fn fetch_wiki_search_results(
+            &self,
+            body: impl Into<models::WikiSearchRequest>,
+        ) -> fetch_wiki_search_results::RequestBuilder {
+            self.client.fetch_wiki_search_results(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod fetch_wiki_search_results {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -776,9 +1150,10 @@ pub mod wiki_search_results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -826,6 +1201,50 @@ pub mod work_item_search_results {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Work Item Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_work_item_search_results(
+            &self,
+            body: impl Into<models::WorkItemSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_work_item_search_results::RequestBuilder {
+            self.client
+                .fetch_work_item_search_results(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Work Item Search Request."]
+        pub fn fetch_work_item_search_results(
+            &self,
+            body: impl Into<models::WorkItemSearchRequest>,
+        ) -> fetch_work_item_search_results::RequestBuilder {
+            self.client.fetch_work_item_search_results(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod fetch_work_item_search_results {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -907,9 +1326,10 @@ pub mod work_item_search_results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
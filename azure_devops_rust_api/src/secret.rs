@@ -0,0 +1,66 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Secret values, such as PATs and access tokens.
+
+use zeroize::Zeroize;
+
+/// A secret string, such as a PAT or access token.
+///
+/// The value is not included in the `Debug` output, and is zeroed in memory when dropped.
+/// Use [`expose_secret()`](Secret::expose_secret) to access the value.
+#[derive(Clone, Default)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Creates a new `Secret`.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Returns the secret value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the secret is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_owned())
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret() {
+        let secret = Secret::from("my-pat");
+        assert_eq!(secret.expose_secret(), "my-pat");
+        assert_eq!(format!("{secret:?}"), "Secret(***)");
+    }
+}
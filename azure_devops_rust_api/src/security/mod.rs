@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn access_control_entries_client(&self) -> access_control_entries::Client {
@@ -131,6 +200,87 @@ impl Client {
         security_namespaces::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn access_control_entries_client(&self) -> access_control_entries::OrganizationClient {
+        access_control_entries::OrganizationClient {
+            client: self.client.access_control_entries_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn access_control_lists_client(&self) -> access_control_lists::OrganizationClient {
+        access_control_lists::OrganizationClient {
+            client: self.client.access_control_lists_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn permissions_client(&self) -> permissions::OrganizationClient {
+        permissions::OrganizationClient {
+            client: self.client.permissions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn security_namespaces_client(&self) -> security_namespaces::OrganizationClient {
+        security_namespaces::OrganizationClient {
+            client: self.client.security_namespaces_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn access_control_entries_client(&self) -> access_control_entries::ProjectClient {
+        access_control_entries::ProjectClient {
+            client: self.client.access_control_entries_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn access_control_lists_client(&self) -> access_control_lists::ProjectClient {
+        access_control_lists::ProjectClient {
+            client: self.client.access_control_lists_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn permissions_client(&self) -> permissions::ProjectClient {
+        permissions::ProjectClient {
+            client: self.client.permissions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn security_namespaces_client(&self) -> security_namespaces::ProjectClient {
+        security_namespaces::ProjectClient {
+            client: self.client.security_namespaces_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod access_control_entries {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -172,6 +322,75 @@ pub mod access_control_entries {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Add or update ACEs in the ACL for the provided token. The request body contains the target token, a list of [ACEs](https://docs.microsoft.com/en-us/rest/api/azure/devops/security/access%20control%20entries/set%20access%20control%20entries?#accesscontrolentry) and a optional merge parameter. In the case of a collision (by identity descriptor) with an existing ACE in the ACL, the \"merge\" parameter determines the behavior. If set, the existing ACE has its allow and deny merged with the incoming ACE's allow and deny. If unset, the existing ACE is displaced."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_entries(
+            &self,
+            body: impl Into<models::JObject>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_entries::RequestBuilder {
+            self.client.set_access_control_entries(
+                body,
+                security_namespace_id,
+                self.organization.clone(),
+            )
+        }
+        #[doc = "Remove the specified ACEs from the ACL belonging to the specified token."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_entries(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_entries::RequestBuilder {
+            self.client
+                .remove_access_control_entries(security_namespace_id, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Add or update ACEs in the ACL for the provided token. The request body contains the target token, a list of [ACEs](https://docs.microsoft.com/en-us/rest/api/azure/devops/security/access%20control%20entries/set%20access%20control%20entries?#accesscontrolentry) and a optional merge parameter. In the case of a collision (by identity descriptor) with an existing ACE in the ACL, the \"merge\" parameter determines the behavior. If set, the existing ACE has its allow and deny merged with the incoming ACE's allow and deny. If unset, the existing ACE is displaced."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_entries(
+            &self,
+            body: impl Into<models::JObject>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_entries::RequestBuilder {
+            self.client.set_access_control_entries(
+                body,
+                security_namespace_id,
+                self.organization.clone(),
+            )
+        }
+        #[doc = "Remove the specified ACEs from the ACL belonging to the specified token."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_entries(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_entries::RequestBuilder {
+            self.client
+                .remove_access_control_entries(security_namespace_id, self.organization.clone())
+        }
+    }
     pub mod set_access_control_entries {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -253,9 +472,10 @@ pub mod access_control_entries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -371,9 +591,10 @@ pub mod access_control_entries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(token) = &this.token {
                             req.url_mut().query_pairs_mut().append_pair("token", token);
                         }
@@ -465,6 +686,93 @@ pub mod access_control_lists {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Return a list of access control lists for the specified security namespace and token. All ACLs in the security namespace will be retrieved if no optional parameters are provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            self.client
+                .query(security_namespace_id, self.organization.clone())
+        }
+        #[doc = "Create or update one or more access control lists. All data that currently exists for the ACLs supplied will be overwritten."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of ACLs to create or update."]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_lists(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_lists::RequestBuilder {
+            self.client.set_access_control_lists(
+                body,
+                security_namespace_id,
+                self.organization.clone(),
+            )
+        }
+        #[doc = "Remove access control lists under the specfied security namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_lists(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_lists::RequestBuilder {
+            self.client
+                .remove_access_control_lists(security_namespace_id, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Return a list of access control lists for the specified security namespace and token. All ACLs in the security namespace will be retrieved if no optional parameters are provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            self.client
+                .query(security_namespace_id, self.organization.clone())
+        }
+        #[doc = "Create or update one or more access control lists. All data that currently exists for the ACLs supplied will be overwritten."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of ACLs to create or update."]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_lists(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_lists::RequestBuilder {
+            self.client.set_access_control_lists(
+                body,
+                security_namespace_id,
+                self.organization.clone(),
+            )
+        }
+        #[doc = "Remove access control lists under the specfied security namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_lists(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_lists::RequestBuilder {
+            self.client
+                .remove_access_control_lists(security_namespace_id, self.organization.clone())
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -569,9 +877,10 @@ pub mod access_control_lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(token) = &this.token {
                             req.url_mut().query_pairs_mut().append_pair("token", token);
                         }
@@ -662,9 +971,10 @@ pub mod access_control_lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -765,9 +1075,10 @@ pub mod access_control_lists {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(tokens) = &this.tokens {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -865,6 +1176,115 @@ pub mod permissions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Evaluates whether the caller has the specified permissions on the specified set of security tokens."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `permissions`: Permissions to evaluate."]
+        pub fn has_permissions(
+            &self,
+            security_namespace_id: impl Into<String>,
+            permissions: i32,
+        ) -> has_permissions::RequestBuilder {
+            self.client.has_permissions(
+                security_namespace_id,
+                self.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Removes the specified permissions on a security token for a user or group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `descriptor`: Identity descriptor of the user to remove permissions for."]
+        #[doc = "* `permissions`: Permissions to remove."]
+        pub fn remove_permission(
+            &self,
+            security_namespace_id: impl Into<String>,
+            descriptor: impl Into<String>,
+            permissions: i32,
+        ) -> remove_permission::RequestBuilder {
+            self.client.remove_permission(
+                security_namespace_id,
+                descriptor,
+                self.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Evaluates multiple permissions for the calling user.  Note: This method does not aggregate the results, nor does it short-circuit if one of the permissions evaluates to false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of evaluation requests."]
+        pub fn has_permissions_batch(
+            &self,
+            body: impl Into<models::PermissionEvaluationBatch>,
+        ) -> has_permissions_batch::RequestBuilder {
+            self.client
+                .has_permissions_batch(body, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Evaluates whether the caller has the specified permissions on the specified set of security tokens."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `permissions`: Permissions to evaluate."]
+        pub fn has_permissions(
+            &self,
+            security_namespace_id: impl Into<String>,
+            permissions: i32,
+        ) -> has_permissions::RequestBuilder {
+            self.client.has_permissions(
+                security_namespace_id,
+                self.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Removes the specified permissions on a security token for a user or group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `descriptor`: Identity descriptor of the user to remove permissions for."]
+        #[doc = "* `permissions`: Permissions to remove."]
+        pub fn remove_permission(
+            &self,
+            security_namespace_id: impl Into<String>,
+            descriptor: impl Into<String>,
+            permissions: i32,
+        ) -> remove_permission::RequestBuilder {
+            self.client.remove_permission(
+                security_namespace_id,
+                descriptor,
+                self.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Evaluates multiple permissions for the calling user.  Note: This method does not aggregate the results, nor does it short-circuit if one of the permissions evaluates to false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of evaluation requests."]
+        pub fn has_permissions_batch(
+            &self,
+            body: impl Into<models::PermissionEvaluationBatch>,
+        ) -> has_permissions_batch::RequestBuilder {
+            self.client
+                .has_permissions_batch(body, self.organization.clone())
+        }
+    }
     pub mod has_permissions {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -967,9 +1387,10 @@ pub mod permissions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(tokens) = &this.tokens {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1096,9 +1517,10 @@ pub mod permissions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let descriptor = &this.descriptor;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1206,9 +1628,10 @@ pub mod permissions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1256,6 +1679,39 @@ pub mod security_namespaces {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "List all security namespaces or just the specified namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), security_namespace_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "List all security namespaces or just the specified namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), security_namespace_id)
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1344,9 +1800,10 @@ pub mod security_namespaces {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(local_only) = &this.local_only {
                             req.url_mut()
                                 .query_pairs_mut()
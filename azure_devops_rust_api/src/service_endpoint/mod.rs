@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn endpointproxy_client(&self) -> endpointproxy::Client {
@@ -131,6 +200,87 @@ impl Client {
         types::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn endpointproxy_client(&self) -> endpointproxy::OrganizationClient {
+        endpointproxy::OrganizationClient {
+            client: self.client.endpointproxy_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn endpoints_client(&self) -> endpoints::OrganizationClient {
+        endpoints::OrganizationClient {
+            client: self.client.endpoints_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn executionhistory_client(&self) -> executionhistory::OrganizationClient {
+        executionhistory::OrganizationClient {
+            client: self.client.executionhistory_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn types_client(&self) -> types::OrganizationClient {
+        types::OrganizationClient {
+            client: self.client.types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn endpointproxy_client(&self) -> endpointproxy::ProjectClient {
+        endpointproxy::ProjectClient {
+            client: self.client.endpointproxy_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn endpoints_client(&self) -> endpoints::ProjectClient {
+        endpoints::ProjectClient {
+            client: self.client.endpoints_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn executionhistory_client(&self) -> executionhistory::ProjectClient {
+        executionhistory::ProjectClient {
+            client: self.client.executionhistory_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn types_client(&self) -> types::ProjectClient {
+        types::ProjectClient {
+            client: self.client.types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod endpointproxy {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -177,6 +327,79 @@ pub mod endpointproxy {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Proxy for a GET request defined by a service endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn execute_service_endpoint_request(
+            &self,
+            body: impl Into<models::ServiceEndpointRequest>,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> execute_service_endpoint_request::RequestBuilder {
+            self.client.execute_service_endpoint_request(
+                self.organization.clone(),
+                body,
+                project,
+                endpoint_id,
+            )
+        }
+        #[doc = "Use ExecuteServiceEndpointRequest API Instead"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Describes the data source to fetch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::DataSourceBinding>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Proxy for a GET request defined by a service endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint request."]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn execute_service_endpoint_request(
+            &self,
+            body: impl Into<models::ServiceEndpointRequest>,
+            endpoint_id: impl Into<String>,
+        ) -> execute_service_endpoint_request::RequestBuilder {
+            self.client.execute_service_endpoint_request(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                endpoint_id,
+            )
+        }
+        #[doc = "Use ExecuteServiceEndpointRequest API Instead"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Describes the data source to fetch."]
+        pub fn query(&self, body: impl Into<models::DataSourceBinding>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod execute_service_endpoint_request {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -262,9 +485,10 @@ pub mod endpointproxy {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let endpoint_id = &this.endpoint_id;
@@ -373,9 +597,10 @@ pub mod endpointproxy {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -582,6 +807,242 @@ pub mod endpoints {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_service_endpoints(
+            &self,
+            project: impl Into<String>,
+        ) -> get_service_endpoints::RequestBuilder {
+            self.client
+                .get_service_endpoints(self.organization.clone(), project)
+        }
+        #[doc = "Creates a new service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint to create"]
+        pub fn create(&self, body: impl Into<models::ServiceEndpoint>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Names of the service endpoints to update."]
+        pub fn update_service_endpoints(
+            &self,
+            body: Vec<models::ServiceEndpoint>,
+        ) -> update_service_endpoints::RequestBuilder {
+            self.client
+                .update_service_endpoints(self.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated data for the endpoint"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to update"]
+        pub fn update_service_endpoint(
+            &self,
+            body: impl Into<models::ServiceEndpoint>,
+            endpoint_id: impl Into<String>,
+        ) -> update_service_endpoint::RequestBuilder {
+            self.client
+                .update_service_endpoint(self.organization.clone(), body, endpoint_id)
+        }
+        #[doc = "Share service endpoint across projects"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Project reference details of the target project"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to share"]
+        pub fn share_service_endpoint(
+            &self,
+            body: Vec<models::ServiceEndpointProjectReference>,
+            endpoint_id: impl Into<String>,
+        ) -> share_service_endpoint::RequestBuilder {
+            self.client
+                .share_service_endpoint(self.organization.clone(), body, endpoint_id)
+        }
+        #[doc = "Delete a service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Endpoint Id of endpoint to delete"]
+        #[doc = "* `project_ids`: project Ids from which endpoint needs to be deleted"]
+        pub fn delete(
+            &self,
+            endpoint_id: impl Into<String>,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), endpoint_id, project_ids)
+        }
+        #[doc = "Get the service endpoints by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_names`: Names of the service endpoints."]
+        pub fn get_service_endpoints_by_names(
+            &self,
+            project: impl Into<String>,
+            endpoint_names: impl Into<String>,
+        ) -> get_service_endpoints_by_names::RequestBuilder {
+            self.client.get_service_endpoints_by_names(
+                self.organization.clone(),
+                project,
+                endpoint_names,
+            )
+        }
+        #[doc = "Gets the service endpoints and patch new authorization parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Scope, Validity of Token requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_ids`: Ids of the service endpoints."]
+        pub fn get_service_endpoints_with_refreshed_authentication(
+            &self,
+            body: Vec<models::RefreshAuthenticationParameters>,
+            project: impl Into<String>,
+            endpoint_ids: impl Into<String>,
+        ) -> get_service_endpoints_with_refreshed_authentication::RequestBuilder {
+            self.client
+                .get_service_endpoints_with_refreshed_authentication(
+                    self.organization.clone(),
+                    body,
+                    project,
+                    endpoint_ids,
+                )
+        }
+        #[doc = "Get the service endpoint details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, endpoint_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the service endpoints."]
+        pub fn get_service_endpoints(&self) -> get_service_endpoints::RequestBuilder {
+            self.client
+                .get_service_endpoints(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Creates a new service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint to create"]
+        pub fn create(&self, body: impl Into<models::ServiceEndpoint>) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Names of the service endpoints to update."]
+        pub fn update_service_endpoints(
+            &self,
+            body: Vec<models::ServiceEndpoint>,
+        ) -> update_service_endpoints::RequestBuilder {
+            self.client
+                .update_service_endpoints(self.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated data for the endpoint"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to update"]
+        pub fn update_service_endpoint(
+            &self,
+            body: impl Into<models::ServiceEndpoint>,
+            endpoint_id: impl Into<String>,
+        ) -> update_service_endpoint::RequestBuilder {
+            self.client
+                .update_service_endpoint(self.organization.clone(), body, endpoint_id)
+        }
+        #[doc = "Share service endpoint across projects"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Project reference details of the target project"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to share"]
+        pub fn share_service_endpoint(
+            &self,
+            body: Vec<models::ServiceEndpointProjectReference>,
+            endpoint_id: impl Into<String>,
+        ) -> share_service_endpoint::RequestBuilder {
+            self.client
+                .share_service_endpoint(self.organization.clone(), body, endpoint_id)
+        }
+        #[doc = "Delete a service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Endpoint Id of endpoint to delete"]
+        #[doc = "* `project_ids`: project Ids from which endpoint needs to be deleted"]
+        pub fn delete(
+            &self,
+            endpoint_id: impl Into<String>,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), endpoint_id, project_ids)
+        }
+        #[doc = "Get the service endpoints by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_names`: Names of the service endpoints."]
+        pub fn get_service_endpoints_by_names(
+            &self,
+            endpoint_names: impl Into<String>,
+        ) -> get_service_endpoints_by_names::RequestBuilder {
+            self.client.get_service_endpoints_by_names(
+                self.organization.clone(),
+                self.project.clone(),
+                endpoint_names,
+            )
+        }
+        #[doc = "Gets the service endpoints and patch new authorization parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Scope, Validity of Token requested."]
+        #[doc = "* `endpoint_ids`: Ids of the service endpoints."]
+        pub fn get_service_endpoints_with_refreshed_authentication(
+            &self,
+            body: Vec<models::RefreshAuthenticationParameters>,
+            endpoint_ids: impl Into<String>,
+        ) -> get_service_endpoints_with_refreshed_authentication::RequestBuilder {
+            self.client
+                .get_service_endpoints_with_refreshed_authentication(
+                    self.organization.clone(),
+                    body,
+                    self.project.clone(),
+                    endpoint_ids,
+                )
+        }
+        #[doc = "Get the service endpoint details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn get(&self, endpoint_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), endpoint_id)
+        }
+    }
     pub mod get_service_endpoints {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -698,9 +1159,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -829,9 +1291,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -933,9 +1396,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1047,9 +1511,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(operation) = &this.operation {
@@ -1125,9 +1590,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1191,9 +1657,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let project_ids = &this.project_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1322,9 +1789,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let endpoint_names = &this.endpoint_names;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1455,9 +1923,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let endpoint_ids = &this.endpoint_ids;
@@ -1574,9 +2043,10 @@ pub mod endpoints {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(action_filter) = &this.action_filter {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1621,6 +2091,31 @@ pub mod types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get service endpoint types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get service endpoint types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1711,9 +2206,10 @@ pub mod types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(type_) = &this.type_ {
                             req.url_mut().query_pairs_mut().append_pair("type", type_);
                         }
@@ -1772,6 +2268,44 @@ pub mod executionhistory {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get service endpoint execution records."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, endpoint_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get service endpoint execution records."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn list(&self, endpoint_id: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), endpoint_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1868,9 +2402,10 @@ pub mod executionhistory {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1887,6 +2422,27 @@ pub mod executionhistory {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ServiceEndpointExecutionRecordList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token =
+                                Some(crate::paging::parse_continuation_token(&continuation)?);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ServiceEndpointExecutionRecordList>;
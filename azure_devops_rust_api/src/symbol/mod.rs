@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://artifacts.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn availability_client(&self) -> availability::Client {
@@ -134,6 +203,100 @@ impl Client {
         symsrv::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn availability_client(&self) -> availability::OrganizationClient {
+        availability::OrganizationClient {
+            client: self.client.availability_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn client_client(&self) -> client::OrganizationClient {
+        client::OrganizationClient {
+            client: self.client.client_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn contents_client(&self) -> contents::OrganizationClient {
+        contents::OrganizationClient {
+            client: self.client.contents_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn requests_client(&self) -> requests::OrganizationClient {
+        requests::OrganizationClient {
+            client: self.client.requests_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn symsrv_client(&self) -> symsrv::OrganizationClient {
+        symsrv::OrganizationClient {
+            client: self.client.symsrv_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn availability_client(&self) -> availability::ProjectClient {
+        availability::ProjectClient {
+            client: self.client.availability_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn client_client(&self) -> client::ProjectClient {
+        client::ProjectClient {
+            client: self.client.client_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn contents_client(&self) -> contents::ProjectClient {
+        contents::ProjectClient {
+            client: self.client.contents_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn requests_client(&self) -> requests::ProjectClient {
+        requests::ProjectClient {
+            client: self.client.requests_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn symsrv_client(&self) -> symsrv::ProjectClient {
+        symsrv::ProjectClient {
+            client: self.client.symsrv_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod requests {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -303,6 +466,259 @@ pub mod requests {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Create debug entries for a symbol request as specified by its name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_name`: The symbol request name."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_name_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_name: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_name_debug_entries::RequestBuilder {
+            self.client.create_requests_request_name_debug_entries(
+                self.organization.clone(),
+                body,
+                request_name,
+                collection,
+            )
+        }
+        #[doc = "Get a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn get_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> get_requests_request_name::RequestBuilder {
+            self.client
+                .get_requests_request_name(self.organization.clone(), request_name)
+        }
+        #[doc = "Create a new symbol request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request to create."]
+        pub fn create_requests(
+            &self,
+            body: impl Into<models::Request>,
+        ) -> create_requests::RequestBuilder {
+            self.client.create_requests(self.organization.clone(), body)
+        }
+        #[doc = "Update a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn update_requests_request_name(
+            &self,
+            body: impl Into<models::Request>,
+            request_name: impl Into<String>,
+        ) -> update_requests_request_name::RequestBuilder {
+            self.client
+                .update_requests_request_name(self.organization.clone(), body, request_name)
+        }
+        #[doc = "Delete a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn delete_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> delete_requests_request_name::RequestBuilder {
+            self.client
+                .delete_requests_request_name(self.organization.clone(), request_name)
+        }
+        #[doc = "Get a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn get_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> get_requests_request_id::RequestBuilder {
+            self.client
+                .get_requests_request_id(self.organization.clone(), request_id)
+        }
+        #[doc = "Create debug entries for a symbol request as specified by its identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_id_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_id: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_id_debug_entries::RequestBuilder {
+            self.client.create_requests_request_id_debug_entries(
+                self.organization.clone(),
+                body,
+                request_id,
+                collection,
+            )
+        }
+        #[doc = "Update a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn update_requests_request_id(
+            &self,
+            body: impl Into<models::Request>,
+            request_id: impl Into<String>,
+        ) -> update_requests_request_id::RequestBuilder {
+            self.client
+                .update_requests_request_id(self.organization.clone(), body, request_id)
+        }
+        #[doc = "Delete a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn delete_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> delete_requests_request_id::RequestBuilder {
+            self.client
+                .delete_requests_request_id(self.organization.clone(), request_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Create debug entries for a symbol request as specified by its name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_name`: The symbol request name."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_name_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_name: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_name_debug_entries::RequestBuilder {
+            self.client.create_requests_request_name_debug_entries(
+                self.organization.clone(),
+                body,
+                request_name,
+                collection,
+            )
+        }
+        #[doc = "Get a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn get_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> get_requests_request_name::RequestBuilder {
+            self.client
+                .get_requests_request_name(self.organization.clone(), request_name)
+        }
+        #[doc = "Create a new symbol request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request to create."]
+        pub fn create_requests(
+            &self,
+            body: impl Into<models::Request>,
+        ) -> create_requests::RequestBuilder {
+            self.client.create_requests(self.organization.clone(), body)
+        }
+        #[doc = "Update a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn update_requests_request_name(
+            &self,
+            body: impl Into<models::Request>,
+            request_name: impl Into<String>,
+        ) -> update_requests_request_name::RequestBuilder {
+            self.client
+                .update_requests_request_name(self.organization.clone(), body, request_name)
+        }
+        #[doc = "Delete a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn delete_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> delete_requests_request_name::RequestBuilder {
+            self.client
+                .delete_requests_request_name(self.organization.clone(), request_name)
+        }
+        #[doc = "Get a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn get_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> get_requests_request_id::RequestBuilder {
+            self.client
+                .get_requests_request_id(self.organization.clone(), request_id)
+        }
+        #[doc = "Create debug entries for a symbol request as specified by its identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_id_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_id: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_id_debug_entries::RequestBuilder {
+            self.client.create_requests_request_id_debug_entries(
+                self.organization.clone(),
+                body,
+                request_id,
+                collection,
+            )
+        }
+        #[doc = "Update a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn update_requests_request_id(
+            &self,
+            body: impl Into<models::Request>,
+            request_id: impl Into<String>,
+        ) -> update_requests_request_id::RequestBuilder {
+            self.client
+                .update_requests_request_id(self.organization.clone(), body, request_id)
+        }
+        #[doc = "Delete a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn delete_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> delete_requests_request_id::RequestBuilder {
+            self.client
+                .delete_requests_request_id(self.organization.clone(), request_id)
+        }
+    }
     pub mod create_requests_request_name_debug_entries {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -385,9 +801,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let request_name = &this.request_name;
@@ -496,9 +913,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let request_name = &this.request_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -602,9 +1020,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -706,9 +1125,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let request_name = &this.request_name;
@@ -787,9 +1207,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let request_name = &this.request_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -886,9 +1307,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -991,9 +1413,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let collection = &this.collection;
@@ -1100,9 +1523,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1178,9 +1602,10 @@ pub mod requests {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(synchronous) = &this.synchronous {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1213,6 +1638,31 @@ pub mod availability {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Check the availability of symbol service. This includes checking for feature flag, and possibly license in future. Note this is NOT an anonymous endpoint, and the caller will be redirected to authentication before hitting it."]
+        pub fn check_availability(&self) -> check_availability::RequestBuilder {
+            self.client.check_availability(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Check the availability of symbol service. This includes checking for feature flag, and possibly license in future. Note this is NOT an anonymous endpoint, and the caller will be redirected to authentication before hitting it."]
+        pub fn check_availability(&self) -> check_availability::RequestBuilder {
+            self.client.check_availability(self.organization.clone())
+        }
+    }
     pub mod check_availability {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1258,9 +1708,10 @@ pub mod availability {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1301,6 +1752,45 @@ pub mod client {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get client version information."]
+        pub fn head_client(&self) -> head_client::RequestBuilder {
+            self.client.head_client(self.organization.clone())
+        }
+        #[doc = "Get the client package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `client_type`: Either \"EXE\" for a zip file containing a Windows symbol client (a.k.a. symbol.exe) along with dependencies, or \"TASK\" for a VSTS task that can be run on a VSTS build agent. All the other values are invalid. The parameter is case-insensitive."]
+        pub fn get(&self, client_type: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), client_type)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get client version information."]
+        pub fn head_client(&self) -> head_client::RequestBuilder {
+            self.client.head_client(self.organization.clone())
+        }
+        #[doc = "Get the client package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `client_type`: Either \"EXE\" for a zip file containing a Windows symbol client (a.k.a. symbol.exe) along with dependencies, or \"TASK\" for a VSTS task that can be run on a VSTS build agent. All the other values are invalid. The parameter is case-insensitive."]
+        pub fn get(&self, client_type: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), client_type)
+        }
+    }
     pub mod head_client {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1346,9 +1836,10 @@ pub mod client {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1381,6 +1872,25 @@ pub mod client {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1436,9 +1946,10 @@ pub mod client {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1484,6 +1995,49 @@ pub mod contents {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a stitched debug entry for a symbol request as specified by symbol request identifier and debug entry identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `debug_entry_id`: The debug entry identifier."]
+        pub fn get(
+            &self,
+            request_id: impl Into<String>,
+            debug_entry_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), request_id, debug_entry_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a stitched debug entry for a symbol request as specified by symbol request identifier and debug entry identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `debug_entry_id`: The debug entry identifier."]
+        pub fn get(
+            &self,
+            request_id: impl Into<String>,
+            debug_entry_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), request_id, debug_entry_id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1533,9 +2087,10 @@ pub mod contents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1566,6 +2121,39 @@ pub mod symsrv {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Given a client key, returns the best matched debug entry."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `debug_entry_client_key`: A \"client key\" used by both ends of Microsoft's symbol protocol to identify a debug entry. The semantics of client key is governed by symsrv and is beyond the scope of this documentation."]
+        pub fn get(&self, debug_entry_client_key: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), debug_entry_client_key)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Given a client key, returns the best matched debug entry."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `debug_entry_client_key`: A \"client key\" used by both ends of Microsoft's symbol protocol to identify a debug entry. The semantics of client key is governed by symsrv and is beyond the scope of this documentation."]
+        pub fn get(&self, debug_entry_client_key: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), debug_entry_client_key)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1613,9 +2201,10 @@ pub mod symsrv {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn attachments_client(&self) -> attachments::Client {
@@ -152,6 +221,180 @@ impl Client {
         test_suites::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn code_coverage_client(&self) -> code_coverage::OrganizationClient {
+        code_coverage::OrganizationClient {
+            client: self.client.code_coverage_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn iterations_client(&self) -> iterations::OrganizationClient {
+        iterations::OrganizationClient {
+            client: self.client.iterations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn points_client(&self) -> points::OrganizationClient {
+        points::OrganizationClient {
+            client: self.client.points_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn result_retention_settings_client(
+        &self,
+    ) -> result_retention_settings::OrganizationClient {
+        result_retention_settings::OrganizationClient {
+            client: self.client.result_retention_settings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn results_client(&self) -> results::OrganizationClient {
+        results::OrganizationClient {
+            client: self.client.results_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn runs_client(&self) -> runs::OrganizationClient {
+        runs::OrganizationClient {
+            client: self.client.runs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn session_client(&self) -> session::OrganizationClient {
+        session::OrganizationClient {
+            client: self.client.session_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_cases_client(&self) -> test_cases::OrganizationClient {
+        test_cases::OrganizationClient {
+            client: self.client.test_cases_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_history_client(&self) -> test_history::OrganizationClient {
+        test_history::OrganizationClient {
+            client: self.client.test_history_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_suites_client(&self) -> test_suites::OrganizationClient {
+        test_suites::OrganizationClient {
+            client: self.client.test_suites_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn code_coverage_client(&self) -> code_coverage::ProjectClient {
+        code_coverage::ProjectClient {
+            client: self.client.code_coverage_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn iterations_client(&self) -> iterations::ProjectClient {
+        iterations::ProjectClient {
+            client: self.client.iterations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn points_client(&self) -> points::ProjectClient {
+        points::ProjectClient {
+            client: self.client.points_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn result_retention_settings_client(&self) -> result_retention_settings::ProjectClient {
+        result_retention_settings::ProjectClient {
+            client: self.client.result_retention_settings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn results_client(&self) -> results::ProjectClient {
+        results::ProjectClient {
+            client: self.client.results_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn runs_client(&self) -> runs::ProjectClient {
+        runs::ProjectClient {
+            client: self.client.runs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn session_client(&self) -> session::ProjectClient {
+        session::ProjectClient {
+            client: self.client.session_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_cases_client(&self) -> test_cases::ProjectClient {
+        test_cases::ProjectClient {
+            client: self.client.test_cases_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_history_client(&self) -> test_history::ProjectClient {
+        test_history::ProjectClient {
+            client: self.client.test_history_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_suites_client(&self) -> test_suites::ProjectClient {
+        test_suites::ProjectClient {
+            client: self.client.test_suites_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod runs {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -316,6 +559,188 @@ pub mod runs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            project: impl Into<String>,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            self.client.query(
+                self.organization.clone(),
+                project,
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        #[doc = "Get a list of test runs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create new test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunCreateModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::RunCreateModel>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_by_id(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_by_id::RequestBuilder {
+            self.client
+                .get_test_run_by_id(self.organization.clone(), project, run_id)
+        }
+        #[doc = "Update test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunUpdateModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Delete a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to delete."]
+        pub fn delete(&self, project: impl Into<String>, run_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, run_id)
+        }
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_statistics(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_statistics::RequestBuilder {
+            self.client
+                .get_test_run_statistics(self.organization.clone(), project, run_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            self.client.query(
+                self.organization.clone(),
+                self.project.clone(),
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        #[doc = "Get a list of test runs."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create new test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunCreateModel"]
+        pub fn create(&self, body: impl Into<models::RunCreateModel>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_by_id(&self, run_id: i32) -> get_test_run_by_id::RequestBuilder {
+            self.client
+                .get_test_run_by_id(self.organization.clone(), self.project.clone(), run_id)
+        }
+        #[doc = "Update test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunUpdateModel"]
+        #[doc = "* `run_id`: ID of the run to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Delete a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to delete."]
+        pub fn delete(&self, run_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), run_id)
+        }
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_statistics(
+            &self,
+            run_id: i32,
+        ) -> get_test_run_statistics::RequestBuilder {
+            self.client.get_test_run_statistics(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -483,9 +908,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let min_last_updated_date = &this.min_last_updated_date;
                         let formatted_date_time =
                             crate::date_time::format_date_time(min_last_updated_date)?;
@@ -572,6 +998,24 @@ pub mod runs {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestRunList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -713,9 +1157,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(build_uri) = &this.build_uri {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -760,6 +1205,34 @@ pub mod runs {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestRunList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::TestRunList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestRun>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::TestRunList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -855,9 +1328,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -967,9 +1441,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_details) = &this.include_details {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1078,9 +1553,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1152,9 +1628,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1245,9 +1722,10 @@ pub mod runs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1491,31 +1969,401 @@ pub mod attachments {
             }
         }
     }
-    pub mod get_test_sub_result_attachments {
-        use super::models;
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::TestAttachmentList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::TestAttachmentList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get list of test sub result attachments"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            self.client.get_test_sub_result_attachments(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment Request Model."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub results against which attachment has to be uploaded."]
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            self.client.create_test_sub_result_attachment(
+                self.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Download a test sub result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded"]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            self.client.get_test_sub_result_attachment_zip(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Get list of test run attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run."]
+        pub fn get_test_run_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            self.client
+                .get_test_run_attachments(self.organization.clone(), project, run_id)
+        }
+        #[doc = "Attach a file to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run against which attachment has to be uploaded."]
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            self.client
+                .create_test_run_attachment(self.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Download a test run attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test run attachment to be downloaded."]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            self.client.get_test_run_attachment_zip(
+                self.organization.clone(),
+                project,
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Get list of test result attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result."]
+        pub fn get_test_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            self.client.get_test_result_attachments(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result against which attachment has to be uploaded."]
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            self.client.create_test_result_attachment(
+                self.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Download a test result attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the testCaseResultId."]
+        #[doc = "* `test_case_result_id`: ID of the test result whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded."]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            self.client.get_test_result_attachment_zip(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get list of test sub result attachments"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            self.client.get_test_sub_result_attachments(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment Request Model."]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub results against which attachment has to be uploaded."]
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            self.client.create_test_sub_result_attachment(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Download a test sub result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded"]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            self.client.get_test_sub_result_attachment_zip(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Get list of test run attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run."]
+        pub fn get_test_run_attachments(
+            &self,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            self.client.get_test_run_attachments(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Attach a file to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `run_id`: ID of the test run against which attachment has to be uploaded."]
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            self.client.create_test_run_attachment(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Download a test run attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test run attachment to be downloaded."]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            self.client.get_test_run_attachment_zip(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Get list of test result attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result."]
+        pub fn get_test_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            self.client.get_test_result_attachments(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result against which attachment has to be uploaded."]
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            self.client.create_test_result_attachment(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Download a test result attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the testCaseResultId."]
+        #[doc = "* `test_case_result_id`: ID of the test result whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded."]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            self.client.get_test_result_attachment_zip(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+    }
+    pub mod get_test_sub_result_attachments {
+        use super::models;
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::TestAttachmentList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::TestAttachmentList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1577,9 +2425,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_sub_result_id = &this.test_sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1692,9 +2541,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let test_sub_result_id = &this.test_sub_result_id;
@@ -1747,6 +2597,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1801,9 +2670,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_sub_result_id = &this.test_sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1910,9 +2780,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2017,9 +2888,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2068,6 +2940,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2127,9 +3018,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2234,9 +3126,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2343,9 +3236,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2394,6 +3288,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2455,9 +3368,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2528,6 +3442,91 @@ pub mod code_coverage {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get code coverage data for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: ID of the build for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_build_code_coverage(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            self.client
+                .get_build_code_coverage(self.organization.clone(), project, build_id, flags)
+        }
+        #[doc = "Get code coverage data for a test run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_test_run_code_coverage(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            self.client.get_test_run_code_coverage(
+                self.organization.clone(),
+                project,
+                run_id,
+                flags,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get code coverage data for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: ID of the build for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_build_code_coverage(
+            &self,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            self.client.get_build_code_coverage(
+                self.organization.clone(),
+                self.project.clone(),
+                build_id,
+                flags,
+            )
+        }
+        #[doc = "Get code coverage data for a test run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_test_run_code_coverage(
+            &self,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            self.client.get_test_run_code_coverage(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                flags,
+            )
+        }
+    }
     pub mod get_build_code_coverage {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2610,9 +3609,10 @@ pub mod code_coverage {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let build_id = &this.build_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2725,9 +3725,10 @@ pub mod code_coverage {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let flags = &this.flags;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2755,70 +3756,163 @@ pub mod code_coverage {
             }
         }
     }
-}
-pub mod points {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod points {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get a list of test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                wit_fields: None,
+                configuration_id: None,
+                test_case_id: None,
+                test_point_ids: None,
+                include_point_details: None,
+                skip: None,
+                top: None,
+            }
+        }
+        #[doc = "Get a test point."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the point."]
+        #[doc = "* `point_ids`: ID of the test point to get."]
+        pub fn get_point(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: i32,
+        ) -> get_point::RequestBuilder {
+            get_point::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                point_ids,
+                wit_fields: None,
+            }
+        }
+        #[doc = "Update test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Data to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PointUpdateModel>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                point_ids: point_ids.into(),
+            }
+        }
+        #[doc = "Get test points using query."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: TestPointsQuery to get test points."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_points_by_query(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestPointsQuery>,
+            project: impl Into<String>,
+        ) -> get_points_by_query::RequestBuilder {
+            get_points_by_query::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                skip: None,
+                top: None,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
         #[doc = "Get a list of test points."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
         #[doc = "* `suite_id`: ID of the suite that contains the points."]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                plan_id,
-                suite_id,
-                wit_fields: None,
-                configuration_id: None,
-                test_case_id: None,
-                test_point_ids: None,
-                include_point_details: None,
-                skip: None,
-                top: None,
-            }
+            self.client
+                .list(self.organization.clone(), project, plan_id, suite_id)
         }
         #[doc = "Get a test point."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
         #[doc = "* `suite_id`: ID of the suite that contains the point."]
         #[doc = "* `point_ids`: ID of the test point to get."]
         pub fn get_point(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             point_ids: i32,
         ) -> get_point::RequestBuilder {
-            get_point::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get_point(
+                self.organization.clone(),
+                project,
                 plan_id,
                 suite_id,
                 point_ids,
-                wit_fields: None,
-            }
+            )
         }
         #[doc = "Update test points."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Data to update."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
@@ -2826,43 +3920,109 @@ pub mod points {
         #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PointUpdateModel>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             point_ids: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
                 plan_id,
                 suite_id,
-                point_ids: point_ids.into(),
-            }
+                point_ids,
+            )
         }
         #[doc = "Get test points using query."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: TestPointsQuery to get test points."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_points_by_query(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestPointsQuery>,
             project: impl Into<String>,
         ) -> get_points_by_query::RequestBuilder {
-            get_points_by_query::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                skip: None,
-                top: None,
-            }
+            self.client
+                .get_points_by_query(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        pub fn list(&self, plan_id: i32, suite_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a test point."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the point."]
+        #[doc = "* `point_ids`: ID of the test point to get."]
+        pub fn get_point(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: i32,
+        ) -> get_point::RequestBuilder {
+            self.client.get_point(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                point_ids,
+            )
+        }
+        #[doc = "Update test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Data to update."]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PointUpdateModel>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                point_ids,
+            )
+        }
+        #[doc = "Get test points using query."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestPointsQuery to get test points."]
+        pub fn get_points_by_query(
+            &self,
+            body: impl Into<models::TestPointsQuery>,
+        ) -> get_points_by_query::RequestBuilder {
+            self.client
+                .get_points_by_query(self.organization.clone(), body, self.project.clone())
         }
     }
     pub mod list {
@@ -2990,9 +4150,10 @@ pub mod points {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(wit_fields) = &this.wit_fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3035,6 +4196,36 @@ pub mod points {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestPointList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TestPointList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestPoint>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::TestPointList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPointList>;
@@ -3141,9 +4332,10 @@ pub mod points {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(wit_fields) = &this.wit_fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3256,9 +4448,10 @@ pub mod points {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3374,9 +4567,10 @@ pub mod points {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(skip) = &this.skip {
@@ -3428,68 +4622,187 @@ pub mod test_suites {
             plan_id: i32,
             suite_id: i32,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                plan_id,
-                suite_id,
-            }
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+            }
+        }
+        #[doc = "Get a specific test case in a test suite with test case id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite that contains the test case."]
+        #[doc = "* `test_case_ids`: ID of the test case to get."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            }
+        }
+        #[doc = "Add test cases to suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn add(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> add::RequestBuilder {
+            add::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                test_case_ids: test_case_ids.into(),
+            }
+        }
+        #[doc = "Updates the properties of the test case association in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Model for updation of the properties of test case suite association."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::SuiteTestCaseUpdateModel>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                test_case_ids: test_case_ids.into(),
+            }
+        }
+        #[doc = "The test points associated with the test cases are removed from the test suite. The test case work item is not deleted from the system. See test cases resource to delete a test case permanently."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to remove from the suite."]
+        pub fn remove_test_cases_from_suite_url(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite_url::RequestBuilder {
+            remove_test_cases_from_suite_url::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                test_case_ids: test_case_ids.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all test cases in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, plan_id, suite_id)
         }
         #[doc = "Get a specific test case in a test suite with test case id."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
         #[doc = "* `suite_id`: ID of the suite that contains the test case."]
         #[doc = "* `test_case_ids`: ID of the test case to get."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             test_case_ids: i32,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get(
+                self.organization.clone(),
+                project,
                 plan_id,
                 suite_id,
                 test_case_ids,
-            }
+            )
         }
         #[doc = "Add test cases to suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
         #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
         #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
         pub fn add(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             test_case_ids: impl Into<String>,
         ) -> add::RequestBuilder {
-            add::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.add(
+                self.organization.clone(),
+                project,
                 plan_id,
                 suite_id,
-                test_case_ids: test_case_ids.into(),
-            }
+                test_case_ids,
+            )
         }
         #[doc = "Updates the properties of the test case association in a suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Model for updation of the properties of test case suite association."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
@@ -3497,47 +4810,142 @@ pub mod test_suites {
         #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::SuiteTestCaseUpdateModel>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             test_case_ids: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
                 plan_id,
                 suite_id,
-                test_case_ids: test_case_ids.into(),
-            }
+                test_case_ids,
+            )
         }
         #[doc = "The test points associated with the test cases are removed from the test suite. The test case work item is not deleted from the system. See test cases resource to delete a test case permanently."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
         #[doc = "* `suite_id`: ID of the suite to get."]
         #[doc = "* `test_case_ids`: IDs of the test cases to remove from the suite."]
         pub fn remove_test_cases_from_suite_url(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             test_case_ids: impl Into<String>,
         ) -> remove_test_cases_from_suite_url::RequestBuilder {
-            remove_test_cases_from_suite_url::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.remove_test_cases_from_suite_url(
+                self.organization.clone(),
+                project,
                 plan_id,
                 suite_id,
-                test_case_ids: test_case_ids.into(),
-            }
+                test_case_ids,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all test cases in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn list(&self, plan_id: i32, suite_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a specific test case in a test suite with test case id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite that contains the test case."]
+        #[doc = "* `test_case_ids`: ID of the test case to get."]
+        pub fn get(&self, plan_id: i32, suite_id: i32, test_case_ids: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Add test cases to suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn add(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Updates the properties of the test case association in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Model for updation of the properties of test case suite association."]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn update(
+            &self,
+            body: impl Into<models::SuiteTestCaseUpdateModel>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "The test points associated with the test cases are removed from the test suite. The test case work item is not deleted from the system. See test cases resource to delete a test case permanently."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to remove from the suite."]
+        pub fn remove_test_cases_from_suite_url(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite_url::RequestBuilder {
+            self.client.remove_test_cases_from_suite_url(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
         }
     }
     pub mod list {
@@ -3624,9 +5032,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3733,9 +5142,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3843,9 +5253,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
                         req.set_body(req_body);
@@ -3955,9 +5366,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4033,9 +5445,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4085,6 +5498,58 @@ pub mod result_retention_settings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+        #[doc = "Update test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test result retention settings details to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ResultRetentionSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get test result retention settings"]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Update test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test result retention settings details to be updated"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ResultRetentionSettings>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4165,9 +5630,10 @@ pub mod result_retention_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4272,9 +5738,10 @@ pub mod result_retention_settings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4324,6 +5791,43 @@ pub mod test_history {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestHistoryQuery>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        pub fn query(&self, body: impl Into<models::TestHistoryQuery>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4405,9 +5909,10 @@ pub mod test_history {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4453,77 +5958,202 @@ pub mod results {
                 project: project.into(),
                 run_id,
                 details_to_include: None,
-                skip: None,
-                top: None,
-                outcomes: None,
+                skip: None,
+                top: None,
+                outcomes: None,
+            }
+        }
+        #[doc = "Add test results to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: List of test results to add."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID into which test results to add."]
+        pub fn add(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> add::RequestBuilder {
+            add::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Update test results in a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: List of test results to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID whose test results to update."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Get a test result for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID of a test result to fetch."]
+        #[doc = "* `test_case_result_id`: Test result ID."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                run_id,
+                test_case_result_id,
+                details_to_include: None,
             }
         }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get test results for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID of test results to fetch."]
+        pub fn list(&self, project: impl Into<String>, run_id: i32) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, run_id)
+        }
         #[doc = "Add test results to a test run."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: List of test results to add."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test run ID into which test results to add."]
         pub fn add(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::TestCaseResult>,
             project: impl Into<String>,
             run_id: i32,
         ) -> add::RequestBuilder {
-            add::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body,
-                project: project.into(),
-                run_id,
-            }
+            self.client
+                .add(self.organization.clone(), body, project, run_id)
         }
         #[doc = "Update test results in a test run."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: List of test results to update."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test run ID whose test results to update."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::TestCaseResult>,
             project: impl Into<String>,
             run_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body,
-                project: project.into(),
-                run_id,
-            }
+            self.client
+                .update(self.organization.clone(), body, project, run_id)
         }
         #[doc = "Get a test result for a test run."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test run ID of a test result to fetch."]
         #[doc = "* `test_case_result_id`: Test result ID."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             run_id: i32,
             test_case_result_id: i32,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.get(
+                self.organization.clone(),
+                project,
                 run_id,
                 test_case_result_id,
-                details_to_include: None,
-            }
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get test results for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Test run ID of test results to fetch."]
+        pub fn list(&self, run_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), run_id)
+        }
+        #[doc = "Add test results to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to add."]
+        #[doc = "* `run_id`: Test run ID into which test results to add."]
+        pub fn add(&self, body: Vec<models::TestCaseResult>, run_id: i32) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Update test results in a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to update."]
+        #[doc = "* `run_id`: Test run ID whose test results to update."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Get a test result for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Test run ID of a test result to fetch."]
+        #[doc = "* `test_case_result_id`: Test result ID."]
+        pub fn get(&self, run_id: i32, test_case_result_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
         }
     }
     pub mod list {
@@ -4632,9 +6262,10 @@ pub mod results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(details_to_include) = &this.details_to_include {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4661,6 +6292,37 @@ pub mod results {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestCaseResultList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TestCaseResultList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestCaseResult>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TestCaseResultList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseResultList>;
@@ -4759,9 +6421,10 @@ pub mod results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4867,9 +6530,10 @@ pub mod results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4981,9 +6645,10 @@ pub mod results {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(details_to_include) = &this.details_to_include {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5065,6 +6730,97 @@ pub mod iterations {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get iterations for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Get iteration for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        #[doc = "* `iteration_id`: Id of the test results Iteration."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get iterations for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        pub fn list(&self, run_id: i32, test_case_result_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Get iteration for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        #[doc = "* `iteration_id`: Id of the test results Iteration."]
+        pub fn get(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5157,9 +6913,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_action_results) = &this.include_action_results {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeActionResults",
@@ -5281,9 +7038,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_action_results) = &this.include_action_results {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "includeActionResults",
@@ -5338,6 +7096,47 @@ pub mod test_cases {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_id`: Id of test case to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_case_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, test_case_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: Id of test case to delete."]
+        pub fn delete(&self, test_case_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                test_case_id,
+            )
+        }
+    }
     pub mod delete {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5387,9 +7186,10 @@ pub mod test_cases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5472,6 +7272,99 @@ pub mod session {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of test sessions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Create a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for creation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSession>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Update a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSession>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of test sessions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Create a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for creation"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSession>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), team)
+        }
+        #[doc = "Update a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for update"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSession>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), team)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5587,9 +7480,10 @@ pub mod session {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(period) = &this.period {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5722,9 +7616,10 @@ pub mod session {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5829,9 +7724,10 @@ pub mod session {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn configurations_client(&self) -> configurations::Client {
@@ -152,6 +221,178 @@ impl Client {
         variables::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn configurations_client(&self) -> configurations::OrganizationClient {
+        configurations::OrganizationClient {
+            client: self.client.configurations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn suite_test_case_client(&self) -> suite_test_case::OrganizationClient {
+        suite_test_case::OrganizationClient {
+            client: self.client.suite_test_case_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_case_clone_client(&self) -> test_case_clone::OrganizationClient {
+        test_case_clone::OrganizationClient {
+            client: self.client.test_case_clone_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_cases_client(&self) -> test_cases::OrganizationClient {
+        test_cases::OrganizationClient {
+            client: self.client.test_cases_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_plan_clone_client(&self) -> test_plan_clone::OrganizationClient {
+        test_plan_clone::OrganizationClient {
+            client: self.client.test_plan_clone_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_plans_client(&self) -> test_plans::OrganizationClient {
+        test_plans::OrganizationClient {
+            client: self.client.test_plans_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_point_client(&self) -> test_point::OrganizationClient {
+        test_point::OrganizationClient {
+            client: self.client.test_point_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_suite_clone_client(&self) -> test_suite_clone::OrganizationClient {
+        test_suite_clone::OrganizationClient {
+            client: self.client.test_suite_clone_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_suite_entry_client(&self) -> test_suite_entry::OrganizationClient {
+        test_suite_entry::OrganizationClient {
+            client: self.client.test_suite_entry_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn test_suites_client(&self) -> test_suites::OrganizationClient {
+        test_suites::OrganizationClient {
+            client: self.client.test_suites_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn variables_client(&self) -> variables::OrganizationClient {
+        variables::OrganizationClient {
+            client: self.client.variables_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn configurations_client(&self) -> configurations::ProjectClient {
+        configurations::ProjectClient {
+            client: self.client.configurations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn suite_test_case_client(&self) -> suite_test_case::ProjectClient {
+        suite_test_case::ProjectClient {
+            client: self.client.suite_test_case_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_case_clone_client(&self) -> test_case_clone::ProjectClient {
+        test_case_clone::ProjectClient {
+            client: self.client.test_case_clone_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_cases_client(&self) -> test_cases::ProjectClient {
+        test_cases::ProjectClient {
+            client: self.client.test_cases_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_plan_clone_client(&self) -> test_plan_clone::ProjectClient {
+        test_plan_clone::ProjectClient {
+            client: self.client.test_plan_clone_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_plans_client(&self) -> test_plans::ProjectClient {
+        test_plans::ProjectClient {
+            client: self.client.test_plans_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_point_client(&self) -> test_point::ProjectClient {
+        test_point::ProjectClient {
+            client: self.client.test_point_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_suite_clone_client(&self) -> test_suite_clone::ProjectClient {
+        test_suite_clone::ProjectClient {
+            client: self.client.test_suite_clone_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_suite_entry_client(&self) -> test_suite_entry::ProjectClient {
+        test_suite_entry::ProjectClient {
+            client: self.client.test_suite_entry_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn test_suites_client(&self) -> test_suites::ProjectClient {
+        test_suites::ProjectClient {
+            client: self.client.test_suites_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn variables_client(&self) -> variables::ProjectClient {
+        variables::ProjectClient {
+            client: self.client.variables_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod suite_test_case {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -308,10 +549,259 @@ pub mod suite_test_case {
                 project: project.into(),
                 plan_id,
                 suite_id,
-                test_case_id: test_case_id.into(),
-                wit_fields: None,
-                return_identity_ref: None,
-            }
+                test_case_id: test_case_id.into(),
+                wit_fields: None,
+                return_identity_ref: None,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_case_ids`: Test Case Ids to be removed."]
+        pub fn remove_test_cases_from_suite(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite::RequestBuilder {
+            self.client.remove_test_cases_from_suite(
+                self.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Get Test Case List return those test cases which have all the configuration Ids as mentioned in the optional parameter. If configuration Ids is null, it return all the test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        pub fn get_test_case_list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_test_case_list::RequestBuilder {
+            self.client
+                .get_test_case_list(self.organization.clone(), project, plan_id, suite_id)
+        }
+        #[doc = "Add test cases to a suite with specified configurations"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be added."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be added."]
+        pub fn add(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, project, plan_id, suite_id)
+        }
+        #[doc = "Update the configurations for test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be updated."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, plan_id, suite_id)
+        }
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided. This API can be used to remove a larger number of test cases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_ids`: Comma separated string of Test Case Ids to be removed."]
+        pub fn remove_test_cases_list_from_suite(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_ids: impl Into<String>,
+        ) -> remove_test_cases_list_from_suite::RequestBuilder {
+            self.client.remove_test_cases_list_from_suite(
+                self.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_ids,
+            )
+        }
+        #[doc = "Get a particular Test Case from a Suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        #[doc = "* `test_case_id`: Test Case Id to be fetched."]
+        pub fn get_test_case(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_id: impl Into<String>,
+        ) -> get_test_case::RequestBuilder {
+            self.client.get_test_case(
+                self.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_case_ids`: Test Case Ids to be removed."]
+        pub fn remove_test_cases_from_suite(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite::RequestBuilder {
+            self.client.remove_test_cases_from_suite(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Get Test Case List return those test cases which have all the configuration Ids as mentioned in the optional parameter. If configuration Ids is null, it return all the test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        pub fn get_test_case_list(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_test_case_list::RequestBuilder {
+            self.client.get_test_case_list(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Add test cases to a suite with specified configurations"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be added."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be added."]
+        pub fn add(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Update the configurations for test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be updated."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided. This API can be used to remove a larger number of test cases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_ids`: Comma separated string of Test Case Ids to be removed."]
+        pub fn remove_test_cases_list_from_suite(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_ids: impl Into<String>,
+        ) -> remove_test_cases_list_from_suite::RequestBuilder {
+            self.client.remove_test_cases_list_from_suite(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_ids,
+            )
+        }
+        #[doc = "Get a particular Test Case from a Suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        #[doc = "* `test_case_id`: Test Case Id to be fetched."]
+        pub fn get_test_case(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_id: impl Into<String>,
+        ) -> get_test_case::RequestBuilder {
+            self.client.get_test_case(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_id,
+            )
         }
     }
     pub mod remove_test_cases_from_suite {
@@ -367,9 +857,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_case_ids = &this.test_case_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -512,9 +1003,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(test_ids) = &this.test_ids {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -561,6 +1053,24 @@ pub mod suite_test_case {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestCaseList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseList>;
@@ -660,9 +1170,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -769,9 +1280,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -846,9 +1358,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_ids = &this.test_ids;
                         req.url_mut()
                             .query_pairs_mut()
@@ -958,9 +1471,10 @@ pub mod suite_test_case {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(wit_fields) = &this.wit_fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1080,6 +1594,134 @@ pub mod test_point {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all the points inside a suite based on some filters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested"]
+        pub fn get_points_list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_points_list::RequestBuilder {
+            self.client
+                .get_points_list(self.organization.clone(), project, plan_id, suite_id)
+        }
+        #[doc = "Get a particular Test Point from a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        #[doc = "* `point_id`: ID of test point to be fetched."]
+        pub fn get_points(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_id: impl Into<String>,
+        ) -> get_points::RequestBuilder {
+            self.client.get_points(
+                self.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                point_id,
+            )
+        }
+        #[doc = "Update Test Points. This is used to Reset test point to active, update the outcome of a test point or update the tester of a test point"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A TestPointUpdateParams Object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestPointUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, plan_id, suite_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all the points inside a suite based on some filters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested"]
+        pub fn get_points_list(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_points_list::RequestBuilder {
+            self.client.get_points_list(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a particular Test Point from a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        #[doc = "* `point_id`: ID of test point to be fetched."]
+        pub fn get_points(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            point_id: impl Into<String>,
+        ) -> get_points::RequestBuilder {
+            self.client.get_points(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+                point_id,
+            )
+        }
+        #[doc = "Update Test Points. This is used to Reset test point to active, update the outcome of a test point or update the tester of a test point"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A TestPointUpdateParams Object."]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestPointUpdateParams>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+    }
     pub mod get_points_list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1199,9 +1841,10 @@ pub mod test_point {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(test_point_ids) = &this.test_point_ids {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1239,6 +1882,24 @@ pub mod test_point {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestPointList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPointList>;
@@ -1350,9 +2011,10 @@ pub mod test_point {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let point_id = &this.point_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1485,9 +2147,10 @@ pub mod test_point {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(include_point_details) = &this.include_point_details {
@@ -1521,140 +2184,331 @@ pub mod test_point {
             }
         }
     }
-}
-pub mod test_suites {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod test_suites {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Find the list of all test suites in which a given test case is present. This is helpful if you need to find out which test suites are using a test case, when you need to make changes to a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `test_case_id`: ID of the test case for which suites need to be fetched."]
+        pub fn get_suites_by_test_case_id(
+            &self,
+            organization: impl Into<String>,
+            test_case_id: i32,
+        ) -> get_suites_by_test_case_id::RequestBuilder {
+            get_suites_by_test_case_id::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                test_case_id,
+            }
+        }
+        #[doc = "Get test suites for plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which suites are requested."]
+        pub fn get_test_suites_for_plan(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> get_test_suites_for_plan::RequestBuilder {
+            get_test_suites_for_plan::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                expand: None,
+                continuation_token: None,
+                as_tree_view: None,
+            }
+        }
+        #[doc = "Create test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Parameters for suite creation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestSuiteCreateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                plan_id,
+            }
+        }
+        #[doc = "Get test suite by suite id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                expand: None,
+            }
+        }
+        #[doc = "Update test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Parameters for suite updation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the parent suite."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestSuiteUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+            }
+        }
+        #[doc = "Delete test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to delete."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
         #[doc = "Find the list of all test suites in which a given test case is present. This is helpful if you need to find out which test suites are using a test case, when you need to make changes to a test case."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `test_case_id`: ID of the test case for which suites need to be fetched."]
         pub fn get_suites_by_test_case_id(
             &self,
-            organization: impl Into<String>,
             test_case_id: i32,
         ) -> get_suites_by_test_case_id::RequestBuilder {
-            get_suites_by_test_case_id::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                test_case_id,
-            }
+            self.client
+                .get_suites_by_test_case_id(self.organization.clone(), test_case_id)
         }
         #[doc = "Get test suites for plan."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan for which suites are requested."]
         pub fn get_test_suites_for_plan(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
         ) -> get_test_suites_for_plan::RequestBuilder {
-            get_test_suites_for_plan::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                plan_id,
-                expand: None,
-                continuation_token: None,
-                as_tree_view: None,
-            }
+            self.client
+                .get_test_suites_for_plan(self.organization.clone(), project, plan_id)
         }
         #[doc = "Create test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Parameters for suite creation"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestSuiteCreateParams>,
             project: impl Into<String>,
             plan_id: i32,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                plan_id,
-            }
+            self.client
+                .create(self.organization.clone(), body, project, plan_id)
         }
         #[doc = "Get test suite by suite id."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
         #[doc = "* `suite_id`: ID of the suite to get."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                plan_id,
-                suite_id,
-                expand: None,
-            }
+            self.client
+                .get(self.organization.clone(), project, plan_id, suite_id)
         }
         #[doc = "Update test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Parameters for suite updation"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
         #[doc = "* `suite_id`: ID of the parent suite."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestSuiteUpdateParams>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                plan_id,
-                suite_id,
-            }
+            self.client
+                .update(self.organization.clone(), body, project, plan_id, suite_id)
         }
         #[doc = "Delete test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
         #[doc = "* `suite_id`: ID of the test suite to delete."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client
+                .delete(self.organization.clone(), project, plan_id, suite_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Find the list of all test suites in which a given test case is present. This is helpful if you need to find out which test suites are using a test case, when you need to make changes to a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: ID of the test case for which suites need to be fetched."]
+        pub fn get_suites_by_test_case_id(
+            &self,
+            test_case_id: i32,
+        ) -> get_suites_by_test_case_id::RequestBuilder {
+            self.client
+                .get_suites_by_test_case_id(self.organization.clone(), test_case_id)
+        }
+        #[doc = "Get test suites for plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which suites are requested."]
+        pub fn get_test_suites_for_plan(
+            &self,
+            plan_id: i32,
+        ) -> get_test_suites_for_plan::RequestBuilder {
+            self.client.get_test_suites_for_plan(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Create test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite creation"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSuiteCreateParams>,
+            plan_id: i32,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Get test suite by suite id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn get(&self, plan_id: i32, suite_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
                 plan_id,
                 suite_id,
-            }
+            )
+        }
+        #[doc = "Update test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite updation"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the parent suite."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSuiteUpdateParams>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Delete test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to delete."]
+        pub fn delete(&self, plan_id: i32, suite_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                plan_id,
+                suite_id,
+            )
         }
     }
     pub mod get_suites_by_test_case_id {
@@ -1735,9 +2589,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_case_id = &this.test_case_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1862,9 +2717,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1886,6 +2742,24 @@ pub mod test_suites {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestSuiteList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestSuiteList>;
@@ -1983,9 +2857,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2097,9 +2972,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2210,9 +3086,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2286,9 +3163,10 @@ pub mod test_suites {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2377,26 +3255,162 @@ pub mod configurations {
                 organization: organization.into(),
                 project: project.into(),
                 test_configuartion_id,
-            }
+            }
+        }
+        #[doc = "Get a test configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuration_id`: ID of the test configuration to get."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            test_configuration_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                test_configuration_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of test configurations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a test configuration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Update a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            project: impl Into<String>,
+            test_configuartion_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Delete a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_configuartion_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, test_configuartion_id)
+        }
+        #[doc = "Get a test configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuration_id`: ID of the test configuration to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            test_configuration_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, test_configuration_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of test configurations."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a test configuration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Update a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            test_configuartion_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Delete a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to delete."]
+        pub fn delete(&self, test_configuartion_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                test_configuartion_id,
+            )
         }
         #[doc = "Get a test configuration"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `test_configuration_id`: ID of the test configuration to get."]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            test_configuration_id: i32,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn get(&self, test_configuration_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
                 test_configuration_id,
-            }
+            )
         }
     }
     pub mod list {
@@ -2485,9 +3499,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2499,6 +3514,26 @@ pub mod configurations {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::TestConfigurationList>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestConfigurationList>;
@@ -2597,9 +3632,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2704,9 +3740,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let test_configuartion_id = &this.test_configuartion_id;
@@ -2781,9 +3818,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_configuartion_id = &this.test_configuartion_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -2878,9 +3916,10 @@ pub mod configurations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3007,6 +4046,123 @@ pub mod test_plans {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of test plans"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanCreateParams object.TestPlanCreateParams"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestPlanCreateParams>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a test plan by Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to get."]
+        pub fn get(&self, project: impl Into<String>, plan_id: i32) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, plan_id)
+        }
+        #[doc = "Update a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanUpdateParams object.TestPlanUpdateParams"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestPlanUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, plan_id)
+        }
+        #[doc = "Delete a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to be deleted."]
+        pub fn delete(&self, project: impl Into<String>, plan_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, plan_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of test plans"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanCreateParams object.TestPlanCreateParams"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestPlanCreateParams>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a test plan by Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan to get."]
+        pub fn get(&self, plan_id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), plan_id)
+        }
+        #[doc = "Update a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanUpdateParams object.TestPlanUpdateParams"]
+        #[doc = "* `plan_id`: ID of the test plan to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestPlanUpdateParams>,
+            plan_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Delete a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan to be deleted."]
+        pub fn delete(&self, plan_id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), plan_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3110,9 +4266,10 @@ pub mod test_plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(owner) = &this.owner {
                             req.url_mut().query_pairs_mut().append_pair("owner", owner);
                         }
@@ -3138,6 +4295,24 @@ pub mod test_plans {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestPlanList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPlanList>;
@@ -3233,9 +4408,10 @@ pub mod test_plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3339,9 +4515,10 @@ pub mod test_plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3445,9 +4622,10 @@ pub mod test_plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3519,9 +4697,10 @@ pub mod test_plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3575,6 +4754,71 @@ pub mod test_plan_clone {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Clone test plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan Clone Request Body detail TestPlanCloneRequest"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_plan(
+            &self,
+            body: impl Into<models::CloneTestPlanParams>,
+            project: impl Into<String>,
+        ) -> clone_test_plan::RequestBuilder {
+            self.client
+                .clone_test_plan(self.organization.clone(), body, project)
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, clone_operation_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Clone test plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan Clone Request Body detail TestPlanCloneRequest"]
+        pub fn clone_test_plan(
+            &self,
+            body: impl Into<models::CloneTestPlanParams>,
+        ) -> clone_test_plan::RequestBuilder {
+            self.client
+                .clone_test_plan(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                clone_operation_id,
+            )
+        }
+    }
     pub mod clone_test_plan {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3664,9 +4908,10 @@ pub mod test_plan_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(deep_clone) = &this.deep_clone {
@@ -3780,9 +5025,10 @@ pub mod test_plan_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3806,52 +5052,118 @@ pub mod test_plan_clone {
             }
         }
     }
-}
-pub mod test_suite_entry {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod test_suite_entry {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get a list of test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent suite."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                suite_id,
+                suite_entry_type: None,
+            }
+        }
+        #[doc = "Reorder test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: List of SuiteEntry to reorder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent test suite."]
+        pub fn reorder_suite_entries(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::SuiteEntryUpdateParams>,
+            project: impl Into<String>,
+            suite_id: i32,
+        ) -> reorder_suite_entries::RequestBuilder {
+            reorder_suite_entries::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                suite_id,
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent suite."]
+        pub fn list(&self, project: impl Into<String>, suite_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, suite_id)
+        }
+        #[doc = "Reorder test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of SuiteEntry to reorder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent test suite."]
+        pub fn reorder_suite_entries(
+            &self,
+            body: Vec<models::SuiteEntryUpdateParams>,
+            project: impl Into<String>,
+            suite_id: i32,
+        ) -> reorder_suite_entries::RequestBuilder {
+            self.client
+                .reorder_suite_entries(self.organization.clone(), body, project, suite_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Get a list of test suite entries in the test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `suite_id`: Id of the parent suite."]
-        pub fn list(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            suite_id: i32,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                suite_id,
-                suite_entry_type: None,
-            }
+        pub fn list(&self, suite_id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), suite_id)
         }
         #[doc = "Reorder test suite entries in the test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: List of SuiteEntry to reorder."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `suite_id`: Id of the parent test suite."]
         pub fn reorder_suite_entries(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::SuiteEntryUpdateParams>,
-            project: impl Into<String>,
             suite_id: i32,
         ) -> reorder_suite_entries::RequestBuilder {
-            reorder_suite_entries::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            self.client.reorder_suite_entries(
+                self.organization.clone(),
                 body,
-                project: project.into(),
+                self.project.clone(),
                 suite_id,
-            }
+            )
         }
     }
     pub mod list {
@@ -3940,9 +5252,10 @@ pub mod test_suite_entry {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(suite_entry_type) = &this.suite_entry_type {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4051,9 +5364,10 @@ pub mod test_suite_entry {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4121,6 +5435,71 @@ pub mod test_suite_clone {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Clone test suite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Suite Clone Request Body detail TestSuiteCloneRequest"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_suite(
+            &self,
+            body: impl Into<models::CloneTestSuiteParams>,
+            project: impl Into<String>,
+        ) -> clone_test_suite::RequestBuilder {
+            self.client
+                .clone_test_suite(self.organization.clone(), body, project)
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, clone_operation_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Clone test suite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Suite Clone Request Body detail TestSuiteCloneRequest"]
+        pub fn clone_test_suite(
+            &self,
+            body: impl Into<models::CloneTestSuiteParams>,
+        ) -> clone_test_suite::RequestBuilder {
+            self.client
+                .clone_test_suite(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                clone_operation_id,
+            )
+        }
+    }
     pub mod clone_test_suite {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4210,9 +5589,10 @@ pub mod test_suite_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(deep_clone) = &this.deep_clone {
@@ -4326,9 +5706,10 @@ pub mod test_suite_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4377,6 +5758,47 @@ pub mod test_cases {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_id`: Id of test case to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_case_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, test_case_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: Id of test case to be deleted."]
+        pub fn delete(&self, test_case_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                test_case_id,
+            )
+        }
+    }
     pub mod delete {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4426,9 +5848,10 @@ pub mod test_cases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4479,6 +5902,65 @@ pub mod test_case_clone {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_case(
+            &self,
+            body: impl Into<models::CloneTestCaseParams>,
+            project: impl Into<String>,
+        ) -> clone_test_case::RequestBuilder {
+            self.client
+                .clone_test_case(self.organization.clone(), body, project)
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, clone_operation_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn clone_test_case(
+            &self,
+            body: impl Into<models::CloneTestCaseParams>,
+        ) -> clone_test_case::RequestBuilder {
+            self.client
+                .clone_test_case(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                clone_operation_id,
+            )
+        }
+    }
     pub mod clone_test_case {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4562,9 +6044,10 @@ pub mod test_case_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4673,9 +6156,10 @@ pub mod test_case_clone {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4801,6 +6285,138 @@ pub mod variables {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of test variables."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Create a test variable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, test_variable_id)
+        }
+        #[doc = "Update a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, test_variable_id)
+        }
+        #[doc = "Delete a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, test_variable_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of test variables."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a test variable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_variable_id`: ID of the test variable to get."]
+        pub fn get(&self, test_variable_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                test_variable_id,
+            )
+        }
+        #[doc = "Update a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `test_variable_id`: ID of the test variable to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            test_variable_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                test_variable_id,
+            )
+        }
+        #[doc = "Delete a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_variable_id`: ID of the test variable to delete."]
+        pub fn delete(&self, test_variable_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                test_variable_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4887,9 +6503,10 @@ pub mod variables {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4901,6 +6518,24 @@ pub mod variables {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestVariableList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestVariableList>;
@@ -4996,9 +6631,10 @@ pub mod variables {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5102,9 +6738,10 @@ pub mod variables {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5208,9 +6845,10 @@ pub mod variables {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5282,9 +6920,10 @@ pub mod variables {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://vstmr.dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn result_meta_data_client(&self) -> result_meta_data::Client {
@@ -128,6 +197,74 @@ impl Client {
         testlogstoreendpoint::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn result_meta_data_client(&self) -> result_meta_data::OrganizationClient {
+        result_meta_data::OrganizationClient {
+            client: self.client.result_meta_data_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn testlog_client(&self) -> testlog::OrganizationClient {
+        testlog::OrganizationClient {
+            client: self.client.testlog_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn testlogstoreendpoint_client(&self) -> testlogstoreendpoint::OrganizationClient {
+        testlogstoreendpoint::OrganizationClient {
+            client: self.client.testlogstoreendpoint_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn result_meta_data_client(&self) -> result_meta_data::ProjectClient {
+        result_meta_data::ProjectClient {
+            client: self.client.result_meta_data_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn testlog_client(&self) -> testlog::ProjectClient {
+        testlog::ProjectClient {
+            client: self.client.testlog_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn testlogstoreendpoint_client(&self) -> testlogstoreendpoint::ProjectClient {
+        testlogstoreendpoint::ProjectClient {
+            client: self.client.testlogstoreendpoint_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod testlog {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -223,6 +360,145 @@ pub mod testlog {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get list of test subresult attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the results"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_sub_result_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_sub_result_logs::RequestBuilder {
+            self.client.get_test_sub_result_logs(
+                self.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test result attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test result"]
+        #[doc = "* `type_`: type of attachments to get"]
+        pub fn get_test_result_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_result_logs::RequestBuilder {
+            self.client.get_test_result_logs(
+                self.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test run attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_run_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_run_logs::RequestBuilder {
+            self.client
+                .get_test_run_logs(self.organization.clone(), project, run_id, type_)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get list of test subresult attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the results"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_sub_result_logs(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_sub_result_logs::RequestBuilder {
+            self.client.get_test_sub_result_logs(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test result attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test result"]
+        #[doc = "* `type_`: type of attachments to get"]
+        pub fn get_test_result_logs(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_result_logs::RequestBuilder {
+            self.client.get_test_result_logs(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test run attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_run_logs(
+            &self,
+            run_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_run_logs::RequestBuilder {
+            self.client.get_test_run_logs(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                type_,
+            )
+        }
+    }
     pub mod get_test_sub_result_logs {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -331,9 +607,10 @@ pub mod testlog {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let sub_result_id = &this.sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -369,6 +646,24 @@ pub mod testlog {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestLogList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestLogList>;
@@ -498,9 +793,10 @@ pub mod testlog {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let type_ = &this.type_;
                         req.url_mut().query_pairs_mut().append_pair("type", type_);
                         if let Some(directory_path) = &this.directory_path {
@@ -532,6 +828,24 @@ pub mod testlog {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestLogList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestLogList>;
@@ -659,9 +973,10 @@ pub mod testlog {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let type_ = &this.type_;
                         req.url_mut().query_pairs_mut().append_pair("type", type_);
                         if let Some(directory_path) = &this.directory_path {
@@ -693,6 +1008,24 @@ pub mod testlog {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TestLogList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestLogList>;
@@ -853,6 +1186,261 @@ pub mod testlogstoreendpoint {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get SAS Uri of a test subresults attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult whose file sas uri is needed"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_sub_result_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_sub_result_log::RequestBuilder {
+            self.client
+                .get_test_log_store_endpoint_details_for_sub_result_log(
+                    self.organization.clone(),
+                    project,
+                    run_id,
+                    result_id,
+                    sub_result_id,
+                    type_,
+                    file_path,
+                )
+        }
+        #[doc = "Get SAS Uri of a test results attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result whose files need to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_result_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_result_log::RequestBuilder {
+            self.client
+                .get_test_log_store_endpoint_details_for_result_log(
+                    self.organization.clone(),
+                    project,
+                    run_id,
+                    result_id,
+                    type_,
+                    file_path,
+                )
+        }
+        #[doc = "Create empty file for a result and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test results that contains sub result"]
+        #[doc = "* `sub_result_id`: Id of the test sub result whose file sas uri is needed"]
+        #[doc = "* `file_path`: file path inside the sub result for which sas uri is needed"]
+        #[doc = "* `type_`: Type of the file for download"]
+        pub fn test_log_store_endpoint_details_for_result(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            file_path: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_result::RequestBuilder {
+            self.client.test_log_store_endpoint_details_for_result(
+                self.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                sub_result_id,
+                file_path,
+                type_,
+            )
+        }
+        #[doc = "Get SAS Uri of a test run attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run whose file has to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_run_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_run_log::RequestBuilder {
+            self.client.get_test_log_store_endpoint_details_for_run_log(
+                self.organization.clone(),
+                project,
+                run_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create empty file for a run and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the run to get endpoint details"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_run(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_run::RequestBuilder {
+            self.client.test_log_store_endpoint_details_for_run(
+                self.organization.clone(),
+                project,
+                run_id,
+                test_log_store_operation_type,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get SAS Uri of a test subresults attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult whose file sas uri is needed"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_sub_result_log(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_sub_result_log::RequestBuilder {
+            self.client
+                .get_test_log_store_endpoint_details_for_sub_result_log(
+                    self.organization.clone(),
+                    self.project.clone(),
+                    run_id,
+                    result_id,
+                    sub_result_id,
+                    type_,
+                    file_path,
+                )
+        }
+        #[doc = "Get SAS Uri of a test results attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result whose files need to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_result_log(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_result_log::RequestBuilder {
+            self.client
+                .get_test_log_store_endpoint_details_for_result_log(
+                    self.organization.clone(),
+                    self.project.clone(),
+                    run_id,
+                    result_id,
+                    type_,
+                    file_path,
+                )
+        }
+        #[doc = "Create empty file for a result and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test results that contains sub result"]
+        #[doc = "* `sub_result_id`: Id of the test sub result whose file sas uri is needed"]
+        #[doc = "* `file_path`: file path inside the sub result for which sas uri is needed"]
+        #[doc = "* `type_`: Type of the file for download"]
+        pub fn test_log_store_endpoint_details_for_result(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            file_path: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_result::RequestBuilder {
+            self.client.test_log_store_endpoint_details_for_result(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                result_id,
+                sub_result_id,
+                file_path,
+                type_,
+            )
+        }
+        #[doc = "Get SAS Uri of a test run attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run whose file has to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_run_log(
+            &self,
+            run_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_run_log::RequestBuilder {
+            self.client.get_test_log_store_endpoint_details_for_run_log(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create empty file for a run and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the run to get endpoint details"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_run(
+            &self,
+            run_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_run::RequestBuilder {
+            self.client.test_log_store_endpoint_details_for_run(
+                self.organization.clone(),
+                self.project.clone(),
+                run_id,
+                test_log_store_operation_type,
+            )
+        }
+    }
     pub mod get_test_log_store_endpoint_details_for_sub_result_log {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -935,9 +1523,10 @@ pub mod testlogstoreendpoint {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let sub_result_id = &this.sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1059,9 +1648,10 @@ pub mod testlogstoreendpoint {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let type_ = &this.type_;
                         req.url_mut().query_pairs_mut().append_pair("type", type_);
                         let file_path = &this.file_path;
@@ -1180,9 +1770,10 @@ pub mod testlogstoreendpoint {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let sub_result_id = &this.sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1303,9 +1894,10 @@ pub mod testlogstoreendpoint {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let type_ = &this.type_;
                         req.url_mut().query_pairs_mut().append_pair("type", type_);
                         let file_path = &this.file_path;
@@ -1432,9 +2024,10 @@ pub mod testlogstoreendpoint {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let test_log_store_operation_type = &this.test_log_store_operation_type;
                         req.url_mut().query_pairs_mut().append_pair(
                             "testLogStoreOperationType",
@@ -1520,6 +2113,79 @@ pub mod result_meta_data {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get list of test Result meta data details for corresponding testcasereferenceId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestCaseReference Ids of the test Result to be queried, comma separated list of valid ids (limit no. of ids 200)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body, project)
+        }
+        #[doc = "Update properties of test result meta data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestResultMetaData update input TestResultMetaDataUpdateInput"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_reference_id`: TestCaseReference Id of Test Result to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultMetaDataUpdateInput>,
+            project: impl Into<String>,
+            test_case_reference_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                test_case_reference_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get list of test Result meta data details for corresponding testcasereferenceId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestCaseReference Ids of the test Result to be queried, comma separated list of valid ids (limit no. of ids 200)."]
+        pub fn query(&self, body: Vec<String>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Update properties of test result meta data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestResultMetaData update input TestResultMetaDataUpdateInput"]
+        #[doc = "* `test_case_reference_id`: TestCaseReference Id of Test Result to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultMetaDataUpdateInput>,
+            test_case_reference_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                test_case_reference_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1607,9 +2273,10 @@ pub mod result_meta_data {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(details_to_include) = &this.details_to_include {
@@ -1722,9 +2389,10 @@ pub mod result_meta_data {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn branches_client(&self) -> branches::Client {
@@ -134,6 +203,100 @@ impl Client {
         shelvesets::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn branches_client(&self) -> branches::OrganizationClient {
+        branches::OrganizationClient {
+            client: self.client.branches_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn changesets_client(&self) -> changesets::OrganizationClient {
+        changesets::OrganizationClient {
+            client: self.client.changesets_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn items_client(&self) -> items::OrganizationClient {
+        items::OrganizationClient {
+            client: self.client.items_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn labels_client(&self) -> labels::OrganizationClient {
+        labels::OrganizationClient {
+            client: self.client.labels_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn shelvesets_client(&self) -> shelvesets::OrganizationClient {
+        shelvesets::OrganizationClient {
+            client: self.client.shelvesets_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn branches_client(&self) -> branches::ProjectClient {
+        branches::ProjectClient {
+            client: self.client.branches_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn changesets_client(&self) -> changesets::ProjectClient {
+        changesets::ProjectClient {
+            client: self.client.changesets_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn items_client(&self) -> items::ProjectClient {
+        items::ProjectClient {
+            client: self.client.items_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn labels_client(&self) -> labels::ProjectClient {
+        labels::ProjectClient {
+            client: self.client.labels_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn shelvesets_client(&self) -> shelvesets::ProjectClient {
+        shelvesets::ProjectClient {
+            client: self.client.shelvesets_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod shelvesets {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -215,6 +378,89 @@ pub mod shelvesets {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Return a collection of shallow shelveset references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a single deep shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get(&self, shelveset_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get changes included in a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_changes(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_changes::RequestBuilder {
+            self.client
+                .get_shelveset_changes(self.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get work items associated with a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_work_items(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_work_items::RequestBuilder {
+            self.client
+                .get_shelveset_work_items(self.organization.clone(), shelveset_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Return a collection of shallow shelveset references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a single deep shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get(&self, shelveset_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get changes included in a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_changes(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_changes::RequestBuilder {
+            self.client
+                .get_shelveset_changes(self.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get work items associated with a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_work_items(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_work_items::RequestBuilder {
+            self.client
+                .get_shelveset_work_items(self.organization.clone(), shelveset_id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -359,9 +605,10 @@ pub mod shelvesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(request_data_include_details) =
                             &this.request_data_include_details
                         {
@@ -426,6 +673,37 @@ pub mod shelvesets {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcShelvesetRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TfvcShelvesetRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcShelvesetRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TfvcShelvesetRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcShelvesetRefList>;
@@ -575,9 +853,10 @@ pub mod shelvesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let shelveset_id = &this.shelveset_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -741,9 +1020,10 @@ pub mod shelvesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let shelveset_id = &this.shelveset_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -764,6 +1044,37 @@ pub mod shelvesets {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChangeList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TfvcChangeList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChange>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TfvcChangeList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangeList>;
@@ -858,9 +1169,10 @@ pub mod shelvesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let shelveset_id = &this.shelveset_id;
                         req.url_mut()
                             .query_pairs_mut()
@@ -956,6 +1268,79 @@ pub mod branches {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a single branch hierarchy at the given path with parents or children as specified."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), path, project)
+        }
+        #[doc = "Get a collection of branch roots -- first-level children, branches with no parents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_branches(&self, project: impl Into<String>) -> get_branches::RequestBuilder {
+            self.client.get_branches(self.organization.clone(), project)
+        }
+        #[doc = "Get branch hierarchies below the specified scopePath"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_branch_refs(
+            &self,
+            scope_path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_branch_refs::RequestBuilder {
+            self.client
+                .get_branch_refs(self.organization.clone(), scope_path, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a single branch hierarchy at the given path with parents or children as specified."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        pub fn get(&self, path: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), path, self.project.clone())
+        }
+        #[doc = "Get a collection of branch roots -- first-level children, branches with no parents."]
+        pub fn get_branches(&self) -> get_branches::RequestBuilder {
+            self.client
+                .get_branches(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get branch hierarchies below the specified scopePath"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        pub fn get_branch_refs(
+            &self,
+            scope_path: impl Into<String>,
+        ) -> get_branch_refs::RequestBuilder {
+            self.client
+                .get_branch_refs(self.organization.clone(), scope_path, self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1049,9 +1434,10 @@ pub mod branches {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let path = &this.path;
                         req.url_mut().query_pairs_mut().append_pair("path", path);
                         if let Some(include_parent) = &this.include_parent {
@@ -1188,9 +1574,10 @@ pub mod branches {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_parent) = &this.include_parent {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1325,9 +1712,10 @@ pub mod branches {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let scope_path = &this.scope_path;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1436,6 +1824,75 @@ pub mod items {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Version control path of an individual item to return."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), path, project)
+        }
+        #[doc = "Post for retrieving a set of items given a list of paths or a long path. Allows for specifying the recursionLevel and version descriptors for each path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::TfvcItemRequestData>,
+            project: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            self.client
+                .get_items_batch(self.organization.clone(), body, project)
+        }
+        #[doc = "Get a list of Tfvc items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Version control path of an individual item to return."]
+        pub fn get(&self, path: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), path, self.project.clone())
+        }
+        #[doc = "Post for retrieving a set of items given a list of paths or a long path. Allows for specifying the recursionLevel and version descriptors for each path."]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::TfvcItemRequestData>,
+        ) -> get_items_batch::RequestBuilder {
+            self.client
+                .get_items_batch(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get a list of Tfvc items"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1575,9 +2032,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let path = &this.path;
                         req.url_mut().query_pairs_mut().append_pair("path", path);
                         if let Some(file_name) = &this.file_name {
@@ -1728,9 +2186,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1875,9 +2334,10 @@ pub mod items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(scope_path) = &this.scope_path {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2076,6 +2536,128 @@ pub mod changesets {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Retrieve Tfvc changes for a given changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset. Default: null"]
+        pub fn get_changeset_changes(&self, id: i32) -> get_changeset_changes::RequestBuilder {
+            self.client
+                .get_changeset_changes(self.organization.clone(), id)
+        }
+        #[doc = "Retrieves the work items associated with a particular changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset."]
+        pub fn get_changeset_work_items(
+            &self,
+            id: i32,
+        ) -> get_changeset_work_items::RequestBuilder {
+            self.client
+                .get_changeset_work_items(self.organization.clone(), id)
+        }
+        #[doc = "Returns changesets for a given list of changeset Ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of changeset IDs."]
+        pub fn get_batched_changesets(
+            &self,
+            body: impl Into<models::TfvcChangesetsRequestData>,
+        ) -> get_batched_changesets::RequestBuilder {
+            self.client
+                .get_batched_changesets(self.organization.clone(), body)
+        }
+        #[doc = "Retrieve Tfvc Changesets\n\nNote: This is a new version of the GetChangesets API that doesn't expose the unneeded queryParams\npresent in the 1.0 version of the API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changesets(&self, project: impl Into<String>) -> get_changesets::RequestBuilder {
+            self.client
+                .get_changesets(self.organization.clone(), project)
+        }
+        #[doc = "Create a new changeset.\n\nAccepts TfvcChangeset as JSON body"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TfvcChangeset>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Retrieve a Tfvc Changeset"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Changeset Id to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Retrieve Tfvc changes for a given changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset. Default: null"]
+        pub fn get_changeset_changes(&self, id: i32) -> get_changeset_changes::RequestBuilder {
+            self.client
+                .get_changeset_changes(self.organization.clone(), id)
+        }
+        #[doc = "Retrieves the work items associated with a particular changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset."]
+        pub fn get_changeset_work_items(
+            &self,
+            id: i32,
+        ) -> get_changeset_work_items::RequestBuilder {
+            self.client
+                .get_changeset_work_items(self.organization.clone(), id)
+        }
+        #[doc = "Returns changesets for a given list of changeset Ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of changeset IDs."]
+        pub fn get_batched_changesets(
+            &self,
+            body: impl Into<models::TfvcChangesetsRequestData>,
+        ) -> get_batched_changesets::RequestBuilder {
+            self.client
+                .get_batched_changesets(self.organization.clone(), body)
+        }
+        #[doc = "Retrieve Tfvc Changesets\n\nNote: This is a new version of the GetChangesets API that doesn't expose the unneeded queryParams\npresent in the 1.0 version of the API."]
+        pub fn get_changesets(&self) -> get_changesets::RequestBuilder {
+            self.client
+                .get_changesets(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a new changeset.\n\nAccepts TfvcChangeset as JSON body"]
+        pub fn create(&self, body: impl Into<models::TfvcChangeset>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Retrieve a Tfvc Changeset"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Changeset Id to retrieve."]
+        pub fn get(&self, id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, self.project.clone())
+        }
+    }
     pub mod get_changeset_changes {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2173,9 +2755,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(skip) = &this.skip {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2197,6 +2780,55 @@ pub mod changesets {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChangeList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChangeList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TfvcChangeList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChange>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TfvcChangeList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangeList>;
@@ -2292,9 +2924,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2397,9 +3030,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2603,9 +3237,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(max_comment_length) = &this.max_comment_length {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2680,6 +3315,37 @@ pub mod changesets {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChangesetRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TfvcChangesetRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcChangesetRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TfvcChangesetRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangesetRefList>;
@@ -2778,9 +3444,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3007,9 +3674,10 @@ pub mod changesets {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(max_change_count) = &this.max_change_count {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3193,6 +3861,78 @@ pub mod labels {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get items under a label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get_label_items(
+            &self,
+            label_id: impl Into<String>,
+        ) -> get_label_items::RequestBuilder {
+            self.client
+                .get_label_items(self.organization.clone(), label_id)
+        }
+        #[doc = "Get a collection of shallow label references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Get a single deep label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            label_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), label_id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get items under a label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get_label_items(
+            &self,
+            label_id: impl Into<String>,
+        ) -> get_label_items::RequestBuilder {
+            self.client
+                .get_label_items(self.organization.clone(), label_id)
+        }
+        #[doc = "Get a collection of shallow label references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get a single deep label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get(&self, label_id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), label_id, self.project.clone())
+        }
+    }
     pub mod get_label_items {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3284,9 +4024,10 @@ pub mod labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3303,6 +4044,34 @@ pub mod labels {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcItemList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::TfvcItemList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcItem>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::TfvcItemList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcItemList>;
@@ -3452,9 +4221,10 @@ pub mod labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(request_data_include_links) = &this.request_data_include_links {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "requestData.includeLinks",
@@ -3507,6 +4277,37 @@ pub mod labels {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcLabelRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::TfvcLabelRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::TfvcLabelRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::TfvcLabelRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcLabelRefList>;
@@ -3645,9 +4446,10 @@ pub mod labels {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(request_data_include_links) = &this.request_data_include_links {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "requestData.includeLinks",
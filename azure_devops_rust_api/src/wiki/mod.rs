@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn attachments_client(&self) -> attachments::Client {
@@ -137,6 +206,113 @@ impl Client {
         wikis::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn page_moves_client(&self) -> page_moves::OrganizationClient {
+        page_moves::OrganizationClient {
+            client: self.client.page_moves_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn page_stats_client(&self) -> page_stats::OrganizationClient {
+        page_stats::OrganizationClient {
+            client: self.client.page_stats_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pages_client(&self) -> pages::OrganizationClient {
+        pages::OrganizationClient {
+            client: self.client.pages_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn pages_batch_client(&self) -> pages_batch::OrganizationClient {
+        pages_batch::OrganizationClient {
+            client: self.client.pages_batch_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn wikis_client(&self) -> wikis::OrganizationClient {
+        wikis::OrganizationClient {
+            client: self.client.wikis_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn page_moves_client(&self) -> page_moves::ProjectClient {
+        page_moves::ProjectClient {
+            client: self.client.page_moves_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn page_stats_client(&self) -> page_stats::ProjectClient {
+        page_stats::ProjectClient {
+            client: self.client.page_stats_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pages_client(&self) -> pages::ProjectClient {
+        pages::ProjectClient {
+            client: self.client.pages_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn pages_batch_client(&self) -> pages_batch::ProjectClient {
+        pages_batch::ProjectClient {
+            client: self.client.pages_batch_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn wikis_client(&self) -> wikis::ProjectClient {
+        wikis::ProjectClient {
+            client: self.client.wikis_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod wikis {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -237,6 +413,138 @@ pub mod wikis {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets all wikis in a project or collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Creates the wiki resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for the wiki creation."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiCreateParametersV2>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Gets the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), wiki_identifier, project)
+        }
+        #[doc = "Updates the wiki corresponding to the wiki ID or wiki name provided using the update parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Update parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, wiki_identifier, project)
+        }
+        #[doc = "Deletes the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), wiki_identifier, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets all wikis in a project or collection."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Creates the wiki resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for the wiki creation."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiCreateParametersV2>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(&self, wiki_identifier: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                wiki_identifier,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Updates the wiki corresponding to the wiki ID or wiki name provided using the update parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Update parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                wiki_identifier,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Deletes the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn delete(&self, wiki_identifier: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                wiki_identifier,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -316,9 +624,10 @@ pub mod wikis {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -420,9 +729,10 @@ pub mod wikis {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -526,9 +836,10 @@ pub mod wikis {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -632,9 +943,10 @@ pub mod wikis {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -738,9 +1050,10 @@ pub mod wikis {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -796,6 +1109,65 @@ pub mod attachments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Creates an attachment in the wiki."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `name`: Wiki attachment name."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                name,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Creates an attachment in the wiki."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `name`: Wiki attachment name."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                wiki_identifier,
+                name,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -917,9 +1289,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/octet-stream");
                         let req_body = azure_core::to_json(&this.body)?;
                         let name = &this.name;
@@ -998,6 +1371,55 @@ pub mod page_moves {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Creates a page move operation that updates the path and order of the page as provided in the parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Page more operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiPageMoveParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, wiki_identifier)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Creates a page move operation that updates the path and order of the page as provided in the parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Page more operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiPageMoveParameters>,
+            wiki_identifier: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                wiki_identifier,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1124,9 +1546,10 @@ pub mod page_moves {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(comment) = &this.comment {
@@ -1341,6 +1764,239 @@ pub mod pages {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets metadata or content of the wiki page for the provided path. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get_page(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> get_page::RequestBuilder {
+            self.client
+                .get_page(self.organization.clone(), project, wiki_identifier)
+        }
+        #[doc = "Creates or edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki create or update operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+            if_match: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            self.client.create_or_update(
+                self.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                path,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        pub fn delete_page(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete_page::RequestBuilder {
+            self.client
+                .delete_page(self.organization.clone(), project, wiki_identifier, path)
+        }
+        #[doc = "Gets metadata or content of the wiki page for the provided page id. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name.."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn get_page_by_id(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> get_page_by_id::RequestBuilder {
+            self.client
+                .get_page_by_id(self.organization.clone(), project, wiki_identifier, id)
+        }
+        #[doc = "Edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki update operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+            if_match: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                id,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn delete_page_by_id(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> delete_page_by_id::RequestBuilder {
+            self.client
+                .delete_page_by_id(self.organization.clone(), project, wiki_identifier, id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets metadata or content of the wiki page for the provided path. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get_page(&self, wiki_identifier: impl Into<String>) -> get_page::RequestBuilder {
+            self.client.get_page(
+                self.organization.clone(),
+                self.project.clone(),
+                wiki_identifier,
+            )
+        }
+        #[doc = "Creates or edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki create or update operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+            if_match: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            self.client.create_or_update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                wiki_identifier,
+                path,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        pub fn delete_page(
+            &self,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete_page::RequestBuilder {
+            self.client.delete_page(
+                self.organization.clone(),
+                self.project.clone(),
+                wiki_identifier,
+                path,
+            )
+        }
+        #[doc = "Gets metadata or content of the wiki page for the provided page id. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name.."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn get_page_by_id(
+            &self,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> get_page_by_id::RequestBuilder {
+            self.client.get_page_by_id(
+                self.organization.clone(),
+                self.project.clone(),
+                wiki_identifier,
+                id,
+            )
+        }
+        #[doc = "Edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki update operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+            if_match: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                wiki_identifier,
+                id,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn delete_page_by_id(
+            &self,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> delete_page_by_id::RequestBuilder {
+            self.client.delete_page_by_id(
+                self.organization.clone(),
+                self.project.clone(),
+                wiki_identifier,
+                id,
+            )
+        }
+    }
     pub mod get_page {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1478,9 +2134,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(path) = &this.path {
                             req.url_mut().query_pairs_mut().append_pair("path", path);
                         }
@@ -1665,9 +2322,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         let path = &this.path;
@@ -1846,9 +2504,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let path = &this.path;
                         req.url_mut().query_pairs_mut().append_pair("path", path);
                         if let Some(comment) = &this.comment {
@@ -2004,9 +2663,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(recursion_level) = &this.recursion_level {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2139,9 +2799,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.insert_header("if-match", &this.if_match);
@@ -2269,9 +2930,10 @@ pub mod pages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(comment) = &this.comment {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2327,6 +2989,51 @@ pub mod page_stats {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns page detail corresponding to Page ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `page_id`: Wiki page ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            page_id: i32,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, wiki_identifier, page_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns page detail corresponding to Page ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `page_id`: Wiki page ID."]
+        pub fn get(&self, wiki_identifier: impl Into<String>, page_id: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                wiki_identifier,
+                page_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2416,9 +3123,10 @@ pub mod page_stats {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(page_views_for_days) = &this.page_views_for_days {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2476,6 +3184,55 @@ pub mod pages_batch {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns pageable list of Wiki Pages"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki batch page request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(
+            &self,
+            body: impl Into<models::WikiPagesBatchRequest>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), body, project, wiki_identifier)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns pageable list of Wiki Pages"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki batch page request."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(
+            &self,
+            body: impl Into<models::WikiPagesBatchRequest>,
+            wiki_identifier: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                wiki_identifier,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2587,9 +3344,10 @@ pub mod pages_batch {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(version_descriptor_version) = &this.version_descriptor_version {
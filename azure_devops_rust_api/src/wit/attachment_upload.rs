@@ -0,0 +1,453 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Uploading work item attachments.
+//!
+//! Attachments are uploaded either in a single request, or in chunks:
+//!
+//! 1. `POST _apis/wit/attachments?uploadType=Chunked&fileName=...` starts a chunked upload and
+//!    returns the attachment ID.
+//! 2. `PUT _apis/wit/attachments/{id}?fileName=...` uploads each chunk, with a
+//!    `Content-Range: bytes {start}-{end}/{total}` header.
+//!
+//! Chunked upload is required for attachments larger than 130MB.
+//! [`UploadAttachmentBuilder`] reads the attachment from an [`AsyncRead`], chooses the upload
+//! mode by size and sends the requests, reading each chunk just before it is sent.
+//!
+//! Each request, including the upload of each chunk, is retried by the retry policy of the
+//! client (see [`ClientBuilder::retry()`](super::ClientBuilder::retry)), so a failed chunk
+//! is sent again without restarting the upload.
+
+use super::attachments;
+use super::models::AttachmentReference;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::Method;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::io::{AsyncRead, AsyncReadExt};
+
+/// The default size of the chunks of a chunked upload (16MB).
+///
+/// Attachments no larger than the chunk size are uploaded in a single request.
+pub const DEFAULT_ATTACHMENT_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// The API version of the attachments `create` operation, which also starts a chunked upload.
+const ATTACHMENTS_CREATE_API_VERSION: &str = "7.1-preview";
+
+/// The API version of the attachments `upload_chunk` operation.
+const ATTACHMENTS_UPLOAD_CHUNK_API_VERSION: &str = "7.1-preview";
+
+impl attachments::Client {
+    /// Uploads an attachment read from `reader`.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    /// * `reader`: The attachment content
+    /// * `file_name`: The name of the file
+    pub fn upload_attachment<R>(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+        reader: R,
+        file_name: impl Into<String>,
+    ) -> UploadAttachmentBuilder<R>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        UploadAttachmentBuilder {
+            reader,
+            upload: Upload {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                file_name: file_name.into(),
+                area_path: None,
+                content_length: None,
+                chunk_size: DEFAULT_ATTACHMENT_CHUNK_SIZE,
+            },
+        }
+    }
+}
+
+scoped_client_methods! {
+    attachments;
+    /// Uploads an attachment read from `reader`.
+    pub fn upload_attachment<R>(
+        [project],
+        reader: R,
+        file_name: impl Into<String>,
+    ) -> UploadAttachmentBuilder<R>
+    where
+        R: AsyncRead + Unpin + Send;
+}
+
+/// Builder for the upload of a work item attachment.
+///
+/// Attachments no larger than the chunk size are uploaded in a single request,
+/// larger attachments are uploaded in chunks. Each chunk states the length of the attachment,
+/// so the upload of an attachment larger than the chunk size fails unless its
+/// [`content_length()`](UploadAttachmentBuilder::content_length) is set.
+///
+/// Example:
+///
+/// ```ignore
+/// let file = tokio::fs::File::open("build.log").await?;
+/// let content_length = file.metadata().await?.len();
+/// let attachment = wit_client
+///     .attachments_client()
+///     .upload_attachment(organization, project, file.compat(), "build.log")
+///     .content_length(content_length)
+///     .await?;
+/// let patch = WorkItemPatch::new()
+///     .add_relation("AttachedFile", attachment.url.unwrap_or_default());
+/// ```
+pub struct UploadAttachmentBuilder<R> {
+    reader: R,
+    upload: Upload,
+}
+
+/// The parameters of an upload, kept apart from the reader so that the requests can be sent
+/// while the reader is borrowed.
+struct Upload {
+    client: super::Client,
+    organization: String,
+    project: String,
+    file_name: String,
+    area_path: Option<String>,
+    content_length: Option<u64>,
+    chunk_size: usize,
+}
+
+impl<R> UploadAttachmentBuilder<R>
+where
+    R: AsyncRead + Unpin + Send,
+{
+    /// Target project Area Path
+    pub fn area_path(mut self, area_path: impl Into<String>) -> Self {
+        self.upload.area_path = Some(area_path.into());
+        self
+    }
+
+    /// The length of the attachment in bytes.
+    ///
+    /// Required for attachments larger than the chunk size, which are uploaded in chunks.
+    pub fn content_length(mut self, content_length: u64) -> Self {
+        self.upload.content_length = Some(content_length);
+        self
+    }
+
+    /// The size of the chunks of a chunked upload (default 16MB).
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.upload.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Uploads the attachment, returning a reference to it.
+    ///
+    /// This is equivalent to `.await` on the builder, but does not require the reader to be `'static`.
+    pub async fn upload(self) -> azure_core::Result<AttachmentReference> {
+        let Self { mut reader, upload } = self;
+        upload.run(&mut reader).await
+    }
+}
+
+impl Upload {
+    async fn run<R>(&self, reader: &mut R) -> azure_core::Result<AttachmentReference>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        match self.content_length {
+            Some(content_length) if content_length > self.chunk_size as u64 => {
+                self.upload_chunked(reader, content_length).await
+            }
+            _ => {
+                // Read one byte more than the chunk size, to detect attachments that require a chunked upload.
+                let limit = self.chunk_size + 1;
+                let content = read_chunk(reader, limit).await?;
+                if content.len() < limit {
+                    self.upload_simple(content.into()).await
+                } else {
+                    Err(Error::with_message(ErrorKind::Other, || {
+                        format!(
+                            "Attachment {} is larger than the chunk size of {} bytes, \
+                             set its content_length() to upload it in chunks",
+                            self.file_name, self.chunk_size
+                        )
+                    }))
+                }
+            }
+        }
+    }
+
+    async fn upload_simple(&self, content: Bytes) -> azure_core::Result<AttachmentReference> {
+        let request = Request::create("Simple", content);
+        self.send_request(&request).await
+    }
+
+    async fn upload_chunked<R>(
+        &self,
+        reader: &mut R,
+        content_length: u64,
+    ) -> azure_core::Result<AttachmentReference>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let request = Request::create("Chunked", Bytes::new());
+        let attachment = self.send_request(&request).await?;
+        let id = attachment.id.clone().ok_or_else(|| {
+            Error::message(
+                ErrorKind::DataConversion,
+                "The response to a chunked attachment upload did not include an attachment id",
+            )
+        })?;
+
+        let mut start: u64 = 0;
+        while start < content_length {
+            let len = (content_length - start).min(self.chunk_size as u64) as usize;
+            let chunk = read_chunk(reader, len).await?;
+            if chunk.len() < len {
+                return Err(Error::with_message(ErrorKind::Io, || {
+                    format!(
+                        "Attachment {} ended after {} bytes, expected {content_length} bytes",
+                        self.file_name,
+                        start + chunk.len() as u64
+                    )
+                }));
+            }
+            let request = Request {
+                method: Method::Put,
+                id: Some(&id),
+                api_version: ATTACHMENTS_UPLOAD_CHUNK_API_VERSION,
+                upload_type: None,
+                content_range: Some(content_range(start, len, content_length)),
+                body: chunk.into(),
+            };
+            self.send_request(&request).await?;
+            start += len as u64;
+        }
+        Ok(attachment)
+    }
+
+    async fn send_request(&self, request: &Request<'_>) -> azure_core::Result<AttachmentReference> {
+        let path = match request.id {
+            Some(id) => format!(
+                "{}/{}/_apis/wit/attachments/{id}",
+                &self.organization, &self.project
+            ),
+            None => format!(
+                "{}/{}/_apis/wit/attachments",
+                &self.organization, &self.project
+            ),
+        };
+        self.client
+            .send_json(request.method, &path, request.api_version, |req| {
+                req.url_mut()
+                    .query_pairs_mut()
+                    .append_pair("fileName", &self.file_name);
+                if let Some(area_path) = &self.area_path {
+                    req.url_mut()
+                        .query_pairs_mut()
+                        .append_pair("areaPath", area_path);
+                }
+                if let Some(upload_type) = request.upload_type {
+                    req.url_mut()
+                        .query_pairs_mut()
+                        .append_pair("uploadType", upload_type);
+                }
+                req.insert_header("content-type", "application/octet-stream");
+                if let Some(content_range) = &request.content_range {
+                    req.insert_header("content-range", content_range.clone());
+                }
+                req.set_body(request.body.clone());
+                Ok(())
+            })
+            .await
+    }
+}
+
+/// A request of an upload.
+struct Request<'a> {
+    method: Method,
+    /// The attachment ID, when uploading a chunk.
+    id: Option<&'a str>,
+    api_version: &'static str,
+    upload_type: Option<&'static str>,
+    content_range: Option<String>,
+    body: Bytes,
+}
+
+impl Request<'_> {
+    /// Returns the request that creates an attachment, with the given `uploadType`.
+    fn create(upload_type: &'static str, body: Bytes) -> Self {
+        Request {
+            method: Method::Post,
+            id: None,
+            api_version: ATTACHMENTS_CREATE_API_VERSION,
+            upload_type: Some(upload_type),
+            content_range: None,
+            body,
+        }
+    }
+}
+
+impl<R> std::future::IntoFuture for UploadAttachmentBuilder<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    type Output = azure_core::Result<AttachmentReference>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<AttachmentReference>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.upload())
+    }
+}
+
+/// Reads up to `len` bytes, returning fewer only at the end of the input.
+async fn read_chunk<R>(reader: &mut R, len: usize) -> azure_core::Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut chunk = Vec::with_capacity(len);
+    reader
+        .take(len as u64)
+        .read_to_end(&mut chunk)
+        .await
+        .context(ErrorKind::Io, "Failed to read attachment")?;
+    Ok(chunk)
+}
+
+/// Returns the `Content-Range` header of a chunk, e.g. `bytes 0-39999/50000`.
+fn content_range(start: u64, len: usize, total: u64) -> String {
+    format!("bytes {}-{}/{}", start, start + len as u64 - 1, total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_range() {
+        assert_eq!(content_range(0, 40000, 50000), "bytes 0-39999/50000");
+        assert_eq!(
+            content_range(40000, 10000, 50000),
+            "bytes 40000-49999/50000"
+        );
+    }
+
+    #[test]
+    fn test_read_chunk() {
+        let mut reader = futures::io::Cursor::new(vec![1u8; 10]);
+        let chunks: Vec<usize> = futures::executor::block_on(async {
+            let mut chunks = Vec::new();
+            for _ in 0..3 {
+                chunks.push(read_chunk(&mut reader, 4).await.unwrap().len());
+            }
+            chunks
+        });
+        assert_eq!(chunks, vec![4, 4, 2]);
+    }
+
+    #[test]
+    fn test_upload_chunked() {
+        use azure_core::headers::{HeaderName, Headers};
+        use azure_core::{Body, BytesStream, Context, Policy, PolicyResult, Response};
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Debug)]
+        struct RecordedRequest {
+            method: Method,
+            url: String,
+            content_range: Option<String>,
+            len: usize,
+        }
+
+        /// Policy that records each request, and responds with an attachment reference.
+        #[derive(Debug, Default)]
+        struct MockTransport {
+            requests: Mutex<Vec<RecordedRequest>>,
+        }
+
+        #[async_trait::async_trait]
+        impl Policy for MockTransport {
+            async fn send(
+                &self,
+                _ctx: &Context,
+                request: &mut azure_core::Request,
+                _next: &[Arc<dyn Policy>],
+            ) -> PolicyResult {
+                let content_range = request
+                    .headers()
+                    .get_optional_string(&HeaderName::from_static("content-range"));
+                let len = match request.body() {
+                    Body::Bytes(bytes) => bytes.len(),
+                    _ => panic!("unexpected streaming body"),
+                };
+                self.requests.lock().unwrap().push(RecordedRequest {
+                    method: *request.method(),
+                    url: request.url().to_string(),
+                    content_range,
+                    len,
+                });
+                Ok(Response::new(
+                    azure_core::StatusCode::Ok,
+                    Headers::new(),
+                    Box::pin(BytesStream::new(
+                        r#"{"id": "1234", "url": "https://a/1234"}"#,
+                    )),
+                ))
+            }
+        }
+
+        let transport = Arc::new(MockTransport::default());
+        let client = super::super::ClientBuilder::new(crate::Credential::from_pat("pat"))
+            .per_call_policies(vec![transport.clone() as Arc<dyn Policy>])
+            .build();
+        let upload = |content_length: Option<u64>| {
+            let reader = futures::io::Cursor::new(vec![1u8; 10]);
+            let mut builder = client
+                .attachments_client()
+                .upload_attachment("org", "project", reader, "build.log")
+                .chunk_size(4);
+            if let Some(content_length) = content_length {
+                builder = builder.content_length(content_length);
+            }
+            futures::executor::block_on(builder.upload())
+        };
+
+        // Without the content length, the attachment is not read into memory to find it.
+        let error = upload(None).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Other);
+        assert!(transport.requests.lock().unwrap().is_empty());
+
+        let attachment = upload(Some(10)).unwrap();
+        assert_eq!(attachment.id.as_deref(), Some("1234"));
+
+        let requests = transport.requests.lock().unwrap();
+        let summary: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                (
+                    request.method,
+                    request.content_range.as_deref(),
+                    request.len,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Method::Post, None, 0),
+                (Method::Put, Some("bytes 0-3/10"), 4),
+                (Method::Put, Some("bytes 4-7/10"), 4),
+                (Method::Put, Some("bytes 8-9/10"), 2),
+            ]
+        );
+        assert!(requests[0].url.starts_with(
+            "https://dev.azure.com/org/project/_apis/wit/attachments?api-version=7.1-preview&fileName=build.log&uploadType=Chunked"
+        ));
+        assert!(requests[1]
+            .url
+            .starts_with("https://dev.azure.com/org/project/_apis/wit/attachments/1234?"));
+    }
+}
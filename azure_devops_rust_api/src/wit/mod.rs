@@ -4,13 +4,32 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 #![allow(clippy::redundant_clone)]
+mod attachment_upload;
 pub mod models;
+pub use attachment_upload::*;
+mod reporting_sync;
+pub use reporting_sync::*;
+mod wiql_builder;
+pub use wiql_builder::*;
+mod wiql_work_items;
+pub use wiql_work_items::*;
+mod work_item_batch;
+pub use work_item_batch::*;
+mod work_item_fields;
+pub use work_item_fields::*;
+mod work_item_history;
+pub use work_item_history::*;
+mod work_item_patch;
+pub use work_item_patch::*;
+mod work_item_tree;
+pub use work_item_tree::*;
 #[derive(Clone)]
 pub struct Client {
     endpoint: String,
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +37,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +50,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +60,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +107,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +127,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -90,11 +137,65 @@ impl Client {
         let mut context = azure_core::Context::default();
         self.pipeline.send(&mut context, request).await
     }
+    #[doc = "Sends a request for an operation that has no generated request builder, and deserializes the JSON response."]
+    #[doc = ""]
+    #[doc = "`path` is relative to the endpoint, and `api_version` is the API version of the operation, which is replaced by the client's API version override (if any)."]
+    #[doc = "`prepare` sets any other query parameters, headers and body of the request."]
+    pub(crate) async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        method: azure_core::Method,
+        path: &str,
+        api_version: &str,
+        prepare: impl FnOnce(&mut azure_core::Request) -> azure_core::Result<()>,
+    ) -> azure_core::Result<T> {
+        let url = azure_core::Url::parse(&format!("{}/{}", self.endpoint(), path))?;
+        let mut req = azure_core::Request::new(url, method);
+        if let Some(auth_header) = self
+            .token_credential()
+            .http_authorization_header(&self.scopes)
+            .await?
+        {
+            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+        }
+        req.url_mut().query_pairs_mut().append_pair(
+            azure_core::query_param::API_VERSION,
+            &self.api_version(api_version),
+        );
+        prepare(&mut req)?;
+        let rsp = self.send(&mut req).await?;
+        let bytes = rsp.into_body().collect().await?;
+        serde_json::from_slice(&bytes).map_err(|e| {
+            azure_core::error::Error::full(
+                azure_core::error::ErrorKind::DataConversion,
+                e,
+                format!(
+                    "Failed to deserialize response:\n{}",
+                    String::from_utf8_lossy(&bytes)
+                ),
+            )
+        })
+    }
     #[doc = "Create a new `ClientBuilder`."]
     #[must_use]
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +209,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn account_my_work_recent_activity_client(
@@ -215,6 +340,447 @@ impl Client {
         work_items::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn account_my_work_recent_activity_client(
+        &self,
+    ) -> account_my_work_recent_activity::OrganizationClient {
+        account_my_work_recent_activity::OrganizationClient {
+            client: self.client.account_my_work_recent_activity_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn artifact_link_types_client(&self) -> artifact_link_types::OrganizationClient {
+        artifact_link_types::OrganizationClient {
+            client: self.client.artifact_link_types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn artifact_uri_query_client(&self) -> artifact_uri_query::OrganizationClient {
+        artifact_uri_query::OrganizationClient {
+            client: self.client.artifact_uri_query_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn classification_nodes_client(&self) -> classification_nodes::OrganizationClient {
+        classification_nodes::OrganizationClient {
+            client: self.client.classification_nodes_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn comment_reactions_engaged_users_client(
+        &self,
+    ) -> comment_reactions_engaged_users::OrganizationClient {
+        comment_reactions_engaged_users::OrganizationClient {
+            client: self.client.comment_reactions_engaged_users_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn comments_client(&self) -> comments::OrganizationClient {
+        comments::OrganizationClient {
+            client: self.client.comments_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn comments_reactions_client(&self) -> comments_reactions::OrganizationClient {
+        comments_reactions::OrganizationClient {
+            client: self.client.comments_reactions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn comments_versions_client(&self) -> comments_versions::OrganizationClient {
+        comments_versions::OrganizationClient {
+            client: self.client.comments_versions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn fields_client(&self) -> fields::OrganizationClient {
+        fields::OrganizationClient {
+            client: self.client.fields_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn project_process_migration_client(
+        &self,
+    ) -> project_process_migration::OrganizationClient {
+        project_process_migration::OrganizationClient {
+            client: self.client.project_process_migration_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn queries_client(&self) -> queries::OrganizationClient {
+        queries::OrganizationClient {
+            client: self.client.queries_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn recyclebin_client(&self) -> recyclebin::OrganizationClient {
+        recyclebin::OrganizationClient {
+            client: self.client.recyclebin_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn reporting_work_item_links_client(
+        &self,
+    ) -> reporting_work_item_links::OrganizationClient {
+        reporting_work_item_links::OrganizationClient {
+            client: self.client.reporting_work_item_links_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn reporting_work_item_revisions_client(
+        &self,
+    ) -> reporting_work_item_revisions::OrganizationClient {
+        reporting_work_item_revisions::OrganizationClient {
+            client: self.client.reporting_work_item_revisions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn revisions_client(&self) -> revisions::OrganizationClient {
+        revisions::OrganizationClient {
+            client: self.client.revisions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn send_mail_client(&self) -> send_mail::OrganizationClient {
+        send_mail::OrganizationClient {
+            client: self.client.send_mail_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn tags_client(&self) -> tags::OrganizationClient {
+        tags::OrganizationClient {
+            client: self.client.tags_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn templates_client(&self) -> templates::OrganizationClient {
+        templates::OrganizationClient {
+            client: self.client.templates_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn updates_client(&self) -> updates::OrganizationClient {
+        updates::OrganizationClient {
+            client: self.client.updates_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn wiql_client(&self) -> wiql::OrganizationClient {
+        wiql::OrganizationClient {
+            client: self.client.wiql_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_icons_client(&self) -> work_item_icons::OrganizationClient {
+        work_item_icons::OrganizationClient {
+            client: self.client.work_item_icons_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_relation_types_client(&self) -> work_item_relation_types::OrganizationClient {
+        work_item_relation_types::OrganizationClient {
+            client: self.client.work_item_relation_types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_revisions_discussions_client(
+        &self,
+    ) -> work_item_revisions_discussions::OrganizationClient {
+        work_item_revisions_discussions::OrganizationClient {
+            client: self.client.work_item_revisions_discussions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_transitions_client(&self) -> work_item_transitions::OrganizationClient {
+        work_item_transitions::OrganizationClient {
+            client: self.client.work_item_transitions_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_type_categories_client(
+        &self,
+    ) -> work_item_type_categories::OrganizationClient {
+        work_item_type_categories::OrganizationClient {
+            client: self.client.work_item_type_categories_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_type_states_client(&self) -> work_item_type_states::OrganizationClient {
+        work_item_type_states::OrganizationClient {
+            client: self.client.work_item_type_states_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::OrganizationClient {
+        work_item_types::OrganizationClient {
+            client: self.client.work_item_types_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_item_types_field_client(&self) -> work_item_types_field::OrganizationClient {
+        work_item_types_field::OrganizationClient {
+            client: self.client.work_item_types_field_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn work_items_client(&self) -> work_items::OrganizationClient {
+        work_items::OrganizationClient {
+            client: self.client.work_items_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn account_my_work_recent_activity_client(
+        &self,
+    ) -> account_my_work_recent_activity::ProjectClient {
+        account_my_work_recent_activity::ProjectClient {
+            client: self.client.account_my_work_recent_activity_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn artifact_link_types_client(&self) -> artifact_link_types::ProjectClient {
+        artifact_link_types::ProjectClient {
+            client: self.client.artifact_link_types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn artifact_uri_query_client(&self) -> artifact_uri_query::ProjectClient {
+        artifact_uri_query::ProjectClient {
+            client: self.client.artifact_uri_query_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient {
+            client: self.client.attachments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn classification_nodes_client(&self) -> classification_nodes::ProjectClient {
+        classification_nodes::ProjectClient {
+            client: self.client.classification_nodes_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn comment_reactions_engaged_users_client(
+        &self,
+    ) -> comment_reactions_engaged_users::ProjectClient {
+        comment_reactions_engaged_users::ProjectClient {
+            client: self.client.comment_reactions_engaged_users_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn comments_client(&self) -> comments::ProjectClient {
+        comments::ProjectClient {
+            client: self.client.comments_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn comments_reactions_client(&self) -> comments_reactions::ProjectClient {
+        comments_reactions::ProjectClient {
+            client: self.client.comments_reactions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn comments_versions_client(&self) -> comments_versions::ProjectClient {
+        comments_versions::ProjectClient {
+            client: self.client.comments_versions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn fields_client(&self) -> fields::ProjectClient {
+        fields::ProjectClient {
+            client: self.client.fields_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn project_process_migration_client(&self) -> project_process_migration::ProjectClient {
+        project_process_migration::ProjectClient {
+            client: self.client.project_process_migration_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn queries_client(&self) -> queries::ProjectClient {
+        queries::ProjectClient {
+            client: self.client.queries_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn recyclebin_client(&self) -> recyclebin::ProjectClient {
+        recyclebin::ProjectClient {
+            client: self.client.recyclebin_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn reporting_work_item_links_client(&self) -> reporting_work_item_links::ProjectClient {
+        reporting_work_item_links::ProjectClient {
+            client: self.client.reporting_work_item_links_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn reporting_work_item_revisions_client(
+        &self,
+    ) -> reporting_work_item_revisions::ProjectClient {
+        reporting_work_item_revisions::ProjectClient {
+            client: self.client.reporting_work_item_revisions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn revisions_client(&self) -> revisions::ProjectClient {
+        revisions::ProjectClient {
+            client: self.client.revisions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn send_mail_client(&self) -> send_mail::ProjectClient {
+        send_mail::ProjectClient {
+            client: self.client.send_mail_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn tags_client(&self) -> tags::ProjectClient {
+        tags::ProjectClient {
+            client: self.client.tags_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn templates_client(&self) -> templates::ProjectClient {
+        templates::ProjectClient {
+            client: self.client.templates_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn updates_client(&self) -> updates::ProjectClient {
+        updates::ProjectClient {
+            client: self.client.updates_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn wiql_client(&self) -> wiql::ProjectClient {
+        wiql::ProjectClient {
+            client: self.client.wiql_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_icons_client(&self) -> work_item_icons::ProjectClient {
+        work_item_icons::ProjectClient {
+            client: self.client.work_item_icons_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_relation_types_client(&self) -> work_item_relation_types::ProjectClient {
+        work_item_relation_types::ProjectClient {
+            client: self.client.work_item_relation_types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_revisions_discussions_client(
+        &self,
+    ) -> work_item_revisions_discussions::ProjectClient {
+        work_item_revisions_discussions::ProjectClient {
+            client: self.client.work_item_revisions_discussions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_transitions_client(&self) -> work_item_transitions::ProjectClient {
+        work_item_transitions::ProjectClient {
+            client: self.client.work_item_transitions_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_type_categories_client(&self) -> work_item_type_categories::ProjectClient {
+        work_item_type_categories::ProjectClient {
+            client: self.client.work_item_type_categories_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_type_states_client(&self) -> work_item_type_states::ProjectClient {
+        work_item_type_states::ProjectClient {
+            client: self.client.work_item_type_states_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::ProjectClient {
+        work_item_types::ProjectClient {
+            client: self.client.work_item_types_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_item_types_field_client(&self) -> work_item_types_field::ProjectClient {
+        work_item_types_field::ProjectClient {
+            client: self.client.work_item_types_field_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn work_items_client(&self) -> work_items::ProjectClient {
+        work_items::ProjectClient {
+            client: self.client.work_items_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod classification_nodes {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -354,6 +920,211 @@ pub mod classification_nodes {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets root classification nodes or list of classification nodes for a given list of nodes ids, for a given project. In case ids parameter is supplied you will  get list of classification nodes for those ids. Otherwise you will get root classification nodes for this project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `ids`: Comma separated integer classification nodes ids. It's not required, if you want root nodes."]
+        pub fn get_classification_nodes(
+            &self,
+            project: impl Into<String>,
+            ids: impl Into<String>,
+        ) -> get_classification_nodes::RequestBuilder {
+            self.client
+                .get_classification_nodes(self.organization.clone(), project, ids)
+        }
+        #[doc = "Gets root classification nodes under the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_root_nodes(&self, project: impl Into<String>) -> get_root_nodes::RequestBuilder {
+            self.client
+                .get_root_nodes(self.organization.clone(), project)
+        }
+        #[doc = "Gets the classification node for a given node path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, structure_group, path)
+        }
+        #[doc = "Create new or update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            self.client.create_or_update(
+                self.organization.clone(),
+                body,
+                project,
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Delete an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, structure_group, path)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets root classification nodes or list of classification nodes for a given list of nodes ids, for a given project. In case ids parameter is supplied you will  get list of classification nodes for those ids. Otherwise you will get root classification nodes for this project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated integer classification nodes ids. It's not required, if you want root nodes."]
+        pub fn get_classification_nodes(
+            &self,
+            ids: impl Into<String>,
+        ) -> get_classification_nodes::RequestBuilder {
+            self.client.get_classification_nodes(
+                self.organization.clone(),
+                self.project.clone(),
+                ids,
+            )
+        }
+        #[doc = "Gets root classification nodes under the project."]
+        pub fn get_root_nodes(&self) -> get_root_nodes::RequestBuilder {
+            self.client
+                .get_root_nodes(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Gets the classification node for a given node path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn get(
+            &self,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Create new or update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            self.client.create_or_update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Delete an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn delete(
+            &self,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+    }
     pub mod get_classification_nodes {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -450,9 +1221,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         if let Some(depth) = &this.depth {
@@ -576,9 +1348,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(depth) = &this.depth {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -697,9 +1470,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(depth) = &this.depth {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -813,9 +1587,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -925,9 +1700,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1009,9 +1785,10 @@ pub mod classification_nodes {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(reclassify_id) = &this.reclassify_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1079,103 +1856,270 @@ pub mod queries {
         #[doc = "* `query`: ID or path of the query."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                query: query.into(),
+                expand: None,
+                depth: None,
+                include_deleted: None,
+                use_iso_date_format: None,
+            }
+        }
+        #[doc = "Creates a query, or moves a query.\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The query to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: The parent id or path under which the query is to be created."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::QueryHierarchyItem>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                query: query.into(),
+                validate_wiql_only: None,
+            }
+        }
+        #[doc = "Update a query or a folder. This allows you to update, rename and move queries and folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The query to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: The ID or path for the query to update."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::QueryHierarchyItem>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                query: query.into(),
+                undelete_descendants: None,
+            }
+        }
+        #[doc = "Delete a query or a folder. This deletes any permission change on the deleted query or folder and any of its descendants if it is a folder. It is important to note that the deleted permission changes cannot be recovered upon undeleting the query or folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: ID or path of the query or folder to delete."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                query: query.into(),
+            }
+        }
+        #[doc = "Gets a list of queries by ids (Maximum 1000)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_queries_batch(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::QueryBatchGetRequest>,
+            project: impl Into<String>,
+        ) -> get_queries_batch::RequestBuilder {
+            get_queries_batch::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Searches all queries the user has access to in the current project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `filter`: The text to filter the queries with."]
+        pub fn search_queries(
+            &self,
+            project: impl Into<String>,
+            filter: impl Into<String>,
+        ) -> search_queries::RequestBuilder {
+            self.client
+                .search_queries(self.organization.clone(), project, filter)
+        }
+        #[doc = "Gets the root queries and their children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Retrieves an individual query and its children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: ID or path of the query."]
+        pub fn get(
+            &self,
             project: impl Into<String>,
             query: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                query: query.into(),
-                expand: None,
-                depth: None,
-                include_deleted: None,
-                use_iso_date_format: None,
-            }
+            self.client.get(self.organization.clone(), project, query)
         }
         #[doc = "Creates a query, or moves a query.\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The query to create."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `query`: The parent id or path under which the query is to be created."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::QueryHierarchyItem>,
             project: impl Into<String>,
             query: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                query: query.into(),
-                validate_wiql_only: None,
-            }
+            self.client
+                .create(self.organization.clone(), body, project, query)
         }
         #[doc = "Update a query or a folder. This allows you to update, rename and move queries and folders."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The query to update."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `query`: The ID or path for the query to update."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::QueryHierarchyItem>,
             project: impl Into<String>,
             query: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                query: query.into(),
-                undelete_descendants: None,
-            }
+            self.client
+                .update(self.organization.clone(), body, project, query)
         }
         #[doc = "Delete a query or a folder. This deletes any permission change on the deleted query or folder and any of its descendants if it is a folder. It is important to note that the deleted permission changes cannot be recovered upon undeleting the query or folder."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `query`: ID or path of the query or folder to delete."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             query: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                query: query.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), project, query)
         }
         #[doc = "Gets a list of queries by ids (Maximum 1000)"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_queries_batch(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::QueryBatchGetRequest>,
             project: impl Into<String>,
         ) -> get_queries_batch::RequestBuilder {
-            get_queries_batch::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client
+                .get_queries_batch(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Searches all queries the user has access to in the current project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `filter`: The text to filter the queries with."]
+        pub fn search_queries(&self, filter: impl Into<String>) -> search_queries::RequestBuilder {
+            self.client
+                .search_queries(self.organization.clone(), self.project.clone(), filter)
+        }
+        #[doc = "Gets the root queries and their children"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Retrieves an individual query and its children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `query`: ID or path of the query."]
+        pub fn get(&self, query: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), query)
+        }
+        #[doc = "Creates a query, or moves a query.\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to create."]
+        #[doc = "* `query`: The parent id or path under which the query is to be created."]
+        pub fn create(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            query: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), query)
+        }
+        #[doc = "Update a query or a folder. This allows you to update, rename and move queries and folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to update."]
+        #[doc = "* `query`: The ID or path for the query to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            query: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), query)
+        }
+        #[doc = "Delete a query or a folder. This deletes any permission change on the deleted query or folder and any of its descendants if it is a folder. It is important to note that the deleted permission changes cannot be recovered upon undeleting the query or folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `query`: ID or path of the query or folder to delete."]
+        pub fn delete(&self, query: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), query)
+        }
+        #[doc = "Gets a list of queries by ids (Maximum 1000)"]
+        pub fn get_queries_batch(
+            &self,
+            body: impl Into<models::QueryBatchGetRequest>,
+        ) -> get_queries_batch::RequestBuilder {
+            self.client
+                .get_queries_batch(self.organization.clone(), body, self.project.clone())
         }
     }
     pub mod search_queries {
@@ -1235,7 +2179,7 @@ pub mod queries {
             pub(crate) project: String,
             pub(crate) filter: String,
             pub(crate) top: Option<i32>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::QueryExpand>,
             pub(crate) include_deleted: Option<bool>,
         }
         impl RequestBuilder {
@@ -1244,7 +2188,7 @@ pub mod queries {
                 self.top = Some(top);
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::QueryExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1277,9 +2221,10 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let filter = &this.filter;
                         req.url_mut()
                             .query_pairs_mut()
@@ -1292,7 +2237,7 @@ pub mod queries {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(include_deleted) = &this.include_deleted {
                             req.url_mut()
@@ -1377,13 +2322,13 @@ pub mod queries {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::QueryExpand>,
             pub(crate) depth: Option<i32>,
             pub(crate) include_deleted: Option<bool>,
         }
         impl RequestBuilder {
             #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::QueryExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1420,13 +2365,14 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(depth) = &this.depth {
                             req.url_mut()
@@ -1517,14 +2463,14 @@ pub mod queries {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) query: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::QueryExpand>,
             pub(crate) depth: Option<i32>,
             pub(crate) include_deleted: Option<bool>,
             pub(crate) use_iso_date_format: Option<bool>,
         }
         impl RequestBuilder {
             #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::QueryExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1567,13 +2513,14 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(depth) = &this.depth {
                             req.url_mut()
@@ -1700,9 +2647,10 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(validate_wiql_only) = &this.validate_wiql_only {
@@ -1819,9 +2767,10 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(undelete_descendants) = &this.undelete_descendants {
@@ -1899,9 +2848,10 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1991,9 +2941,10 @@ pub mod queries {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2035,88 +2986,214 @@ pub mod recyclebin {
             ids: impl Into<String>,
             project: impl Into<String>,
         ) -> get_deleted_work_items::RequestBuilder {
-            get_deleted_work_items::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                ids: ids.into(),
-                project: project.into(),
-            }
+            get_deleted_work_items::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                ids: ids.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Gets a list of the IDs and the URLs of the deleted the work items in the Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_work_item_shallow_references(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_deleted_work_item_shallow_references::RequestBuilder {
+            get_deleted_work_item_shallow_references::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Gets a deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `id`: ID of the work item to be returned"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Restores the deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Paylod with instructions to update the IsDeleted flag to false"]
+        #[doc = "* `id`: ID of the work item to be restored"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_work_item(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemDeleteUpdate>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> restore_work_item::RequestBuilder {
+            restore_work_item::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Destroys the specified work item permanently from the Recycle Bin. This action can not be undone."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `id`: ID of the work item to be destroyed permanently"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn destroy_work_item(
+            &self,
+            organization: impl Into<String>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> destroy_work_item::RequestBuilder {
+            destroy_work_item::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                id,
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the work items from the recycle bin, whose IDs have been specified in the parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated list of IDs of the deleted work items to be returned"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_work_items(
+            &self,
+            ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_deleted_work_items::RequestBuilder {
+            self.client
+                .get_deleted_work_items(self.organization.clone(), ids, project)
+        }
+        #[doc = "Gets a list of the IDs and the URLs of the deleted the work items in the Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_work_item_shallow_references(
+            &self,
+            project: impl Into<String>,
+        ) -> get_deleted_work_item_shallow_references::RequestBuilder {
+            self.client
+                .get_deleted_work_item_shallow_references(self.organization.clone(), project)
+        }
+        #[doc = "Gets a deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be returned"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), id, project)
+        }
+        #[doc = "Restores the deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Paylod with instructions to update the IsDeleted flag to false"]
+        #[doc = "* `id`: ID of the work item to be restored"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_work_item(
+            &self,
+            body: impl Into<models::WorkItemDeleteUpdate>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> restore_work_item::RequestBuilder {
+            self.client
+                .restore_work_item(self.organization.clone(), body, id, project)
+        }
+        #[doc = "Destroys the specified work item permanently from the Recycle Bin. This action can not be undone."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be destroyed permanently"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn destroy_work_item(
+            &self,
+            id: i32,
+            project: impl Into<String>,
+        ) -> destroy_work_item::RequestBuilder {
+            self.client
+                .destroy_work_item(self.organization.clone(), id, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the work items from the recycle bin, whose IDs have been specified in the parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated list of IDs of the deleted work items to be returned"]
+        pub fn get_deleted_work_items(
+            &self,
+            ids: impl Into<String>,
+        ) -> get_deleted_work_items::RequestBuilder {
+            self.client
+                .get_deleted_work_items(self.organization.clone(), ids, self.project.clone())
         }
         #[doc = "Gets a list of the IDs and the URLs of the deleted the work items in the Recycle Bin."]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_deleted_work_item_shallow_references(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_deleted_work_item_shallow_references::RequestBuilder {
-            get_deleted_work_item_shallow_references::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-            }
+            self.client.get_deleted_work_item_shallow_references(
+                self.organization.clone(),
+                self.project.clone(),
+            )
         }
         #[doc = "Gets a deleted work item from Recycle Bin."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `id`: ID of the work item to be returned"]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            id: i32,
-            project: impl Into<String>,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                id,
-                project: project.into(),
-            }
+        pub fn get(&self, id: i32) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, self.project.clone())
         }
         #[doc = "Restores the deleted work item from Recycle Bin."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Paylod with instructions to update the IsDeleted flag to false"]
         #[doc = "* `id`: ID of the work item to be restored"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_work_item(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemDeleteUpdate>,
             id: i32,
-            project: impl Into<String>,
         ) -> restore_work_item::RequestBuilder {
-            restore_work_item::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                id,
-                project: project.into(),
-            }
+            self.client
+                .restore_work_item(self.organization.clone(), body, id, self.project.clone())
         }
         #[doc = "Destroys the specified work item permanently from the Recycle Bin. This action can not be undone."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `id`: ID of the work item to be destroyed permanently"]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn destroy_work_item(
-            &self,
-            organization: impl Into<String>,
-            id: i32,
-            project: impl Into<String>,
-        ) -> destroy_work_item::RequestBuilder {
-            destroy_work_item::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                id,
-                project: project.into(),
-            }
+        pub fn destroy_work_item(&self, id: i32) -> destroy_work_item::RequestBuilder {
+            self.client
+                .destroy_work_item(self.organization.clone(), id, self.project.clone())
         }
     }
     pub mod get_deleted_work_items {
@@ -2203,9 +3280,10 @@ pub mod recyclebin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         let req_body = azure_core::EMPTY_BODY;
@@ -2313,9 +3391,10 @@ pub mod recyclebin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2420,9 +3499,10 @@ pub mod recyclebin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2526,9 +3606,10 @@ pub mod recyclebin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2600,9 +3681,10 @@ pub mod recyclebin {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2636,7 +3718,7 @@ pub mod comments {
                 top: None,
                 continuation_token: None,
                 include_deleted: None,
-                expand: None,
+                expand: Vec::new(),
                 order: None,
             }
         }
@@ -2661,7 +3743,7 @@ pub mod comments {
                 work_item_id,
                 ids: ids.into(),
                 include_deleted: None,
-                expand: None,
+                expand: Vec::new(),
             }
         }
         #[doc = "Add a comment on a work item."]
@@ -2707,7 +3789,7 @@ pub mod comments {
                 work_item_id,
                 comment_id,
                 include_deleted: None,
-                expand: None,
+                expand: Vec::new(),
             }
         }
         #[doc = "Update a comment on a work item."]
@@ -2756,6 +3838,209 @@ pub mod comments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of work item comments, pageable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        pub fn get_comments(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+        ) -> get_comments::RequestBuilder {
+            self.client
+                .get_comments(self.organization.clone(), project, work_item_id)
+        }
+        #[doc = "Returns a list of work item comments by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        #[doc = "* `ids`: Comma-separated list of comment ids to return."]
+        pub fn get_comments_batch(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            ids: impl Into<String>,
+        ) -> get_comments_batch::RequestBuilder {
+            self.client
+                .get_comments_batch(self.organization.clone(), project, work_item_id, ids)
+        }
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn add(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+        ) -> add::RequestBuilder {
+            self.client
+                .add(self.organization.clone(), body, project, work_item_id)
+        }
+        #[doc = "Returns a work item comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get the comment."]
+        #[doc = "* `comment_id`: Id of the comment to return."]
+        pub fn get_comment(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> get_comment::RequestBuilder {
+            self.client
+                .get_comment(self.organization.clone(), project, work_item_id, comment_id)
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn update(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Delete a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, work_item_id, comment_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of work item comments, pageable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        pub fn get_comments(&self, work_item_id: i32) -> get_comments::RequestBuilder {
+            self.client.get_comments(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+            )
+        }
+        #[doc = "Returns a list of work item comments by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        #[doc = "* `ids`: Comma-separated list of comment ids to return."]
+        pub fn get_comments_batch(
+            &self,
+            work_item_id: i32,
+            ids: impl Into<String>,
+        ) -> get_comments_batch::RequestBuilder {
+            self.client.get_comments_batch(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                ids,
+            )
+        }
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn add(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            work_item_id: i32,
+        ) -> add::RequestBuilder {
+            self.client.add(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                work_item_id,
+            )
+        }
+        #[doc = "Returns a work item comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get the comment."]
+        #[doc = "* `comment_id`: Id of the comment to return."]
+        pub fn get_comment(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> get_comment::RequestBuilder {
+            self.client.get_comment(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn update(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Delete a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn delete(&self, work_item_id: i32, comment_id: i32) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+    }
     pub mod get_comments {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2814,7 +4099,7 @@ pub mod comments {
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<String>,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::CommentExpandOptions>,
             pub(crate) order: Option<String>,
         }
         impl RequestBuilder {
@@ -2834,8 +4119,8 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::CommentExpandOptions>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Order in which the comments should be returned."]
@@ -2867,9 +4152,10 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -2885,10 +4171,19 @@ pub mod comments {
                                 .query_pairs_mut()
                                 .append_pair("includeDeleted", &include_deleted.to_string());
                         }
-                        if let Some(expand) = &this.expand {
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         if let Some(order) = &this.order {
                             req.url_mut().query_pairs_mut().append_pair("order", order);
@@ -2899,6 +4194,24 @@ pub mod comments {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::CommentList>>
+            {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::CommentList>;
@@ -2971,7 +4284,7 @@ pub mod comments {
             pub(crate) work_item_id: i32,
             pub(crate) ids: String,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::CommentExpandOptions>,
         }
         impl RequestBuilder {
             #[doc = "Specify if the deleted comments should be retrieved."]
@@ -2980,8 +4293,8 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::CommentExpandOptions>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -3008,9 +4321,10 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         if let Some(include_deleted) = &this.include_deleted {
@@ -3018,10 +4332,19 @@ pub mod comments {
                                 .query_pairs_mut()
                                 .append_pair("includeDeleted", &include_deleted.to_string());
                         }
-                        if let Some(expand) = &this.expand {
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -3126,9 +4449,10 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3208,7 +4532,7 @@ pub mod comments {
             pub(crate) work_item_id: i32,
             pub(crate) comment_id: i32,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::CommentExpandOptions>,
         }
         impl RequestBuilder {
             #[doc = "Specify if the deleted comment should be retrieved."]
@@ -3217,8 +4541,8 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::CommentExpandOptions>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -3246,18 +4570,28 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(include_deleted) = &this.include_deleted {
                             req.url_mut()
                                 .query_pairs_mut()
                                 .append_pair("includeDeleted", &include_deleted.to_string());
                         }
-                        if let Some(expand) = &this.expand {
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -3364,9 +4698,10 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3440,9 +4775,10 @@ pub mod comments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3467,6 +4803,31 @@ pub mod artifact_link_types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the list of work item tracking outbound artifact link types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the list of work item tracking outbound artifact link types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3545,9 +4906,10 @@ pub mod artifact_link_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3605,6 +4967,45 @@ pub mod work_item_icons {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of all work item icons."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a work item icon given the friendly name and icon color."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `icon`: The name of the icon"]
+        pub fn get(&self, icon: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(icon, self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of all work item icons."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Get a work item icon given the friendly name and icon color."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `icon`: The name of the icon"]
+        pub fn get(&self, icon: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(icon, self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3683,9 +5084,10 @@ pub mod work_item_icons {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3798,9 +5200,10 @@ pub mod work_item_icons {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(color) = &this.color {
                             req.url_mut().query_pairs_mut().append_pair("color", color);
                         }
@@ -3862,6 +5265,45 @@ pub mod work_item_relation_types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the work item relation types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Gets the work item relation type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `relation`: The relation name"]
+        pub fn get(&self, relation: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), relation)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the work item relation types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+        #[doc = "Gets the work item relation type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `relation`: The relation name"]
+        pub fn get(&self, relation: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), relation)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3940,9 +5382,10 @@ pub mod work_item_relation_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4046,9 +5489,10 @@ pub mod work_item_relation_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4095,6 +5539,37 @@ pub mod work_item_transitions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the next state on the given work item IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: list of work item ids"]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), ids)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the next state on the given work item IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: list of work item ids"]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), ids)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4182,9 +5657,10 @@ pub mod work_item_transitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         if let Some(action) = &this.action {
@@ -4231,6 +5707,31 @@ pub mod account_my_work_recent_activity {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets recent work item activities"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets recent work item activities"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client.list(self.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4311,9 +5812,10 @@ pub mod account_my_work_recent_activity {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4362,6 +5864,43 @@ pub mod artifact_uri_query {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Queries work items linked to a given list of artifact URI."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Defines a list of artifact URI for querying work items."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::ArtifactUriQuery>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            self.client.query(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Queries work items linked to a given list of artifact URI."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Defines a list of artifact URI for querying work items."]
+        pub fn query(&self, body: impl Into<models::ArtifactUriQuery>) -> query::RequestBuilder {
+            self.client
+                .query(self.organization.clone(), body, self.project.clone())
+        }
+    }
     pub mod query {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4443,9 +5982,10 @@ pub mod artifact_uri_query {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4545,6 +6085,105 @@ pub mod attachments {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Uploads an attachment.\n\nOn accounts with higher attachment upload limits (>130MB), you will need to use chunked upload.\nTo upload an attachment in multiple chunks, you first need to [**Start a Chunked Upload**](#start_a_chunked_upload) and then follow the example from the **Upload Chunk** section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Downloads an attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Attachment ID"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), id, project)
+        }
+        #[doc = "Uploads an attachment chunk.\n\nBefore performing [**Upload a Chunk**](#upload_a_chunk), make sure to have an attachment id returned in **Start a Chunked Upload** example on **Create** section. Specify the byte range of the chunk using Content-Length. For example: \"Content - Length\": \"bytes 0 - 39999 / 50000\" for the first 40000 bytes of a 50000 byte file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `id`: The id of the attachment"]
+        #[doc = "* `content_range_header`: starting and ending byte positions for chunked file upload, format is \"Content-Range\": \"bytes 0-10000/50000\""]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn upload_chunk(
+            &self,
+            body: impl Into<String>,
+            id: impl Into<String>,
+            content_range_header: impl Into<String>,
+            project: impl Into<String>,
+        ) -> upload_chunk::RequestBuilder {
+            self.client.upload_chunk(
+                self.organization.clone(),
+                body,
+                id,
+                content_range_header,
+                project,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Uploads an attachment.\n\nOn accounts with higher attachment upload limits (>130MB), you will need to use chunked upload.\nTo upload an attachment in multiple chunks, you first need to [**Start a Chunked Upload**](#start_a_chunked_upload) and then follow the example from the **Upload Chunk** section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        pub fn create(&self, body: impl Into<String>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Downloads an attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Attachment ID"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, self.project.clone())
+        }
+        #[doc = "Uploads an attachment chunk.\n\nBefore performing [**Upload a Chunk**](#upload_a_chunk), make sure to have an attachment id returned in **Start a Chunked Upload** example on **Create** section. Specify the byte range of the chunk using Content-Length. For example: \"Content - Length\": \"bytes 0 - 39999 / 50000\" for the first 40000 bytes of a 50000 byte file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `id`: The id of the attachment"]
+        #[doc = "* `content_range_header`: starting and ending byte positions for chunked file upload, format is \"Content-Range\": \"bytes 0-10000/50000\""]
+        pub fn upload_chunk(
+            &self,
+            body: impl Into<String>,
+            id: impl Into<String>,
+            content_range_header: impl Into<String>,
+        ) -> upload_chunk::RequestBuilder {
+            self.client.upload_chunk(
+                self.organization.clone(),
+                body,
+                id,
+                content_range_header,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod create {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4644,9 +6283,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/octet-stream");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(file_name) = &this.file_name {
@@ -4710,6 +6350,25 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -4779,9 +6438,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(file_name) = &this.file_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4901,9 +6561,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/octet-stream");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.insert_header("contentrangeheader", &this.content_range_header);
@@ -4944,96 +6605,229 @@ pub mod fields {
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn list(
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                expand: Vec::new(),
+            }
+        }
+        #[doc = "Create a new field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: New field definition"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemField>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Gets information on a specific field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            field_name_or_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                field_name_or_ref_name: field_name_or_ref_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Update a field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Payload contains desired value of the field's properties"]
+        #[doc = "* `field_name_or_ref_name`: Name/reference name of the field to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateWorkItemField>,
+            field_name_or_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                field_name_or_ref_name: field_name_or_ref_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Deletes the field. To undelete a filed, see \"Update Field\" API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
             &self,
             organization: impl Into<String>,
+            field_name_or_ref_name: impl Into<String>,
             project: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
+                field_name_or_ref_name: field_name_or_ref_name.into(),
                 project: project.into(),
-                expand: None,
             }
         }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns information for all fields. The project ID/name parameter is optional."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
         #[doc = "Create a new field."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: New field definition"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemField>,
             project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client.create(self.organization.clone(), body, project)
         }
         #[doc = "Gets information on a specific field."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             field_name_or_ref_name: impl Into<String>,
             project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                field_name_or_ref_name: field_name_or_ref_name.into(),
-                project: project.into(),
-            }
+            self.client
+                .get(self.organization.clone(), field_name_or_ref_name, project)
         }
         #[doc = "Update a field."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Payload contains desired value of the field's properties"]
         #[doc = "* `field_name_or_ref_name`: Name/reference name of the field to be updated"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateWorkItemField>,
             field_name_or_ref_name: impl Into<String>,
             project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                field_name_or_ref_name: field_name_or_ref_name.into(),
-                project: project.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                field_name_or_ref_name,
+                project,
+            )
         }
         #[doc = "Deletes the field. To undelete a filed, see \"Update Field\" API."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             field_name_or_ref_name: impl Into<String>,
             project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                field_name_or_ref_name: field_name_or_ref_name.into(),
-                project: project.into(),
-            }
+            self.client
+                .delete(self.organization.clone(), field_name_or_ref_name, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns information for all fields. The project ID/name parameter is optional."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Create a new field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New field definition"]
+        pub fn create(&self, body: impl Into<models::WorkItemField>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Gets information on a specific field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        pub fn get(&self, field_name_or_ref_name: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                field_name_or_ref_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Update a field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Payload contains desired value of the field's properties"]
+        #[doc = "* `field_name_or_ref_name`: Name/reference name of the field to be updated"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateWorkItemField>,
+            field_name_or_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                field_name_or_ref_name,
+                self.project.clone(),
+            )
+        }
+        #[doc = "Deletes the field. To undelete a filed, see \"Update Field\" API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        pub fn delete(&self, field_name_or_ref_name: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                field_name_or_ref_name,
+                self.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -5091,12 +6885,12 @@ pub mod fields {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::GetFieldsExpand>,
         }
         impl RequestBuilder {
             #[doc = "Use ExtensionFields to include extension fields, otherwise exclude them. Unless the feature flag for this parameter is enabled, extension fields are always included."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::GetFieldsExpand>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -5122,13 +6916,23 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(expand) = &this.expand {
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -5231,9 +7035,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5337,9 +7142,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5443,9 +7249,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5517,9 +7324,10 @@ pub mod fields {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5552,6 +7360,46 @@ pub mod project_process_migration {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Migrates a project to a different process within the same OOB type. For example, you can only migrate a project from agile/custom-agile to agile/custom-agile."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn migrate_projects_process(
+            &self,
+            body: impl Into<models::ProcessIdModel>,
+            project: impl Into<String>,
+        ) -> migrate_projects_process::RequestBuilder {
+            self.client
+                .migrate_projects_process(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Migrates a project to a different process within the same OOB type. For example, you can only migrate a project from agile/custom-agile to agile/custom-agile."]
+        pub fn migrate_projects_process(
+            &self,
+            body: impl Into<models::ProcessIdModel>,
+        ) -> migrate_projects_process::RequestBuilder {
+            self.client.migrate_projects_process(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod migrate_projects_process {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5635,9 +7483,10 @@ pub mod project_process_migration {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5688,6 +7537,35 @@ pub mod reporting_work_item_links {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a batch of work item links"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a batch of work item links"]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5797,9 +7675,10 @@ pub mod reporting_work_item_links {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(link_types) = &this.link_types {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5826,6 +7705,26 @@ pub mod reporting_work_item_links {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ReportingWorkItemLinksBatch>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReportingWorkItemLinksBatch>;
@@ -5898,6 +7797,66 @@ pub mod reporting_work_item_revisions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a batch of work item revisions with the option of including deleted items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_revisions_get(
+            &self,
+            project: impl Into<String>,
+        ) -> read_reporting_revisions_get::RequestBuilder {
+            self.client
+                .read_reporting_revisions_get(self.organization.clone(), project)
+        }
+        #[doc = "Get a batch of work item revisions. This request may be used if your list of fields is large enough that it may run the URL over the length limit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains request settings: field filter, type filter, identity format"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_revisions_post(
+            &self,
+            body: impl Into<models::ReportingWorkItemRevisionsFilter>,
+            project: impl Into<String>,
+        ) -> read_reporting_revisions_post::RequestBuilder {
+            self.client
+                .read_reporting_revisions_post(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a batch of work item revisions with the option of including deleted items"]
+        pub fn read_reporting_revisions_get(&self) -> read_reporting_revisions_get::RequestBuilder {
+            self.client
+                .read_reporting_revisions_get(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get a batch of work item revisions. This request may be used if your list of fields is large enough that it may run the URL over the length limit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains request settings: field filter, type filter, identity format"]
+        pub fn read_reporting_revisions_post(
+            &self,
+            body: impl Into<models::ReportingWorkItemRevisionsFilter>,
+        ) -> read_reporting_revisions_post::RequestBuilder {
+            self.client.read_reporting_revisions_post(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod read_reporting_revisions_get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5963,7 +7922,7 @@ pub mod reporting_work_item_revisions {
             pub(crate) include_deleted: Option<bool>,
             pub(crate) include_tag_ref: Option<bool>,
             pub(crate) include_latest_only: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::ReportingRevisionsExpand>,
             pub(crate) include_discussion_changes_only: Option<bool>,
             pub(crate) max_page_size: Option<i32>,
         }
@@ -6012,7 +7971,7 @@ pub mod reporting_work_item_revisions {
                 self
             }
             #[doc = "Return all the fields in work item revisions, including long text fields which are not returned by default"]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::ReportingRevisionsExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -6052,9 +8011,10 @@ pub mod reporting_work_item_revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(fields) = &this.fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6099,7 +8059,7 @@ pub mod reporting_work_item_revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(include_discussion_changes_only) =
                             &this.include_discussion_changes_only
@@ -6120,6 +8080,26 @@ pub mod reporting_work_item_revisions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ReportingWorkItemRevisionsBatch>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReportingWorkItemRevisionsBatch>;
@@ -6197,7 +8177,7 @@ pub mod reporting_work_item_revisions {
             pub(crate) project: String,
             pub(crate) continuation_token: Option<String>,
             pub(crate) start_date_time: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::ReportingRevisionsExpand>,
         }
         impl RequestBuilder {
             #[doc = "Specifies the watermark to start the batch from. Omit this parameter to get the first batch of revisions."]
@@ -6213,7 +8193,7 @@ pub mod reporting_work_item_revisions {
                 self.start_date_time = Some(start_date_time.into());
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::ReportingRevisionsExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -6240,9 +8220,10 @@ pub mod reporting_work_item_revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(continuation_token) = &this.continuation_token {
@@ -6260,13 +8241,33 @@ pub mod reporting_work_item_revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ReportingWorkItemRevisionsBatch>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReportingWorkItemRevisionsBatch>;
@@ -6307,6 +8308,37 @@ pub mod work_item_revisions_discussions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_discussions(
+            &self,
+            project: impl Into<String>,
+        ) -> read_reporting_discussions::RequestBuilder {
+            self.client
+                .read_reporting_discussions(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn read_reporting_discussions(&self) -> read_reporting_discussions::RequestBuilder {
+            self.client
+                .read_reporting_discussions(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod read_reporting_discussions {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6399,9 +8431,10 @@ pub mod work_item_revisions_discussions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(continuation_token) = &this.continuation_token {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6418,6 +8451,26 @@ pub mod work_item_revisions_discussions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, following the continuation token returned by the service until all pages have been fetched."]
+            #[doc = ""]
+            #[doc = "Each item in the stream is the parsed response body for one page of results."]
+            pub fn into_stream(
+                self,
+            ) -> futures::stream::BoxStream<
+                'static,
+                azure_core::Result<models::ReportingWorkItemRevisionsBatch>,
+            > {
+                let make_request = move |continuation: Option<String>| {
+                    let mut this = self.clone();
+                    async move {
+                        if let Some(continuation) = continuation {
+                            this.continuation_token = Some(continuation);
+                        }
+                        Ok(this.send().await?.into_raw_response())
+                    }
+                };
+                crate::paging::continuation_stream(make_request)
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReportingWorkItemRevisionsBatch>;
@@ -6447,16 +8500,53 @@ pub mod send_mail {
         #[doc = "* `project`: Project ID or project name"]
         pub fn send_mail(
             &self,
-            organization: impl Into<String>,
+            organization: impl Into<String>,
+            body: impl Into<models::SendMailBody>,
+            project: impl Into<String>,
+        ) -> send_mail::RequestBuilder {
+            send_mail::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "RESTful method to send mail for selected/queried work items."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn send_mail(
+            &self,
+            body: impl Into<models::SendMailBody>,
+            project: impl Into<String>,
+        ) -> send_mail::RequestBuilder {
+            self.client
+                .send_mail(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "RESTful method to send mail for selected/queried work items."]
+        pub fn send_mail(
+            &self,
             body: impl Into<models::SendMailBody>,
-            project: impl Into<String>,
         ) -> send_mail::RequestBuilder {
-            send_mail::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client
+                .send_mail(self.organization.clone(), body, self.project.clone())
         }
     }
     pub mod send_mail {
@@ -6507,9 +8597,10 @@ pub mod send_mail {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6593,6 +8684,93 @@ pub mod tags {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, tag_id_or_name)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTagDefinition>,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, tag_id_or_name)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, tag_id_or_name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        pub fn get(&self, tag_id_or_name: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                tag_id_or_name,
+            )
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTagDefinition>,
+            tag_id_or_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                tag_id_or_name,
+            )
+        }
+        pub fn delete(&self, tag_id_or_name: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                tag_id_or_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6673,9 +8851,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6781,9 +8960,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6890,9 +9070,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6966,9 +9147,10 @@ pub mod tags {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7056,88 +9238,265 @@ pub mod work_items {
         #[doc = "Returns a single work item."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `id`: The work item id"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_item(
+            &self,
+            organization: impl Into<String>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> get_work_item::RequestBuilder {
+            get_work_item::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                id,
+                project: project.into(),
+                fields: None,
+                as_of: None,
+                expand: None,
+            }
+        }
+        #[doc = "Updates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: A list of operations to perform when updating a Work Item"]
+        #[doc = "* `id`: The id of the work item to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::JsonPatchOperation>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                id,
+                project: project.into(),
+                validate_only: None,
+                bypass_rules: None,
+                suppress_notifications: None,
+                expand: None,
+            }
+        }
+        #[doc = "Deletes the specified work item and sends it to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion. It is recommended NOT to use this parameter. If you do, please use this parameter with extreme caution."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `id`: ID of the work item to be deleted"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                id,
+                project: project.into(),
+                destroy: None,
+            }
+        }
+        #[doc = "Gets work items for a list of work item ids (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_items_batch(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemBatchGetRequest>,
+            project: impl Into<String>,
+        ) -> get_work_items_batch::RequestBuilder {
+            get_work_items_batch::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a list of work items (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: The comma-separated list of requested work item ids. (Maximum 200 ids allowed)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), ids, project)
+        }
+        #[doc = "Returns a single work item from a template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The work item type name"]
+        pub fn get_work_item_template(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_work_item_template::RequestBuilder {
+            self.client
+                .get_work_item_template(self.organization.clone(), project, type_)
+        }
+        #[doc = "Creates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when creating a Work Item"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The work item type of the work item to create"]
+        pub fn create(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, type_)
+        }
+        #[doc = "Returns a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The work item id"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_item(
+            &self,
+            id: i32,
+            project: impl Into<String>,
+        ) -> get_work_item::RequestBuilder {
+            self.client
+                .get_work_item(self.organization.clone(), id, project)
+        }
+        #[doc = "Updates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when updating a Work Item"]
+        #[doc = "* `id`: The id of the work item to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, id, project)
+        }
+        #[doc = "Deletes the specified work item and sends it to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion. It is recommended NOT to use this parameter. If you do, please use this parameter with extreme caution."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be deleted"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(&self, id: i32, project: impl Into<String>) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), id, project)
+        }
+        #[doc = "Gets work items for a list of work item ids (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_items_batch(
+            &self,
+            body: impl Into<models::WorkItemBatchGetRequest>,
+            project: impl Into<String>,
+        ) -> get_work_items_batch::RequestBuilder {
+            self.client
+                .get_work_items_batch(self.organization.clone(), body, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a list of work items (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: The comma-separated list of requested work item ids. (Maximum 200 ids allowed)."]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), ids, self.project.clone())
+        }
+        #[doc = "Returns a single work item from a template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: The work item type name"]
+        pub fn get_work_item_template(
+            &self,
+            type_: impl Into<String>,
+        ) -> get_work_item_template::RequestBuilder {
+            self.client.get_work_item_template(
+                self.organization.clone(),
+                self.project.clone(),
+                type_,
+            )
+        }
+        #[doc = "Creates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when creating a Work Item"]
+        #[doc = "* `type_`: The work item type of the work item to create"]
+        pub fn create(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            type_: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), type_)
+        }
+        #[doc = "Returns a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `id`: The work item id"]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn get_work_item(
-            &self,
-            organization: impl Into<String>,
-            id: i32,
-            project: impl Into<String>,
-        ) -> get_work_item::RequestBuilder {
-            get_work_item::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                id,
-                project: project.into(),
-                fields: None,
-                as_of: None,
-                expand: None,
-            }
+        pub fn get_work_item(&self, id: i32) -> get_work_item::RequestBuilder {
+            self.client
+                .get_work_item(self.organization.clone(), id, self.project.clone())
         }
         #[doc = "Updates a single work item."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: A list of operations to perform when updating a Work Item"]
         #[doc = "* `id`: The id of the work item to update"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::JsonPatchOperation>,
             id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body,
-                id,
-                project: project.into(),
-                validate_only: None,
-                bypass_rules: None,
-                suppress_notifications: None,
-                expand: None,
-            }
+            self.client
+                .update(self.organization.clone(), body, id, self.project.clone())
         }
         #[doc = "Deletes the specified work item and sends it to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion. It is recommended NOT to use this parameter. If you do, please use this parameter with extreme caution."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `id`: ID of the work item to be deleted"]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            id: i32,
-            project: impl Into<String>,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                id,
-                project: project.into(),
-                destroy: None,
-            }
+        pub fn delete(&self, id: i32) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), id, self.project.clone())
         }
         #[doc = "Gets work items for a list of work item ids (Maximum 200)"]
-        #[doc = ""]
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_work_items_batch(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemBatchGetRequest>,
-            project: impl Into<String>,
         ) -> get_work_items_batch::RequestBuilder {
-            get_work_items_batch::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            self.client
+                .get_work_items_batch(self.organization.clone(), body, self.project.clone())
         }
     }
     pub mod list {
@@ -7197,7 +9556,7 @@ pub mod work_items {
             pub(crate) project: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
             pub(crate) error_policy: Option<String>,
         }
         impl RequestBuilder {
@@ -7212,7 +9571,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7244,9 +9603,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let ids = &this.ids;
                         req.url_mut().query_pairs_mut().append_pair("ids", ids);
                         if let Some(fields) = &this.fields {
@@ -7263,7 +9623,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         if let Some(error_policy) = &this.error_policy {
                             req.url_mut()
@@ -7348,7 +9708,7 @@ pub mod work_items {
             pub(crate) type_: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
             #[doc = "Comma-separated list of requested fields"]
@@ -7362,7 +9722,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7390,9 +9750,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(fields) = &this.fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7407,7 +9768,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -7489,7 +9850,7 @@ pub mod work_items {
             pub(crate) validate_only: Option<bool>,
             pub(crate) bypass_rules: Option<bool>,
             pub(crate) suppress_notifications: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
             #[doc = "Indicate if you only want to validate the changes without saving the work item"]
@@ -7508,7 +9869,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7536,9 +9897,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(validate_only) = &this.validate_only {
@@ -7560,7 +9922,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7639,7 +10001,7 @@ pub mod work_items {
             pub(crate) project: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
             #[doc = "Comma-separated list of requested fields"]
@@ -7653,7 +10015,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7681,9 +10043,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(fields) = &this.fields {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -7698,7 +10061,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -7780,7 +10143,7 @@ pub mod work_items {
             pub(crate) validate_only: Option<bool>,
             pub(crate) bypass_rules: Option<bool>,
             pub(crate) suppress_notifications: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
             #[doc = "Indicate if you only want to validate the changes without saving the work item"]
@@ -7799,7 +10162,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7827,9 +10190,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json-patch+json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(validate_only) = &this.validate_only {
@@ -7851,7 +10215,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7960,9 +10324,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(destroy) = &this.destroy {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8069,9 +10434,10 @@ pub mod work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -8142,6 +10508,57 @@ pub mod revisions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the list of fully hydrated work item revisions, paged."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, id: i32, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), id, project)
+        }
+        #[doc = "Returns a fully hydrated work item for the requested revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: i32,
+            revision_number: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, revision_number, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the list of fully hydrated work item revisions, paged."]
+        pub fn list(&self, id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), id, self.project.clone())
+        }
+        #[doc = "Returns a fully hydrated work item for the requested revision"]
+        pub fn get(&self, id: i32, revision_number: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                id,
+                revision_number,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8199,7 +10616,7 @@ pub mod revisions {
             pub(crate) project: String,
             pub(crate) top: Option<i32>,
             pub(crate) skip: Option<i32>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
             pub fn top(mut self, top: i32) -> Self {
@@ -8210,7 +10627,7 @@ pub mod revisions {
                 self.skip = Some(skip);
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -8238,9 +10655,10 @@ pub mod revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8254,7 +10672,7 @@ pub mod revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -8262,6 +10680,34 @@ pub mod revisions {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WorkItemList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(make_request, skip, |page: &models::WorkItemList| {
+                    page.value.len()
+                })
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WorkItem>>
+            {
+                crate::paging::items_stream(self.pages(page_size), |page: models::WorkItemList| {
+                    page.value
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WorkItemList>;
@@ -8333,10 +10779,10 @@ pub mod revisions {
             pub(crate) id: i32,
             pub(crate) revision_number: i32,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<models::WorkItemExpand>,
         }
         impl RequestBuilder {
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<models::WorkItemExpand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -8365,13 +10811,14 @@ pub mod revisions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &expand.to_string());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -8440,6 +10887,57 @@ pub mod updates {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns a the deltas between work item revisions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, id: i32, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), id, project)
+        }
+        #[doc = "Returns a single update for a work item"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: i32,
+            update_number: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, update_number, project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns a the deltas between work item revisions"]
+        pub fn list(&self, id: i32) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), id, self.project.clone())
+        }
+        #[doc = "Returns a single update for a work item"]
+        pub fn get(&self, id: i32, update_number: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                id,
+                update_number,
+                self.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -8532,9 +11030,10 @@ pub mod updates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8551,6 +11050,37 @@ pub mod updates {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WorkItemUpdateList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::WorkItemUpdateList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::WorkItemUpdate>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::WorkItemUpdateList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WorkItemUpdateList>;
@@ -8649,9 +11179,10 @@ pub mod updates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8681,74 +11212,203 @@ pub mod comments_reactions {
         #[doc = "Gets reactions of a comment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                work_item_id,
+                comment_id,
+            }
+        }
+        #[doc = "Adds a new reaction to a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                work_item_id,
+                comment_id,
+                reaction_type: reaction_type.into(),
+            }
+        }
+        #[doc = "Deletes an existing reaction on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                work_item_id,
+                comment_id,
+                reaction_type: reaction_type.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets reactions of a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, work_item_id, comment_id)
+        }
+        #[doc = "Adds a new reaction to a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn create(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(
+                self.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+        #[doc = "Deletes an existing reaction on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
-        pub fn list(
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            reaction_type: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                project,
                 work_item_id,
                 comment_id,
-            }
+                reaction_type,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets reactions of a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        pub fn list(&self, work_item_id: i32, comment_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+            )
         }
         #[doc = "Adds a new reaction to a comment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
         #[doc = "* `reaction_type`: Type of the reaction"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
             reaction_type: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.create(
+                self.organization.clone(),
+                self.project.clone(),
                 work_item_id,
                 comment_id,
-                reaction_type: reaction_type.into(),
-            }
+                reaction_type,
+            )
         }
         #[doc = "Deletes an existing reaction on a comment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
         #[doc = "* `reaction_type`: Type of the reaction"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
             reaction_type: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
                 work_item_id,
                 comment_id,
-                reaction_type: reaction_type.into(),
-            }
+                reaction_type,
+            )
         }
     }
     pub mod list {
@@ -8835,9 +11495,10 @@ pub mod comments_reactions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8947,9 +11608,10 @@ pub mod comments_reactions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9057,9 +11719,10 @@ pub mod comments_reactions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9114,6 +11777,65 @@ pub mod comment_reactions_engaged_users {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get users who reacted on the comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID."]
+        #[doc = "* `comment_id`: Comment ID."]
+        #[doc = "* `reaction_type`: Type of the reaction."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get users who reacted on the comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID."]
+        #[doc = "* `comment_id`: Comment ID."]
+        #[doc = "* `reaction_type`: Type of the reaction."]
+        pub fn list(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9210,9 +11932,10 @@ pub mod comment_reactions_engaged_users {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(top) = &this.top {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -9229,6 +11952,37 @@ pub mod comment_reactions_engaged_users {
                     }
                 })
             }
+            #[doc = "Returns a stream of responses, requesting pages of up to `page_size` items until the service returns an empty page."]
+            #[doc = ""]
+            #[doc = "Each request skips the items returned so far, so all items are fetched even if the service caps the page size below `page_size` (e.g. at 100 or 1000 items)."]
+            #[doc = ""]
+            #[doc = "Paging starts from the `skip` value set on the request builder, if any."]
+            pub fn pages(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::IdentityRefList>>
+            {
+                let skip = self.skip.unwrap_or(0);
+                let make_request = move |skip: i32| {
+                    std::future::IntoFuture::into_future(self.clone().top(page_size).skip(skip))
+                };
+                crate::paging::skip_top_stream(
+                    make_request,
+                    skip,
+                    |page: &models::IdentityRefList| page.value.len(),
+                )
+            }
+            #[doc = "Returns a stream of the items in all pages, requesting pages of up to `page_size` items until the service returns an empty page."]
+            pub fn items(
+                self,
+                page_size: i32,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::IdentityRef>>
+            {
+                crate::paging::items_stream(
+                    self.pages(page_size),
+                    |page: models::IdentityRefList| page.value,
+                )
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::IdentityRefList>;
@@ -9290,6 +12044,70 @@ pub mod comments_versions {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, work_item_id, comment_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            version: i32,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+                version,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        pub fn list(&self, work_item_id: i32, comment_id: i32) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        pub fn get(&self, work_item_id: i32, comment_id: i32, version: i32) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                work_item_id,
+                comment_id,
+                version,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9374,9 +12192,10 @@ pub mod comments_versions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9483,9 +12302,10 @@ pub mod comments_versions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9548,6 +12368,56 @@ pub mod work_item_type_categories {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get all work item type categories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Get specific work item type category by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `category`: The category name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            category: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, category)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get all work item type categories."]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Get specific work item type category by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `category`: The category name"]
+        pub fn get(&self, category: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), category)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9628,9 +12498,10 @@ pub mod work_item_type_categories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9736,9 +12607,10 @@ pub mod work_item_type_categories {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9803,6 +12675,55 @@ pub mod work_item_types {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the list of work item types"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Returns a work item type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, type_)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the list of work item types"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Returns a work item type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Work item type name"]
+        pub fn get(&self, type_: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), type_)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -9883,9 +12804,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9988,9 +12910,10 @@ pub mod work_item_types {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10034,7 +12957,7 @@ pub mod work_item_types_field {
                 organization: organization.into(),
                 project: project.into(),
                 type_: type_.into(),
-                expand: None,
+                expand: Vec::new(),
             }
         }
         #[doc = "Get a field for a work item type with detailed references."]
@@ -10045,19 +12968,86 @@ pub mod work_item_types_field {
         #[doc = "* `type_`: Work item type."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+            field: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                type_: type_.into(),
+                field: field.into(),
+                expand: Vec::new(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a list of fields for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, type_)
+        }
+        #[doc = "Get a field for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+            field: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, type_, field)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a list of fields for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn list(&self, type_: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), type_)
+        }
+        #[doc = "Get a field for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn get(
+            &self,
             type_: impl Into<String>,
             field: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                type_: type_.into(),
-                field: field.into(),
-                expand: None,
-            }
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                type_,
+                field,
+            )
         }
     }
     pub mod list {
@@ -10118,12 +13108,12 @@ pub mod work_item_types_field {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) type_: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::WorkItemTypeFieldsExpandLevel>,
         }
         impl RequestBuilder {
             #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::WorkItemTypeFieldsExpandLevel>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -10150,13 +13140,23 @@ pub mod work_item_types_field {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(expand) = &this.expand {
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -10240,12 +13240,12 @@ pub mod work_item_types_field {
             pub(crate) project: String,
             pub(crate) type_: String,
             pub(crate) field: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Vec<models::WorkItemTypeFieldsExpandLevel>,
         }
         impl RequestBuilder {
             #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
-                self.expand = Some(expand.into());
+            pub fn expand(mut self, expand: Vec<models::WorkItemTypeFieldsExpandLevel>) -> Self {
+                self.expand = expand;
                 self
             }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
@@ -10273,13 +13273,23 @@ pub mod work_item_types_field {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                        if let Some(expand) = &this.expand {
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
+                        let expand = &this.expand;
+                        if !expand.is_empty() {
+                            let mut values: Vec<String> = Vec::new();
+                            for value in expand {
+                                let value = value.to_string();
+                                if !values.contains(&value) {
+                                    values.push(value);
+                                }
+                            }
+                            let value = values.join(",");
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", &value);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -10329,6 +13339,43 @@ pub mod work_item_type_states {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the state names and colors for a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The state name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, type_)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the state names and colors for a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: The state name"]
+        pub fn list(&self, type_: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), type_)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10411,9 +13458,10 @@ pub mod work_item_type_states {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10554,6 +13602,177 @@ pub mod templates {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Creates a template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Gets the template with specified id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template Id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, team, template_id)
+        }
+        #[doc = "Replace template contents"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents to replace with"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn replace_template(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> replace_template::RequestBuilder {
+            self.client.replace_template(
+                self.organization.clone(),
+                body,
+                project,
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Deletes the template with given id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, team, template_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Creates a template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone(), team)
+        }
+        #[doc = "Gets the template with specified id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template Id"]
+        pub fn get(
+            &self,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Replace template contents"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents to replace with"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn replace_template(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> replace_template::RequestBuilder {
+            self.client.replace_template(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Deletes the template with given id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn delete(
+            &self,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                template_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -10644,9 +13863,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(workitemtypename) = &this.workitemtypename {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -10758,9 +13978,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -10867,9 +14088,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -10976,9 +14198,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -11052,9 +14275,10 @@ pub mod templates {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -11141,6 +14365,103 @@ pub mod wiql {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets the results of the query given its WIQL."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query containing the WIQL."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_wiql(
+            &self,
+            body: impl Into<models::Wiql>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_wiql::RequestBuilder {
+            self.client
+                .query_by_wiql(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_id(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_id::RequestBuilder {
+            self.client
+                .query_by_id(self.organization.clone(), id, project, team)
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets the results of the query given its WIQL."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query containing the WIQL."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_wiql(
+            &self,
+            body: impl Into<models::Wiql>,
+            team: impl Into<String>,
+        ) -> query_by_wiql::RequestBuilder {
+            self.client
+                .query_by_wiql(self.organization.clone(), body, self.project.clone(), team)
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_id(
+            &self,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_id::RequestBuilder {
+            self.client
+                .query_by_id(self.organization.clone(), id, self.project.clone(), team)
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), id, self.project.clone(), team)
+        }
+    }
     pub mod query_by_wiql {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -11236,9 +14557,10 @@ pub mod wiql {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(time_precision) = &this.time_precision {
@@ -11369,9 +14691,10 @@ pub mod wiql {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(time_precision) = &this.time_precision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -11500,9 +14823,10 @@ pub mod wiql {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(time_precision) = &this.time_precision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -397,6 +397,72 @@ impl CommentCreate {
         Self::default()
     }
 }
+#[doc = "Additional data to include in work item comments"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "CommentExpandOptions")]
+pub enum CommentExpandOptions {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "reactions")]
+    Reactions,
+    #[serde(rename = "renderedText")]
+    RenderedText,
+    #[serde(rename = "renderedTextOnly")]
+    RenderedTextOnly,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for CommentExpandOptions {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for CommentExpandOptions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for CommentExpandOptions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("CommentExpandOptions", 0u32, "none"),
+            Self::Reactions => {
+                serializer.serialize_unit_variant("CommentExpandOptions", 1u32, "reactions")
+            }
+            Self::RenderedText => {
+                serializer.serialize_unit_variant("CommentExpandOptions", 2u32, "renderedText")
+            }
+            Self::RenderedTextOnly => {
+                serializer.serialize_unit_variant("CommentExpandOptions", 3u32, "renderedTextOnly")
+            }
+            Self::All => serializer.serialize_unit_variant("CommentExpandOptions", 4u32, "all"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for CommentExpandOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Reactions => f.write_str("reactions"),
+            Self::RenderedText => f.write_str("renderedText"),
+            Self::RenderedTextOnly => f.write_str("renderedTextOnly"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = "Represents a list of work item comments."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CommentList {
@@ -809,6 +875,62 @@ impl FieldDependentRule {
         }
     }
 }
+#[doc = "Additional fields to include in the list of work item fields"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "GetFieldsExpand")]
+pub enum GetFieldsExpand {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "extensionFields")]
+    ExtensionFields,
+    #[serde(rename = "includeDeleted")]
+    IncludeDeleted,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for GetFieldsExpand {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for GetFieldsExpand {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for GetFieldsExpand {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("GetFieldsExpand", 0u32, "none"),
+            Self::ExtensionFields => {
+                serializer.serialize_unit_variant("GetFieldsExpand", 1u32, "extensionFields")
+            }
+            Self::IncludeDeleted => {
+                serializer.serialize_unit_variant("GetFieldsExpand", 2u32, "includeDeleted")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for GetFieldsExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::ExtensionFields => f.write_str("extensionFields"),
+            Self::IncludeDeleted => f.write_str("includeDeleted"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct GraphSubjectBase {
@@ -1193,6 +1315,66 @@ pub mod query_batch_get_request {
         Omit,
     }
 }
+#[doc = "Properties to include in query results"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "QueryExpand")]
+pub enum QueryExpand {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "wiql")]
+    Wiql,
+    #[serde(rename = "clauses")]
+    Clauses,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "minimal")]
+    Minimal,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for QueryExpand {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for QueryExpand {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for QueryExpand {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("QueryExpand", 0u32, "none"),
+            Self::Wiql => serializer.serialize_unit_variant("QueryExpand", 1u32, "wiql"),
+            Self::Clauses => serializer.serialize_unit_variant("QueryExpand", 2u32, "clauses"),
+            Self::All => serializer.serialize_unit_variant("QueryExpand", 3u32, "all"),
+            Self::Minimal => serializer.serialize_unit_variant("QueryExpand", 4u32, "minimal"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for QueryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Wiql => f.write_str("wiql"),
+            Self::Clauses => f.write_str("clauses"),
+            Self::All => f.write_str("all"),
+            Self::Minimal => f.write_str("minimal"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = "Represents an item in the work item query hierarchy. This can be either a query or a folder."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct QueryHierarchyItem {
@@ -1457,6 +1639,58 @@ impl ReferenceLinks {
         Self::default()
     }
 }
+#[doc = "Properties to include in reporting work item revisions"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "ReportingRevisionsExpand")]
+pub enum ReportingRevisionsExpand {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "fields")]
+    Fields,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ReportingRevisionsExpand {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ReportingRevisionsExpand {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ReportingRevisionsExpand {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => {
+                serializer.serialize_unit_variant("ReportingRevisionsExpand", 0u32, "none")
+            }
+            Self::Fields => {
+                serializer.serialize_unit_variant("ReportingRevisionsExpand", 1u32, "fields")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for ReportingRevisionsExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Fields => f.write_str("fields"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReportingWorkItemLinksBatch {
@@ -1468,11 +1702,33 @@ impl ReportingWorkItemLinksBatch {
         Self::default()
     }
 }
-#[doc = ""]
+#[doc = "The class describes reporting work item revision batch."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReportingWorkItemRevisionsBatch {
-    #[serde(flatten)]
-    pub streamed_batch: StreamedBatch,
+    #[doc = "ContinuationToken acts as a waterMark. Used while querying large results."]
+    #[serde(
+        rename = "continuationToken",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub continuation_token: Option<String>,
+    #[doc = "Returns 'true' if it's last batch, 'false' otherwise."]
+    #[serde(
+        rename = "isLastBatch",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_last_batch: Option<bool>,
+    #[doc = "The next link for the work item."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    #[doc = "The work item revisions in the batch."]
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::serde::deserialize_null_default"
+    )]
+    pub values: Vec<WorkItem>,
 }
 impl ReportingWorkItemRevisionsBatch {
     pub fn new() -> Self {
@@ -2133,6 +2389,68 @@ impl WorkItemDeleteUpdate {
         Self::default()
     }
 }
+#[doc = "Work item attributes to include in the response"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "WorkItemExpand")]
+pub enum WorkItemExpand {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "relations")]
+    Relations,
+    #[serde(rename = "fields")]
+    Fields,
+    #[serde(rename = "links")]
+    Links,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for WorkItemExpand {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for WorkItemExpand {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for WorkItemExpand {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => serializer.serialize_unit_variant("WorkItemExpand", 0u32, "none"),
+            Self::Relations => {
+                serializer.serialize_unit_variant("WorkItemExpand", 1u32, "relations")
+            }
+            Self::Fields => serializer.serialize_unit_variant("WorkItemExpand", 2u32, "fields"),
+            Self::Links => serializer.serialize_unit_variant("WorkItemExpand", 3u32, "links"),
+            Self::All => serializer.serialize_unit_variant("WorkItemExpand", 4u32, "all"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for WorkItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Relations => f.write_str("relations"),
+            Self::Fields => f.write_str("fields"),
+            Self::Links => f.write_str("links"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = "Describes a field on a work item and it's properties specific to that work item type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkItemField {
@@ -3222,6 +3540,74 @@ impl WorkItemTypeFieldWithReferencesList {
         Self::default()
     }
 }
+#[doc = "Additional properties to include in work item type fields"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "WorkItemTypeFieldsExpandLevel")]
+pub enum WorkItemTypeFieldsExpandLevel {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "allowedValues")]
+    AllowedValues,
+    #[serde(rename = "dependentFields")]
+    DependentFields,
+    #[serde(rename = "all")]
+    All,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for WorkItemTypeFieldsExpandLevel {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for WorkItemTypeFieldsExpandLevel {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for WorkItemTypeFieldsExpandLevel {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::None => {
+                serializer.serialize_unit_variant("WorkItemTypeFieldsExpandLevel", 0u32, "none")
+            }
+            Self::AllowedValues => serializer.serialize_unit_variant(
+                "WorkItemTypeFieldsExpandLevel",
+                1u32,
+                "allowedValues",
+            ),
+            Self::DependentFields => serializer.serialize_unit_variant(
+                "WorkItemTypeFieldsExpandLevel",
+                2u32,
+                "dependentFields",
+            ),
+            Self::All => {
+                serializer.serialize_unit_variant("WorkItemTypeFieldsExpandLevel", 3u32, "all")
+            }
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl std::fmt::Display for WorkItemTypeFieldsExpandLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::AllowedValues => f.write_str("allowedValues"),
+            Self::DependentFields => f.write_str("dependentFields"),
+            Self::All => f.write_str("all"),
+            Self::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
 #[doc = ""]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct WorkItemTypeList {
@@ -0,0 +1,279 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Incremental extraction of work item revisions via the reporting revisions API.
+//!
+//! The reporting revisions API returns the revisions of all work items in a project in batches.
+//! Each batch includes a `continuationToken`, which acts as a watermark: passing it to the next
+//! request returns the revisions made after that batch. The last batch of the currently available
+//! revisions is flagged with `isLastBatch`, and its continuation token can be stored and used
+//! later to fetch only the revisions made since.
+//!
+//! [`ReportingSync`] drives these requests, yielding each batch together with the
+//! [`ReportingWatermark`] to persist once the batch has been processed.
+
+use super::models::{
+    ReportingRevisionsExpand, ReportingWorkItemRevisionsBatch, ReportingWorkItemRevisionsFilter,
+    WorkItem,
+};
+use super::reporting_work_item_revisions;
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// The position of a [`ReportingSync`] in the stream of work item revisions.
+///
+/// The watermark is serializable, so that it can be persisted after each batch and used to
+/// resume the sync after a restart.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportingWatermark {
+    /// The continuation token returned with the last batch that was processed.
+    #[serde(
+        rename = "continuationToken",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub continuation_token: Option<String>,
+}
+
+impl ReportingWatermark {
+    /// Creates a watermark from a continuation token.
+    pub fn new(continuation_token: impl Into<String>) -> Self {
+        Self {
+            continuation_token: Some(continuation_token.into()),
+        }
+    }
+}
+
+/// A batch of work item revisions returned by a [`ReportingSync`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReportingSyncBatch {
+    /// The work item revisions in the batch.
+    pub revisions: Vec<WorkItem>,
+    /// The watermark to persist once the batch has been processed.
+    pub watermark: ReportingWatermark,
+    /// `true` if this is the last batch of the currently available revisions.
+    pub is_last_batch: bool,
+}
+
+impl reporting_work_item_revisions::Client {
+    /// Creates a [`ReportingSync`] that reads the work item revisions of a project.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    pub fn sync(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ReportingSync {
+        ReportingSync {
+            client: self.0.clone(),
+            organization: organization.into(),
+            project: project.into(),
+            filter: ReportingWorkItemRevisionsFilter::default(),
+            start_date_time: None,
+            expand: None,
+            watermark: ReportingWatermark::default(),
+        }
+    }
+}
+
+scoped_client_methods! {
+    reporting_work_item_revisions;
+    /// Creates a [`ReportingSync`] that reads the work item revisions of a project.
+    pub fn sync([project]) -> ReportingSync;
+}
+
+/// Reads work item revisions incrementally, resuming from a [`ReportingWatermark`].
+///
+/// Example:
+///
+/// ```ignore
+/// let watermark: ReportingWatermark = load_watermark()?;
+/// let mut batches = wit_client
+///     .reporting_work_item_revisions_client()
+///     .sync(organization, project)
+///     .fields(vec!["System.Id".to_owned(), "System.State".to_owned()])
+///     .watermark(watermark)
+///     .into_stream();
+/// while let Some(batch) = batches.try_next().await? {
+///     store_revisions(&batch.revisions)?;
+///     save_watermark(&batch.watermark)?;
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReportingSync {
+    client: super::Client,
+    organization: String,
+    project: String,
+    filter: ReportingWorkItemRevisionsFilter,
+    start_date_time: Option<OffsetDateTime>,
+    expand: Option<ReportingRevisionsExpand>,
+    watermark: ReportingWatermark,
+}
+
+impl ReportingSync {
+    /// A list of fields to return in work item revisions. Omit this parameter to get all reportable fields.
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.filter.fields = fields;
+        self
+    }
+
+    /// A list of types to filter the results to specific work item types. Omit this parameter to get work item revisions of all work item types.
+    pub fn types(mut self, types: Vec<String>) -> Self {
+        self.filter.types = types;
+        self
+    }
+
+    /// Include deleted work item in the result.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.filter.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Return an identity reference instead of a string value for identity fields.
+    pub fn include_identity_ref(mut self, include_identity_ref: bool) -> Self {
+        self.filter.include_identity_ref = Some(include_identity_ref);
+        self
+    }
+
+    /// Include only the latest version of a work item, skipping over all previous revisions of the work item.
+    pub fn include_latest_only(mut self, include_latest_only: bool) -> Self {
+        self.filter.include_latest_only = Some(include_latest_only);
+        self
+    }
+
+    /// Include tag reference instead of string value for System.Tags field
+    pub fn include_tag_ref(mut self, include_tag_ref: bool) -> Self {
+        self.filter.include_tag_ref = Some(include_tag_ref);
+        self
+    }
+
+    /// Date/time to use as a starting point for revisions, all revisions will occur after this date/time.
+    /// Cannot be used in conjunction with a watermark.
+    pub fn start_date_time(mut self, start_date_time: impl Into<OffsetDateTime>) -> Self {
+        self.start_date_time = Some(start_date_time.into());
+        self
+    }
+
+    /// Return all the fields in work item revisions, including long text fields which are not returned by default
+    pub fn expand(mut self, expand: ReportingRevisionsExpand) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Resumes the sync from a watermark returned with a previous batch.
+    pub fn watermark(mut self, watermark: ReportingWatermark) -> Self {
+        self.watermark = watermark;
+        self
+    }
+
+    /// Returns a stream of batches of work item revisions.
+    ///
+    /// The stream ends after the last batch of the currently available revisions.
+    pub fn into_stream(self) -> BoxStream<'static, azure_core::Result<ReportingSyncBatch>> {
+        stream::unfold(Some(self), |sync| async move {
+            let mut sync = sync?;
+            match sync.read_batch().await {
+                Ok(batch) => {
+                    let (batch, done) = next_batch(batch, &sync.watermark);
+                    sync.watermark = batch.watermark.clone();
+                    Some((Ok(batch), if done { None } else { Some(sync) }))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+        .boxed()
+    }
+
+    async fn read_batch(&self) -> azure_core::Result<ReportingWorkItemRevisionsBatch> {
+        let mut request = reporting_work_item_revisions::Client(self.client.clone())
+            .read_reporting_revisions_post(
+                self.organization.clone(),
+                self.filter.clone(),
+                self.project.clone(),
+            );
+        match &self.watermark.continuation_token {
+            Some(continuation_token) => {
+                request = request.continuation_token(continuation_token);
+            }
+            None => {
+                if let Some(start_date_time) = self.start_date_time {
+                    request = request.start_date_time(start_date_time);
+                }
+            }
+        }
+        if let Some(expand) = &self.expand {
+            request = request.expand(expand.clone());
+        }
+        request.await
+    }
+}
+
+/// Converts a response into a batch, returning `true` if the sync is done.
+///
+/// The sync is done after the last batch, or if the service does not return a new watermark
+/// (which would otherwise request the same batch again).
+fn next_batch(
+    batch: ReportingWorkItemRevisionsBatch,
+    watermark: &ReportingWatermark,
+) -> (ReportingSyncBatch, bool) {
+    let is_last_batch = batch.is_last_batch.unwrap_or(false);
+    let next = match batch.continuation_token {
+        Some(continuation_token) if !continuation_token.is_empty() => {
+            ReportingWatermark::new(continuation_token)
+        }
+        _ => watermark.clone(),
+    };
+    let done = is_last_batch || next == *watermark;
+    (
+        ReportingSyncBatch {
+            revisions: batch.values,
+            watermark: next,
+            is_last_batch,
+        },
+        done,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_next_batch() {
+        let batch: ReportingWorkItemRevisionsBatch = serde_json::from_value(json!({
+            "values": [
+                { "id": 1, "rev": 2, "url": "https://example.com/1", "fields": { "System.State": "Active" } }
+            ],
+            "continuationToken": "8765;1",
+            "isLastBatch": false
+        }))
+        .unwrap();
+        let (batch, done) = next_batch(batch, &ReportingWatermark::default());
+        assert!(!done);
+        assert_eq!(batch.revisions.len(), 1);
+        assert_eq!(batch.watermark, ReportingWatermark::new("8765;1"));
+        assert_eq!(
+            serde_json::to_value(&batch.watermark).unwrap(),
+            json!({ "continuationToken": "8765;1" })
+        );
+
+        let last = ReportingWorkItemRevisionsBatch {
+            continuation_token: Some("9000;1".to_owned()),
+            is_last_batch: Some(true),
+            ..Default::default()
+        };
+        let (batch, done) = next_batch(last, &batch.watermark);
+        assert!(done);
+        assert!(batch.is_last_batch);
+        assert_eq!(batch.watermark, ReportingWatermark::new("9000;1"));
+
+        let no_token = ReportingWorkItemRevisionsBatch::default();
+        let (batch, done) = next_batch(no_token, &batch.watermark);
+        assert!(done);
+        assert_eq!(batch.watermark, ReportingWatermark::new("9000;1"));
+    }
+}
@@ -0,0 +1,823 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Typed construction of WIQL (Work Item Query Language) queries.
+//!
+//! [`WiqlBuilder`] composes the clauses of a WIQL query, e.g.
+//!
+//! ```text
+//! SELECT [System.Id], [System.Title]
+//! FROM WorkItems
+//! WHERE [System.TeamProject] = @project AND [System.State] IN ('Active', 'New')
+//! ORDER BY [System.ChangedDate] DESC
+//! ASOF '2023-03-01T10:12:43Z'
+//! ```
+//!
+//! and renders it to the [`Wiql`] body of a `query_by_wiql` request, quoting field names
+//! and escaping string values.
+
+use super::models::Wiql;
+use azure_core::error::{Error, ErrorKind};
+use std::fmt;
+use time::{Date, OffsetDateTime, UtcOffset};
+
+/// Builder for a WIQL query.
+///
+/// Example:
+///
+/// ```ignore
+/// let query = WiqlBuilder::work_items()
+///     .select(["System.Id", "System.Title", "System.State"])
+///     .filter(WiqlField::new("System.TeamProject").eq(WiqlMacro::Project))
+///     .filter(WiqlField::new("System.State").is_in(["Active", "New"]))
+///     .filter(
+///         WiqlField::new("System.AssignedTo")
+///             .eq(WiqlMacro::Me)
+///             .or(WiqlField::new("System.ChangedDate").ge(WiqlMacro::Today(-7))),
+///     )
+///     .order_by("System.ChangedDate", WiqlSortOrder::Desc)
+///     .build();
+/// let result = wit_client
+///     .wiql_client()
+///     .query_by_wiql(organization, query, project, team)
+///     .await?;
+/// ```
+///
+/// Link queries select work items together with the links between them:
+///
+/// ```ignore
+/// let query = WiqlBuilder::work_item_tree()
+///     .filter(WiqlField::source("System.Id").eq(42))
+///     .filter(WiqlField::link_type().eq("System.LinkTypes.Hierarchy-Forward"))
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WiqlBuilder {
+    from: FromClause,
+    columns: Vec<WiqlField>,
+    conditions: Vec<WiqlCondition>,
+    order_by: Vec<(WiqlField, WiqlSortOrder)>,
+    as_of: Option<OffsetDateTime>,
+    mode: Option<WiqlLinkMode>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FromClause {
+    WorkItems,
+    WorkItemLinks,
+}
+
+impl WiqlBuilder {
+    /// Creates a query that returns work items (`FROM WorkItems`).
+    pub fn work_items() -> Self {
+        Self::new(FromClause::WorkItems, None)
+    }
+
+    /// Creates a one-hop link query (`FROM WorkItemLinks`), which returns work items
+    /// and the links between them.
+    ///
+    /// The link mode defaults to [`WiqlLinkMode::MustContain`].
+    pub fn work_item_links() -> Self {
+        Self::new(FromClause::WorkItemLinks, None)
+    }
+
+    /// Creates a tree query (`FROM WorkItemLinks ... MODE (Recursive)`), which returns a
+    /// hierarchy of work items.
+    ///
+    /// The query must filter on a tree link type, e.g. `System.LinkTypes.Hierarchy-Forward`.
+    pub fn work_item_tree() -> Self {
+        Self::new(FromClause::WorkItemLinks, Some(WiqlLinkMode::Recursive))
+    }
+
+    fn new(from: FromClause, mode: Option<WiqlLinkMode>) -> Self {
+        Self {
+            from,
+            columns: Vec::new(),
+            conditions: Vec::new(),
+            order_by: Vec::new(),
+            as_of: None,
+            mode,
+        }
+    }
+
+    /// Adds fields to the `SELECT` clause.
+    ///
+    /// If no fields are selected then `[System.Id]` is selected.
+    pub fn select<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<WiqlField>,
+    {
+        self.columns.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a condition to the `WHERE` clause.
+    ///
+    /// Multiple conditions are combined with `AND`.
+    pub fn filter(mut self, condition: WiqlCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Adds a field to the `ORDER BY` clause.
+    pub fn order_by(mut self, field: impl Into<WiqlField>, order: WiqlSortOrder) -> Self {
+        self.order_by.push((field.into(), order));
+        self
+    }
+
+    /// Runs the query against the state of the work items at a past date and time (`ASOF`).
+    pub fn as_of(mut self, as_of: OffsetDateTime) -> Self {
+        self.as_of = Some(as_of);
+        self
+    }
+
+    /// Sets the `MODE` of a link query.
+    pub fn mode(mut self, mode: WiqlLinkMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Returns the query text.
+    pub fn query(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the [`Wiql`] body of a `query_by_wiql` request.
+    pub fn build(&self) -> Wiql {
+        Wiql {
+            query: Some(self.query()),
+        }
+    }
+}
+
+impl fmt::Display for WiqlBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SELECT ")?;
+        if self.columns.is_empty() {
+            f.write_str("[System.Id]")?;
+        } else {
+            for (i, field) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                field.fmt(f)?;
+            }
+        }
+        match self.from {
+            FromClause::WorkItems => f.write_str(" FROM WorkItems")?,
+            FromClause::WorkItemLinks => f.write_str(" FROM WorkItemLinks")?,
+        }
+        let conditions = WiqlCondition::all(self.conditions.iter().cloned());
+        if !conditions.is_empty() {
+            write!(f, " WHERE {conditions}")?;
+        }
+        if !self.order_by.is_empty() {
+            f.write_str(" ORDER BY ")?;
+            for (i, (field, order)) in self.order_by.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{field}")?;
+                if *order == WiqlSortOrder::Desc {
+                    f.write_str(" DESC")?;
+                }
+            }
+        }
+        if let Some(as_of) = &self.as_of {
+            write!(f, " ASOF {}", WiqlValue::DateTime(*as_of))?;
+        }
+        if let (FromClause::WorkItemLinks, Some(mode)) = (self.from, self.mode) {
+            write!(f, " MODE ({mode})")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<WiqlBuilder> for Wiql {
+    fn from(builder: WiqlBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<&WiqlBuilder> for Wiql {
+    fn from(builder: &WiqlBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// The sort order of an `ORDER BY` field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WiqlSortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// The `MODE` of a link query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WiqlLinkMode {
+    /// Returns only source work items that have links matching the link and target conditions.
+    MustContain,
+    /// Returns all source work items matching the source conditions, with any matching links.
+    MayContain,
+    /// Returns only source work items that have no links matching the link and target conditions.
+    DoesNotContain,
+    /// Returns the tree of work items below the source work items.
+    Recursive,
+    /// Returns the tree of work items below the source work items, including children that
+    /// match the target conditions even if their parent does not.
+    RecursiveReturnMatchingChildren,
+}
+
+impl fmt::Display for WiqlLinkMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MustContain => "MustContain",
+            Self::MayContain => "MayContain",
+            Self::DoesNotContain => "DoesNotContain",
+            Self::Recursive => "Recursive",
+            Self::RecursiveReturnMatchingChildren => "Recursive, ReturnMatchingChildren",
+        })
+    }
+}
+
+/// A field referenced in a WIQL query, e.g. `[System.State]` or `[Source].[System.Id]`.
+///
+/// Field names are quoted with `[` and `]`, which cannot be escaped, so names containing
+/// them are rejected. The constructors panic on such names: use [`try_new()`](Self::try_new)
+/// for names that are not known to be valid, e.g. names entered by a user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WiqlField {
+    prefix: Option<&'static str>,
+    name: String,
+}
+
+impl WiqlField {
+    /// A work item field, given its reference name, e.g. `System.State`.
+    ///
+    /// Panics if `name` contains `[` or `]`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::with_prefix(None, name.into())
+    }
+
+    /// A work item field, given its reference name.
+    ///
+    /// Returns an error if `name` contains `[` or `]`.
+    pub fn try_new(name: impl Into<String>) -> azure_core::Result<Self> {
+        Self::try_with_prefix(None, name.into())
+    }
+
+    /// A field of the source work item of a link query, e.g. `[Source].[System.Id]`.
+    ///
+    /// Panics if `name` contains `[` or `]`.
+    pub fn source(name: impl Into<String>) -> Self {
+        Self::with_prefix(Some("Source"), name.into())
+    }
+
+    /// A field of the target work item of a link query, e.g. `[Target].[System.State]`.
+    ///
+    /// Panics if `name` contains `[` or `]`.
+    pub fn target(name: impl Into<String>) -> Self {
+        Self::with_prefix(Some("Target"), name.into())
+    }
+
+    fn with_prefix(prefix: Option<&'static str>, name: String) -> Self {
+        match Self::try_with_prefix(prefix, name) {
+            Ok(field) => field,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    fn try_with_prefix(prefix: Option<&'static str>, name: String) -> azure_core::Result<Self> {
+        if name.contains(['[', ']']) {
+            return Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!("Invalid WIQL field name: {name}")
+            }));
+        }
+        Ok(Self { prefix, name })
+    }
+
+    /// The link type of a link query (`[System.Links.LinkType]`).
+    pub fn link_type() -> Self {
+        Self::new("System.Links.LinkType")
+    }
+
+    fn condition(&self, operator: &str, value: impl Into<WiqlValue>) -> WiqlCondition {
+        WiqlCondition::clause(format!("{self} {operator} {}", value.into()))
+    }
+
+    fn list_condition<I>(&self, operator: &str, values: I) -> WiqlCondition
+    where
+        I: IntoIterator,
+        I::Item: Into<WiqlValue>,
+    {
+        let values: Vec<WiqlValue> = values.into_iter().map(Into::into).collect();
+        let mut clause = format!("{self} {operator} (");
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                clause.push_str(", ");
+            }
+            clause.push_str(&value.to_string());
+        }
+        clause.push(')');
+        WiqlCondition::clause(clause)
+    }
+
+    /// `[Field] = value`
+    pub fn eq(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("=", value)
+    }
+
+    /// `[Field] <> value`
+    pub fn ne(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("<>", value)
+    }
+
+    /// `[Field] > value`
+    pub fn gt(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition(">", value)
+    }
+
+    /// `[Field] >= value`
+    pub fn ge(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition(">=", value)
+    }
+
+    /// `[Field] < value`
+    pub fn lt(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("<", value)
+    }
+
+    /// `[Field] <= value`
+    pub fn le(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("<=", value)
+    }
+
+    /// `[Field] CONTAINS value`
+    pub fn contains(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("CONTAINS", value)
+    }
+
+    /// `[Field] NOT CONTAINS value`
+    pub fn not_contains(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("NOT CONTAINS", value)
+    }
+
+    /// `[Field] CONTAINS WORDS value`, a full-text search.
+    pub fn contains_words(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("CONTAINS WORDS", value)
+    }
+
+    /// `[Field] NOT CONTAINS WORDS value`
+    pub fn not_contains_words(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("NOT CONTAINS WORDS", value)
+    }
+
+    /// `[Field] IN (values)`
+    pub fn is_in<I>(&self, values: I) -> WiqlCondition
+    where
+        I: IntoIterator,
+        I::Item: Into<WiqlValue>,
+    {
+        self.list_condition("IN", values)
+    }
+
+    /// `[Field] NOT IN (values)`
+    pub fn not_in<I>(&self, values: I) -> WiqlCondition
+    where
+        I: IntoIterator,
+        I::Item: Into<WiqlValue>,
+    {
+        self.list_condition("NOT IN", values)
+    }
+
+    /// `[Field] UNDER value`, for area and iteration paths.
+    pub fn under(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("UNDER", value)
+    }
+
+    /// `[Field] NOT UNDER value`
+    pub fn not_under(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("NOT UNDER", value)
+    }
+
+    /// `[Field] IN GROUP value`, for identity and work item type fields.
+    pub fn in_group(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("IN GROUP", value)
+    }
+
+    /// `[Field] NOT IN GROUP value`
+    pub fn not_in_group(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("NOT IN GROUP", value)
+    }
+
+    /// `[Field] EVER value`, matching work items where the field ever had the value.
+    pub fn ever(&self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.condition("EVER", value)
+    }
+
+    /// `[Field] IS EMPTY`, for HTML and text fields.
+    pub fn is_empty(&self) -> WiqlCondition {
+        WiqlCondition::clause(format!("{self} IS EMPTY"))
+    }
+
+    /// `[Field] IS NOT EMPTY`
+    pub fn is_not_empty(&self) -> WiqlCondition {
+        WiqlCondition::clause(format!("{self} IS NOT EMPTY"))
+    }
+}
+
+impl fmt::Display for WiqlField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = self.prefix {
+            write!(f, "[{prefix}].")?;
+        }
+        write!(f, "[{}]", self.name)
+    }
+}
+
+impl From<&str> for WiqlField {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for WiqlField {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+/// A condition of the `WHERE` clause of a WIQL query.
+///
+/// Conditions are created from a [`WiqlField`], e.g. `WiqlField::new("System.State").eq("Active")`,
+/// and combined with [`and()`](WiqlCondition::and) and [`or()`](WiqlCondition::or), or
+/// [`all()`](WiqlCondition::all) and [`any()`](WiqlCondition::any).
+///
+/// Groups without any conditions are left out of the query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WiqlCondition(Condition);
+
+#[derive(Clone, Debug, PartialEq)]
+enum Condition {
+    Clause(String),
+    And(Vec<WiqlCondition>),
+    Or(Vec<WiqlCondition>),
+}
+
+impl WiqlCondition {
+    fn clause(clause: String) -> Self {
+        Self(Condition::Clause(clause))
+    }
+
+    /// A condition written in WIQL, e.g. `[System.Reason] = 'Obsolete'`.
+    ///
+    /// The text is added to the query as is, without any quoting or escaping, so it must not
+    /// include untrusted input.
+    pub fn raw(clause: impl Into<String>) -> Self {
+        Self::clause(clause.into())
+    }
+
+    /// Combines `conditions` with `AND`.
+    pub fn all(conditions: impl IntoIterator<Item = WiqlCondition>) -> Self {
+        Self(Condition::And(conditions.into_iter().collect()))
+    }
+
+    /// Combines `conditions` with `OR`.
+    pub fn any(conditions: impl IntoIterator<Item = WiqlCondition>) -> Self {
+        Self(Condition::Or(conditions.into_iter().collect()))
+    }
+
+    /// Combines this condition and `other` with `AND`.
+    pub fn and(self, other: WiqlCondition) -> Self {
+        match self.0 {
+            Condition::And(mut conditions) => {
+                conditions.push(other);
+                Self(Condition::And(conditions))
+            }
+            condition => Self::all([Self(condition), other]),
+        }
+    }
+
+    /// Combines this condition and `other` with `OR`.
+    pub fn or(self, other: WiqlCondition) -> Self {
+        match self.0 {
+            Condition::Or(mut conditions) => {
+                conditions.push(other);
+                Self(Condition::Or(conditions))
+            }
+            condition => Self::any([Self(condition), other]),
+        }
+    }
+
+    /// Returns `true` if the condition is a group that contains no clauses.
+    fn is_empty(&self) -> bool {
+        match &self.0 {
+            Condition::Clause(_) => false,
+            Condition::And(conditions) | Condition::Or(conditions) => {
+                conditions.iter().all(WiqlCondition::is_empty)
+            }
+        }
+    }
+}
+
+impl fmt::Display for WiqlCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (conditions, operator) = match &self.0 {
+            Condition::Clause(clause) => return f.write_str(clause),
+            Condition::And(conditions) => (conditions, " AND "),
+            Condition::Or(conditions) => (conditions, " OR "),
+        };
+        let conditions = conditions.iter().filter(|condition| !condition.is_empty());
+        for (i, condition) in conditions.enumerate() {
+            if i > 0 {
+                f.write_str(operator)?;
+            }
+            match condition.0 {
+                Condition::Clause(_) => condition.fmt(f)?,
+                _ => write!(f, "({condition})")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A WIQL macro, e.g. `@project` or `@Today - 7`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WiqlMacro {
+    /// `@project`, the project the query is run in.
+    Project,
+    /// `@Me`, the current user.
+    Me,
+    /// `@Today`, offset by a number of days.
+    Today(i32),
+    /// `@StartOfDay`, offset by a number of days.
+    StartOfDay(i32),
+    /// `@StartOfWeek`, offset by a number of weeks.
+    StartOfWeek(i32),
+    /// `@StartOfMonth`, offset by a number of months.
+    StartOfMonth(i32),
+    /// `@StartOfYear`, offset by a number of years.
+    StartOfYear(i32),
+    /// `@CurrentIteration`, the current iteration of the team the query is run for.
+    CurrentIteration,
+    /// `@Follows`, the work items followed by the current user (used with `IN`).
+    Follows,
+    /// `@MyRecentActivity`, the work items recently viewed or updated by the current user (used with `IN`).
+    MyRecentActivity,
+    /// `@RecentMentions`, the work items where the current user was recently mentioned (used with `IN`).
+    RecentMentions,
+}
+
+impl fmt::Display for WiqlMacro {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, offset) = match self {
+            Self::Project => ("@project", 0),
+            Self::Me => ("@Me", 0),
+            Self::Today(offset) => ("@Today", *offset),
+            Self::StartOfDay(offset) => ("@StartOfDay", *offset),
+            Self::StartOfWeek(offset) => ("@StartOfWeek", *offset),
+            Self::StartOfMonth(offset) => ("@StartOfMonth", *offset),
+            Self::StartOfYear(offset) => ("@StartOfYear", *offset),
+            Self::CurrentIteration => ("@CurrentIteration", 0),
+            Self::Follows => ("@Follows", 0),
+            Self::MyRecentActivity => ("@MyRecentActivity", 0),
+            Self::RecentMentions => ("@RecentMentions", 0),
+        };
+        f.write_str(name)?;
+        match offset {
+            0 => Ok(()),
+            offset if offset > 0 => write!(f, " + {offset}"),
+            offset => write!(f, " - {}", offset.unsigned_abs()),
+        }
+    }
+}
+
+/// A value compared against a field in a WIQL condition.
+///
+/// Values are usually created via `From`, e.g. from `&str`, `i32`, `bool`, [`time::Date`],
+/// [`OffsetDateTime`], [`WiqlMacro`] or [`WiqlField`] (to compare two fields).
+#[derive(Clone, Debug, PartialEq)]
+pub enum WiqlValue {
+    /// A string, quoted and escaped when rendered.
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    /// A date, e.g. `'2023-03-01'`.
+    Date(Date),
+    /// A date and time, rendered in UTC, e.g. `'2023-03-01T10:12:43Z'`.
+    ///
+    /// Conditions that compare a date and time require `time_precision(true)` on the
+    /// `query_by_wiql` request.
+    DateTime(OffsetDateTime),
+    Macro(WiqlMacro),
+    Field(WiqlField),
+}
+
+impl fmt::Display for WiqlValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => write!(f, "'{}'", value.replace('\'', "''")),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Date(date) => write!(f, "'{date}'"),
+            Self::DateTime(date_time) => {
+                let utc = date_time.to_offset(UtcOffset::UTC);
+                write!(
+                    f,
+                    "'{}T{:02}:{:02}:{:02}Z'",
+                    utc.date(),
+                    utc.hour(),
+                    utc.minute(),
+                    utc.second()
+                )
+            }
+            Self::Macro(value) => value.fmt(f),
+            Self::Field(field) => field.fmt(f),
+        }
+    }
+}
+
+impl From<&str> for WiqlValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for WiqlValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&String> for WiqlValue {
+    fn from(value: &String) -> Self {
+        Self::String(value.clone())
+    }
+}
+
+impl From<i32> for WiqlValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<i64> for WiqlValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for WiqlValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for WiqlValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Date> for WiqlValue {
+    fn from(value: Date) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl From<OffsetDateTime> for WiqlValue {
+    fn from(value: OffsetDateTime) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl From<WiqlMacro> for WiqlValue {
+    fn from(value: WiqlMacro) -> Self {
+        Self::Macro(value)
+    }
+}
+
+impl From<WiqlField> for WiqlValue {
+    fn from(value: WiqlField) -> Self {
+        Self::Field(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn test_work_items_query() {
+        let query = WiqlBuilder::work_items()
+            .select(["System.Id", "System.Title"])
+            .filter(WiqlField::new("System.TeamProject").eq(WiqlMacro::Project))
+            .filter(WiqlField::new("System.Title").contains("Bob's"))
+            .filter(WiqlField::new("System.State").is_in(["Active", "New"]))
+            .filter(
+                WiqlField::new("System.AssignedTo")
+                    .eq(WiqlMacro::Me)
+                    .or(WiqlField::new("System.ChangedDate").ge(WiqlMacro::Today(-7)))
+                    .or(
+                        WiqlField::new("System.CreatedDate").gt(Date::from_calendar_date(
+                            2023,
+                            Month::March,
+                            1,
+                        )
+                        .unwrap()),
+                    ),
+            )
+            .order_by("Microsoft.VSTS.Common.Priority", WiqlSortOrder::Asc)
+            .order_by("System.ChangedDate", WiqlSortOrder::Desc)
+            .as_of(
+                OffsetDateTime::from_unix_timestamp(1677665563)
+                    .unwrap()
+                    .to_offset(UtcOffset::from_hms(1, 0, 0).unwrap()),
+            )
+            .query();
+        assert_eq!(
+            query,
+            "SELECT [System.Id], [System.Title] FROM WorkItems \
+             WHERE [System.TeamProject] = @project \
+             AND [System.Title] CONTAINS 'Bob''s' \
+             AND [System.State] IN ('Active', 'New') \
+             AND ([System.AssignedTo] = @Me OR [System.ChangedDate] >= @Today - 7 OR [System.CreatedDate] > '2023-03-01') \
+             ORDER BY [Microsoft.VSTS.Common.Priority], [System.ChangedDate] DESC \
+             ASOF '2023-03-01T10:12:43Z'"
+        );
+    }
+
+    #[test]
+    fn test_link_queries() {
+        let query = WiqlBuilder::work_item_tree()
+            .select(["System.Id"])
+            .filter(WiqlField::source("System.Id").eq(42))
+            .filter(WiqlField::link_type().eq("System.LinkTypes.Hierarchy-Forward"))
+            .filter(
+                WiqlField::target("System.State")
+                    .ne("Closed")
+                    .and(WiqlField::target("System.Title").is_not_empty()),
+            )
+            .mode(WiqlLinkMode::RecursiveReturnMatchingChildren)
+            .build();
+        assert_eq!(
+            query.query.as_deref(),
+            Some(
+                "SELECT [System.Id] FROM WorkItemLinks \
+                 WHERE [Source].[System.Id] = 42 \
+                 AND [System.Links.LinkType] = 'System.LinkTypes.Hierarchy-Forward' \
+                 AND ([Target].[System.State] <> 'Closed' AND [Target].[System.Title] IS NOT EMPTY) \
+                 MODE (Recursive, ReturnMatchingChildren)"
+            )
+        );
+
+        let query = WiqlBuilder::work_item_links()
+            .filter(WiqlField::source("System.Id").eq(WiqlField::target("Custom.ParentId")))
+            .mode(WiqlLinkMode::MayContain)
+            .query();
+        assert_eq!(
+            query,
+            "SELECT [System.Id] FROM WorkItemLinks \
+             WHERE [Source].[System.Id] = [Target].[Custom.ParentId] \
+             MODE (MayContain)"
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        let query = WiqlBuilder::work_items()
+            .filter(WiqlCondition::all([]))
+            .filter(WiqlCondition::any([WiqlCondition::all([])]))
+            .query();
+        assert_eq!(query, "SELECT [System.Id] FROM WorkItems");
+
+        let query = WiqlBuilder::work_items()
+            .filter(WiqlCondition::any([]))
+            .filter(WiqlField::new("System.State").eq("Active"))
+            .filter(WiqlCondition::any([
+                WiqlCondition::raw("[System.Reason] = 'Obsolete'"),
+                WiqlCondition::all([]),
+                WiqlField::new("System.Tags").contains("stale"),
+            ]))
+            .query();
+        assert_eq!(
+            query,
+            "SELECT [System.Id] FROM WorkItems \
+             WHERE [System.State] = 'Active' \
+             AND ([System.Reason] = 'Obsolete' OR [System.Tags] CONTAINS 'stale')"
+        );
+    }
+
+    #[test]
+    fn test_invalid_field_name() {
+        assert!(WiqlField::try_new("Custom.Size").is_ok());
+        let error = WiqlField::try_new("System.Id] = 1 OR [System.Id").unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+        assert!(std::panic::catch_unwind(|| WiqlField::source("Custom[1]")).is_err());
+    }
+}
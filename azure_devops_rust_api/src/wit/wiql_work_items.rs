@@ -0,0 +1,282 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Running WIQL queries that return fully populated work items.
+//!
+//! `query_by_wiql` only returns references (IDs and URLs) to the matching work items,
+//! and `get_work_items_batch` accepts at most 200 IDs per request.
+//! [`QueryWorkItemsBuilder`] runs the query, then fetches the work items in batches of
+//! up to 200 IDs, requesting the query's columns as the field list and using the query's
+//! `asOf` time, so that all batches see the same revision of the work items.
+
+use super::models::{
+    work_item_batch_get_request::Expand, Wiql, WorkItem, WorkItemBatchGetRequest,
+    WorkItemQueryResult,
+};
+use super::{wiql, work_items};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use std::collections::HashSet;
+use std::future::IntoFuture;
+
+/// The maximum number of work items that `get_work_items_batch` returns per request.
+pub const WORK_ITEMS_BATCH_SIZE: usize = 200;
+
+/// The default number of `get_work_items_batch` requests that are sent concurrently.
+pub(crate) const DEFAULT_CONCURRENCY: usize = 4;
+
+impl wiql::Client {
+    /// Runs a WIQL query and returns the matching work items.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `body`: The WIQL query.
+    /// * `project`: Project ID or project name
+    /// * `team`: Team ID or team name
+    pub fn query_work_items(
+        &self,
+        organization: impl Into<String>,
+        body: impl Into<Wiql>,
+        project: impl Into<String>,
+        team: impl Into<String>,
+    ) -> QueryWorkItemsBuilder {
+        QueryWorkItemsBuilder {
+            client: self.0.clone(),
+            organization: organization.into(),
+            body: body.into(),
+            project: project.into(),
+            team: team.into(),
+            time_precision: None,
+            top: None,
+            fields: None,
+            expand: None,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+scoped_client_methods! {
+    wiql;
+    /// Runs a WIQL query and returns the matching work items.
+    pub fn query_work_items(
+        body: impl Into<Wiql>,
+        [project],
+        team: impl Into<String>
+    ) -> QueryWorkItemsBuilder;
+}
+
+/// Builder for a WIQL query that returns fully populated work items.
+///
+/// Example:
+///
+/// ```ignore
+/// let query = wit::models::Wiql {
+///     query: Some("SELECT [System.Id], [System.Title] FROM WorkItems WHERE [System.State] = 'Active'".to_owned()),
+/// };
+/// let mut work_items = wit_client
+///     .wiql_client()
+///     .query_work_items(organization, query, project, team)
+///     .concurrency(8)
+///     .into_stream();
+/// while let Some(work_item) = work_items.try_next().await? {
+///     println!("{:?}", work_item.typed_fields().title());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct QueryWorkItemsBuilder {
+    client: super::Client,
+    organization: String,
+    body: Wiql,
+    project: String,
+    team: String,
+    time_precision: Option<bool>,
+    top: Option<i32>,
+    fields: Option<Vec<String>>,
+    expand: Option<Expand>,
+    concurrency: usize,
+}
+
+impl QueryWorkItemsBuilder {
+    /// Whether or not to use time precision.
+    pub fn time_precision(mut self, time_precision: bool) -> Self {
+        self.time_precision = Some(time_precision);
+        self
+    }
+
+    /// The max number of results to return.
+    pub fn top(mut self, top: i32) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// The fields to return, overriding the columns of the query.
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// The expand parameters for work item attributes.
+    ///
+    /// The service does not allow a field list to be combined with `expand`,
+    /// so when this is set all fields are returned.
+    pub fn expand(mut self, expand: Expand) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// The maximum number of `get_work_items_batch` requests to send concurrently (default 4).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Returns a stream of the work items, in the order returned by the query.
+    pub fn into_stream(self) -> BoxStream<'static, azure_core::Result<WorkItem>> {
+        let client = self.client.clone();
+        let organization = self.organization.clone();
+        let project = self.project.clone();
+        let concurrency = self.concurrency;
+        let fields = self.fields.clone();
+        let expand = self.expand.clone();
+
+        stream::once(self.query())
+            .map_ok(move |result| {
+                let requests = batch_requests(&result, fields.as_deref(), expand.as_ref());
+                get_work_items_batches(&client, &organization, &project, requests, concurrency)
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    async fn query(self) -> azure_core::Result<WorkItemQueryResult> {
+        let mut request = wiql::Client(self.client).query_by_wiql(
+            self.organization,
+            self.body,
+            self.project,
+            self.team,
+        );
+        if let Some(time_precision) = self.time_precision {
+            request = request.time_precision(time_precision);
+        }
+        if let Some(top) = self.top {
+            request = request.top(top);
+        }
+        request.await
+    }
+}
+
+/// Sends `get_work_items_batch` requests, up to `concurrency` at a time, and returns a stream
+/// of the work items in the order of the requests.
+pub(crate) fn get_work_items_batches(
+    client: &super::Client,
+    organization: &str,
+    project: &str,
+    requests: Vec<WorkItemBatchGetRequest>,
+    concurrency: usize,
+) -> BoxStream<'static, azure_core::Result<WorkItem>> {
+    let work_items_client = work_items::Client(client.clone());
+    let organization = organization.to_owned();
+    let project = project.to_owned();
+    let batches = requests.into_iter().map(move |request| {
+        work_items_client
+            .get_work_items_batch(&organization, request, &project)
+            .into_future()
+    });
+    stream::iter(batches)
+        .buffered(concurrency.max(1))
+        .map_ok(|list| stream::iter(list.value.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+/// Returns the IDs of the work items returned by a query, without duplicates.
+///
+/// Flat queries return `workItems`. Link queries return `workItemRelations`, where each
+/// work item may appear as the target of several links.
+fn work_item_ids(result: &WorkItemQueryResult) -> Vec<i32> {
+    let references = result.work_items.iter().chain(
+        result
+            .work_item_relations
+            .iter()
+            .flat_map(|link| link.source.iter().chain(link.target.iter())),
+    );
+    let mut seen = HashSet::new();
+    references
+        .filter_map(|reference| reference.id)
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+/// Splits the work items returned by a query into `get_work_items_batch` requests.
+fn batch_requests(
+    result: &WorkItemQueryResult,
+    fields: Option<&[String]>,
+    expand: Option<&Expand>,
+) -> Vec<WorkItemBatchGetRequest> {
+    let fields: Vec<String> = match (expand, fields) {
+        (Some(_), _) => Vec::new(),
+        (None, Some(fields)) => fields.to_vec(),
+        (None, None) => result
+            .columns
+            .iter()
+            .filter_map(|column| column.reference_name.clone())
+            .collect(),
+    };
+    work_item_ids(result)
+        .chunks(WORK_ITEMS_BATCH_SIZE)
+        .map(|ids| WorkItemBatchGetRequest {
+            expand: expand.cloned(),
+            as_of: result.as_of,
+            error_policy: None,
+            fields: fields.clone(),
+            ids: ids.to_vec(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_batch_requests() {
+        let work_items: Vec<_> = (1..=450).map(|id| json!({ "id": id })).collect();
+        let result: WorkItemQueryResult = serde_json::from_value(json!({
+            "asOf": "2023-03-01T10:12:43.193Z",
+            "columns": [
+                { "referenceName": "System.Id" },
+                { "referenceName": "System.Title" }
+            ],
+            "workItems": work_items
+        }))
+        .unwrap();
+
+        let requests = batch_requests(&result, None, None);
+        assert_eq!(
+            requests.iter().map(|r| r.ids.len()).collect::<Vec<_>>(),
+            vec![200, 200, 50]
+        );
+        assert_eq!(requests[2].ids[0], 401);
+        assert_eq!(requests[0].fields, vec!["System.Id", "System.Title"]);
+        assert_eq!(requests[0].as_of, result.as_of);
+        assert!(requests[0].as_of.is_some());
+
+        let requests = batch_requests(&result, None, Some(&Expand::Relations));
+        assert!(requests[0].fields.is_empty());
+        assert_eq!(requests[0].expand, Some(Expand::Relations));
+    }
+
+    #[test]
+    fn test_link_query_ids() {
+        let result: WorkItemQueryResult = serde_json::from_value(json!({
+            "workItemRelations": [
+                { "target": { "id": 1 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 2 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 3 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 3 }, "target": { "id": 2 } }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(work_item_ids(&result), vec![1, 2, 3]);
+    }
+}
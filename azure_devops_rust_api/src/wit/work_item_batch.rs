@@ -0,0 +1,463 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Creating, updating and deleting work items in batches.
+//!
+//! `POST _apis/wit/$batch` takes a list of up to 200 work item requests, e.g.
+//!
+//! ```json
+//! [
+//!   {
+//!     "method": "PATCH",
+//!     "uri": "/MyProject/_apis/wit/workitems/1?api-version=7.1-preview",
+//!     "headers": { "Content-Type": "application/json-patch+json" },
+//!     "body": [{ "op": "add", "path": "/fields/System.State", "value": "Closed" }]
+//!   }
+//! ]
+//! ```
+//!
+//! and returns the response to each request, with its own status code:
+//!
+//! ```json
+//! { "count": 1, "value": [{ "code": 200, "headers": { ... }, "body": "{\"id\":1,...}" }] }
+//! ```
+//!
+//! [`WorkItemBatchBuilder`] builds these requests from [`WorkItemBatchOperation`]s, sends them
+//! in batches of up to 200, and returns a [`WorkItemBatchResult`] for each operation.
+
+use super::models::{JsonPatchOperation, WorkItem};
+use super::wiql_work_items::WORK_ITEMS_BATCH_SIZE;
+use super::work_items;
+use crate::error::{parse_error, AdoError};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::{Method, StatusCode};
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// The API version of the work items `$batch` operation, also used for the requests in a batch.
+const WORK_ITEMS_BATCH_API_VERSION: &str = "7.1-preview";
+
+/// An operation in a work item batch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorkItemBatchOperation {
+    /// Creates a work item of the given type, e.g. `Bug`.
+    Create {
+        work_item_type: String,
+        operations: Vec<JsonPatchOperation>,
+    },
+    /// Updates a work item.
+    Update {
+        id: i32,
+        operations: Vec<JsonPatchOperation>,
+    },
+    /// Deletes a work item, sending it to the Recycle Bin.
+    Delete { id: i32 },
+}
+
+/// The result of an operation in a work item batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkItemBatchResult {
+    /// The status code of the operation.
+    pub status: StatusCode,
+    /// The response headers of the operation.
+    pub headers: HashMap<String, String>,
+    /// The response body of the operation, if any.
+    pub body: Option<Value>,
+}
+
+impl WorkItemBatchResult {
+    /// Returns `true` if the operation succeeded.
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    /// Returns the error returned for the operation, if it failed.
+    pub fn error(&self) -> Option<AdoError> {
+        if self.is_success() {
+            return None;
+        }
+        let bytes = self
+            .body
+            .as_ref()
+            .map(|body| body.to_string())
+            .unwrap_or_default();
+        Some(
+            parse_error(self.status, bytes.as_bytes()).unwrap_or_else(|| AdoError {
+                status: Some(self.status),
+                message: bytes,
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Returns the work item created or updated by the operation.
+    ///
+    /// Returns an error if the operation failed.
+    pub fn work_item(&self) -> azure_core::Result<WorkItem> {
+        if let Some(error) = self.error() {
+            let message = format!("server returned error status {}: {error}", self.status);
+            let kind = ErrorKind::HttpResponse {
+                status: self.status,
+                error_code: error.type_key.clone(),
+            };
+            return Err(Error::full(kind, error, message));
+        }
+        let body = self.body.clone().unwrap_or_default();
+        serde_json::from_value(body).context(
+            ErrorKind::DataConversion,
+            "Failed to deserialize work item batch response",
+        )
+    }
+}
+
+impl work_items::Client {
+    /// Creates a [`WorkItemBatchBuilder`] that creates, updates and deletes work items in batches.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    pub fn batch(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> WorkItemBatchBuilder {
+        WorkItemBatchBuilder {
+            client: self.0.clone(),
+            organization: organization.into(),
+            operations: Vec::new(),
+            options: BatchOptions {
+                project: project.into(),
+                api_version: self.0.api_version(WORK_ITEMS_BATCH_API_VERSION),
+                bypass_rules: None,
+                suppress_notifications: None,
+            },
+        }
+    }
+}
+
+scoped_client_methods! {
+    work_items;
+    /// Creates a [`WorkItemBatchBuilder`] that creates, updates and deletes work items in batches.
+    pub fn batch([project]) -> WorkItemBatchBuilder;
+}
+
+/// Builder for a batch of work item operations.
+///
+/// The operations are sent in batches of up to 200, and a result is returned for each operation,
+/// in the order the operations were added.
+/// A failed operation does not fail the batch: check the status of each result.
+///
+/// Example:
+///
+/// ```ignore
+/// let results = wit_client
+///     .work_items_client()
+///     .batch(organization, project)
+///     .update(1, WorkItemPatch::new().state("Closed"))
+///     .update(2, WorkItemPatch::new().state("Closed"))
+///     .create("Task", WorkItemPatch::new().title("Follow up"))
+///     .suppress_notifications(true)
+///     .await?;
+/// for result in results {
+///     if let Some(error) = result.error() {
+///         println!("{}: {error}", result.status);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct WorkItemBatchBuilder {
+    client: super::Client,
+    organization: String,
+    operations: Vec<WorkItemBatchOperation>,
+    options: BatchOptions,
+}
+
+/// The parameters that apply to every operation in a batch.
+#[derive(Clone, Debug)]
+struct BatchOptions {
+    project: String,
+    api_version: String,
+    bypass_rules: Option<bool>,
+    suppress_notifications: Option<bool>,
+}
+
+impl WorkItemBatchBuilder {
+    /// Adds an operation to the batch.
+    pub fn operation(mut self, operation: WorkItemBatchOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Adds operations to the batch.
+    pub fn operations(
+        mut self,
+        operations: impl IntoIterator<Item = WorkItemBatchOperation>,
+    ) -> Self {
+        self.operations.extend(operations);
+        self
+    }
+
+    /// Creates a work item of the given type, e.g. `Bug`.
+    pub fn create(
+        self,
+        work_item_type: impl Into<String>,
+        body: impl Into<Vec<JsonPatchOperation>>,
+    ) -> Self {
+        self.operation(WorkItemBatchOperation::Create {
+            work_item_type: work_item_type.into(),
+            operations: body.into(),
+        })
+    }
+
+    /// Updates a work item.
+    pub fn update(self, id: i32, body: impl Into<Vec<JsonPatchOperation>>) -> Self {
+        self.operation(WorkItemBatchOperation::Update {
+            id,
+            operations: body.into(),
+        })
+    }
+
+    /// Deletes a work item, sending it to the Recycle Bin.
+    pub fn delete(self, id: i32) -> Self {
+        self.operation(WorkItemBatchOperation::Delete { id })
+    }
+
+    /// Do not enforce the work item type rules when creating or updating work items.
+    pub fn bypass_rules(mut self, bypass_rules: bool) -> Self {
+        self.options.bypass_rules = Some(bypass_rules);
+        self
+    }
+
+    /// Do not fire any notifications when creating or updating work items.
+    pub fn suppress_notifications(mut self, suppress_notifications: bool) -> Self {
+        self.options.suppress_notifications = Some(suppress_notifications);
+        self
+    }
+
+    /// Sends the operations, returning a result for each operation.
+    pub async fn send(self) -> azure_core::Result<Vec<WorkItemBatchResult>> {
+        let mut results = Vec::with_capacity(self.operations.len());
+        for operations in self.operations.chunks(WORK_ITEMS_BATCH_SIZE) {
+            let body = self.options.request_body(operations)?;
+            results.extend(self.send_batch(body, operations.len()).await?);
+        }
+        Ok(results)
+    }
+
+    async fn send_batch(
+        &self,
+        body: Value,
+        len: usize,
+    ) -> azure_core::Result<Vec<WorkItemBatchResult>> {
+        let body = azure_core::to_json(&body)?;
+        let path = format!("{}/_apis/wit/$batch", &self.organization);
+        let response = self
+            .client
+            .send_json(Method::Post, &path, WORK_ITEMS_BATCH_API_VERSION, |req| {
+                req.insert_header("content-type", "application/json");
+                req.set_body(body);
+                Ok(())
+            })
+            .await?;
+        batch_results(response, len)
+    }
+}
+
+impl std::future::IntoFuture for WorkItemBatchBuilder {
+    type Output = azure_core::Result<Vec<WorkItemBatchResult>>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<Vec<WorkItemBatchResult>>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+impl BatchOptions {
+    /// Returns the body of a `$batch` request.
+    fn request_body(&self, operations: &[WorkItemBatchOperation]) -> azure_core::Result<Value> {
+        operations
+            .iter()
+            .map(|operation| self.request(operation))
+            .collect::<azure_core::Result<Vec<Value>>>()
+            .map(Value::Array)
+    }
+
+    /// Returns the request for an operation.
+    fn request(&self, operation: &WorkItemBatchOperation) -> azure_core::Result<Value> {
+        let request = match operation {
+            WorkItemBatchOperation::Create {
+                work_item_type,
+                operations,
+            } => json!({
+                "method": "PATCH",
+                "uri": self.uri(&format!("${work_item_type}"), true)?,
+                "headers": { "Content-Type": "application/json-patch+json" },
+                "body": operations,
+            }),
+            WorkItemBatchOperation::Update { id, operations } => json!({
+                "method": "PATCH",
+                "uri": self.uri(&id.to_string(), true)?,
+                "headers": { "Content-Type": "application/json-patch+json" },
+                "body": operations,
+            }),
+            WorkItemBatchOperation::Delete { id } => json!({
+                "method": "DELETE",
+                "uri": self.uri(&id.to_string(), false)?,
+            }),
+        };
+        Ok(request)
+    }
+
+    /// Returns the URI of a work item request, relative to the organization.
+    ///
+    /// `bypassRules` and `suppressNotifications` are only supported by create and update requests.
+    fn uri(&self, segment: &str, flags: bool) -> azure_core::Result<String> {
+        // Build the URI on a placeholder base URL, to percent-encode the path segments.
+        let mut url = azure_core::Url::parse("https://localhost")?;
+        url.path_segments_mut()
+            .map_err(|_| Error::message(ErrorKind::DataConversion, "Invalid batch request URI"))?
+            .extend([&self.project, "_apis", "wit", "workitems", segment]);
+        url.query_pairs_mut()
+            .append_pair(azure_core::query_param::API_VERSION, &self.api_version);
+        if flags {
+            if let Some(bypass_rules) = &self.bypass_rules {
+                url.query_pairs_mut()
+                    .append_pair("bypassRules", &bypass_rules.to_string());
+            }
+            if let Some(suppress_notifications) = &self.suppress_notifications {
+                url.query_pairs_mut()
+                    .append_pair("suppressNotifications", &suppress_notifications.to_string());
+            }
+        }
+        Ok(format!(
+            "{}?{}",
+            url.path(),
+            url.query().unwrap_or_default()
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchResponse {
+    #[serde(default)]
+    value: Vec<BatchResponseItem>,
+}
+
+#[derive(Deserialize)]
+struct BatchResponseItem {
+    code: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(default)]
+    body: Option<Value>,
+}
+
+/// Returns the results of a `$batch` request with `len` operations.
+fn batch_results(
+    response: BatchResponse,
+    len: usize,
+) -> azure_core::Result<Vec<WorkItemBatchResult>> {
+    if response.value.len() != len {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!(
+                "Work item batch returned {} results for {len} operations",
+                response.value.len()
+            )
+        }));
+    }
+    response
+        .value
+        .into_iter()
+        .map(|item| {
+            let status = StatusCode::try_from(item.code).map_err(|_| {
+                Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "Invalid status code in work item batch response: {}",
+                        item.code
+                    )
+                })
+            })?;
+            // The body of each response is returned as a JSON-encoded string.
+            let body = match item.body {
+                Some(Value::String(body)) => {
+                    Some(serde_json::from_str(&body).unwrap_or(Value::String(body)))
+                }
+                body => body,
+            };
+            Ok(WorkItemBatchResult {
+                status,
+                headers: item.headers,
+                body,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wit::WorkItemPatch;
+
+    #[test]
+    fn test_request_body() {
+        let options = BatchOptions {
+            project: "My Project".to_owned(),
+            api_version: "7.1-preview".to_owned(),
+            bypass_rules: Some(true),
+            suppress_notifications: None,
+        };
+        let body = options
+            .request_body(&[
+                WorkItemBatchOperation::Create {
+                    work_item_type: "Bug".to_owned(),
+                    operations: WorkItemPatch::new().title("Fix the build").build(),
+                },
+                WorkItemBatchOperation::Delete { id: 2 },
+            ])
+            .unwrap();
+        assert_eq!(
+            body,
+            json!([
+                {
+                    "method": "PATCH",
+                    "uri": "/My%20Project/_apis/wit/workitems/$Bug?api-version=7.1-preview&bypassRules=true",
+                    "headers": { "Content-Type": "application/json-patch+json" },
+                    "body": [{ "op": "add", "path": "/fields/System.Title", "value": "Fix the build" }]
+                },
+                {
+                    "method": "DELETE",
+                    "uri": "/My%20Project/_apis/wit/workitems/2?api-version=7.1-preview"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_batch_results() {
+        let response = json!({
+            "count": 2,
+            "value": [
+                {
+                    "code": 200,
+                    "headers": { "Content-Type": "application/json; charset=utf-8" },
+                    "body": "{\"id\":1,\"rev\":2,\"url\":\"https://dev.azure.com/org/_apis/wit/workItems/1\",\"fields\":{}}"
+                },
+                {
+                    "code": 400,
+                    "body": "{\"message\":\"TF401320: Rule Error for field Title.\",\"typeKey\":\"RuleValidationException\"}"
+                }
+            ]
+        });
+        let results = batch_results(serde_json::from_value(response.clone()).unwrap(), 2).unwrap();
+        assert!(results[0].is_success());
+        assert_eq!(results[0].work_item().unwrap().id, 1);
+        assert!(!results[1].is_success());
+        let error = results[1].error().unwrap();
+        assert_eq!(error.type_key(), Some("RuleValidationException"));
+        assert_eq!(error.status, Some(StatusCode::BadRequest));
+        assert!(results[1].work_item().is_err());
+        assert!(batch_results(serde_json::from_value(response).unwrap(), 3).is_err());
+    }
+}
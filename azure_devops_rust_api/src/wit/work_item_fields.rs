@@ -0,0 +1,351 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Typed access to work item fields.
+//!
+//! The fields of a work item are returned as a JSON map from field reference name to value, e.g.
+//!
+//! ```json
+//! {
+//!   "System.State": "Active",
+//!   "System.AssignedTo": { "displayName": "Jamal Hartnett", "uniqueName": "fabrikamfiber4@hotmail.com", "id": "..." },
+//!   "System.CreatedDate": "2023-03-01T10:12:43.193Z",
+//!   "System.Tags": "build; urgent",
+//!   "Custom.Team": "Tools"
+//! }
+//! ```
+//!
+//! [`WorkItemFields`] provides typed accessors for the well-known system fields, and
+//! [`WorkItemFields::get()`] deserializes any other field.
+
+use super::models::{IdentityRef, WorkItem};
+use azure_core::error::{ErrorKind, ResultExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use time::OffsetDateTime;
+
+/// A typed view of the fields of a work item.
+///
+/// Example:
+///
+/// ```ignore
+/// let fields = work_item.typed_fields();
+/// let state = fields.state().unwrap_or_default();
+/// let assigned_to = fields.assigned_to()?.and_then(|identity| identity.graph_subject_base.display_name);
+/// let team: Option<String> = fields.get("Custom.Team")?;
+/// ```
+///
+/// Custom process fields can also be mapped to a struct via [`WorkItemFields::deserialize()`]:
+///
+/// ```ignore
+/// #[derive(serde::Deserialize)]
+/// struct CustomFields {
+///     #[serde(rename = "Custom.Team")]
+///     team: Option<String>,
+///     #[serde(rename = "Custom.DueDate", default, with = "azure_devops_rust_api::date_time::rfc3339::option")]
+///     due_date: Option<time::OffsetDateTime>,
+/// }
+/// let custom: CustomFields = work_item.typed_fields().deserialize()?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorkItemFields<'a> {
+    fields: &'a Value,
+}
+
+impl<'a> WorkItemFields<'a> {
+    /// Creates a typed view of a map of work item fields.
+    pub fn new(fields: &'a Value) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the raw value of a field, given its reference name, e.g. `System.State`.
+    ///
+    /// Returns `None` if the field is not set.
+    pub fn value(&self, name: &str) -> Option<&'a Value> {
+        self.fields.get(name).filter(|value| !value.is_null())
+    }
+
+    /// Deserializes the value of a field, given its reference name, e.g. `Custom.Team`.
+    ///
+    /// Returns `Ok(None)` if the field is not set.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> azure_core::Result<Option<T>> {
+        self.value(name)
+            .map(|value| {
+                serde_json::from_value(value.clone())
+                    .with_context(ErrorKind::DataConversion, || {
+                        format!("Failed to deserialize work item field {name}")
+                    })
+            })
+            .transpose()
+    }
+
+    /// Returns the value of a string field.
+    pub fn get_str(&self, name: &str) -> Option<&'a str> {
+        self.value(name).and_then(Value::as_str)
+    }
+
+    /// Returns the value of an integer field.
+    pub fn get_i32(&self, name: &str) -> Option<i32> {
+        self.value(name)
+            .and_then(Value::as_i64)
+            .and_then(|value| i32::try_from(value).ok())
+    }
+
+    /// Returns the value of a decimal field.
+    pub fn get_f64(&self, name: &str) -> Option<f64> {
+        self.value(name).and_then(Value::as_f64)
+    }
+
+    /// Returns the value of a date-time field.
+    pub fn get_date_time(&self, name: &str) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_str(name)
+            .map(crate::date_time::parse_date_time)
+            .transpose()
+    }
+
+    /// Returns the value of an identity field, e.g. `System.AssignedTo`.
+    pub fn get_identity(&self, name: &str) -> azure_core::Result<Option<IdentityRef>> {
+        self.get(name)
+    }
+
+    /// Deserializes all fields into `T`, e.g. a struct that maps the fields of a custom process.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> azure_core::Result<T> {
+        serde_json::from_value(self.fields.clone()).context(
+            ErrorKind::DataConversion,
+            "Failed to deserialize work item fields",
+        )
+    }
+
+    /// `System.Id`
+    pub fn id(&self) -> Option<i32> {
+        self.get_i32("System.Id")
+    }
+
+    /// `System.Rev`
+    pub fn rev(&self) -> Option<i32> {
+        self.get_i32("System.Rev")
+    }
+
+    /// `System.Parent`, the ID of the parent work item.
+    pub fn parent(&self) -> Option<i32> {
+        self.get_i32("System.Parent")
+    }
+
+    /// `System.TeamProject`
+    pub fn team_project(&self) -> Option<&'a str> {
+        self.get_str("System.TeamProject")
+    }
+
+    /// `System.WorkItemType`
+    pub fn work_item_type(&self) -> Option<&'a str> {
+        self.get_str("System.WorkItemType")
+    }
+
+    /// `System.Title`
+    pub fn title(&self) -> Option<&'a str> {
+        self.get_str("System.Title")
+    }
+
+    /// `System.Description` (HTML)
+    pub fn description(&self) -> Option<&'a str> {
+        self.get_str("System.Description")
+    }
+
+    /// `System.State`
+    pub fn state(&self) -> Option<&'a str> {
+        self.get_str("System.State")
+    }
+
+    /// `System.Reason`
+    pub fn reason(&self) -> Option<&'a str> {
+        self.get_str("System.Reason")
+    }
+
+    /// `System.AreaPath`
+    pub fn area_path(&self) -> Option<&'a str> {
+        self.get_str("System.AreaPath")
+    }
+
+    /// `System.IterationPath`
+    pub fn iteration_path(&self) -> Option<&'a str> {
+        self.get_str("System.IterationPath")
+    }
+
+    /// `System.BoardColumn`
+    pub fn board_column(&self) -> Option<&'a str> {
+        self.get_str("System.BoardColumn")
+    }
+
+    /// `System.Tags`, split into individual tags.
+    pub fn tags(&self) -> Vec<String> {
+        self.get_str("System.Tags")
+            .map(|tags| {
+                tags.split(';')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// `System.AssignedTo`
+    pub fn assigned_to(&self) -> azure_core::Result<Option<IdentityRef>> {
+        self.get_identity("System.AssignedTo")
+    }
+
+    /// `System.CreatedBy`
+    pub fn created_by(&self) -> azure_core::Result<Option<IdentityRef>> {
+        self.get_identity("System.CreatedBy")
+    }
+
+    /// `System.ChangedBy`
+    pub fn changed_by(&self) -> azure_core::Result<Option<IdentityRef>> {
+        self.get_identity("System.ChangedBy")
+    }
+
+    /// `System.CreatedDate`
+    pub fn created_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("System.CreatedDate")
+    }
+
+    /// `System.ChangedDate`
+    pub fn changed_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("System.ChangedDate")
+    }
+
+    /// `Microsoft.VSTS.Common.StateChangeDate`
+    pub fn state_change_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("Microsoft.VSTS.Common.StateChangeDate")
+    }
+
+    /// `Microsoft.VSTS.Common.ClosedDate`
+    pub fn closed_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("Microsoft.VSTS.Common.ClosedDate")
+    }
+
+    /// `Microsoft.VSTS.Common.Priority`
+    pub fn priority(&self) -> Option<i32> {
+        self.get_i32("Microsoft.VSTS.Common.Priority")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.StoryPoints`
+    pub fn story_points(&self) -> Option<f64> {
+        self.get_f64("Microsoft.VSTS.Scheduling.StoryPoints")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.Effort`
+    pub fn effort(&self) -> Option<f64> {
+        self.get_f64("Microsoft.VSTS.Scheduling.Effort")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.OriginalEstimate`
+    pub fn original_estimate(&self) -> Option<f64> {
+        self.get_f64("Microsoft.VSTS.Scheduling.OriginalEstimate")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.RemainingWork`
+    pub fn remaining_work(&self) -> Option<f64> {
+        self.get_f64("Microsoft.VSTS.Scheduling.RemainingWork")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.CompletedWork`
+    pub fn completed_work(&self) -> Option<f64> {
+        self.get_f64("Microsoft.VSTS.Scheduling.CompletedWork")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.StartDate`
+    pub fn start_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("Microsoft.VSTS.Scheduling.StartDate")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.FinishDate`
+    pub fn finish_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("Microsoft.VSTS.Scheduling.FinishDate")
+    }
+
+    /// `Microsoft.VSTS.Scheduling.TargetDate`
+    pub fn target_date(&self) -> azure_core::Result<Option<OffsetDateTime>> {
+        self.get_date_time("Microsoft.VSTS.Scheduling.TargetDate")
+    }
+}
+
+impl WorkItem {
+    /// Returns a typed view of the work item fields.
+    pub fn typed_fields(&self) -> WorkItemFields<'_> {
+        WorkItemFields::new(&self.fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    fn fields() -> Value {
+        json!({
+            "System.Id": 42,
+            "System.State": "Active",
+            "System.AssignedTo": {
+                "displayName": "Jamal Hartnett",
+                "uniqueName": "fabrikamfiber4@hotmail.com",
+                "id": "d291b0c4-a05c-4ea6-8df1-4b41d5f39eff"
+            },
+            "System.CreatedDate": "2023-03-01T10:12:43.193Z",
+            "System.Tags": "build; urgent ;",
+            "Microsoft.VSTS.Scheduling.StoryPoints": 3.5,
+            "Custom.Team": "Tools",
+            "Custom.Empty": null
+        })
+    }
+
+    #[test]
+    fn test_system_fields() {
+        let value = fields();
+        let fields = WorkItemFields::new(&value);
+        assert_eq!(fields.id(), Some(42));
+        assert_eq!(fields.state(), Some("Active"));
+        assert_eq!(fields.title(), None);
+        assert_eq!(
+            fields
+                .assigned_to()
+                .unwrap()
+                .unwrap()
+                .graph_subject_base
+                .display_name
+                .as_deref(),
+            Some("Jamal Hartnett")
+        );
+        assert_eq!(
+            fields.created_date().unwrap().unwrap().unix_timestamp(),
+            1677665563
+        );
+        assert_eq!(fields.tags(), vec!["build", "urgent"]);
+        assert_eq!(fields.story_points(), Some(3.5));
+    }
+
+    #[test]
+    fn test_custom_fields() {
+        #[derive(Deserialize)]
+        struct CustomFields {
+            #[serde(rename = "Custom.Team")]
+            team: String,
+            #[serde(rename = "Custom.Empty")]
+            empty: Option<String>,
+        }
+
+        let value = fields();
+        let fields = WorkItemFields::new(&value);
+        assert_eq!(
+            fields.get::<String>("Custom.Team").unwrap().unwrap(),
+            "Tools"
+        );
+        assert_eq!(fields.get::<String>("Custom.Empty").unwrap(), None);
+        assert!(fields.get::<i32>("Custom.Team").is_err());
+
+        let custom: CustomFields = fields.deserialize().unwrap();
+        assert_eq!(custom.team, "Tools");
+        assert_eq!(custom.empty, None);
+    }
+}
@@ -0,0 +1,351 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Typed work item history, built from the work item updates.
+//!
+//! Each work item update lists the fields changed by a revision, with their old and new values,
+//! and the relations that were added, removed or updated, e.g.
+//!
+//! ```json
+//! {
+//!   "rev": 3,
+//!   "revisedBy": { "displayName": "Jamal Hartnett", ... },
+//!   "fields": {
+//!     "System.State": { "oldValue": "New", "newValue": "Active" },
+//!     "System.ChangedDate": { "oldValue": "2023-03-01T10:12:43.193Z", "newValue": "2023-03-02T09:00:00.000Z" }
+//!   },
+//!   "relations": { "added": [ { "rel": "System.LinkTypes.Hierarchy-Reverse", "url": "...", "attributes": {} } ] }
+//! }
+//! ```
+//!
+//! [`WorkItemHistory`] converts these updates into typed changes and state transitions.
+
+use super::models::{IdentityReference, WorkItemRelation, WorkItemUpdate};
+use super::updates;
+use azure_core::error::{ErrorKind, ResultExt};
+use futures::TryStreamExt;
+use serde_json::Value;
+use time::{Duration, OffsetDateTime};
+
+/// The number of updates requested per page.
+const UPDATES_PAGE_SIZE: i32 = 200;
+
+impl updates::Client {
+    /// Returns the history of a work item, fetching all its updates.
+    ///
+    /// Arguments:
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `id`: The work item ID.
+    /// * `project`: Project ID or project name
+    pub async fn history(
+        &self,
+        organization: impl Into<String>,
+        id: i32,
+        project: impl Into<String>,
+    ) -> azure_core::Result<WorkItemHistory> {
+        let updates: Vec<WorkItemUpdate> = self
+            .list(organization, id, project)
+            .items(UPDATES_PAGE_SIZE)
+            .try_collect()
+            .await?;
+        WorkItemHistory::from_updates(updates)
+    }
+}
+
+scoped_client_methods! {
+    updates;
+    /// Returns the history of a work item, fetching all its updates.
+    pub async fn history(id: i32, [project]) -> azure_core::Result<WorkItemHistory>;
+}
+
+/// The history of a work item: the changes made by each of its revisions.
+///
+/// Example:
+///
+/// ```ignore
+/// let history = wit_client
+///     .updates_client()
+///     .history(organization, id, project)
+///     .await?;
+/// for transition in history.state_transitions() {
+///     println!("{:?} -> {} at {:?}", transition.from, transition.to, transition.date);
+/// }
+/// let cycle_time = history.cycle_time(&["Active"], &["Closed"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkItemHistory {
+    changes: Vec<WorkItemChange>,
+}
+
+/// The changes made by a single revision of a work item.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkItemChange {
+    /// The revision number.
+    pub rev: Option<i32>,
+    /// When the change was made (the new value of `System.ChangedDate`).
+    pub changed_date: Option<OffsetDateTime>,
+    /// Who made the change.
+    pub revised_by: Option<IdentityReference>,
+    /// The changed fields, sorted by reference name.
+    pub fields: Vec<FieldChange>,
+    /// Relations added by the change.
+    pub relations_added: Vec<WorkItemRelation>,
+    /// Relations removed by the change.
+    pub relations_removed: Vec<WorkItemRelation>,
+    /// Relations updated by the change, e.g. a changed comment.
+    pub relations_updated: Vec<WorkItemRelation>,
+}
+
+/// A change to the value of a field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    /// The reference name of the field, e.g. `System.State`.
+    pub name: String,
+    /// The value before the change, `None` if the field was not set.
+    pub old_value: Option<Value>,
+    /// The value after the change, `None` if the field was cleared.
+    pub new_value: Option<Value>,
+}
+
+/// A change to the `System.State` of a work item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateTransition {
+    /// The revision number.
+    pub rev: Option<i32>,
+    /// The previous state, `None` when the work item was created.
+    pub from: Option<String>,
+    /// The new state.
+    pub to: String,
+    /// When the state changed.
+    pub date: Option<OffsetDateTime>,
+    /// Who changed the state.
+    pub changed_by: Option<IdentityReference>,
+}
+
+impl FieldChange {
+    /// Returns the old value of a string field.
+    pub fn old_str(&self) -> Option<&str> {
+        self.old_value.as_ref().and_then(Value::as_str)
+    }
+
+    /// Returns the new value of a string field.
+    pub fn new_str(&self) -> Option<&str> {
+        self.new_value.as_ref().and_then(Value::as_str)
+    }
+}
+
+impl WorkItemChange {
+    /// Builds the change from a work item update.
+    pub fn from_update(update: WorkItemUpdate) -> azure_core::Result<Self> {
+        let mut fields: Vec<FieldChange> = match update.fields {
+            Some(Value::Object(fields)) => fields
+                .into_iter()
+                .map(|(name, mut value)| FieldChange {
+                    name,
+                    old_value: take_value(&mut value, "oldValue"),
+                    new_value: take_value(&mut value, "newValue"),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let changed_date = fields
+            .iter()
+            .find(|field| field.name == "System.ChangedDate")
+            .and_then(FieldChange::new_str)
+            .map(crate::date_time::parse_date_time)
+            .transpose()
+            .with_context(ErrorKind::DataConversion, || {
+                format!(
+                    "Failed to parse System.ChangedDate of work item revision {:?}",
+                    update.rev
+                )
+            })?;
+
+        let relations = update.relations.unwrap_or_default();
+        Ok(Self {
+            rev: update.rev,
+            changed_date,
+            revised_by: update.revised_by,
+            fields,
+            relations_added: relations.added,
+            relations_removed: relations.removed,
+            relations_updated: relations.updated,
+        })
+    }
+
+    /// Returns the change to a field, given its reference name.
+    pub fn field(&self, name: &str) -> Option<&FieldChange> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl WorkItemHistory {
+    /// Builds the history from the updates of a work item, in revision order.
+    pub fn from_updates(
+        updates: impl IntoIterator<Item = WorkItemUpdate>,
+    ) -> azure_core::Result<Self> {
+        let changes = updates
+            .into_iter()
+            .map(WorkItemChange::from_update)
+            .collect::<azure_core::Result<_>>()?;
+        Ok(Self { changes })
+    }
+
+    /// Returns the changes made by each revision, oldest first.
+    pub fn changes(&self) -> &[WorkItemChange] {
+        &self.changes
+    }
+
+    /// Returns the changes to a field, given its reference name, oldest first.
+    pub fn field_changes<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a WorkItemChange, &'a FieldChange)> + 'a {
+        self.changes
+            .iter()
+            .filter_map(move |change| change.field(name).map(|field| (change, field)))
+    }
+
+    /// Returns the changes to `System.State`, oldest first.
+    pub fn state_transitions(&self) -> Vec<StateTransition> {
+        self.field_changes("System.State")
+            .filter_map(|(change, field)| {
+                Some(StateTransition {
+                    rev: change.rev,
+                    from: field.old_str().map(String::from),
+                    to: field.new_str()?.to_owned(),
+                    date: change.changed_date,
+                    changed_by: change.revised_by.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns when the work item was created, i.e. the date of its first revision.
+    pub fn created_date(&self) -> Option<OffsetDateTime> {
+        self.changes.first().and_then(|change| change.changed_date)
+    }
+
+    /// Returns when the work item first entered any of `states`.
+    pub fn first_entered(&self, states: &[&str]) -> Option<OffsetDateTime> {
+        self.state_transitions()
+            .into_iter()
+            .find(|transition| states.contains(&transition.to.as_str()))
+            .and_then(|transition| transition.date)
+    }
+
+    /// Returns when the work item last entered any of `states`, if it is still in one of them.
+    ///
+    /// Returns `None` if the work item has since moved to a state that is not in `states`,
+    /// e.g. it was reopened.
+    pub fn last_entered(&self, states: &[&str]) -> Option<OffsetDateTime> {
+        let transitions = self.state_transitions();
+        let mut entered = None;
+        for transition in transitions.iter().rev() {
+            if !states.contains(&transition.to.as_str()) {
+                break;
+            }
+            entered = transition.date;
+        }
+        entered
+    }
+
+    /// Returns the lead time: from creation until the work item entered one of the `done_states`.
+    pub fn lead_time(&self, done_states: &[&str]) -> Option<Duration> {
+        Some(self.last_entered(done_states)? - self.created_date()?)
+    }
+
+    /// Returns the cycle time: from when the work item first entered one of the `start_states`
+    /// until it entered one of the `done_states`.
+    pub fn cycle_time(&self, start_states: &[&str], done_states: &[&str]) -> Option<Duration> {
+        Some(self.last_entered(done_states)? - self.first_entered(start_states)?)
+    }
+}
+
+/// Takes a value from a JSON object, treating `null` as not set.
+fn take_value(value: &mut Value, key: &str) -> Option<Value> {
+    value
+        .get_mut(key)
+        .map(Value::take)
+        .filter(|value| !value.is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn update(rev: i32, date: &str, state: Option<(Option<&str>, &str)>) -> WorkItemUpdate {
+        let mut fields = json!({
+            "System.ChangedDate": { "newValue": date },
+            "System.Rev": { "oldValue": rev - 1, "newValue": rev }
+        });
+        if let Some((from, to)) = state {
+            fields["System.State"] = json!({ "oldValue": from, "newValue": to });
+        }
+        serde_json::from_value(json!({
+            "id": rev,
+            "rev": rev,
+            "url": "https://dev.azure.com/org/_apis/wit/workItems/1/updates",
+            "revisedBy": { "displayName": "Jamal Hartnett" },
+            "fields": fields,
+            "relations": {
+                "added": [
+                    { "rel": "System.LinkTypes.Hierarchy-Reverse", "url": "https://example.com/2", "attributes": {} }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_history() {
+        let history = WorkItemHistory::from_updates(vec![
+            update(1, "2023-03-01T00:00:00Z", Some((None, "New"))),
+            update(2, "2023-03-02T00:00:00Z", Some((Some("New"), "Active"))),
+            update(3, "2023-03-03T00:00:00Z", None),
+            update(4, "2023-03-05T00:00:00Z", Some((Some("Active"), "Closed"))),
+            update(5, "2023-03-06T00:00:00Z", Some((Some("Closed"), "Active"))),
+            update(6, "2023-03-08T00:00:00Z", Some((Some("Active"), "Closed"))),
+        ])
+        .unwrap();
+
+        let change = &history.changes()[1];
+        assert_eq!(change.rev, Some(2));
+        assert_eq!(
+            change
+                .fields
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["System.ChangedDate", "System.Rev", "System.State"]
+        );
+        assert_eq!(
+            change.field("System.Rev").unwrap().old_value,
+            Some(json!(1))
+        );
+        assert_eq!(change.relations_added.len(), 1);
+
+        let transitions = history.state_transitions();
+        assert_eq!(transitions.len(), 5);
+        assert_eq!(transitions[0].from, None);
+        assert_eq!(transitions[1].from.as_deref(), Some("New"));
+        assert_eq!(transitions[1].to, "Active");
+        assert_eq!(
+            transitions[1]
+                .changed_by
+                .as_ref()
+                .and_then(|identity| identity.graph_subject_base.display_name.as_deref()),
+            Some("Jamal Hartnett")
+        );
+
+        assert_eq!(history.lead_time(&["Closed"]), Some(Duration::days(7)));
+        assert_eq!(
+            history.cycle_time(&["Active"], &["Closed"]),
+            Some(Duration::days(6))
+        );
+        assert_eq!(history.cycle_time(&["Active"], &["Resolved"]), None);
+    }
+}
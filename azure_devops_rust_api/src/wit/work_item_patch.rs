@@ -0,0 +1,221 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! JSON Patch documents for creating and updating work items.
+//!
+//! The work item `create` and `update` operations take a list of JSON Patch operations
+//! that set the fields and relations of the work item, e.g.
+//!
+//! ```json
+//! [
+//!   { "op": "test", "path": "/rev", "value": 3 },
+//!   { "op": "add", "path": "/fields/System.Title", "value": "Fix the build" },
+//!   { "op": "add", "path": "/relations/-", "value": { "rel": "System.LinkTypes.Hierarchy-Reverse", "url": "..." } }
+//! ]
+//! ```
+//!
+//! [`WorkItemPatch`] builds these operations.
+
+use super::models::{json_patch_operation::Op, JsonPatchOperation};
+use serde_json::{json, Value};
+
+/// Builder for the JSON Patch operations of a work item `create` or `update` request.
+///
+/// Example:
+///
+/// ```ignore
+/// let body = WorkItemPatch::new()
+///     .title("Fix the build")
+///     .assigned_to("someone@example.com")
+///     .field("Microsoft.VSTS.Common.Priority", 1)
+///     .tags(["build", "urgent"])
+///     .build();
+/// let work_item = wit_client
+///     .work_items_client()
+///     .create(organization, body, project, "Bug")
+///     .await?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkItemPatch {
+    operations: Vec<JsonPatchOperation>,
+}
+
+impl WorkItemPatch {
+    /// Creates an empty `WorkItemPatch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of a field, given its reference name, e.g. `Custom.Team`.
+    pub fn field(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.operations
+            .push(operation(Op::Add, field_path(name), Some(value.into())));
+        self
+    }
+
+    /// Removes the value of a field, given its reference name.
+    pub fn remove_field(mut self, name: &str) -> Self {
+        self.operations
+            .push(operation(Op::Remove, field_path(name), None));
+        self
+    }
+
+    /// Sets the `System.Title` field.
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.field("System.Title", title.into())
+    }
+
+    /// Sets the `System.Description` field (HTML).
+    pub fn description(self, description: impl Into<String>) -> Self {
+        self.field("System.Description", description.into())
+    }
+
+    /// Sets the `System.State` field, e.g. `Active`.
+    pub fn state(self, state: impl Into<String>) -> Self {
+        self.field("System.State", state.into())
+    }
+
+    /// Sets the `System.AssignedTo` field.
+    ///
+    /// The identity may be specified by its unique name (e.g. an email address) or display name.
+    pub fn assigned_to(self, identity: impl Into<String>) -> Self {
+        self.field("System.AssignedTo", identity.into())
+    }
+
+    /// Sets the `System.AreaPath` field.
+    pub fn area_path(self, area_path: impl Into<String>) -> Self {
+        self.field("System.AreaPath", area_path.into())
+    }
+
+    /// Sets the `System.IterationPath` field.
+    pub fn iteration_path(self, iteration_path: impl Into<String>) -> Self {
+        self.field("System.IterationPath", iteration_path.into())
+    }
+
+    /// Adds a comment to the work item discussion, via the `System.History` field.
+    pub fn history(self, comment: impl Into<String>) -> Self {
+        self.field("System.History", comment.into())
+    }
+
+    /// Sets the `System.Tags` field, a `;` separated list of tags.
+    ///
+    /// This replaces the existing tags of the work item, so when updating a work item the
+    /// existing tags that are to be kept must also be passed.
+    pub fn tags<I>(self, tags: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let tags: Vec<String> = tags.into_iter().map(Into::into).collect();
+        self.field("System.Tags", tags.join("; "))
+    }
+
+    /// Adds a relation to another work item or resource.
+    ///
+    /// `rel` is the relation type, e.g. `System.LinkTypes.Hierarchy-Reverse` to add a parent,
+    /// and `url` is the URL of the related resource,
+    /// e.g. `https://dev.azure.com/{organization}/_apis/wit/workItems/{id}`.
+    pub fn add_relation(mut self, rel: impl Into<String>, url: impl Into<String>) -> Self {
+        let value = json!({ "rel": rel.into(), "url": url.into() });
+        self.operations
+            .push(operation(Op::Add, "/relations/-".to_owned(), Some(value)));
+        self
+    }
+
+    /// Removes the relation at `index` in the work item `relations`.
+    pub fn remove_relation(mut self, index: usize) -> Self {
+        self.operations
+            .push(operation(Op::Remove, format!("/relations/{index}"), None));
+        self
+    }
+
+    /// Checks that the work item revision is `rev`, so that the update fails if
+    /// the work item has been changed by someone else.
+    ///
+    /// The test operation is placed before all other operations, replacing any previous
+    /// `test_rev()` operation.
+    pub fn test_rev(mut self, rev: i32) -> Self {
+        self.operations.retain(|operation| {
+            !(operation.op == Some(Op::Test) && operation.path.as_deref() == Some("/rev"))
+        });
+        self.operations
+            .insert(0, operation(Op::Test, "/rev".to_owned(), Some(rev.into())));
+        self
+    }
+
+    /// Returns `true` if no operations have been added.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Returns the JSON Patch operations, to pass as the body of a `create` or `update` request.
+    pub fn build(self) -> Vec<JsonPatchOperation> {
+        self.operations
+    }
+}
+
+impl From<WorkItemPatch> for Vec<JsonPatchOperation> {
+    fn from(patch: WorkItemPatch) -> Self {
+        patch.build()
+    }
+}
+
+fn operation(op: Op, path: String, value: Option<Value>) -> JsonPatchOperation {
+    JsonPatchOperation {
+        from: None,
+        op: Some(op),
+        path: Some(path),
+        value,
+    }
+}
+
+fn field_path(name: &str) -> String {
+    format!("/fields/{}", escape_path_segment(name))
+}
+
+/// Escapes a JSON Pointer path segment (RFC 6901).
+fn escape_path_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_item_patch() {
+        let operations = WorkItemPatch::new()
+            .title("Fix the build")
+            .field("Custom.Size", 3)
+            .tags(["build", "urgent"])
+            .add_relation(
+                "System.LinkTypes.Hierarchy-Reverse",
+                "https://example.com/1",
+            )
+            .remove_relation(2)
+            .test_rev(6)
+            .test_rev(7)
+            .build();
+        let json = serde_json::to_value(&operations).unwrap();
+        assert_eq!(
+            json,
+            json!([
+                { "op": "test", "path": "/rev", "value": 7 },
+                { "op": "add", "path": "/fields/System.Title", "value": "Fix the build" },
+                { "op": "add", "path": "/fields/Custom.Size", "value": 3 },
+                { "op": "add", "path": "/fields/System.Tags", "value": "build; urgent" },
+                {
+                    "op": "add",
+                    "path": "/relations/-",
+                    "value": { "rel": "System.LinkTypes.Hierarchy-Reverse", "url": "https://example.com/1" }
+                },
+                { "op": "remove", "path": "/relations/2" }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_escape_path_segment() {
+        assert_eq!(field_path("Custom.A/B~C"), "/fields/Custom.A~1B~0C");
+    }
+}
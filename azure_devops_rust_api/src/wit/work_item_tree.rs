@@ -0,0 +1,339 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Work item hierarchies built from the results of WIQL tree queries.
+//!
+//! A tree query (`FROM WorkItemLinks ... MODE (Recursive)`) returns the hierarchy as a flat
+//! list of `workItemRelations`, e.g.
+//!
+//! ```json
+//! [
+//!   { "target": { "id": 1 } },
+//!   { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 2 } },
+//!   { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 2 }, "target": { "id": 3 } }
+//! ]
+//! ```
+//!
+//! where links without a source are the roots of the hierarchy.
+//! [`WorkItemTree`] converts these links into a tree that can be navigated from parent to
+//! child and back, and optionally holds the work item of each node.
+
+use super::models::{WorkItem, WorkItemBatchGetRequest, WorkItemLink, WorkItemQueryResult};
+use super::wiql_work_items::{get_work_items_batches, DEFAULT_CONCURRENCY, WORK_ITEMS_BATCH_SIZE};
+use super::work_items;
+use azure_core::error::{Error, ErrorKind};
+use futures::TryStreamExt;
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// A hierarchy of work items, e.g. Epic → Feature → User Story → Task.
+///
+/// Nodes are identified by work item ID.
+///
+/// Example:
+///
+/// ```ignore
+/// let query = WiqlBuilder::work_item_tree()
+///     .filter(WiqlField::source("System.WorkItemType").eq("Epic"))
+///     .filter(WiqlField::link_type().eq("System.LinkTypes.Hierarchy-Forward"));
+/// let result = wit_client
+///     .wiql_client()
+///     .query_by_wiql(organization, query, project, team)
+///     .await?;
+/// let mut tree = WorkItemTree::from_query_result(&result)?;
+/// tree.hydrate(&wit_client.work_items_client(), organization, project, vec![])
+///     .await?;
+/// for &epic in tree.roots() {
+///     let remaining_work =
+///         tree.rollup(epic, |work_item| work_item.typed_fields().remaining_work());
+///     println!("{epic}: {remaining_work}");
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkItemTree {
+    roots: Vec<i32>,
+    nodes: HashMap<i32, Node>,
+    as_of: Option<OffsetDateTime>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Node {
+    parent: Option<i32>,
+    rel: Option<String>,
+    children: Vec<i32>,
+    work_item: Option<WorkItem>,
+}
+
+impl WorkItemTree {
+    /// Builds the tree from the links returned by a tree query.
+    ///
+    /// Links with a source are treated as parent (source) → child (target) links,
+    /// and links without a source as roots.
+    ///
+    /// Returns an error if a work item has more than one parent, or if the links contain a cycle.
+    pub fn from_links<'a, I>(links: I) -> azure_core::Result<Self>
+    where
+        I: IntoIterator<Item = &'a WorkItemLink>,
+    {
+        let mut tree = Self::default();
+        for link in links {
+            let target = match link.target.as_ref().and_then(|target| target.id) {
+                Some(target) => target,
+                None => continue,
+            };
+            match link.source.as_ref().and_then(|source| source.id) {
+                Some(source) => tree.add_link(source, target, link.rel.clone())?,
+                None => tree.add_root(target),
+            }
+        }
+        Ok(tree)
+    }
+
+    /// Builds the tree from the result of a tree query.
+    ///
+    /// The `asOf` time of the query is used when the tree is [hydrated](WorkItemTree::hydrate).
+    pub fn from_query_result(result: &WorkItemQueryResult) -> azure_core::Result<Self> {
+        let mut tree = Self::from_links(&result.work_item_relations)?;
+        tree.as_of = result.as_of;
+        Ok(tree)
+    }
+
+    fn add_root(&mut self, id: i32) {
+        let node = self.nodes.entry(id).or_default();
+        if node.parent.is_none() && !self.roots.contains(&id) {
+            self.roots.push(id);
+        }
+    }
+
+    fn add_link(&mut self, parent: i32, child: i32, rel: Option<String>) -> azure_core::Result<()> {
+        if parent == child || self.ancestors(parent).contains(&child) {
+            return Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!("Work item links contain a cycle: {child} is an ancestor of {parent}")
+            }));
+        }
+        if !self.nodes.contains_key(&parent) {
+            self.add_root(parent);
+        }
+        let node = self.nodes.entry(child).or_default();
+        match node.parent {
+            Some(existing) if existing == parent => return Ok(()),
+            Some(existing) => {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!("Work item {child} has more than one parent: {existing} and {parent}")
+                }))
+            }
+            None => {
+                node.parent = Some(parent);
+                node.rel = rel;
+            }
+        }
+        // The child may have been added as a root by an earlier link.
+        self.roots.retain(|&root| root != child);
+        self.nodes.entry(parent).or_default().children.push(child);
+        Ok(())
+    }
+
+    /// Returns the IDs of the root work items, in query order.
+    pub fn roots(&self) -> &[i32] {
+        &self.roots
+    }
+
+    /// Returns `true` if the tree contains the work item.
+    pub fn contains(&self, id: i32) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// Returns the number of work items in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the tree contains no work items.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the IDs of all work items in the tree, depth first from each root.
+    pub fn ids(&self) -> Vec<i32> {
+        self.roots
+            .iter()
+            .flat_map(|&root| std::iter::once(root).chain(self.descendants(root)))
+            .collect()
+    }
+
+    /// Returns the ID of the parent of a work item.
+    pub fn parent(&self, id: i32) -> Option<i32> {
+        self.nodes.get(&id).and_then(|node| node.parent)
+    }
+
+    /// Returns the link type from the parent of a work item, e.g. `System.LinkTypes.Hierarchy-Forward`.
+    pub fn parent_rel(&self, id: i32) -> Option<&str> {
+        self.nodes.get(&id).and_then(|node| node.rel.as_deref())
+    }
+
+    /// Returns the IDs of the children of a work item, in query order.
+    pub fn children(&self, id: i32) -> &[i32] {
+        self.nodes
+            .get(&id)
+            .map(|node| node.children.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the IDs of the ancestors of a work item, starting with its parent.
+    pub fn ancestors(&self, id: i32) -> Vec<i32> {
+        let mut ancestors = Vec::new();
+        let mut current = self.parent(id);
+        while let Some(parent) = current {
+            ancestors.push(parent);
+            current = self.parent(parent);
+        }
+        ancestors
+    }
+
+    /// Returns the IDs of the descendants of a work item, depth first.
+    pub fn descendants(&self, id: i32) -> Vec<i32> {
+        let mut descendants = Vec::new();
+        let mut stack: Vec<i32> = self.children(id).iter().rev().copied().collect();
+        while let Some(next) = stack.pop() {
+            descendants.push(next);
+            stack.extend(self.children(next).iter().rev());
+        }
+        descendants
+    }
+
+    /// Returns the depth of a work item, where roots have depth 0.
+    pub fn depth(&self, id: i32) -> usize {
+        self.ancestors(id).len()
+    }
+
+    /// Returns the work item of a node, if the tree has been hydrated.
+    pub fn work_item(&self, id: i32) -> Option<&WorkItem> {
+        self.nodes.get(&id).and_then(|node| node.work_item.as_ref())
+    }
+
+    /// Sets the work items of the nodes of the tree.
+    ///
+    /// Work items that are not in the tree are ignored.
+    pub fn set_work_items(&mut self, work_items: impl IntoIterator<Item = WorkItem>) {
+        for work_item in work_items {
+            if let Some(node) = self.nodes.get_mut(&work_item.id) {
+                node.work_item = Some(work_item);
+            }
+        }
+    }
+
+    /// Fetches the work items of all nodes of the tree, in batches of up to 200.
+    ///
+    /// `fields` is the list of fields to fetch (all fields if empty).
+    /// If the tree was built from a query result then the work items are fetched as of
+    /// the time the query was run.
+    pub async fn hydrate(
+        &mut self,
+        client: &work_items::Client,
+        organization: &str,
+        project: &str,
+        fields: Vec<String>,
+    ) -> azure_core::Result<()> {
+        let requests = self
+            .ids()
+            .chunks(WORK_ITEMS_BATCH_SIZE)
+            .map(|ids| WorkItemBatchGetRequest {
+                expand: None,
+                as_of: self.as_of,
+                error_policy: None,
+                fields: fields.clone(),
+                ids: ids.to_vec(),
+            })
+            .collect();
+        let work_items: Vec<WorkItem> = get_work_items_batches(
+            &client.0,
+            organization,
+            project,
+            requests,
+            DEFAULT_CONCURRENCY,
+        )
+        .try_collect()
+        .await?;
+        self.set_work_items(work_items);
+        Ok(())
+    }
+
+    /// Sums a value over a work item and all its descendants, e.g. to roll up remaining work.
+    ///
+    /// Nodes without a work item, or for which `value` returns `None`, count as zero.
+    pub fn rollup<F>(&self, id: i32, value: F) -> f64
+    where
+        F: Fn(&WorkItem) -> Option<f64>,
+    {
+        std::iter::once(id)
+            .chain(self.descendants(id))
+            .filter_map(|id| self.work_item(id))
+            .filter_map(&value)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn links(value: serde_json::Value) -> Vec<WorkItemLink> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn work_item(id: i32, remaining_work: f64) -> WorkItem {
+        serde_json::from_value(json!({
+            "id": id,
+            "url": format!("https://dev.azure.com/org/_apis/wit/workItems/{id}"),
+            "fields": { "Microsoft.VSTS.Scheduling.RemainingWork": remaining_work }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tree() {
+        let links = links(json!([
+            { "target": { "id": 1 } },
+            { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 2 } },
+            { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 2 }, "target": { "id": 4 } },
+            { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 3 } },
+            { "target": { "id": 5 } }
+        ]));
+        let mut tree = WorkItemTree::from_links(&links).unwrap();
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.roots(), &[1, 5]);
+        assert_eq!(tree.children(1), &[2, 3]);
+        assert_eq!(tree.parent(4), Some(2));
+        assert_eq!(
+            tree.parent_rel(4),
+            Some("System.LinkTypes.Hierarchy-Forward")
+        );
+        assert_eq!(tree.ancestors(4), vec![2, 1]);
+        assert_eq!(tree.depth(4), 2);
+        assert_eq!(tree.descendants(1), vec![2, 4, 3]);
+        assert_eq!(tree.ids(), vec![1, 2, 4, 3, 5]);
+
+        tree.set_work_items((1..=4).map(|id| work_item(id, id.into())));
+        let remaining_work = |work_item: &WorkItem| work_item.typed_fields().remaining_work();
+        assert_eq!(tree.rollup(1, remaining_work), 10.0);
+        assert_eq!(tree.rollup(2, remaining_work), 6.0);
+        assert_eq!(tree.rollup(5, remaining_work), 0.0);
+    }
+
+    #[test]
+    fn test_invalid_links() {
+        let cycle = links(json!([
+            { "source": { "id": 1 }, "target": { "id": 2 } },
+            { "source": { "id": 2 }, "target": { "id": 3 } },
+            { "source": { "id": 3 }, "target": { "id": 1 } }
+        ]));
+        assert!(WorkItemTree::from_links(&cycle).is_err());
+
+        let two_parents = links(json!([
+            { "source": { "id": 1 }, "target": { "id": 3 } },
+            { "source": { "id": 2 }, "target": { "id": 3 } }
+        ]));
+        assert!(WorkItemTree::from_links(&two_parents).is_err());
+    }
+}
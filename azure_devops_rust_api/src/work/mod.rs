@@ -11,6 +11,7 @@ pub struct Client {
     credential: crate::Credential,
     scopes: Vec<String>,
     pipeline: azure_core::Pipeline,
+    api_version: Option<String>,
 }
 #[derive(Clone)]
 pub struct ClientBuilder {
@@ -18,6 +19,8 @@ pub struct ClientBuilder {
     endpoint: Option<String>,
     scopes: Option<Vec<String>>,
     options: azure_core::ClientOptions,
+    deployment: crate::Deployment,
+    api_version: Option<String>,
 }
 pub const DEFAULT_ENDPOINT: &str = "https://dev.azure.com";
 impl ClientBuilder {
@@ -29,6 +32,8 @@ impl ClientBuilder {
             endpoint: None,
             scopes: None,
             options: azure_core::ClientOptions::default(),
+            deployment: crate::Deployment::default(),
+            api_version: None,
         }
     }
     #[doc = "Set the endpoint."]
@@ -37,6 +42,23 @@ impl ClientBuilder {
         self.endpoint = Some(endpoint.into());
         self
     }
+    #[doc = "Set the deployment model, i.e. Azure DevOps Services (cloud) or Azure DevOps Server (on-premises)."]
+    #[doc = ""]
+    #[doc = "The deployment model determines the default endpoint. An endpoint set via `endpoint()` takes precedence."]
+    #[must_use]
+    pub fn deployment(mut self, deployment: crate::Deployment) -> Self {
+        self.deployment = deployment;
+        self
+    }
+    #[doc = "Set the API version, overriding the API version of each operation."]
+    #[doc = ""]
+    #[doc = "This is typically required for Azure DevOps Server releases that do not support the latest API version, e.g. `7.0` for Azure DevOps Server 2022."]
+    #[doc = "The `-preview` suffix (and resource version, e.g. `-preview.2`) of preview operations is retained."]
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
     #[doc = "Set the scopes."]
     #[must_use]
     pub fn scopes(mut self, scopes: &[&str]) -> Self {
@@ -67,9 +89,13 @@ impl ClientBuilder {
     #[doc = "Convert the builder into a `Client` instance."]
     #[must_use]
     pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| self.deployment.endpoint(DEFAULT_ENDPOINT));
         let scopes = self.scopes.unwrap_or_else(|| vec![format!("{endpoint}/")]);
-        Client::new(endpoint, self.credential, scopes, self.options)
+        let mut client = Client::new(endpoint, self.credential, scopes, self.options);
+        client.api_version = self.api_version;
+        client
     }
 }
 impl Client {
@@ -83,6 +109,9 @@ impl Client {
     pub(crate) fn scopes(&self) -> Vec<&str> {
         self.scopes.iter().map(String::as_str).collect()
     }
+    pub(crate) fn api_version(&self, default: &str) -> String {
+        crate::deployment::api_version(self.api_version.as_deref(), default)
+    }
     pub(crate) async fn send(
         &self,
         request: &mut azure_core::Request,
@@ -95,6 +124,22 @@ impl Client {
     pub fn builder(credential: crate::Credential) -> ClientBuilder {
         ClientBuilder::new(credential)
     }
+    #[doc = "Create a new `Client` that sends requests via an existing pipeline."]
+    pub(crate) fn with_pipeline(
+        endpoint: String,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+        api_version: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+            api_version,
+        }
+    }
     #[doc = "Create a new `Client`."]
     #[must_use]
     pub fn new(
@@ -108,14 +153,38 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![std::sync::Arc::new(crate::error::AdoErrorPolicy)],
+            vec![std::sync::Arc::new(crate::error::AdoErrorBodyPolicy)],
         );
         Self {
             endpoint,
             credential,
             scopes,
             pipeline,
+            api_version: None,
+        }
+    }
+    #[doc = "Returns a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take an `organization` parameter."]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
+    #[doc = "Returns a client scoped to a project."]
+    #[doc = ""]
+    #[doc = "Operations of the scoped client do not take `organization` or `project` parameters."]
+    pub fn for_project(
+        &self,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> ProjectClient {
+        ProjectClient {
+            client: self.clone(),
+            organization: organization.into(),
+            project: project.into(),
         }
     }
     pub fn backlogconfiguration_client(&self) -> backlogconfiguration::Client {
@@ -194,6 +263,360 @@ impl Client {
         workitemsorder::Client(self.clone())
     }
 }
+#[doc = "A [`Client`] scoped to an organization, created by [`Client::for_organization()`]."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    pub fn backlogconfiguration_client(&self) -> backlogconfiguration::OrganizationClient {
+        backlogconfiguration::OrganizationClient {
+            client: self.client.backlogconfiguration_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn backlogs_client(&self) -> backlogs::OrganizationClient {
+        backlogs::OrganizationClient {
+            client: self.client.backlogs_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn boardcolumns_client(&self) -> boardcolumns::OrganizationClient {
+        boardcolumns::OrganizationClient {
+            client: self.client.boardcolumns_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn boardparents_client(&self) -> boardparents::OrganizationClient {
+        boardparents::OrganizationClient {
+            client: self.client.boardparents_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn boardrows_client(&self) -> boardrows::OrganizationClient {
+        boardrows::OrganizationClient {
+            client: self.client.boardrows_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn boards_client(&self) -> boards::OrganizationClient {
+        boards::OrganizationClient {
+            client: self.client.boards_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn boardusersettings_client(&self) -> boardusersettings::OrganizationClient {
+        boardusersettings::OrganizationClient {
+            client: self.client.boardusersettings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn capacities_client(&self) -> capacities::OrganizationClient {
+        capacities::OrganizationClient {
+            client: self.client.capacities_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn cardrulesettings_client(&self) -> cardrulesettings::OrganizationClient {
+        cardrulesettings::OrganizationClient {
+            client: self.client.cardrulesettings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn cardsettings_client(&self) -> cardsettings::OrganizationClient {
+        cardsettings::OrganizationClient {
+            client: self.client.cardsettings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn chartimages_client(&self) -> chartimages::OrganizationClient {
+        chartimages::OrganizationClient {
+            client: self.client.chartimages_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn charts_client(&self) -> charts::OrganizationClient {
+        charts::OrganizationClient {
+            client: self.client.charts_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn columns_client(&self) -> columns::OrganizationClient {
+        columns::OrganizationClient {
+            client: self.client.columns_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn deliverytimeline_client(&self) -> deliverytimeline::OrganizationClient {
+        deliverytimeline::OrganizationClient {
+            client: self.client.deliverytimeline_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn iterationcapacities_client(&self) -> iterationcapacities::OrganizationClient {
+        iterationcapacities::OrganizationClient {
+            client: self.client.iterationcapacities_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn iterations_client(&self) -> iterations::OrganizationClient {
+        iterations::OrganizationClient {
+            client: self.client.iterations_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn plans_client(&self) -> plans::OrganizationClient {
+        plans::OrganizationClient {
+            client: self.client.plans_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn processconfiguration_client(&self) -> processconfiguration::OrganizationClient {
+        processconfiguration::OrganizationClient {
+            client: self.client.processconfiguration_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn rows_client(&self) -> rows::OrganizationClient {
+        rows::OrganizationClient {
+            client: self.client.rows_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn taskboard_columns_client(&self) -> taskboard_columns::OrganizationClient {
+        taskboard_columns::OrganizationClient {
+            client: self.client.taskboard_columns_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn taskboard_work_items_client(&self) -> taskboard_work_items::OrganizationClient {
+        taskboard_work_items::OrganizationClient {
+            client: self.client.taskboard_work_items_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn teamdaysoff_client(&self) -> teamdaysoff::OrganizationClient {
+        teamdaysoff::OrganizationClient {
+            client: self.client.teamdaysoff_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn teamfieldvalues_client(&self) -> teamfieldvalues::OrganizationClient {
+        teamfieldvalues::OrganizationClient {
+            client: self.client.teamfieldvalues_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn teamsettings_client(&self) -> teamsettings::OrganizationClient {
+        teamsettings::OrganizationClient {
+            client: self.client.teamsettings_client(),
+            organization: self.organization.clone(),
+        }
+    }
+    pub fn workitemsorder_client(&self) -> workitemsorder::OrganizationClient {
+        workitemsorder::OrganizationClient {
+            client: self.client.workitemsorder_client(),
+            organization: self.organization.clone(),
+        }
+    }
+}
+#[doc = "A [`Client`] scoped to a project, created by [`Client::for_project()`]."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization of the scoped client."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project of the scoped client."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn backlogconfiguration_client(&self) -> backlogconfiguration::ProjectClient {
+        backlogconfiguration::ProjectClient {
+            client: self.client.backlogconfiguration_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn backlogs_client(&self) -> backlogs::ProjectClient {
+        backlogs::ProjectClient {
+            client: self.client.backlogs_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn boardcolumns_client(&self) -> boardcolumns::ProjectClient {
+        boardcolumns::ProjectClient {
+            client: self.client.boardcolumns_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn boardparents_client(&self) -> boardparents::ProjectClient {
+        boardparents::ProjectClient {
+            client: self.client.boardparents_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn boardrows_client(&self) -> boardrows::ProjectClient {
+        boardrows::ProjectClient {
+            client: self.client.boardrows_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn boards_client(&self) -> boards::ProjectClient {
+        boards::ProjectClient {
+            client: self.client.boards_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn boardusersettings_client(&self) -> boardusersettings::ProjectClient {
+        boardusersettings::ProjectClient {
+            client: self.client.boardusersettings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn capacities_client(&self) -> capacities::ProjectClient {
+        capacities::ProjectClient {
+            client: self.client.capacities_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn cardrulesettings_client(&self) -> cardrulesettings::ProjectClient {
+        cardrulesettings::ProjectClient {
+            client: self.client.cardrulesettings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn cardsettings_client(&self) -> cardsettings::ProjectClient {
+        cardsettings::ProjectClient {
+            client: self.client.cardsettings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn chartimages_client(&self) -> chartimages::ProjectClient {
+        chartimages::ProjectClient {
+            client: self.client.chartimages_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn charts_client(&self) -> charts::ProjectClient {
+        charts::ProjectClient {
+            client: self.client.charts_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn columns_client(&self) -> columns::ProjectClient {
+        columns::ProjectClient {
+            client: self.client.columns_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn deliverytimeline_client(&self) -> deliverytimeline::ProjectClient {
+        deliverytimeline::ProjectClient {
+            client: self.client.deliverytimeline_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn iterationcapacities_client(&self) -> iterationcapacities::ProjectClient {
+        iterationcapacities::ProjectClient {
+            client: self.client.iterationcapacities_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn iterations_client(&self) -> iterations::ProjectClient {
+        iterations::ProjectClient {
+            client: self.client.iterations_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn plans_client(&self) -> plans::ProjectClient {
+        plans::ProjectClient {
+            client: self.client.plans_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn processconfiguration_client(&self) -> processconfiguration::ProjectClient {
+        processconfiguration::ProjectClient {
+            client: self.client.processconfiguration_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn rows_client(&self) -> rows::ProjectClient {
+        rows::ProjectClient {
+            client: self.client.rows_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn taskboard_columns_client(&self) -> taskboard_columns::ProjectClient {
+        taskboard_columns::ProjectClient {
+            client: self.client.taskboard_columns_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn taskboard_work_items_client(&self) -> taskboard_work_items::ProjectClient {
+        taskboard_work_items::ProjectClient {
+            client: self.client.taskboard_work_items_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn teamdaysoff_client(&self) -> teamdaysoff::ProjectClient {
+        teamdaysoff::ProjectClient {
+            client: self.client.teamdaysoff_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn teamfieldvalues_client(&self) -> teamfieldvalues::ProjectClient {
+        teamfieldvalues::ProjectClient {
+            client: self.client.teamfieldvalues_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn teamsettings_client(&self) -> teamsettings::ProjectClient {
+        teamsettings::ProjectClient {
+            client: self.client.teamsettings_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+    pub fn workitemsorder_client(&self) -> workitemsorder::ProjectClient {
+        workitemsorder::ProjectClient {
+            client: self.client.workitemsorder_client(),
+            organization: self.organization.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
 pub mod boardcolumns {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -215,6 +638,35 @@ pub mod boardcolumns {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get available board columns in a project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get available board columns in a project"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -295,9 +747,10 @@ pub mod boardcolumns {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -343,6 +796,35 @@ pub mod boardrows {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get available board rows in a project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get available board rows in a project"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -423,9 +905,10 @@ pub mod boardrows {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -474,6 +957,47 @@ pub mod iterationcapacities {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get an iteration's capacity for all teams in iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, iteration_id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get an iteration's capacity for all teams in iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        pub fn get(&self, iteration_id: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                iteration_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -556,9 +1080,10 @@ pub mod iterationcapacities {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -681,6 +1206,123 @@ pub mod plans {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get the information for all the plans configured for the given team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project)
+        }
+        #[doc = "Add a new plan for the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePlan>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            self.client.create(self.organization.clone(), body, project)
+        }
+        #[doc = "Get the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, id)
+        }
+        #[doc = "Update the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdatePlan>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, id)
+        }
+        #[doc = "Delete the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client.delete(self.organization.clone(), project, id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get the information for all the plans configured for the given team"]
+        pub fn list(&self) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone())
+        }
+        #[doc = "Add a new plan for the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition"]
+        pub fn create(&self, body: impl Into<models::CreatePlan>) -> create::RequestBuilder {
+            self.client
+                .create(self.organization.clone(), body, self.project.clone())
+        }
+        #[doc = "Get the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), id)
+        }
+        #[doc = "Update the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition to be updated"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdatePlan>,
+            id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), id)
+        }
+        #[doc = "Delete the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn delete(&self, id: impl Into<String>) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -760,9 +1402,10 @@ pub mod plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -864,9 +1507,10 @@ pub mod plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -969,9 +1613,10 @@ pub mod plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1074,9 +1719,10 @@ pub mod plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -1147,9 +1793,10 @@ pub mod plans {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1186,6 +1833,43 @@ pub mod deliverytimeline {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get Delivery View Data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier for delivery view"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get Delivery View Data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier for delivery view"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), id)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1286,9 +1970,10 @@ pub mod deliverytimeline {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(revision) = &this.revision {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1350,6 +2035,35 @@ pub mod processconfiguration {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get process configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get process configuration"]
+        pub fn get(&self) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1430,9 +2144,10 @@ pub mod processconfiguration {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1481,6 +2196,43 @@ pub mod backlogconfiguration {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Gets backlog configuration for a team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Gets backlog configuration for a team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), team)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1563,9 +2315,10 @@ pub mod backlogconfiguration {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1657,6 +2410,105 @@ pub mod backlogs {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "List all backlog levels"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Get a list of work items within a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_backlog_level_work_items(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            backlog_id: impl Into<String>,
+        ) -> get_backlog_level_work_items::RequestBuilder {
+            self.client.get_backlog_level_work_items(
+                self.organization.clone(),
+                project,
+                team,
+                backlog_id,
+            )
+        }
+        #[doc = "Get a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `id`: The id of the backlog level"]
+        pub fn get_backlog(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get_backlog::RequestBuilder {
+            self.client
+                .get_backlog(self.organization.clone(), project, team, id)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "List all backlog levels"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Get a list of work items within a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_backlog_level_work_items(
+            &self,
+            team: impl Into<String>,
+            backlog_id: impl Into<String>,
+        ) -> get_backlog_level_work_items::RequestBuilder {
+            self.client.get_backlog_level_work_items(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                backlog_id,
+            )
+        }
+        #[doc = "Get a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `id`: The id of the backlog level"]
+        pub fn get_backlog(
+            &self,
+            team: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get_backlog::RequestBuilder {
+            self.client
+                .get_backlog(self.organization.clone(), self.project.clone(), team, id)
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -1741,9 +2593,10 @@ pub mod backlogs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1851,9 +2704,10 @@ pub mod backlogs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1961,9 +2815,10 @@ pub mod backlogs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2059,6 +2914,104 @@ pub mod boards {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get boards"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Get board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, id, team)
+        }
+        #[doc = "Update board options"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: options to updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: identifier for board, either category plural name (Eg:\"Stories\") or guid"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn set_board_options(
+            &self,
+            body: impl Into<serde_json::Value>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> set_board_options::RequestBuilder {
+            self.client
+                .set_board_options(self.organization.clone(), body, project, id, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get boards"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Get board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), id, team)
+        }
+        #[doc = "Update board options"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: options to updated"]
+        #[doc = "* `id`: identifier for board, either category plural name (Eg:\"Stories\") or guid"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn set_board_options(
+            &self,
+            body: impl Into<serde_json::Value>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> set_board_options::RequestBuilder {
+            self.client.set_board_options(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                id,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2141,9 +3094,10 @@ pub mod boards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2248,9 +3202,10 @@ pub mod boards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2356,9 +3311,10 @@ pub mod boards {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2432,6 +3388,84 @@ pub mod boardusersettings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get board user settings for a board id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Board ID or Name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Update board user settings for the board id\n\nWe don't want stakeholders to update board settings (currently just autorefresh). The BacklogManagement feature check validates this."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<serde_json::Value>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, board, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get board user settings for a board id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Board ID or Name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), board, team)
+        }
+        #[doc = "Update board user settings for the board id\n\nWe don't want stakeholders to update board settings (currently just autorefresh). The BacklogManagement feature check validates this."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<serde_json::Value>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                board,
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -2516,9 +3550,10 @@ pub mod boardusersettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2625,9 +3660,10 @@ pub mod boardusersettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2650,75 +3686,190 @@ pub mod boardusersettings {
             }
         }
     }
-}
-pub mod cardrulesettings {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod cardrulesettings {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                board: board.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Update board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_rule_settings(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_rule_settings::RequestBuilder {
+            update_board_card_rule_settings::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                board: board.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Update taskboard card Rule settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_rule_settings(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_rule_settings::RequestBuilder {
+            update_taskboard_card_rule_settings::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                team: team.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Update board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_rule_settings::RequestBuilder {
+            self.client.update_board_card_rule_settings(
+                self.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card Rule settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_rule_settings::RequestBuilder {
+            self.client.update_taskboard_card_rule_settings(
+                self.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Get board card Rule settings for the board id or board by name"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             board: impl Into<String>,
             team: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                board: board.into(),
-                team: team.into(),
-            }
+            self.client
+                .get(self.organization.clone(), self.project.clone(), board, team)
         }
         #[doc = "Update board card Rule settings for the board id or board by name"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn update_board_card_rule_settings(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::BoardCardRuleSettings>,
-            project: impl Into<String>,
             board: impl Into<String>,
             team: impl Into<String>,
         ) -> update_board_card_rule_settings::RequestBuilder {
-            update_board_card_rule_settings::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                board: board.into(),
-                team: team.into(),
-            }
+            self.client.update_board_card_rule_settings(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                board,
+                team,
+            )
         }
         #[doc = "Update taskboard card Rule settings"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn update_taskboard_card_rule_settings(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::BoardCardRuleSettings>,
-            project: impl Into<String>,
             team: impl Into<String>,
         ) -> update_taskboard_card_rule_settings::RequestBuilder {
-            update_taskboard_card_rule_settings::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                team: team.into(),
-            }
+            self.client.update_taskboard_card_rule_settings(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+            )
         }
     }
     pub mod get {
@@ -2805,9 +3956,10 @@ pub mod cardrulesettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2916,9 +4068,10 @@ pub mod cardrulesettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -2993,9 +4146,10 @@ pub mod cardrulesettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3076,6 +4230,121 @@ pub mod cardsettings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Update board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_settings::RequestBuilder {
+            self.client.update_board_card_settings(
+                self.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_settings::RequestBuilder {
+            self.client.update_taskboard_card_settings(
+                self.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), board, team)
+        }
+        #[doc = "Update board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_settings::RequestBuilder {
+            self.client.update_board_card_settings(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_settings::RequestBuilder {
+            self.client.update_taskboard_card_settings(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3160,9 +4429,10 @@ pub mod cardsettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3269,9 +4539,10 @@ pub mod cardsettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3344,9 +4615,10 @@ pub mod cardsettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -3447,6 +4719,134 @@ pub mod chartimages {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a board chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (e.g. \"Issues\") or Id."]
+        #[doc = "* `name`: The chart name. e.g. CumulativeFlow."]
+        pub fn get_board_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_board_chart_image::RequestBuilder {
+            self.client
+                .get_board_chart_image(self.organization.clone(), project, team, board, name)
+        }
+        #[doc = "Get an iteration chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: ID of the iteration."]
+        #[doc = "* `name`: The chart name. e.g. Burndown."]
+        pub fn get_iteration_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iteration_chart_image::RequestBuilder {
+            self.client.get_iteration_chart_image(
+                self.organization.clone(),
+                project,
+                team,
+                iteration_id,
+                name,
+            )
+        }
+        #[doc = "Get an iterations chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `name`: The chart name. e.g. Velocity."]
+        pub fn get_iterations_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iterations_chart_image::RequestBuilder {
+            self.client
+                .get_iterations_chart_image(self.organization.clone(), project, team, name)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a board chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (e.g. \"Issues\") or Id."]
+        #[doc = "* `name`: The chart name. e.g. CumulativeFlow."]
+        pub fn get_board_chart_image(
+            &self,
+            team: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_board_chart_image::RequestBuilder {
+            self.client.get_board_chart_image(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                board,
+                name,
+            )
+        }
+        #[doc = "Get an iteration chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: ID of the iteration."]
+        #[doc = "* `name`: The chart name. e.g. Burndown."]
+        pub fn get_iteration_chart_image(
+            &self,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iteration_chart_image::RequestBuilder {
+            self.client.get_iteration_chart_image(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                iteration_id,
+                name,
+            )
+        }
+        #[doc = "Get an iterations chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `name`: The chart name. e.g. Velocity."]
+        pub fn get_iterations_chart_image(
+            &self,
+            team: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iterations_chart_image::RequestBuilder {
+            self.client.get_iterations_chart_image(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                name,
+            )
+        }
+    }
     pub mod get_board_chart_image {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -3471,6 +4871,25 @@ pub mod chartimages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3556,9 +4975,10 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(width) = &this.width {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3618,8 +5038,27 @@ pub mod chartimages {
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
             }
         }
         impl From<Response> for azure_core::Response {
@@ -3706,9 +5145,10 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(width) = &this.width {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3771,6 +5211,25 @@ pub mod chartimages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "Returns the raw response body."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                crate::body::into_text(self.0.into_body()).await
+            }
+            #[doc = "Returns the response body as a stream of `Bytes` chunks, without buffering the whole body in memory."]
+            pub fn into_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::body::write_to(self.0.into_body(), writer).await
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3860,9 +5319,10 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(iterations_number) = &this.iterations_number {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -3986,6 +5446,128 @@ pub mod charts {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get board charts"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Get a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, board, name, team)
+        }
+        #[doc = "Update a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::BoardChart>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, board, name, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get board charts"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), board, team)
+        }
+        #[doc = "Get a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                board,
+                name,
+                team,
+            )
+        }
+        #[doc = "Update a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::BoardChart>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                board,
+                name,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4070,9 +5652,10 @@ pub mod charts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4181,9 +5764,10 @@ pub mod charts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4291,9 +5875,10 @@ pub mod charts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4369,6 +5954,88 @@ pub mod columns {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Update columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board columns to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardColumn>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, board, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), board, team)
+        }
+        #[doc = "Update columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board columns to update"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardColumn>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                board,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4453,9 +6120,10 @@ pub mod columns {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4562,9 +6230,10 @@ pub mod columns {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4587,57 +6256,139 @@ pub mod columns {
             }
         }
     }
-}
-pub mod rows {
-    use super::models;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod rows {
+    use super::models;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                board: board.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Update rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: List of board rows to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::BoardRow>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                board: board.into(),
+                team: team.into(),
+            }
+        }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, board, team)
+        }
+        #[doc = "Update rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board rows to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardRow>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, board, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
         #[doc = "Get rows on a board"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `board`: Name or ID of the specific board"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             board: impl Into<String>,
             team: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                board: board.into(),
-                team: team.into(),
-            }
+            self.client
+                .list(self.organization.clone(), self.project.clone(), board, team)
         }
         #[doc = "Update rows on a board"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: List of board rows to update"]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `board`: Name or ID of the specific board"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::BoardRow>,
-            project: impl Into<String>,
             board: impl Into<String>,
             team: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            self.client.update(
+                self.organization.clone(),
                 body,
-                project: project.into(),
-                board: board.into(),
-                team: team.into(),
-            }
+                self.project.clone(),
+                board,
+                team,
+            )
         }
     }
     pub mod list {
@@ -4723,9 +6474,10 @@ pub mod rows {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4831,9 +6583,10 @@ pub mod rows {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -4886,6 +6639,61 @@ pub mod boardparents {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Returns the list of parent field filter model for the given list of workitem ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            child_backlog_context_category_ref_name: impl Into<String>,
+            workitem_ids: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                project,
+                child_backlog_context_category_ref_name,
+                workitem_ids,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Returns the list of parent field filter model for the given list of workitem ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            child_backlog_context_category_ref_name: impl Into<String>,
+            workitem_ids: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                child_backlog_context_category_ref_name,
+                workitem_ids,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -4970,9 +6778,10 @@ pub mod boardparents {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let child_backlog_context_category_ref_name =
                             &this.child_backlog_context_category_ref_name;
                         req.url_mut().query_pairs_mut().append_pair(
@@ -5057,6 +6866,93 @@ pub mod workitemsorder {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Reorder Sprint Backlog/Taskboard Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: The id of the iteration"]
+        pub fn reorder_iteration_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> reorder_iteration_work_items::RequestBuilder {
+            self.client.reorder_iteration_work_items(
+                self.organization.clone(),
+                body,
+                project,
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Reorder Product Backlog/Boards Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn reorder_backlog_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> reorder_backlog_work_items::RequestBuilder {
+            self.client
+                .reorder_backlog_work_items(self.organization.clone(), body, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Reorder Sprint Backlog/Taskboard Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: The id of the iteration"]
+        pub fn reorder_iteration_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> reorder_iteration_work_items::RequestBuilder {
+            self.client.reorder_iteration_work_items(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Reorder Product Backlog/Boards Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn reorder_backlog_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            team: impl Into<String>,
+        ) -> reorder_backlog_work_items::RequestBuilder {
+            self.client.reorder_backlog_work_items(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod reorder_iteration_work_items {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5142,9 +7038,10 @@ pub mod workitemsorder {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5250,9 +7147,10 @@ pub mod workitemsorder {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5319,6 +7217,65 @@ pub mod taskboard_columns {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, team)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::UpdateTaskboardColumn>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::UpdateTaskboardColumn>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), team)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5401,9 +7358,10 @@ pub mod taskboard_columns {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5508,9 +7466,10 @@ pub mod taskboard_columns {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5583,6 +7542,91 @@ pub mod taskboard_work_items {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), project, team, iteration_id)
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateTaskboardWorkItemColumn>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            work_item_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                team,
+                iteration_id,
+                work_item_id,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(
+                self.organization.clone(),
+                self.project.clone(),
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateTaskboardWorkItemColumn>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            work_item_id: i32,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+                iteration_id,
+                work_item_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5669,9 +7713,10 @@ pub mod taskboard_work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5749,9 +7794,10 @@ pub mod taskboard_work_items {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -5808,6 +7854,71 @@ pub mod teamsettings {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, team)
+        }
+        #[doc = "Update a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TeamSettings changes"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsPatch>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Update a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TeamSettings changes"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsPatch>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), team)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -5889,9 +8000,10 @@ pub mod teamsettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5995,9 +8107,10 @@ pub mod teamsettings {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6134,6 +8247,163 @@ pub mod iterations {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a team's iterations using timeframe filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            self.client.list(self.organization.clone(), project, team)
+        }
+        #[doc = "Add an iteration to the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Iteration to add"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn post_team_iteration(
+            &self,
+            body: impl Into<models::TeamSettingsIteration>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> post_team_iteration::RequestBuilder {
+            self.client
+                .post_team_iteration(self.organization.clone(), body, project, team)
+        }
+        #[doc = "Get team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, id, team)
+        }
+        #[doc = "Delete a team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), project, id, team)
+        }
+        #[doc = "Get work items for iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_iteration_work_items(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_iteration_work_items::RequestBuilder {
+            self.client.get_iteration_work_items(
+                self.organization.clone(),
+                project,
+                iteration_id,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a team's iterations using timeframe filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            self.client
+                .list(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Add an iteration to the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Iteration to add"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn post_team_iteration(
+            &self,
+            body: impl Into<models::TeamSettingsIteration>,
+            team: impl Into<String>,
+        ) -> post_team_iteration::RequestBuilder {
+            self.client.post_team_iteration(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), id, team)
+        }
+        #[doc = "Delete a team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            self.client
+                .delete(self.organization.clone(), self.project.clone(), id, team)
+        }
+        #[doc = "Get work items for iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_iteration_work_items(
+            &self,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_iteration_work_items::RequestBuilder {
+            self.client.get_iteration_work_items(
+                self.organization.clone(),
+                self.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -6222,9 +8492,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         if let Some(timeframe) = &this.timeframe {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -6336,9 +8607,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -6447,9 +8719,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6524,9 +8797,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6619,9 +8893,10 @@ pub mod iterations {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6655,75 +8930,173 @@ pub mod capacities {
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `iteration_id`: ID of the iteration"]
         #[doc = "* `team`: Team ID or team name"]
-        pub fn get_capacities_with_identity_ref_and_totals(
+        pub fn get_capacities_with_identity_ref_and_totals(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
+            get_capacities_with_identity_ref_and_totals::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                iteration_id: iteration_id.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Replace a team's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Team capacity to replace"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_capacities_with_identity_ref(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::TeamMemberCapacityIdentityRef>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_capacities_with_identity_ref::RequestBuilder {
+            replace_capacities_with_identity_ref::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                iteration_id: iteration_id.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Get a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacity_with_identity_ref(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacity_with_identity_ref::RequestBuilder {
+            get_capacity_with_identity_ref::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                iteration_id: iteration_id.into(),
+                team_member_id: team_member_id.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Update a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Updated capacity"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
             &self,
             organization: impl Into<String>,
+            body: impl Into<models::CapacityPatch>,
             project: impl Into<String>,
             iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
             team: impl Into<String>,
-        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
-            get_capacities_with_identity_ref_and_totals::RequestBuilder {
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
+                body: body.into(),
                 project: project.into(),
                 iteration_id: iteration_id.into(),
+                team_member_id: team_member_id.into(),
                 team: team.into(),
             }
         }
+    }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a team's capacity including total capacity and days off"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacities_with_identity_ref_and_totals(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
+            self.client.get_capacities_with_identity_ref_and_totals(
+                self.organization.clone(),
+                project,
+                iteration_id,
+                team,
+            )
+        }
         #[doc = "Replace a team's capacity"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Team capacity to replace"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `iteration_id`: ID of the iteration"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn replace_capacities_with_identity_ref(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::TeamMemberCapacityIdentityRef>,
             project: impl Into<String>,
             iteration_id: impl Into<String>,
             team: impl Into<String>,
         ) -> replace_capacities_with_identity_ref::RequestBuilder {
-            replace_capacities_with_identity_ref::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            self.client.replace_capacities_with_identity_ref(
+                self.organization.clone(),
                 body,
-                project: project.into(),
-                iteration_id: iteration_id.into(),
-                team: team.into(),
-            }
+                project,
+                iteration_id,
+                team,
+            )
         }
         #[doc = "Get a team member's capacity"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `iteration_id`: ID of the iteration"]
         #[doc = "* `team_member_id`: ID of the team member"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn get_capacity_with_identity_ref(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             iteration_id: impl Into<String>,
             team_member_id: impl Into<String>,
             team: impl Into<String>,
         ) -> get_capacity_with_identity_ref::RequestBuilder {
-            get_capacity_with_identity_ref::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                iteration_id: iteration_id.into(),
-                team_member_id: team_member_id.into(),
-                team: team.into(),
-            }
+            self.client.get_capacity_with_identity_ref(
+                self.organization.clone(),
+                project,
+                iteration_id,
+                team_member_id,
+                team,
+            )
         }
         #[doc = "Update a team member's capacity"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Updated capacity"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `iteration_id`: ID of the iteration"]
@@ -6731,22 +9104,109 @@ pub mod capacities {
         #[doc = "* `team`: Team ID or team name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::CapacityPatch>,
             project: impl Into<String>,
             iteration_id: impl Into<String>,
             team_member_id: impl Into<String>,
             team: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                iteration_id: iteration_id.into(),
-                team_member_id: team_member_id.into(),
-                team: team.into(),
-            }
+            self.client.update(
+                self.organization.clone(),
+                body,
+                project,
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a team's capacity including total capacity and days off"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacities_with_identity_ref_and_totals(
+            &self,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
+            self.client.get_capacities_with_identity_ref_and_totals(
+                self.organization.clone(),
+                self.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Replace a team's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team capacity to replace"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_capacities_with_identity_ref(
+            &self,
+            body: Vec<models::TeamMemberCapacityIdentityRef>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_capacities_with_identity_ref::RequestBuilder {
+            self.client.replace_capacities_with_identity_ref(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Get a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacity_with_identity_ref(
+            &self,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacity_with_identity_ref::RequestBuilder {
+            self.client.get_capacity_with_identity_ref(
+                self.organization.clone(),
+                self.project.clone(),
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+        #[doc = "Update a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated capacity"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::CapacityPatch>,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                iteration_id,
+                team_member_id,
+                team,
+            )
         }
     }
     pub mod get_capacities_with_identity_ref_and_totals {
@@ -6832,9 +9292,10 @@ pub mod capacities {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6943,9 +9404,10 @@ pub mod capacities {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7058,9 +9520,10 @@ pub mod capacities {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7173,9 +9636,10 @@ pub mod capacities {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7253,6 +9717,92 @@ pub mod teamdaysoff {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), project, iteration_id, team)
+        }
+        #[doc = "Set a team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team's days off patch containing a list of start and end dates"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsDaysOffPatch>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, iteration_id, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(
+                self.organization.clone(),
+                self.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Set a team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team's days off patch containing a list of start and end dates"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsDaysOffPatch>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client.update(
+                self.organization.clone(),
+                body,
+                self.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7337,9 +9887,10 @@ pub mod teamdaysoff {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7448,9 +9999,10 @@ pub mod teamdaysoff {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -7521,6 +10073,69 @@ pub mod teamfieldvalues {
             }
         }
     }
+    #[doc = "A [`Client`] scoped to an organization."]
+    #[allow(dead_code)]
+    pub struct OrganizationClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+    }
+    impl OrganizationClient {
+        #[doc = "Get a collection of team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            self.client.get(self.organization.clone(), project, team)
+        }
+        #[doc = "Update team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamFieldValuesPatch>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, project, team)
+        }
+    }
+    #[doc = "A [`Client`] scoped to a project."]
+    #[allow(dead_code)]
+    pub struct ProjectClient {
+        pub(crate) client: Client,
+        pub(crate) organization: String,
+        pub(crate) project: String,
+    }
+    impl ProjectClient {
+        #[doc = "Get a collection of team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            self.client
+                .get(self.organization.clone(), self.project.clone(), team)
+        }
+        #[doc = "Update team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamFieldValuesPatch>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            self.client
+                .update(self.organization.clone(), body, self.project.clone(), team)
+        }
+    }
     pub mod get {
         use super::models;
         pub struct Response(azure_core::Response);
@@ -7603,9 +10218,10 @@ pub mod teamfieldvalues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7710,9 +10326,10 @@ pub mod teamfieldvalues {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                        req.url_mut().query_pairs_mut().append_pair(
+                            azure_core::query_param::API_VERSION,
+                            &this.client.api_version("7.1-preview"),
+                        );
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
@@ -68,11 +68,436 @@ const SPEC_DESCRIPTIONS: &[(&str, &str)] = &[
     ("workItemTracking.json", "Work item tracking"),
 ];
 
+// An enum definition for a query parameter that accepts a fixed set of values.
+struct QueryParameterEnum {
+    name: &'static str,
+    description: &'static str,
+    // Flags can be combined by passing a comma-separated list of values.
+    flags: bool,
+    values: &'static [(&'static str, &'static str)],
+}
+
+const QUERY_PARAMETER_ENUMS: &[QueryParameterEnum] = &[
+    QueryParameterEnum {
+        name: "PullRequestStatus",
+        description: "Pull request status",
+        flags: false,
+        values: &[
+            ("notSet", "Status not set. Default state."),
+            ("active", "Pull request is active."),
+            ("abandoned", "Pull request is abandoned."),
+            ("completed", "Pull request is completed."),
+            (
+                "all",
+                "Used in pull request search criteria to include all statuses.",
+            ),
+        ],
+    },
+    QueryParameterEnum {
+        name: "BuildStatus",
+        description: "Build status",
+        flags: false,
+        values: &[
+            ("none", "No status."),
+            ("inProgress", "The build is currently in progress."),
+            ("completed", "The build has completed."),
+            ("cancelling", "The build is cancelling"),
+            ("postponed", "The build is inactive in the queue."),
+            ("notStarted", "The build has not yet started."),
+            ("all", "All status."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "BuildResult",
+        description: "Build result",
+        flags: true,
+        values: &[
+            ("none", "No result"),
+            ("succeeded", "The build completed successfully."),
+            (
+                "partiallySucceeded",
+                "The build completed compilation successfully but had other errors.",
+            ),
+            ("failed", "The build completed unsuccessfully."),
+            ("canceled", "The build was canceled before starting."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "BuildReason",
+        description: "Build reason",
+        flags: true,
+        values: &[
+            ("none", "No reason. This value should not be used."),
+            ("manual", "The build was started manually."),
+            (
+                "individualCI",
+                "The build was started for the trigger TriggerType.ContinuousIntegration.",
+            ),
+            (
+                "batchedCI",
+                "The build was started for the trigger TriggerType.BatchedContinuousIntegration.",
+            ),
+            (
+                "schedule",
+                "The build was started for the trigger TriggerType.Schedule.",
+            ),
+            (
+                "scheduleForced",
+                "The build was started for the trigger TriggerType.ScheduleForced.",
+            ),
+            ("userCreated", "The build was created by a user."),
+            (
+                "validateShelveset",
+                "The build was started manually for private validation.",
+            ),
+            (
+                "checkInShelveset",
+                "The build was started for the trigger ContinuousIntegrationType.Gated.",
+            ),
+            ("pullRequest", "The build was started by a pull request."),
+            (
+                "buildCompletion",
+                "The build was started when another build completed.",
+            ),
+            (
+                "resourceTrigger",
+                "The build was started when resources in pipeline triggered it",
+            ),
+            (
+                "triggered",
+                "The build was triggered for retention policy purposes.",
+            ),
+            ("all", "All reasons."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "BuildQueryOrder",
+        description: "Build query order",
+        flags: false,
+        values: &[
+            ("finishTimeAscending", "Order by finish time ascending."),
+            ("finishTimeDescending", "Order by finish time descending."),
+            ("queueTimeDescending", "Order by queue time descending."),
+            ("queueTimeAscending", "Order by queue time ascending."),
+            ("startTimeDescending", "Order by start time descending."),
+            ("startTimeAscending", "Order by start time ascending."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "QueryDeletedOption",
+        description: "Whether to include deleted items in query results",
+        flags: false,
+        values: &[
+            ("excludeDeleted", "Include only non-deleted builds."),
+            ("includeDeleted", "Include deleted and non-deleted builds."),
+            ("onlyDeleted", "Include only deleted builds."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "QueryExpand",
+        description: "Properties to include in query results",
+        flags: false,
+        values: &[
+            ("none", "Expands Columns, Links and ChangeInfo"),
+            ("wiql", "Expands Columns, Links, ChangeInfo and WIQL text"),
+            (
+                "clauses",
+                "Expands Columns, Links, ChangeInfo, WIQL text and clauses",
+            ),
+            ("all", "Expands all properties"),
+            ("minimal", "Displays minimal properties and the WIQL text"),
+        ],
+    },
+    QueryParameterEnum {
+        name: "CommentExpandOptions",
+        description: "Additional data to include in work item comments",
+        flags: true,
+        values: &[
+            ("none", "No additional data."),
+            ("reactions", "Include comment reactions."),
+            (
+                "renderedText",
+                "Include the rendered text (html) in addition to MD text.",
+            ),
+            (
+                "renderedTextOnly",
+                "If specified, then ONLY rendered text (html) will be returned, w/o markdown.",
+            ),
+            ("all", "All additional data."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "GetFieldsExpand",
+        description: "Additional fields to include in the list of work item fields",
+        flags: true,
+        values: &[
+            ("none", "No additional fields."),
+            ("extensionFields", "Include extension fields."),
+            ("includeDeleted", "Include deleted fields."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "ReportingRevisionsExpand",
+        description: "Properties to include in reporting work item revisions",
+        flags: false,
+        values: &[
+            ("none", "Default behavior."),
+            ("fields", "Add fields to the response."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "WorkItemExpand",
+        description: "Work item attributes to include in the response",
+        flags: false,
+        values: &[
+            ("none", "Default behavior."),
+            ("relations", "Relations work item expand."),
+            ("fields", "Fields work item expand."),
+            ("links", "Links work item expand."),
+            ("all", "Expands all."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "WorkItemTypeFieldsExpandLevel",
+        description: "Additional properties to include in work item type fields",
+        flags: true,
+        values: &[
+            ("none", "Includes only basic properties of the field."),
+            ("allowedValues", "Includes allowed values for the field."),
+            ("dependentFields", "Includes dependent fields of the field."),
+            (
+                "all",
+                "Includes allowed values and dependent fields of the field.",
+            ),
+        ],
+    },
+    QueryParameterEnum {
+        name: "SingleReleaseExpands",
+        description: "Properties to include in a release",
+        flags: false,
+        values: &[
+            ("none", "Return top level properties of object."),
+            ("tasks", "Expand release with tasks."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "ReleaseExpands",
+        description: "Properties to include in a list of releases",
+        flags: true,
+        values: &[
+            ("none", "Return top level properties of object."),
+            ("environments", "Include environments in the releases."),
+            ("artifacts", "Include artifacts in the releases."),
+            ("approvals", "Include approvals in the releases."),
+            (
+                "manualInterventions",
+                "Include manual interventions in the releases.",
+            ),
+            ("variables", "Include variables in the releases."),
+            ("tags", "Include tags in the releases."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "ReleaseEnvironmentExpands",
+        description: "Properties to include in a release environment",
+        flags: false,
+        values: &[
+            ("none", "Return top level properties of object."),
+            ("tasks", "Expand environment with tasks."),
+        ],
+    },
+    QueryParameterEnum {
+        name: "ReleaseDefinitionExpands",
+        description: "Properties to include in a list of release definitions",
+        flags: true,
+        values: &[
+            ("none", "Returns top level properties of object."),
+            (
+                "environments",
+                "Include environments in the release definitions.",
+            ),
+            ("artifacts", "Include artifacts in the release definitions."),
+            ("triggers", "Include triggers in the release definitions."),
+            ("variables", "Include variables in the release definitions."),
+            ("tags", "Include tags in the release definitions."),
+            (
+                "lastRelease",
+                "Include the last release in the release definitions.",
+            ),
+        ],
+    },
+];
+
+// Query parameters that accept a fixed set of values, as (spec, operation id, parameter, enum).
+// The spec documents these parameters as strings.
+const QUERY_PARAMETER_ENUM_PATCHES: &[(&str, &str, &str, &str)] = &[
+    (
+        "git.json",
+        "Pull Requests_Get Pull Requests",
+        "searchCriteria.status",
+        "PullRequestStatus",
+    ),
+    (
+        "git.json",
+        "Pull Requests_Get Pull Requests By Project",
+        "searchCriteria.status",
+        "PullRequestStatus",
+    ),
+    ("build.json", "Builds_List", "statusFilter", "BuildStatus"),
+    ("build.json", "Builds_List", "resultFilter", "BuildResult"),
+    ("build.json", "Builds_List", "reasonFilter", "BuildReason"),
+    ("build.json", "Builds_List", "queryOrder", "BuildQueryOrder"),
+    (
+        "build.json",
+        "Builds_List",
+        "deletedFilter",
+        "QueryDeletedOption",
+    ),
+    (
+        "workItemTracking.json",
+        "Queries_Search Queries",
+        "$expand",
+        "QueryExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Queries_List",
+        "$expand",
+        "QueryExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Queries_Get",
+        "$expand",
+        "QueryExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Comments_Get Comments",
+        "$expand",
+        "CommentExpandOptions",
+    ),
+    (
+        "workItemTracking.json",
+        "Comments_Get Comments Batch",
+        "$expand",
+        "CommentExpandOptions",
+    ),
+    (
+        "workItemTracking.json",
+        "Comments_Get Comment",
+        "$expand",
+        "CommentExpandOptions",
+    ),
+    (
+        "workItemTracking.json",
+        "Fields_List",
+        "$expand",
+        "GetFieldsExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Reporting Work Item Revisions_Read Reporting Revisions Get",
+        "$expand",
+        "ReportingRevisionsExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Reporting Work Item Revisions_Read Reporting Revisions Post",
+        "$expand",
+        "ReportingRevisionsExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Items_List",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Items_Get Work Item Template",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Items_Create",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Items_Get Work Item",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Items_Update",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Revisions_List",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Revisions_Get",
+        "$expand",
+        "WorkItemExpand",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Item Types Field_List",
+        "$expand",
+        "WorkItemTypeFieldsExpandLevel",
+    ),
+    (
+        "workItemTracking.json",
+        "Work Item Types Field_Get",
+        "$expand",
+        "WorkItemTypeFieldsExpandLevel",
+    ),
+    (
+        "release.json",
+        "Releases_Get Release",
+        "$expand",
+        "SingleReleaseExpands",
+    ),
+    ("release.json", "Releases_List", "$expand", "ReleaseExpands"),
+    (
+        "release.json",
+        "Releases_Get Release Environment",
+        "$expand",
+        "ReleaseEnvironmentExpands",
+    ),
+    (
+        "release.json",
+        "Definitions_List",
+        "$expand",
+        "ReleaseDefinitionExpands",
+    ),
+];
+
 struct Patcher {
     spec_path: PathBuf,
     new_definitions: BTreeMap<String, JsonValue>,
 }
 
+// Return true if the operation ids are equal, ignoring case and whitespace
+fn operation_id_eq(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    normalize(a) == normalize(b)
+}
+
 // Return true if the specified `entry` appears to be a an OpenAPI specification filename
 fn is_spec(entry: &DirEntry) -> bool {
     let path = entry.path().to_string_lossy().to_string();
@@ -161,6 +586,7 @@ impl Patcher {
         Patcher::patch_git_policy_configuration,
         Patcher::patch_git_pull_request_create,
         Patcher::patch_git_pull_request_update,
+        Patcher::patch_git_pull_request_conflicts,
         Patcher::patch_ims_identity_base,
         Patcher::patch_input_validation_min_max,
         Patcher::patch_probation_retries_type,
@@ -168,7 +594,9 @@ impl Patcher {
         Patcher::patch_extension_flags,
         Patcher::patch_wit_create_update_item,
         Patcher::patch_wit_identity_reference,
+        Patcher::patch_wit_reporting_work_item_revisions_batch,
         Patcher::patch_wiki_pages_update,
+        Patcher::patch_query_parameter_enums,
         // This must be done after the other patches
         Patcher::patch_definition_required_fields,
     ];
@@ -871,45 +1299,20 @@ impl Patcher {
                           "status": {
                             "description": "The status of the pull request.",
                                 "$ref": "#/definitions/PullRequestStatus",
+                          },
+                          "autoCompleteSetBy": {
+                            "description": "The identity that enabled auto-complete. Set the ID to all zeros to cancel auto-complete.",
+                                "$ref": "#/definitions/IdentityId"
+                          },
+                          "completionOptions": {
+                            "description": "Options which affect how the pull request will be merged when it is completed.",
+                                "$ref": "#/definitions/GitPullRequestCompletionOptions"
                           }
                         }
                     },
                 );
 
-                self.new_definitions.insert(
-                    "PullRequestStatus".to_string(),
-                    json::object! {
-                            "description": "Pull request status",
-                                "enum": [
-                                    "notSet",
-                                    "active",
-                                    "abandoned",
-                                    "completed",
-                                  ],
-                                  "x-ms-enum": {
-                                    "name": "PullRequestStatus",
-                                    "values": [
-                                      {
-                                        "value": "notSet",
-                                        "description": "Status not set. Default state."
-                                      },
-                                      {
-                                        "value": "active",
-                                        "description": "Pull request is active."
-                                      },
-                                      {
-                                        "value": "abandoned",
-                                        "description": "Pull request is abandoned."
-                                      },
-                                      {
-                                        "value": "completed",
-                                        "description": "Pull request is completed."
-                                      }
-                                    ]
-
-                        },
-                    },
-                );
+                self.add_query_parameter_enum("PullRequestStatus");
 
                 Some(json::array![
                     {
@@ -974,6 +1377,215 @@ impl Patcher {
         }
     }
 
+    // The git spec defines the `GitConflict` family of definitions, but not the Pull Request
+    // Conflicts operations that return them. This adds the operations to list, get and resolve
+    // the merge conflicts of a pull request.
+    fn patch_git_pull_request_conflicts(
+        &mut self,
+        key: &[&str],
+        value: &JsonValue,
+    ) -> Option<JsonValue> {
+        // Only applies to git specs
+        if !self.spec_path.ends_with("git.json") {
+            return None;
+        }
+        const CONFLICTS_PATH: &str = "/{organization}/{project}/_apis/git/repositories/{repositoryId}/pullRequests/{pullRequestId}/conflicts";
+        const CONFLICT_PATH: &str = "/{organization}/{project}/_apis/git/repositories/{repositoryId}/pullRequests/{pullRequestId}/conflicts/{conflictId}";
+        match key {
+            ["paths"] if !value.has_key(CONFLICTS_PATH) => {
+                println!("Add git Pull Request Conflicts operations");
+                let organization = json::object! {
+                    "in": "path",
+                    "name": "organization",
+                    "description": "The name of the Azure DevOps organization.",
+                    "required": true,
+                    "type": "string"
+                };
+                let repository_id = json::object! {
+                    "in": "path",
+                    "name": "repositoryId",
+                    "description": "The repository ID of the pull request's target branch.",
+                    "required": true,
+                    "type": "string"
+                };
+                let pull_request_id = json::object! {
+                    "in": "path",
+                    "name": "pullRequestId",
+                    "description": "ID of the pull request.",
+                    "required": true,
+                    "type": "integer",
+                    "format": "int32"
+                };
+                let conflict_id = json::object! {
+                    "in": "path",
+                    "name": "conflictId",
+                    "description": "ID of the conflict.",
+                    "required": true,
+                    "type": "integer",
+                    "format": "int32"
+                };
+                let project = json::object! {
+                    "in": "path",
+                    "name": "project",
+                    "description": "Project ID or project name",
+                    "required": true,
+                    "type": "string"
+                };
+                let api_version = json::object! {
+                    "$ref": "#/parameters/api-Version-preview.1"
+                };
+
+                let mut value = value.clone();
+                value[CONFLICTS_PATH] = json::object! {
+                    "get": {
+                        "tags": [ "Pull Request Conflicts" ],
+                        "operationId": "Pull Request Conflicts_List",
+                        "description": "Retrieve all conflicts for a pull request",
+                        "produces": [ "application/json" ],
+                        "parameters": [
+                            organization.clone(),
+                            repository_id.clone(),
+                            pull_request_id.clone(),
+                            project.clone(),
+                            {
+                                "in": "query",
+                                "name": "$skip",
+                                "description": "Conflicts to skip.",
+                                "required": false,
+                                "type": "integer",
+                                "format": "int32"
+                            },
+                            {
+                                "in": "query",
+                                "name": "$top",
+                                "description": "Conflicts to return after skip.",
+                                "required": false,
+                                "type": "integer",
+                                "format": "int32"
+                            },
+                            {
+                                "in": "query",
+                                "name": "includeObsolete",
+                                "description": "Includes obsolete conflicts.",
+                                "required": false,
+                                "type": "boolean"
+                            },
+                            {
+                                "in": "query",
+                                "name": "excludeResolved",
+                                "description": "Excludes conflicts already resolved.",
+                                "required": false,
+                                "type": "boolean"
+                            },
+                            {
+                                "in": "query",
+                                "name": "onlyResolved",
+                                "description": "Returns only the conflicts that are resolved.",
+                                "required": false,
+                                "type": "boolean"
+                            },
+                            api_version.clone()
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "successful operation",
+                                "schema": {
+                                    "type": "array",
+                                    "items": { "$ref": "#/definitions/GitConflict" }
+                                }
+                            }
+                        }
+                    },
+                    "patch": {
+                        "tags": [ "Pull Request Conflicts" ],
+                        "operationId": "Pull Request Conflicts_Update Conflicts",
+                        "description": "Update multiple merge conflict resolutions.",
+                        "consumes": [ "application/json" ],
+                        "produces": [ "application/json" ],
+                        "parameters": [
+                            organization.clone(),
+                            {
+                                "in": "body",
+                                "name": "body",
+                                "description": "An array of conflicts, each with the resolution to apply.",
+                                "required": true,
+                                "schema": {
+                                    "type": "array",
+                                    "items": { "$ref": "#/definitions/GitConflict" }
+                                }
+                            },
+                            repository_id.clone(),
+                            pull_request_id.clone(),
+                            project.clone(),
+                            api_version.clone()
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "successful operation",
+                                "schema": {
+                                    "type": "array",
+                                    "items": { "$ref": "#/definitions/GitConflictUpdateResult" }
+                                }
+                            }
+                        }
+                    }
+                };
+                value[CONFLICT_PATH] = json::object! {
+                    "get": {
+                        "tags": [ "Pull Request Conflicts" ],
+                        "operationId": "Pull Request Conflicts_Get",
+                        "description": "Retrieve one conflict for a pull request by ID",
+                        "produces": [ "application/json" ],
+                        "parameters": [
+                            organization.clone(),
+                            repository_id.clone(),
+                            pull_request_id.clone(),
+                            conflict_id.clone(),
+                            project.clone(),
+                            api_version.clone()
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "successful operation",
+                                "schema": { "$ref": "#/definitions/GitConflict" }
+                            }
+                        }
+                    },
+                    "patch": {
+                        "tags": [ "Pull Request Conflicts" ],
+                        "operationId": "Pull Request Conflicts_Update",
+                        "description": "Update merge conflict resolution",
+                        "consumes": [ "application/json" ],
+                        "produces": [ "application/json" ],
+                        "parameters": [
+                            organization,
+                            {
+                                "in": "body",
+                                "name": "body",
+                                "description": "The conflict, with the resolution to apply.",
+                                "required": true,
+                                "schema": { "$ref": "#/definitions/GitConflict" }
+                            },
+                            repository_id,
+                            pull_request_id,
+                            conflict_id,
+                            project,
+                            api_version
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "successful operation",
+                                "schema": { "$ref": "#/definitions/GitConflict" }
+                            }
+                        }
+                    }
+                };
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
     fn patch_build_reference_links(
         &mut self,
         key: &[&str],
@@ -1051,6 +1663,94 @@ impl Patcher {
         }
     }
 
+    // Several query parameters accept a fixed set of values, but are declared as strings.
+    // This patch replaces the type of the parameters in QUERY_PARAMETER_ENUM_PATCHES with a
+    // reference to a new enum definition. Parameters that accept flags are replaced with an array
+    // of the enum, which is passed as a comma-separated list.
+    fn patch_query_parameter_enums(
+        &mut self,
+        key: &[&str],
+        value: &JsonValue,
+    ) -> Option<JsonValue> {
+        match key {
+            ["paths", _path, _verb] => {
+                let operation_id = value["operationId"].as_str()?;
+                let patches: Vec<_> = QUERY_PARAMETER_ENUM_PATCHES
+                    .iter()
+                    .filter(|(spec, id, _, _)| {
+                        self.spec_path.ends_with(spec) && operation_id_eq(id, operation_id)
+                    })
+                    .collect();
+                if patches.is_empty() {
+                    return None;
+                }
+                let mut value = value.clone();
+                for param in value["parameters"].members_mut() {
+                    if param["in"] != "query" {
+                        continue;
+                    }
+                    let patch = patches
+                        .iter()
+                        .find(|(_, _, name, _)| param["name"] == *name);
+                    if let Some((_, _, name, enum_name)) = patch {
+                        println!("Replace {operation_id} {name} parameter type with {enum_name}");
+                        let query_parameter_enum = self.add_query_parameter_enum(enum_name);
+                        let enum_ref = json::object! {
+                            "$ref": format!("#/definitions/{enum_name}")
+                        };
+                        param.remove("enum");
+                        param.remove("x-ms-enum");
+                        if query_parameter_enum.flags {
+                            param["type"] = "array".into();
+                            param["items"] = enum_ref;
+                            param["collectionFormat"] = "csv".into();
+                        } else {
+                            param.remove("type");
+                            param["schema"] = enum_ref;
+                        }
+                    }
+                }
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    // Adds the definition of an enum from QUERY_PARAMETER_ENUMS.
+    // The enums are marked `modelAsString` so that values added by the service are accepted
+    // (as `UnknownValue`) and can be passed to operations that predate the enum definition.
+    fn add_query_parameter_enum(&mut self, name: &str) -> &'static QueryParameterEnum {
+        let query_parameter_enum = QUERY_PARAMETER_ENUMS
+            .iter()
+            .find(|e| e.name == name)
+            .unwrap_or_else(|| panic!("Unknown query parameter enum {name}"));
+        let mut values = JsonValue::new_array();
+        let mut ms_enum_values = JsonValue::new_array();
+        for (value, description) in query_parameter_enum.values {
+            values.push(*value).unwrap();
+            ms_enum_values
+                .push(json::object! {
+                    "value": *value,
+                    "description": *description,
+                })
+                .unwrap();
+        }
+        self.new_definitions.insert(
+            name.to_string(),
+            json::object! {
+                "description": query_parameter_enum.description,
+                "type": "string",
+                "enum": values,
+                "x-ms-enum": {
+                    "name": name,
+                    "modelAsString": true,
+                    "values": ms_enum_values,
+                },
+            },
+        );
+        query_parameter_enum
+    }
+
     fn patch_probation_retries_type(
         &mut self,
         key: &[&str],
@@ -1831,6 +2531,50 @@ impl Patcher {
         }
     }
 
+    /// Patch the WorkItemTracking reporting revisions batch
+    ///
+    /// `ReportingWorkItemRevisionsBatch` is defined as a `StreamedBatch`, whose `values` are strings,
+    /// but the reporting revisions APIs return work item revisions. This replaces the definition
+    /// with one whose `values` are `WorkItem`s.
+    fn patch_wit_reporting_work_item_revisions_batch(
+        &mut self,
+        key: &[&str],
+        _value: &JsonValue,
+    ) -> Option<JsonValue> {
+        if !self.spec_path.ends_with("workItemTracking.json") {
+            return None;
+        }
+
+        match key {
+            ["definitions", "ReportingWorkItemRevisionsBatch"] => Some(json::object!(
+                "description": "The class describes reporting work item revision batch.",
+                "type": "object",
+                "properties": {
+                    "continuationToken": {
+                        "description": "ContinuationToken acts as a waterMark. Used while querying large results.",
+                        "type": "string"
+                    },
+                    "isLastBatch": {
+                        "description": "Returns 'true' if it's last batch, 'false' otherwise.",
+                        "type": "boolean"
+                    },
+                    "nextLink": {
+                        "description": "The next link for the work item.",
+                        "type": "string"
+                    },
+                    "values": {
+                        "description": "The work item revisions in the batch.",
+                        "type": "array",
+                        "items": {
+                            "$ref": "#/definitions/WorkItem"
+                        }
+                    }
+                }
+            )),
+            _ => None,
+        }
+    }
+
     /// Patch Wiki Pages
     ///
     /// To update a Wiki Page an `If-Match` header must be supplied with an `eTag` (page version)